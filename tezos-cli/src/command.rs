@@ -0,0 +1,395 @@
+use std::{collections::HashMap, env, fs};
+
+use tezos_rpc::client::TezosRpc;
+
+use crate::{
+    offline,
+    online::{self, Account},
+    Error, Result,
+};
+
+pub const USAGE: &str = "Usage:
+    tezos forge <operation>
+    tezos unforge <bytes>
+    tezos pack <value> [--schema <type>]
+    tezos unpack <bytes> [--schema <type>]
+    tezos hash-expr <value> [--schema <type>]
+    tezos sign <bytes> [--secret-key <key>]
+    tezos verify <bytes> <signature> <public key>
+    tezos address <public key>
+    tezos micheline-to-bytes <value>
+    tezos transfer <destination> <amount> --from <address> [--secret-key <key>] [--rpc <url>]
+    tezos call <contract> <entrypoint> [<arguments>] --from <address> [--secret-key <key>] [--amount <amount>] [--rpc <url>]
+    tezos storage <contract> [--rpc <url>]
+    tezos big-map-get <contract> <big map name or index> <key> [--rpc <url>]
+
+Operations, values and types are JSON encoded (Micheline for values and types), and can be read
+from a file with `@<path>`. Amounts are in mutez.
+
+The secret key and the node URL default to the TEZOS_SECRET_KEY and TEZOS_RPC_URL environment variables.";
+
+const SECRET_KEY_VARIABLE: &str = "TEZOS_SECRET_KEY";
+const RPC_URL_VARIABLE: &str = "TEZOS_RPC_URL";
+
+/// A `tezos` command, parsed from the command line arguments.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    Forge {
+        operation: String,
+    },
+    Unforge {
+        bytes: String,
+    },
+    Pack {
+        value: String,
+        schema: Option<String>,
+    },
+    Unpack {
+        bytes: String,
+        schema: Option<String>,
+    },
+    HashExpression {
+        value: String,
+        schema: Option<String>,
+    },
+    Sign {
+        bytes: String,
+        secret_key: String,
+    },
+    Verify {
+        bytes: String,
+        signature: String,
+        public_key: String,
+    },
+    Address {
+        public_key: String,
+    },
+    MichelineToBytes {
+        value: String,
+    },
+    Transfer {
+        rpc_url: String,
+        from: String,
+        secret_key: String,
+        destination: String,
+        amount: String,
+    },
+    Call {
+        rpc_url: String,
+        from: String,
+        secret_key: String,
+        contract: String,
+        entrypoint: String,
+        arguments: String,
+        amount: Option<String>,
+    },
+    Storage {
+        rpc_url: String,
+        contract: String,
+    },
+    BigMapGet {
+        rpc_url: String,
+        contract: String,
+        big_map: String,
+        key: String,
+    },
+}
+
+impl Command {
+    /// Parses the command line arguments, without the program name.
+    pub fn parse<I: IntoIterator<Item = String>>(args: I) -> Result<Self> {
+        let mut args = Arguments::parse(args)?;
+        let name = args.positional(0)?;
+        let command = match name.as_str() {
+            "forge" => Self::Forge {
+                operation: args.content(1)?,
+            },
+            "unforge" => Self::Unforge {
+                bytes: args.positional(1)?,
+            },
+            "pack" => Self::Pack {
+                value: args.content(1)?,
+                schema: args.optional_content("schema")?,
+            },
+            "unpack" => Self::Unpack {
+                bytes: args.positional(1)?,
+                schema: args.optional_content("schema")?,
+            },
+            "hash-expr" => Self::HashExpression {
+                value: args.content(1)?,
+                schema: args.optional_content("schema")?,
+            },
+            "sign" => Self::Sign {
+                bytes: args.positional(1)?,
+                secret_key: args.option_or_env("secret-key", SECRET_KEY_VARIABLE)?,
+            },
+            "verify" => Self::Verify {
+                bytes: args.positional(1)?,
+                signature: args.positional(2)?,
+                public_key: args.positional(3)?,
+            },
+            "address" => Self::Address {
+                public_key: args.positional(1)?,
+            },
+            "micheline-to-bytes" => Self::MichelineToBytes {
+                value: args.content(1)?,
+            },
+            "transfer" => Self::Transfer {
+                rpc_url: args.option_or_env("rpc", RPC_URL_VARIABLE)?,
+                from: args.option("from")?,
+                secret_key: args.option_or_env("secret-key", SECRET_KEY_VARIABLE)?,
+                destination: args.positional(1)?,
+                amount: args.positional(2)?,
+            },
+            "call" => Self::Call {
+                rpc_url: args.option_or_env("rpc", RPC_URL_VARIABLE)?,
+                from: args.option("from")?,
+                secret_key: args.option_or_env("secret-key", SECRET_KEY_VARIABLE)?,
+                contract: args.positional(1)?,
+                entrypoint: args.positional(2)?,
+                arguments: args
+                    .optional_positional(3)
+                    .map(|value| read_content(&value))
+                    .transpose()?
+                    .unwrap_or_else(|| "null".into()),
+                amount: args.options.remove("amount"),
+            },
+            "storage" => Self::Storage {
+                rpc_url: args.option_or_env("rpc", RPC_URL_VARIABLE)?,
+                contract: args.positional(1)?,
+            },
+            "big-map-get" => Self::BigMapGet {
+                rpc_url: args.option_or_env("rpc", RPC_URL_VARIABLE)?,
+                contract: args.positional(1)?,
+                big_map: args.positional(2)?,
+                key: args.content(3)?,
+            },
+            _ => {
+                return Err(Error::Usage {
+                    description: format!("unknown command `{}`", name),
+                })
+            }
+        };
+        args.finish()?;
+
+        Ok(command)
+    }
+
+    /// Runs the command and returns its output.
+    pub async fn run(self) -> Result<String> {
+        match self {
+            Self::Forge { operation } => offline::forge(&operation),
+            Self::Unforge { bytes } => offline::unforge(&bytes),
+            Self::Pack { value, schema } => offline::pack(&value, schema.as_deref()),
+            Self::Unpack { bytes, schema } => offline::unpack(&bytes, schema.as_deref()),
+            Self::HashExpression { value, schema } => {
+                offline::hash_expression(&value, schema.as_deref())
+            }
+            Self::Sign { bytes, secret_key } => offline::sign(&bytes, &secret_key),
+            Self::Verify {
+                bytes,
+                signature,
+                public_key,
+            } => offline::verify(&bytes, &signature, &public_key).map(|valid| valid.to_string()),
+            Self::Address { public_key } => offline::address(&public_key),
+            Self::MichelineToBytes { value } => offline::micheline_to_bytes(&value),
+            Self::Transfer {
+                rpc_url,
+                from,
+                secret_key,
+                destination,
+                amount,
+            } => {
+                let rpc = TezosRpc::new(rpc_url);
+                let account = Account::new(&from, &secret_key)?;
+                online::transfer(&rpc, &account, &destination, &amount).await
+            }
+            Self::Call {
+                rpc_url,
+                from,
+                secret_key,
+                contract,
+                entrypoint,
+                arguments,
+                amount,
+            } => {
+                let rpc = TezosRpc::new(rpc_url);
+                let account = Account::new(&from, &secret_key)?;
+                online::call(
+                    &rpc,
+                    &account,
+                    &contract,
+                    &entrypoint,
+                    &arguments,
+                    amount.as_deref(),
+                )
+                .await
+            }
+            Self::Storage { rpc_url, contract } => {
+                online::get_storage(&TezosRpc::new(rpc_url), &contract).await
+            }
+            Self::BigMapGet {
+                rpc_url,
+                contract,
+                big_map,
+                key,
+            } => {
+                online::get_big_map_value(&TezosRpc::new(rpc_url), &contract, &big_map, &key).await
+            }
+        }
+    }
+}
+
+struct Arguments {
+    positional: Vec<String>,
+    options: HashMap<String, String>,
+}
+
+impl Arguments {
+    fn parse<I: IntoIterator<Item = String>>(args: I) -> Result<Self> {
+        let mut positional = vec![];
+        let mut options = HashMap::new();
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            match arg.strip_prefix("--") {
+                Some(name) => {
+                    let value = args.next().ok_or_else(|| Error::Usage {
+                        description: format!("missing value for `--{}`", name),
+                    })?;
+                    options.insert(name.to_owned(), value);
+                }
+                None => positional.push(arg),
+            }
+        }
+
+        Ok(Self {
+            positional,
+            options,
+        })
+    }
+
+    fn optional_positional(&self, index: usize) -> Option<String> {
+        self.positional.get(index).cloned()
+    }
+
+    fn positional(&self, index: usize) -> Result<String> {
+        self.optional_positional(index).ok_or_else(|| Error::Usage {
+            description: format!("missing argument {}", index),
+        })
+    }
+
+    fn content(&self, index: usize) -> Result<String> {
+        read_content(&self.positional(index)?)
+    }
+
+    fn option(&mut self, name: &str) -> Result<String> {
+        self.options.remove(name).ok_or_else(|| Error::Usage {
+            description: format!("missing option `--{}`", name),
+        })
+    }
+
+    fn optional_content(&mut self, name: &str) -> Result<Option<String>> {
+        self.options
+            .remove(name)
+            .map(|value| read_content(&value))
+            .transpose()
+    }
+
+    fn option_or_env(&mut self, name: &str, variable: &str) -> Result<String> {
+        self.option(name)
+            .or_else(|error| env::var(variable).map_err(|_| error))
+    }
+
+    fn finish(&self) -> Result<()> {
+        match self.options.keys().next() {
+            Some(name) => Err(Error::Usage {
+                description: format!("unknown option `--{}`", name),
+            }),
+            None => Ok(()),
+        }
+    }
+}
+
+fn read_content(value: &str) -> Result<String> {
+    match value.strip_prefix('@') {
+        Some(path) => Ok(fs::read_to_string(path)?),
+        None => Ok(value.to_owned()),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn args(value: &str) -> Vec<String> {
+        value.split(' ').map(|arg| arg.to_owned()).collect()
+    }
+
+    #[test]
+    fn test_parse() -> Result<()> {
+        assert_eq!(
+            Command::parse(args("pack {\"int\":\"1\"} --schema {\"prim\":\"nat\"}"))?,
+            Command::Pack {
+                value: "{\"int\":\"1\"}".into(),
+                schema: Some("{\"prim\":\"nat\"}".into()),
+            }
+        );
+        assert_eq!(
+            Command::parse(args("transfer tz1RFxSHjxxyaaXyAvJG3uRQeJUCCm4jk2LX 1000 --from tz1PwXjsrgYBi9wpe3tFhazJpt7JMTVzBp5c --secret-key edsk --rpc http://localhost:8732"))?,
+            Command::Transfer {
+                rpc_url: "http://localhost:8732".into(),
+                from: "tz1PwXjsrgYBi9wpe3tFhazJpt7JMTVzBp5c".into(),
+                secret_key: "edsk".into(),
+                destination: "tz1RFxSHjxxyaaXyAvJG3uRQeJUCCm4jk2LX".into(),
+                amount: "1000".into(),
+            }
+        );
+        assert_eq!(
+            Command::parse(args(
+                "call KT1 transfer --from tz1 --secret-key edsk --rpc http://localhost:8732"
+            ))?,
+            Command::Call {
+                rpc_url: "http://localhost:8732".into(),
+                from: "tz1".into(),
+                secret_key: "edsk".into(),
+                contract: "KT1".into(),
+                entrypoint: "transfer".into(),
+                arguments: "null".into(),
+                amount: None,
+            }
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!(matches!(
+            Command::parse(args("unknown")),
+            Err(Error::Usage { .. })
+        ));
+        assert!(matches!(
+            Command::parse(args("unforge")),
+            Err(Error::Usage { .. })
+        ));
+        assert!(matches!(
+            Command::parse(args("unforge 00 --schema")),
+            Err(Error::Usage { .. })
+        ));
+        assert!(matches!(
+            Command::parse(args("unforge 00 --unknown 1")),
+            Err(Error::Usage { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_run() -> Result<()> {
+        let output = Command::parse(args("hash-expr {\"int\":\"0\"}"))?
+            .run()
+            .await?;
+        assert_eq!(
+            output,
+            "exprtZBwZUeYYYfUs9B9Rg2ywHezVHnCCnmF9WsDQVrs582dSK63dC"
+        );
+        Ok(())
+    }
+}
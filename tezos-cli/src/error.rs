@@ -0,0 +1,39 @@
+use std::result;
+
+use derive_more::{Display, Error as DError, From};
+
+#[derive(DError, Display, Debug, From)]
+pub enum Error {
+    Core {
+        source: tezos_core::Error,
+    },
+    Michelson {
+        source: tezos_michelson::Error,
+    },
+    Operation {
+        source: tezos_operation::Error,
+    },
+    Rpc {
+        source: tezos_rpc::Error,
+    },
+    Contract {
+        source: tezos_contract::Error,
+    },
+    Json {
+        source: serde_json::Error,
+    },
+    Hex {
+        source: hex::FromHexError,
+    },
+    Io {
+        source: std::io::Error,
+    },
+    #[display(fmt = "{}", description)]
+    #[from(ignore)]
+    Usage {
+        description: String,
+    },
+    BigMapNotFound,
+}
+
+pub type Result<T> = result::Result<T, Error>;
@@ -0,0 +1,48 @@
+//! The `tezos-cli` crate provides the `tezos` command line tool, built on top of the other crates of the SDK.
+//!
+//! It comes with offline utilities:
+//!
+//! ```shell
+//! # forge an operation and read it back
+//! tezos forge @operation.json
+//! tezos unforge <bytes>
+//!
+//! # pack and unpack Micheline values, optionally following a type
+//! tezos pack '{ "string": "tz1RFxSHjxxyaaXyAvJG3uRQeJUCCm4jk2LX" }' --schema '{ "prim": "address" }'
+//! tezos unpack 050001
+//!
+//! # compute the script expression hash of a big map key
+//! tezos hash-expr '{ "int": "0" }' --schema '{ "prim": "nat" }'
+//!
+//! # sign and verify watermarked bytes
+//! tezos sign 05010000000548656c6c6f --secret-key <secret key>
+//! tezos verify 05010000000548656c6c6f <signature> <public key>
+//!
+//! # derive the address of a public key
+//! tezos address edpkttZKC51wemRqL2QxwpMnEKxWnbd35pq47Y6xsCHp5M1f7LN8NP
+//!
+//! # encode a Micheline value to binary
+//! tezos micheline-to-bytes '{ "prim": "Unit" }'
+//! ```
+//!
+//! and commands interacting with a node:
+//!
+//! ```shell
+//! export TEZOS_RPC_URL=https://ghostnet.smartpy.io
+//! export TEZOS_SECRET_KEY=<secret key>
+//!
+//! tezos transfer tz1RFxSHjxxyaaXyAvJG3uRQeJUCCm4jk2LX 1000 --from <address>
+//! tezos call KT1... transfer '[{ "from_": "tz1...", "txs": [] }]' --from <address>
+//! tezos storage KT1...
+//! tezos big-map-get KT1... ledger '{ "string": "tz1..." }'
+//! ```
+//!
+//! The same commands can be used as a library through [Command], or the functions of the [offline] and [online] modules.
+
+mod command;
+mod error;
+pub mod offline;
+pub mod online;
+
+pub use command::{Command, USAGE};
+pub use error::{Error, Result};
@@ -0,0 +1,19 @@
+use std::{env, process};
+
+use tezos_cli::{Command, USAGE};
+
+#[tokio::main(flavor = "current_thread")]
+async fn main() {
+    let command = Command::parse(env::args().skip(1)).unwrap_or_else(|error| {
+        eprintln!("{}\n\n{}", error, USAGE);
+        process::exit(1);
+    });
+
+    match command.run().await {
+        Ok(output) => println!("{}", output),
+        Err(error) => {
+            eprintln!("{}", error);
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,193 @@
+//! Commands that do not need a connection to a node.
+
+use tezos_core::{
+    hash,
+    types::encoded::{Encoded, PublicKey, SecretKey, Signature},
+};
+use tezos_michelson::micheline::Micheline;
+use tezos_operation::{
+    message,
+    operations::{Operation, SignedOperation, UnsignedOperation},
+};
+use tezos_rpc::models::operation::Operation as RpcOperation;
+
+use crate::Result;
+
+/// Forges the JSON encoded operation (as returned by the node, with `branch` and `contents`).
+///
+/// If the operation has a `signature`, it is appended to the forged bytes, which can then be injected.
+pub fn forge(operation: &str) -> Result<String> {
+    let operation: RpcOperation = serde_json::from_str(operation)?;
+    if operation.signature.is_some() {
+        let operation: SignedOperation = operation.try_into()?;
+        return Ok(operation.to_injectable_string()?);
+    }
+    let operation: UnsignedOperation = operation.try_into()?;
+
+    Ok(hex::encode(operation.to_forged_bytes()?))
+}
+
+/// Unforges the operation bytes (without signature) into its JSON representation.
+pub fn unforge(bytes: &str) -> Result<String> {
+    let operation = UnsignedOperation::from_forged_bytes(decode_hex(bytes)?)?;
+    let operation: RpcOperation = operation.into();
+
+    Ok(serde_json::to_string_pretty(&operation)?)
+}
+
+/// Packs the JSON encoded Micheline `value`, optionally following the JSON encoded Micheline `schema`.
+pub fn pack(value: &str, schema: Option<&str>) -> Result<String> {
+    Ok(hex::encode(packed(value, schema)?))
+}
+
+/// Unpacks the `bytes` into JSON encoded Micheline, optionally following the JSON encoded Micheline `schema`.
+pub fn unpack(bytes: &str, schema: Option<&str>) -> Result<String> {
+    let schema = schema.map(parse_micheline).transpose()?;
+    let value = Micheline::unpack(&decode_hex(bytes)?, schema.as_ref())?;
+
+    Ok(serde_json::to_string(&value)?)
+}
+
+/// Computes the script expression hash of the JSON encoded Micheline `value`, e.g. to look up a big map key.
+pub fn hash_expression(value: &str, schema: Option<&str>) -> Result<String> {
+    Ok(hash::script_expr_hash(&packed(value, schema)?)?.into_string())
+}
+
+/// Signs the `bytes` (watermark included) with the `secret_key`.
+pub fn sign(bytes: &str, secret_key: &str) -> Result<String> {
+    let secret_key: SecretKey = secret_key.try_into()?;
+    let signature = message::sign_bytes(&decode_hex(bytes)?, &secret_key)?;
+
+    Ok(signature.into_string())
+}
+
+/// Verifies the `signature` of the `bytes` (watermark included) with the `public_key`.
+pub fn verify(bytes: &str, signature: &str, public_key: &str) -> Result<bool> {
+    let signature: Signature = signature.try_into()?;
+    let public_key: PublicKey = public_key.try_into()?;
+
+    Ok(message::verify_bytes(
+        &decode_hex(bytes)?,
+        &signature,
+        &public_key,
+    )?)
+}
+
+/// Derives the implicit address of the `public_key`.
+pub fn address(public_key: &str) -> Result<String> {
+    let public_key: PublicKey = public_key.try_into()?;
+
+    Ok(hash::public_key_hash(&public_key)?.into_string())
+}
+
+/// Converts the JSON encoded Micheline `value` to its binary representation (without the pack prefix).
+pub fn micheline_to_bytes(value: &str) -> Result<String> {
+    Ok(hex::encode(parse_micheline(value)?.to_bytes()?))
+}
+
+pub(crate) fn parse_micheline(value: &str) -> Result<Micheline> {
+    Ok(serde_json::from_str(value)?)
+}
+
+fn packed(value: &str, schema: Option<&str>) -> Result<Vec<u8>> {
+    let schema = schema.map(parse_micheline).transpose()?;
+
+    Ok(parse_micheline(value)?.pack(schema.as_ref())?)
+}
+
+fn decode_hex(value: &str) -> Result<Vec<u8>> {
+    Ok(hex::decode(value.strip_prefix("0x").unwrap_or(value))?)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_forge_and_unforge() -> Result<()> {
+        let operation = r#"{
+            "branch": "BLjg4HU2BwnCgJfRutxJX5rHACzLDxRJes1MXqbXXdxvHWdK3Te",
+            "contents": [
+                {
+                    "kind": "transaction",
+                    "source": "tz1PwXjsrgYBi9wpe3tFhazJpt7JMTVzBp5c",
+                    "fee": "1000",
+                    "counter": "1",
+                    "gas_limit": "1500",
+                    "storage_limit": "0",
+                    "amount": "1000",
+                    "destination": "tz2AjVPbMHdDF1XwHVhUrTg6ZvqY83AYhJEy"
+                }
+            ]
+        }"#;
+
+        let forged = forge(operation)?;
+        let unforged: serde_json::Value = serde_json::from_str(&unforge(&forged)?)?;
+        let expected: serde_json::Value = serde_json::from_str(operation)?;
+        assert_eq!(unforged, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_pack_and_unpack() -> Result<()> {
+        let schema = r#"{ "prim": "address" }"#;
+        let value = r#"{"string":"tz1PwXjsrgYBi9wpe3tFhazJpt7JMTVzBp5c"}"#;
+
+        let packed = pack(value, Some(schema))?;
+        assert_eq!(
+            packed,
+            "050a0000001600002f2fd2798d6b000eabbbbc234648465aa2d762d9"
+        );
+        assert_eq!(unpack(&packed, Some(schema))?, value);
+        assert_eq!(pack(r#"{ "int": "1" }"#, None)?, "050001");
+        assert_eq!(unpack("0x050001", None)?, r#"{"int":"1"}"#);
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_expression() -> Result<()> {
+        assert_eq!(
+            hash_expression(r#"{ "int": "0" }"#, None)?,
+            "exprtZBwZUeYYYfUs9B9Rg2ywHezVHnCCnmF9WsDQVrs582dSK63dC"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_sign_and_verify() -> Result<()> {
+        let bytes = "0x05010000000548656c6c6f";
+        let signature = sign(bytes, "edskRv7VyXGVZb8EsrR7D9XKUbbAQNQGtALP6QeB16ZCD7SmmJpzyeneJVg3Mq56YLbxRA1kSdAXiswwPiaVfR3NHGMCXCziuZ")?;
+
+        assert!(verify(
+            bytes,
+            &signature,
+            "edpkttZKC51wemRqL2QxwpMnEKxWnbd35pq47Y6xsCHp5M1f7LN8NP"
+        )?);
+        assert!(!verify(
+            "050001",
+            &signature,
+            "edpkttZKC51wemRqL2QxwpMnEKxWnbd35pq47Y6xsCHp5M1f7LN8NP"
+        )?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_address() -> Result<()> {
+        assert_eq!(
+            address("edpkttZKC51wemRqL2QxwpMnEKxWnbd35pq47Y6xsCHp5M1f7LN8NP")?,
+            "tz1RFxSHjxxyaaXyAvJG3uRQeJUCCm4jk2LX"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_micheline_to_bytes() -> Result<()> {
+        assert_eq!(
+            micheline_to_bytes(
+                r#"{ "prim": "Pair", "args": [{ "int": "1" }, { "string": "a" }] }"#
+            )?,
+            "07070001010000000161"
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,217 @@
+//! Commands that need a connection to a node.
+
+use serde_json::Value;
+use tezos_contract::ContractFetcher;
+use tezos_core::types::{
+    encoded::{Address, ContractHash, Encoded, ImplicitAddress, SecretKey},
+    mutez::Mutez,
+    number::Nat,
+};
+use tezos_michelson::{micheline::Micheline, michelson::Michelson};
+use tezos_operation::operations::{OperationContent, Transaction, UnsignedOperation};
+use tezos_rpc::{client::TezosRpc, http::Http};
+
+use crate::{offline::parse_micheline, Error, Result};
+
+/// The implicit account sending the operations.
+pub struct Account {
+    pub address: ImplicitAddress,
+    pub secret_key: SecretKey,
+}
+
+impl Account {
+    pub fn new(address: &str, secret_key: &str) -> Result<Self> {
+        Ok(Self {
+            address: address.try_into()?,
+            secret_key: secret_key.try_into()?,
+        })
+    }
+}
+
+/// Transfers `amount` (in mutez) from `account` to `destination` and returns the injected operation hash.
+pub async fn transfer<HttpClient: Http + Send + Sync>(
+    rpc: &TezosRpc<HttpClient>,
+    account: &Account,
+    destination: &str,
+    amount: &str,
+) -> Result<String> {
+    let destination: Address = destination.try_into()?;
+    let amount: Mutez = amount.try_into()?;
+    let counter = next_counter(rpc, account).await?;
+    let transaction = Transaction::new(
+        account.address.clone(),
+        0u8.into(),
+        counter,
+        0u8.into(),
+        0u8.into(),
+        amount,
+        destination,
+        None,
+    );
+
+    inject(rpc, account, transaction.into()).await
+}
+
+/// Calls the `entrypoint` of `contract` with the JSON-like `arguments` (see [ContractCallBuilder](tezos_contract::ContractCallBuilder))
+/// and returns the injected operation hash.
+pub async fn call<HttpClient: Http + Send + Sync>(
+    rpc: &TezosRpc<HttpClient>,
+    account: &Account,
+    contract: &str,
+    entrypoint: &str,
+    arguments: &str,
+    amount: Option<&str>,
+) -> Result<String> {
+    let contract = rpc.contract_at(contract.try_into()?, None).await?;
+    let mut builder = contract
+        .call_builder(entrypoint.into())?
+        .args(serde_json::from_str::<Value>(arguments)?);
+    if let Some(amount) = amount {
+        builder = builder.amount(amount.try_into()?);
+    }
+    let partial_transaction = builder.build()?;
+    let amount = partial_transaction.amount;
+    let counter = next_counter(rpc, account).await?;
+    let transaction =
+        partial_transaction.complete_with(account.address.clone(), counter, None, Some(amount));
+
+    inject(rpc, account, transaction.into()).await
+}
+
+/// Returns the JSON encoded storage of `contract`.
+pub async fn get_storage<HttpClient: Http + Sync>(
+    rpc: &TezosRpc<HttpClient>,
+    contract: &str,
+) -> Result<String> {
+    let contract = rpc.contract_at(contract.try_into()?, None).await?;
+
+    Ok(serde_json::to_string_pretty(
+        &contract.storage().to_json()?,
+    )?)
+}
+
+/// Returns the JSON encoded Micheline value found at `key` (JSON encoded Micheline) in the big map of `contract`
+/// named or at the index `big_map`.
+pub async fn get_big_map_value<HttpClient: Http + Sync>(
+    rpc: &TezosRpc<HttpClient>,
+    contract: &str,
+    big_map: &str,
+    key: &str,
+) -> Result<String> {
+    let contract_hash: ContractHash = contract.try_into()?;
+    let contract = rpc.contract_at(contract_hash, None).await?;
+    let big_maps = contract.storage().big_maps();
+    let big_map = match big_map.parse::<usize>() {
+        Ok(index) => big_maps.get_by_index(index),
+        Err(_) => big_maps.get_by_name(big_map),
+    }
+    .ok_or(Error::BigMapNotFound)?;
+    let key: Michelson = parse_micheline(key)?.try_into()?;
+    let value: Micheline = big_map.get_value(key, None).await?;
+
+    Ok(serde_json::to_string(&value)?)
+}
+
+async fn next_counter<HttpClient: Http>(
+    rpc: &TezosRpc<HttpClient>,
+    account: &Account,
+) -> Result<Nat> {
+    let address: Address = account.address.clone().into();
+    let counter = rpc.get_contract_counter(&address).send().await?;
+
+    Ok((counter + 1u8).into())
+}
+
+async fn inject<HttpClient: Http + Send + Sync>(
+    rpc: &TezosRpc<HttpClient>,
+    account: &Account,
+    content: OperationContent,
+) -> Result<String> {
+    let branch = rpc.get_block_hash().send().await?;
+    let operation = rpc
+        .min_fee(UnsignedOperation::new(branch, vec![content]), None)
+        .await?;
+    let signed_operation = operation.into_signed_operation(&account.secret_key)?;
+    let operation_hash = rpc
+        .inject_operation(&signed_operation.to_injectable_string()?)
+        .send()
+        .await?;
+
+    Ok(operation_hash.into_string())
+}
+
+#[cfg(test)]
+mod test {
+    use httpmock::{Method::POST, MockServer};
+    use tezos_core::types::encoded::PublicKey;
+    use tezos_rpc::{client::TezosRpcContext, http::mock::MockNode};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_transfer() -> Result<()> {
+        let public_key: PublicKey =
+            "edpkttZKC51wemRqL2QxwpMnEKxWnbd35pq47Y6xsCHp5M1f7LN8NP".try_into()?;
+        let node = MockNode::default().with_revealed_account(public_key, 10_000_000u32.into())?;
+        let rpc = TezosRpc::from_context(TezosRpcContext::new(Default::default(), node.clone()));
+        let account = Account::new(
+            "tz1RFxSHjxxyaaXyAvJG3uRQeJUCCm4jk2LX",
+            "edskRv7VyXGVZb8EsrR7D9XKUbbAQNQGtALP6QeB16ZCD7SmmJpzyeneJVg3Mq56YLbxRA1kSdAXiswwPiaVfR3NHGMCXCziuZ",
+        )?;
+
+        let hash = transfer(
+            &rpc,
+            &account,
+            "tz1PwXjsrgYBi9wpe3tFhazJpt7JMTVzBp5c",
+            "1000",
+        )
+        .await?;
+        assert_eq!(node.pending_operations()[0].value(), hash);
+
+        node.bake()?;
+        let destination = node
+            .account(&"tz1PwXjsrgYBi9wpe3tFhazJpt7JMTVzBp5c".try_into()?)
+            .map(|account| account.balance);
+        assert_eq!(destination, Some(1000u16.into()));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_big_map_value() -> Result<()> {
+        let server = MockServer::start();
+        let rpc = TezosRpc::new(server.base_url());
+        let contract = "KT1J4CiyWPmtFPXAjpgBezM5hoVHXHNzWBHK";
+
+        server.mock(|when, then| {
+            when.method(POST).path(format!(
+                "/chains/main/blocks/head/context/contracts/{}/script/normalized",
+                contract
+            ));
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(include_str!(
+                    "../../tezos-contract/src/__TEST_DATA__/contract.json"
+                ));
+        });
+        server.mock(|when, then| {
+            when.method(POST)
+                .path("/chains/main/blocks/head/context/big_maps/108614/exprua97oRxvyAVsw5QEcWaELYNdeqsZUoFRMXTBuKUoG6MHtbV7C3/normalized");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(include_str!(
+                    "../../tezos-contract/src/__TEST_DATA__/big_map_value.json"
+                ));
+        });
+
+        let key = r#"{ "prim": "Pair", "args": [{ "string": "tz1YY1LvD6TFH4z74pvxPQXBjAKHE5tB5Q8f" }, { "int": "0" }] }"#;
+        assert_eq!(
+            get_big_map_value(&rpc, contract, "ledger", key).await?,
+            r#"{"int":"164748675300576703"}"#
+        );
+        assert!(matches!(
+            get_big_map_value(&rpc, contract, "unknown", key).await,
+            Err(Error::BigMapNotFound)
+        ));
+        Ok(())
+    }
+}
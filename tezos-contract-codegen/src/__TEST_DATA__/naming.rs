@@ -0,0 +1,228 @@
+#[allow(unused_imports)]
+use ::tezos_contract::{Contract, ContractFetcher, Error, PartialTransaction, Result};
+#[allow(unused_imports)]
+use ::tezos_core::types::{
+    encoded::{Address, ChainId, ContractHash, ImplicitAddress, PublicKey, Signature},
+    mutez::Mutez, number::{Int, Nat},
+    timestamp::Timestamp,
+};
+#[allow(unused_imports)]
+use ::tezos_michelson::{
+    micheline::{self, Micheline},
+    michelson::{
+        convert::{internal, FromMichelson, IntoMichelson, MichelsonType},
+        data::{self, Bytes, Data},
+        types::{self, Type},
+        Michelson,
+    },
+};
+#[allow(unused_imports)]
+use ::tezos_operation::operations::Parameters;
+#[allow(unused_imports)]
+use ::tezos_rpc::{client::TezosRpc, http::Http, models::block::BlockId};
+/// Typed bindings to the `Naming` contract.
+pub struct Naming<'a, HttpClient: Http> {
+    contract: Contract<'a, HttpClient>,
+}
+impl<'a, HttpClient: Http + Sync> Naming<'a, HttpClient> {
+    pub fn new(contract: Contract<'a, HttpClient>) -> Self {
+        Self { contract }
+    }
+    /// Fetches the contract at `address`.
+    pub async fn at(
+        client: &'a TezosRpc<HttpClient>,
+        address: ContractHash,
+        block_id: Option<&BlockId>,
+    ) -> Result<Self> {
+        Ok(Self::new(client.contract_at(address, block_id).await?))
+    }
+    pub fn contract(&self) -> &Contract<'a, HttpClient> {
+        &self.contract
+    }
+    /// Decodes the storage fetched along with the contract.
+    pub fn storage(&self) -> Result<NamingStorage> {
+        self.contract.storage().decode_as()
+    }
+    /// Creates a call to the `default` entrypoint.
+    pub fn default(&self) -> Result<PartialTransaction> {
+        let value = Micheline::from(data::unit::<Data>());
+        Ok(
+            PartialTransaction::new(
+                0u8.into(),
+                self.contract.address().into(),
+                Some(Parameters::new("default".into(), value.normalized())),
+            ),
+        )
+    }
+}
+#[derive(Debug, Clone, PartialEq)]
+pub enum State {
+    DoIt,
+    DoIt2,
+}
+impl MichelsonType for State {
+    fn michelson_type() -> ::tezos_michelson::Result<Type> {
+        Ok(
+            Type::Or(
+                types::Or::new(
+                    types::unit::<Type>().with_field_annotation("do_it".into()),
+                    types::unit::<Type>().with_field_annotation("doIt".into()),
+                    None,
+                ),
+            ),
+        )
+    }
+}
+impl IntoMichelson for State {
+    fn into_michelson(self) -> ::tezos_michelson::Result<Data> {
+        Ok(
+            match self {
+                Self::DoIt => Data::Left(data::Left::new(data::unit::<Data>())),
+                Self::DoIt2 => Data::Right(data::Right::new(data::unit::<Data>())),
+            },
+        )
+    }
+}
+impl FromMichelson for State {
+    fn from_michelson(data: Data) -> ::tezos_michelson::Result<Self> {
+        Ok(
+            match internal::branch(data)? {
+                internal::Branch::Left(value) => {
+                    <()>::from_michelson(value)?;
+                    Self::DoIt
+                }
+                internal::Branch::Right(value) => {
+                    <()>::from_michelson(value)?;
+                    Self::DoIt2
+                }
+            },
+        )
+    }
+}
+#[derive(Debug, Clone, PartialEq)]
+pub struct NamingStorage {
+    pub token_id: Nat,
+    pub arg1: Nat,
+    pub token_id2: Nat,
+    pub arg12: Nat,
+    pub state: State,
+}
+impl MichelsonType for NamingStorage {
+    fn michelson_type() -> ::tezos_michelson::Result<Type> {
+        Ok(
+            Type::Pair(
+                types::Pair::new(
+                    Vec::from([
+                        types::nat::<Type>().with_field_annotation("tokenId".into()),
+                        Type::Pair(
+                            types::Pair::new(
+                                Vec::from([
+                                    types::nat::<Type>(),
+                                    Type::Pair(
+                                        types::Pair::new(
+                                            Vec::from([
+                                                types::nat::<Type>()
+                                                    .with_field_annotation("token_id".into()),
+                                                Type::Pair(
+                                                    types::Pair::new(
+                                                        Vec::from([
+                                                            types::nat::<Type>().with_field_annotation("arg1".into()),
+                                                            <State as MichelsonType>::michelson_type()?
+                                                                .with_field_annotation("state".into()),
+                                                        ]),
+                                                        None,
+                                                    ),
+                                                ),
+                                            ]),
+                                            None,
+                                        ),
+                                    ),
+                                ]),
+                                None,
+                            ),
+                        ),
+                    ]),
+                    None,
+                ),
+            ),
+        )
+    }
+}
+impl IntoMichelson for NamingStorage {
+    fn into_michelson(self) -> ::tezos_michelson::Result<Data> {
+        let Self { token_id, arg1, token_id2, arg12, state } = self;
+        Ok(
+            Data::Pair(
+                data::Pair::new(
+                    Vec::from([
+                        IntoMichelson::into_michelson(token_id)?,
+                        Data::Pair(
+                            data::Pair::new(
+                                Vec::from([
+                                    IntoMichelson::into_michelson(arg1)?,
+                                    Data::Pair(
+                                        data::Pair::new(
+                                            Vec::from([
+                                                IntoMichelson::into_michelson(token_id2)?,
+                                                Data::Pair(
+                                                    data::Pair::new(
+                                                        Vec::from([
+                                                            IntoMichelson::into_michelson(arg12)?,
+                                                            IntoMichelson::into_michelson(state)?,
+                                                        ]),
+                                                    ),
+                                                ),
+                                            ]),
+                                        ),
+                                    ),
+                                ]),
+                            ),
+                        ),
+                    ]),
+                ),
+            ),
+        )
+    }
+}
+impl FromMichelson for NamingStorage {
+    fn from_michelson(data: Data) -> ::tezos_michelson::Result<Self> {
+        let mut values_0 = internal::pair_values(data, 2)?.into_iter();
+        let token_id = FromMichelson::from_michelson(
+            values_0.next().ok_or(::tezos_michelson::Error::InvalidMichelsonData)?,
+        )?;
+        let mut values_1 = internal::pair_values(
+                values_0.next().ok_or(::tezos_michelson::Error::InvalidMichelsonData)?,
+                2,
+            )?
+            .into_iter();
+        let arg1 = FromMichelson::from_michelson(
+            values_1.next().ok_or(::tezos_michelson::Error::InvalidMichelsonData)?,
+        )?;
+        let mut values_2 = internal::pair_values(
+                values_1.next().ok_or(::tezos_michelson::Error::InvalidMichelsonData)?,
+                2,
+            )?
+            .into_iter();
+        let token_id2 = FromMichelson::from_michelson(
+            values_2.next().ok_or(::tezos_michelson::Error::InvalidMichelsonData)?,
+        )?;
+        let mut values_3 = internal::pair_values(
+                values_2.next().ok_or(::tezos_michelson::Error::InvalidMichelsonData)?,
+                2,
+            )?
+            .into_iter();
+        let arg12 = FromMichelson::from_michelson(
+            values_3.next().ok_or(::tezos_michelson::Error::InvalidMichelsonData)?,
+        )?;
+        let state = FromMichelson::from_michelson(
+            values_3.next().ok_or(::tezos_michelson::Error::InvalidMichelsonData)?,
+        )?;
+        Ok(Self {
+            token_id,
+            arg1,
+            token_id2,
+            arg12,
+            state,
+        })
+    }
+}
@@ -0,0 +1,1361 @@
+#[allow(unused_imports)]
+use ::tezos_contract::{Contract, ContractFetcher, Error, PartialTransaction, Result};
+#[allow(unused_imports)]
+use ::tezos_core::types::{
+    encoded::{Address, ChainId, ContractHash, ImplicitAddress, PublicKey, Signature},
+    mutez::Mutez, number::{Int, Nat},
+    timestamp::Timestamp,
+};
+#[allow(unused_imports)]
+use ::tezos_michelson::{
+    micheline::{self, Micheline},
+    michelson::{
+        convert::{internal, FromMichelson, IntoMichelson, MichelsonType},
+        data::{self, Bytes, Data},
+        types::{self, Type},
+        Michelson,
+    },
+};
+#[allow(unused_imports)]
+use ::tezos_operation::operations::Parameters;
+#[allow(unused_imports)]
+use ::tezos_rpc::{client::TezosRpc, http::Http, models::block::BlockId};
+/// Typed bindings to the `Token` contract.
+pub struct Token<'a, HttpClient: Http> {
+    contract: Contract<'a, HttpClient>,
+}
+impl<'a, HttpClient: Http + Sync> Token<'a, HttpClient> {
+    pub fn new(contract: Contract<'a, HttpClient>) -> Self {
+        Self { contract }
+    }
+    /// Fetches the contract at `address`.
+    pub async fn at(
+        client: &'a TezosRpc<HttpClient>,
+        address: ContractHash,
+        block_id: Option<&BlockId>,
+    ) -> Result<Self> {
+        Ok(Self::new(client.contract_at(address, block_id).await?))
+    }
+    pub fn contract(&self) -> &Contract<'a, HttpClient> {
+        &self.contract
+    }
+    /// Decodes the storage fetched along with the contract.
+    pub fn storage(&self) -> Result<TokenStorage> {
+        self.contract.storage().decode_as()
+    }
+    /// Creates a call to the `balance_of` entrypoint.
+    pub fn balance_of(&self, value: BalanceOf) -> Result<PartialTransaction> {
+        let value = Micheline::from(IntoMichelson::into_michelson(value)?);
+        Ok(
+            PartialTransaction::new(
+                0u8.into(),
+                self.contract.address().into(),
+                Some(Parameters::new("balance_of".into(), value.normalized())),
+            ),
+        )
+    }
+    /// Creates a call to the `claim` entrypoint.
+    pub fn claim(&self) -> Result<PartialTransaction> {
+        let value = Micheline::from(data::unit::<Data>());
+        Ok(
+            PartialTransaction::new(
+                0u8.into(),
+                self.contract.address().into(),
+                Some(Parameters::new("claim".into(), value.normalized())),
+            ),
+        )
+    }
+    /// Creates a call to the `execute` entrypoint.
+    pub fn execute(&self, value: Data) -> Result<PartialTransaction> {
+        let value = Micheline::from(value);
+        Ok(
+            PartialTransaction::new(
+                0u8.into(),
+                self.contract.address().into(),
+                Some(Parameters::new("execute".into(), value.normalized())),
+            ),
+        )
+    }
+    /// Creates a call to the `remove_administrator` entrypoint.
+    pub fn remove_administrator(
+        &self,
+        value: RemoveAdministrator,
+    ) -> Result<PartialTransaction> {
+        let value = Micheline::from(IntoMichelson::into_michelson(value)?);
+        Ok(
+            PartialTransaction::new(
+                0u8.into(),
+                self.contract.address().into(),
+                Some(Parameters::new("remove_administrator".into(), value.normalized())),
+            ),
+        )
+    }
+    /// Creates a call to the `set_administrator` entrypoint.
+    pub fn set_administrator(
+        &self,
+        value: SetAdministrator,
+    ) -> Result<PartialTransaction> {
+        let value = Micheline::from(IntoMichelson::into_michelson(value)?);
+        Ok(
+            PartialTransaction::new(
+                0u8.into(),
+                self.contract.address().into(),
+                Some(Parameters::new("set_administrator".into(), value.normalized())),
+            ),
+        )
+    }
+    /// Creates a call to the `set_treasury` entrypoint.
+    pub fn set_treasury(&self, value: SetTreasury) -> Result<PartialTransaction> {
+        let value = Micheline::from(IntoMichelson::into_michelson(value)?);
+        Ok(
+            PartialTransaction::new(
+                0u8.into(),
+                self.contract.address().into(),
+                Some(Parameters::new("set_treasury".into(), value.normalized())),
+            ),
+        )
+    }
+    /// Creates a call to the `transfer` entrypoint.
+    pub fn transfer(&self, value: Vec<TransferItem>) -> Result<PartialTransaction> {
+        let value = Micheline::from(
+            Data::Sequence(
+                data::Sequence::new(
+                    value
+                        .into_iter()
+                        .map(IntoMichelson::into_michelson)
+                        .collect::<::tezos_michelson::Result<Vec<_>>>()?,
+                ),
+            ),
+        );
+        Ok(
+            PartialTransaction::new(
+                0u8.into(),
+                self.contract.address().into(),
+                Some(Parameters::new("transfer".into(), value.normalized())),
+            ),
+        )
+    }
+    /// Creates a call to the `update_operators` entrypoint.
+    pub fn update_operators(
+        &self,
+        value: Vec<UpdateOperatorsItem>,
+    ) -> Result<PartialTransaction> {
+        let value = Micheline::from(
+            Data::Sequence(
+                data::Sequence::new(
+                    value
+                        .into_iter()
+                        .map(IntoMichelson::into_michelson)
+                        .collect::<::tezos_michelson::Result<Vec<_>>>()?,
+                ),
+            ),
+        );
+        Ok(
+            PartialTransaction::new(
+                0u8.into(),
+                self.contract.address().into(),
+                Some(Parameters::new("update_operators".into(), value.normalized())),
+            ),
+        )
+    }
+    /// Creates a call to the `update_stake` entrypoint.
+    pub fn update_stake(&self, value: UpdateStake) -> Result<PartialTransaction> {
+        let value = Micheline::from(IntoMichelson::into_michelson(value)?);
+        Ok(
+            PartialTransaction::new(
+                0u8.into(),
+                self.contract.address().into(),
+                Some(Parameters::new("update_stake".into(), value.normalized())),
+            ),
+        )
+    }
+    /// Retrieves the value stored at `key` in the `administrators` big map.
+    pub async fn get_administrators(
+        &self,
+        key: AdministratorsKey,
+        block_id: Option<&BlockId>,
+    ) -> Result<()> {
+        let big_map = self
+            .contract
+            .storage()
+            .big_maps()
+            .get_by_name("administrators")
+            .ok_or(Error::Internal {
+                description: "big map `administrators` not found".into(),
+            })?;
+        let data = Data::try_from(
+            big_map
+                .get_value(
+                    Michelson::from(IntoMichelson::into_michelson(key)?),
+                    block_id,
+                )
+                .await?,
+        )?;
+        Ok(FromMichelson::from_michelson(data)?)
+    }
+    /// Retrieves the value stored at `key` in the `dist_factors` big map.
+    pub async fn get_dist_factors(
+        &self,
+        key: Address,
+        block_id: Option<&BlockId>,
+    ) -> Result<Nat> {
+        let big_map = self
+            .contract
+            .storage()
+            .big_maps()
+            .get_by_name("dist_factors")
+            .ok_or(Error::Internal {
+                description: "big map `dist_factors` not found".into(),
+            })?;
+        let data = Data::try_from(
+            big_map
+                .get_value(
+                    Michelson::from(IntoMichelson::into_michelson(key)?),
+                    block_id,
+                )
+                .await?,
+        )?;
+        Ok(FromMichelson::from_michelson(data)?)
+    }
+    /// Retrieves the value stored at `key` in the `ledger` big map.
+    pub async fn get_ledger(
+        &self,
+        key: LedgerKey,
+        block_id: Option<&BlockId>,
+    ) -> Result<Nat> {
+        let big_map = self
+            .contract
+            .storage()
+            .big_maps()
+            .get_by_name("ledger")
+            .ok_or(Error::Internal {
+                description: "big map `ledger` not found".into(),
+            })?;
+        let data = Data::try_from(
+            big_map
+                .get_value(
+                    Michelson::from(IntoMichelson::into_michelson(key)?),
+                    block_id,
+                )
+                .await?,
+        )?;
+        Ok(FromMichelson::from_michelson(data)?)
+    }
+    /// Retrieves the value stored at `key` in the `operators` big map.
+    pub async fn get_operators(
+        &self,
+        key: OperatorsKey,
+        block_id: Option<&BlockId>,
+    ) -> Result<()> {
+        let big_map = self
+            .contract
+            .storage()
+            .big_maps()
+            .get_by_name("operators")
+            .ok_or(Error::Internal {
+                description: "big map `operators` not found".into(),
+            })?;
+        let data = Data::try_from(
+            big_map
+                .get_value(
+                    Michelson::from(IntoMichelson::into_michelson(key)?),
+                    block_id,
+                )
+                .await?,
+        )?;
+        Ok(FromMichelson::from_michelson(data)?)
+    }
+    /// Retrieves the value stored at `key` in the `stakes` big map.
+    pub async fn get_stakes(
+        &self,
+        key: Address,
+        block_id: Option<&BlockId>,
+    ) -> Result<Nat> {
+        let big_map = self
+            .contract
+            .storage()
+            .big_maps()
+            .get_by_name("stakes")
+            .ok_or(Error::Internal {
+                description: "big map `stakes` not found".into(),
+            })?;
+        let data = Data::try_from(
+            big_map
+                .get_value(
+                    Michelson::from(IntoMichelson::into_michelson(key)?),
+                    block_id,
+                )
+                .await?,
+        )?;
+        Ok(FromMichelson::from_michelson(data)?)
+    }
+    /// Retrieves the value stored at `key` in the `token_metadata` big map.
+    pub async fn get_token_metadata(
+        &self,
+        key: Nat,
+        block_id: Option<&BlockId>,
+    ) -> Result<TokenMetadataValue> {
+        let big_map = self
+            .contract
+            .storage()
+            .big_maps()
+            .get_by_name("token_metadata")
+            .ok_or(Error::Internal {
+                description: "big map `token_metadata` not found".into(),
+            })?;
+        let data = Data::try_from(
+            big_map
+                .get_value(
+                    Michelson::from(IntoMichelson::into_michelson(key)?),
+                    block_id,
+                )
+                .await?,
+        )?;
+        Ok(FromMichelson::from_michelson(data)?)
+    }
+    /// Retrieves the value stored at `key` in the `total_supply` big map.
+    pub async fn get_total_supply(
+        &self,
+        key: Nat,
+        block_id: Option<&BlockId>,
+    ) -> Result<Nat> {
+        let big_map = self
+            .contract
+            .storage()
+            .big_maps()
+            .get_by_name("total_supply")
+            .ok_or(Error::Internal {
+                description: "big map `total_supply` not found".into(),
+            })?;
+        let data = Data::try_from(
+            big_map
+                .get_value(
+                    Michelson::from(IntoMichelson::into_michelson(key)?),
+                    block_id,
+                )
+                .await?,
+        )?;
+        Ok(FromMichelson::from_michelson(data)?)
+    }
+}
+#[derive(Debug, Clone, PartialEq)]
+pub struct TreasuryLedgerKey {
+    pub owner: Address,
+    pub token_id: Nat,
+}
+impl MichelsonType for TreasuryLedgerKey {
+    fn michelson_type() -> ::tezos_michelson::Result<Type> {
+        Ok(
+            Type::Pair(
+                types::Pair::new(
+                    Vec::from([
+                        types::address::<Type>().with_field_annotation("owner".into()),
+                        types::nat::<Type>().with_field_annotation("token_id".into()),
+                    ]),
+                    None,
+                ),
+            ),
+        )
+    }
+}
+impl IntoMichelson for TreasuryLedgerKey {
+    fn into_michelson(self) -> ::tezos_michelson::Result<Data> {
+        let Self { owner, token_id } = self;
+        Ok(
+            Data::Pair(
+                data::Pair::new(
+                    Vec::from([
+                        IntoMichelson::into_michelson(owner)?,
+                        IntoMichelson::into_michelson(token_id)?,
+                    ]),
+                ),
+            ),
+        )
+    }
+}
+impl FromMichelson for TreasuryLedgerKey {
+    fn from_michelson(data: Data) -> ::tezos_michelson::Result<Self> {
+        let mut values_0 = internal::pair_values(data, 2)?.into_iter();
+        let owner = FromMichelson::from_michelson(
+            values_0.next().ok_or(::tezos_michelson::Error::InvalidMichelsonData)?,
+        )?;
+        let token_id = FromMichelson::from_michelson(
+            values_0.next().ok_or(::tezos_michelson::Error::InvalidMichelsonData)?,
+        )?;
+        Ok(Self { owner, token_id })
+    }
+}
+#[derive(Debug, Clone, PartialEq)]
+pub struct TokenStorage {
+    pub administrators: Int,
+    pub dist_factor: Nat,
+    pub dist_factors: Int,
+    pub epoch_start_timestamp: Timestamp,
+    pub last_update_timestamp: Timestamp,
+    pub ledger: Int,
+    pub operators: Int,
+    pub stakes: Int,
+    pub token_metadata: Int,
+    pub total_stake: Nat,
+    pub total_supply: Int,
+    pub treasury_ledger_key: TreasuryLedgerKey,
+}
+impl MichelsonType for TokenStorage {
+    fn michelson_type() -> ::tezos_michelson::Result<Type> {
+        Ok(
+            Type::Pair(
+                types::Pair::new(
+                    Vec::from([
+                        Type::Pair(
+                            types::Pair::new(
+                                Vec::from([
+                                    Type::Pair(
+                                        types::Pair::new(
+                                            Vec::from([
+                                                Type::BigMap(
+                                                        types::BigMap::new(
+                                                            <TreasuryLedgerKey as MichelsonType>::michelson_type()?,
+                                                            types::unit::<Type>(),
+                                                            None,
+                                                        ),
+                                                    )
+                                                    .with_field_annotation("administrators".into()),
+                                                Type::Pair(
+                                                    types::Pair::new(
+                                                        Vec::from([
+                                                            types::nat::<Type>()
+                                                                .with_field_annotation("dist_factor".into()),
+                                                            Type::BigMap(
+                                                                    types::BigMap::new(
+                                                                        types::address::<Type>(),
+                                                                        types::nat::<Type>(),
+                                                                        None,
+                                                                    ),
+                                                                )
+                                                                .with_field_annotation("dist_factors".into()),
+                                                        ]),
+                                                        None,
+                                                    ),
+                                                ),
+                                            ]),
+                                            None,
+                                        ),
+                                    ),
+                                    Type::Pair(
+                                        types::Pair::new(
+                                            Vec::from([
+                                                types::timestamp::<Type>()
+                                                    .with_field_annotation("epoch_start_timestamp".into()),
+                                                Type::Pair(
+                                                    types::Pair::new(
+                                                        Vec::from([
+                                                            types::timestamp::<Type>()
+                                                                .with_field_annotation("last_update_timestamp".into()),
+                                                            Type::BigMap(
+                                                                    types::BigMap::new(
+                                                                        <TreasuryLedgerKey as MichelsonType>::michelson_type()?,
+                                                                        types::nat::<Type>(),
+                                                                        None,
+                                                                    ),
+                                                                )
+                                                                .with_field_annotation("ledger".into()),
+                                                        ]),
+                                                        None,
+                                                    ),
+                                                ),
+                                            ]),
+                                            None,
+                                        ),
+                                    ),
+                                ]),
+                                None,
+                            ),
+                        ),
+                        Type::Pair(
+                            types::Pair::new(
+                                Vec::from([
+                                    Type::Pair(
+                                        types::Pair::new(
+                                            Vec::from([
+                                                Type::BigMap(
+                                                        types::BigMap::new(
+                                                            Type::Pair(
+                                                                types::Pair::new(
+                                                                    Vec::from([
+                                                                        types::address::<Type>()
+                                                                            .with_field_annotation("owner".into()),
+                                                                        Type::Pair(
+                                                                            types::Pair::new(
+                                                                                Vec::from([
+                                                                                    types::address::<Type>()
+                                                                                        .with_field_annotation("operator".into()),
+                                                                                    types::nat::<Type>()
+                                                                                        .with_field_annotation("token_id".into()),
+                                                                                ]),
+                                                                                None,
+                                                                            ),
+                                                                        ),
+                                                                    ]),
+                                                                    None,
+                                                                ),
+                                                            ),
+                                                            types::unit::<Type>(),
+                                                            None,
+                                                        ),
+                                                    )
+                                                    .with_field_annotation("operators".into()),
+                                                Type::Pair(
+                                                    types::Pair::new(
+                                                        Vec::from([
+                                                            Type::BigMap(
+                                                                    types::BigMap::new(
+                                                                        types::address::<Type>(),
+                                                                        types::nat::<Type>(),
+                                                                        None,
+                                                                    ),
+                                                                )
+                                                                .with_field_annotation("stakes".into()),
+                                                            Type::BigMap(
+                                                                    types::BigMap::new(
+                                                                        types::nat::<Type>(),
+                                                                        Type::Pair(
+                                                                            types::Pair::new(
+                                                                                Vec::from([
+                                                                                    types::nat::<Type>()
+                                                                                        .with_field_annotation("token_id".into()),
+                                                                                    Type::Map(
+                                                                                            types::Map::new(
+                                                                                                types::string::<Type>(),
+                                                                                                types::bytes::<Type>(),
+                                                                                                None,
+                                                                                            ),
+                                                                                        )
+                                                                                        .with_field_annotation("token_info".into()),
+                                                                                ]),
+                                                                                None,
+                                                                            ),
+                                                                        ),
+                                                                        None,
+                                                                    ),
+                                                                )
+                                                                .with_field_annotation("token_metadata".into()),
+                                                        ]),
+                                                        None,
+                                                    ),
+                                                ),
+                                            ]),
+                                            None,
+                                        ),
+                                    ),
+                                    Type::Pair(
+                                        types::Pair::new(
+                                            Vec::from([
+                                                types::nat::<Type>()
+                                                    .with_field_annotation("total_stake".into()),
+                                                Type::Pair(
+                                                    types::Pair::new(
+                                                        Vec::from([
+                                                            Type::BigMap(
+                                                                    types::BigMap::new(
+                                                                        types::nat::<Type>(),
+                                                                        types::nat::<Type>(),
+                                                                        None,
+                                                                    ),
+                                                                )
+                                                                .with_field_annotation("total_supply".into()),
+                                                            <TreasuryLedgerKey as MichelsonType>::michelson_type()?
+                                                                .with_field_annotation("treasury_ledger_key".into()),
+                                                        ]),
+                                                        None,
+                                                    ),
+                                                ),
+                                            ]),
+                                            None,
+                                        ),
+                                    ),
+                                ]),
+                                None,
+                            ),
+                        ),
+                    ]),
+                    None,
+                ),
+            ),
+        )
+    }
+}
+impl IntoMichelson for TokenStorage {
+    fn into_michelson(self) -> ::tezos_michelson::Result<Data> {
+        let Self {
+            administrators,
+            dist_factor,
+            dist_factors,
+            epoch_start_timestamp,
+            last_update_timestamp,
+            ledger,
+            operators,
+            stakes,
+            token_metadata,
+            total_stake,
+            total_supply,
+            treasury_ledger_key,
+        } = self;
+        Ok(
+            Data::Pair(
+                data::Pair::new(
+                    Vec::from([
+                        Data::Pair(
+                            data::Pair::new(
+                                Vec::from([
+                                    Data::Pair(
+                                        data::Pair::new(
+                                            Vec::from([
+                                                IntoMichelson::into_michelson(administrators)?,
+                                                Data::Pair(
+                                                    data::Pair::new(
+                                                        Vec::from([
+                                                            IntoMichelson::into_michelson(dist_factor)?,
+                                                            IntoMichelson::into_michelson(dist_factors)?,
+                                                        ]),
+                                                    ),
+                                                ),
+                                            ]),
+                                        ),
+                                    ),
+                                    Data::Pair(
+                                        data::Pair::new(
+                                            Vec::from([
+                                                IntoMichelson::into_michelson(epoch_start_timestamp)?,
+                                                Data::Pair(
+                                                    data::Pair::new(
+                                                        Vec::from([
+                                                            IntoMichelson::into_michelson(last_update_timestamp)?,
+                                                            IntoMichelson::into_michelson(ledger)?,
+                                                        ]),
+                                                    ),
+                                                ),
+                                            ]),
+                                        ),
+                                    ),
+                                ]),
+                            ),
+                        ),
+                        Data::Pair(
+                            data::Pair::new(
+                                Vec::from([
+                                    Data::Pair(
+                                        data::Pair::new(
+                                            Vec::from([
+                                                IntoMichelson::into_michelson(operators)?,
+                                                Data::Pair(
+                                                    data::Pair::new(
+                                                        Vec::from([
+                                                            IntoMichelson::into_michelson(stakes)?,
+                                                            IntoMichelson::into_michelson(token_metadata)?,
+                                                        ]),
+                                                    ),
+                                                ),
+                                            ]),
+                                        ),
+                                    ),
+                                    Data::Pair(
+                                        data::Pair::new(
+                                            Vec::from([
+                                                IntoMichelson::into_michelson(total_stake)?,
+                                                Data::Pair(
+                                                    data::Pair::new(
+                                                        Vec::from([
+                                                            IntoMichelson::into_michelson(total_supply)?,
+                                                            IntoMichelson::into_michelson(treasury_ledger_key)?,
+                                                        ]),
+                                                    ),
+                                                ),
+                                            ]),
+                                        ),
+                                    ),
+                                ]),
+                            ),
+                        ),
+                    ]),
+                ),
+            ),
+        )
+    }
+}
+impl FromMichelson for TokenStorage {
+    fn from_michelson(data: Data) -> ::tezos_michelson::Result<Self> {
+        let mut values_0 = internal::pair_values(data, 2)?.into_iter();
+        let mut values_1 = internal::pair_values(
+                values_0.next().ok_or(::tezos_michelson::Error::InvalidMichelsonData)?,
+                2,
+            )?
+            .into_iter();
+        let mut values_2 = internal::pair_values(
+                values_1.next().ok_or(::tezos_michelson::Error::InvalidMichelsonData)?,
+                2,
+            )?
+            .into_iter();
+        let administrators = FromMichelson::from_michelson(
+            values_2.next().ok_or(::tezos_michelson::Error::InvalidMichelsonData)?,
+        )?;
+        let mut values_3 = internal::pair_values(
+                values_2.next().ok_or(::tezos_michelson::Error::InvalidMichelsonData)?,
+                2,
+            )?
+            .into_iter();
+        let dist_factor = FromMichelson::from_michelson(
+            values_3.next().ok_or(::tezos_michelson::Error::InvalidMichelsonData)?,
+        )?;
+        let dist_factors = FromMichelson::from_michelson(
+            values_3.next().ok_or(::tezos_michelson::Error::InvalidMichelsonData)?,
+        )?;
+        let mut values_4 = internal::pair_values(
+                values_1.next().ok_or(::tezos_michelson::Error::InvalidMichelsonData)?,
+                2,
+            )?
+            .into_iter();
+        let epoch_start_timestamp = FromMichelson::from_michelson(
+            values_4.next().ok_or(::tezos_michelson::Error::InvalidMichelsonData)?,
+        )?;
+        let mut values_5 = internal::pair_values(
+                values_4.next().ok_or(::tezos_michelson::Error::InvalidMichelsonData)?,
+                2,
+            )?
+            .into_iter();
+        let last_update_timestamp = FromMichelson::from_michelson(
+            values_5.next().ok_or(::tezos_michelson::Error::InvalidMichelsonData)?,
+        )?;
+        let ledger = FromMichelson::from_michelson(
+            values_5.next().ok_or(::tezos_michelson::Error::InvalidMichelsonData)?,
+        )?;
+        let mut values_6 = internal::pair_values(
+                values_0.next().ok_or(::tezos_michelson::Error::InvalidMichelsonData)?,
+                2,
+            )?
+            .into_iter();
+        let mut values_7 = internal::pair_values(
+                values_6.next().ok_or(::tezos_michelson::Error::InvalidMichelsonData)?,
+                2,
+            )?
+            .into_iter();
+        let operators = FromMichelson::from_michelson(
+            values_7.next().ok_or(::tezos_michelson::Error::InvalidMichelsonData)?,
+        )?;
+        let mut values_8 = internal::pair_values(
+                values_7.next().ok_or(::tezos_michelson::Error::InvalidMichelsonData)?,
+                2,
+            )?
+            .into_iter();
+        let stakes = FromMichelson::from_michelson(
+            values_8.next().ok_or(::tezos_michelson::Error::InvalidMichelsonData)?,
+        )?;
+        let token_metadata = FromMichelson::from_michelson(
+            values_8.next().ok_or(::tezos_michelson::Error::InvalidMichelsonData)?,
+        )?;
+        let mut values_9 = internal::pair_values(
+                values_6.next().ok_or(::tezos_michelson::Error::InvalidMichelsonData)?,
+                2,
+            )?
+            .into_iter();
+        let total_stake = FromMichelson::from_michelson(
+            values_9.next().ok_or(::tezos_michelson::Error::InvalidMichelsonData)?,
+        )?;
+        let mut values_10 = internal::pair_values(
+                values_9.next().ok_or(::tezos_michelson::Error::InvalidMichelsonData)?,
+                2,
+            )?
+            .into_iter();
+        let total_supply = FromMichelson::from_michelson(
+            values_10.next().ok_or(::tezos_michelson::Error::InvalidMichelsonData)?,
+        )?;
+        let treasury_ledger_key = FromMichelson::from_michelson(
+            values_10.next().ok_or(::tezos_michelson::Error::InvalidMichelsonData)?,
+        )?;
+        Ok(Self {
+            administrators,
+            dist_factor,
+            dist_factors,
+            epoch_start_timestamp,
+            last_update_timestamp,
+            ledger,
+            operators,
+            stakes,
+            token_metadata,
+            total_stake,
+            total_supply,
+            treasury_ledger_key,
+        })
+    }
+}
+/// The same type as [`TreasuryLedgerKey`].
+pub type RequestsItem = TreasuryLedgerKey;
+#[derive(Debug, Clone, PartialEq)]
+pub struct BalanceOf {
+    pub requests: Vec<RequestsItem>,
+    pub callback: Address,
+}
+impl MichelsonType for BalanceOf {
+    fn michelson_type() -> ::tezos_michelson::Result<Type> {
+        Ok(
+            Type::Pair(
+                types::Pair::new(
+                    Vec::from([
+                        Type::List(
+                                types::List::new(
+                                    <TreasuryLedgerKey as MichelsonType>::michelson_type()?,
+                                    None,
+                                ),
+                            )
+                            .with_field_annotation("requests".into()),
+                        Type::Contract(
+                                types::Contract::new(
+                                    Type::List(
+                                        types::List::new(
+                                            Type::Pair(
+                                                types::Pair::new(
+                                                    Vec::from([
+                                                        <TreasuryLedgerKey as MichelsonType>::michelson_type()?
+                                                            .with_field_annotation("request".into()),
+                                                        types::nat::<Type>().with_field_annotation("balance".into()),
+                                                    ]),
+                                                    None,
+                                                ),
+                                            ),
+                                            None,
+                                        ),
+                                    ),
+                                    None,
+                                ),
+                            )
+                            .with_field_annotation("callback".into()),
+                    ]),
+                    None,
+                ),
+            ),
+        )
+    }
+}
+impl IntoMichelson for BalanceOf {
+    fn into_michelson(self) -> ::tezos_michelson::Result<Data> {
+        let Self { requests, callback } = self;
+        Ok(
+            Data::Pair(
+                data::Pair::new(
+                    Vec::from([
+                        Data::Sequence(
+                            data::Sequence::new(
+                                requests
+                                    .into_iter()
+                                    .map(IntoMichelson::into_michelson)
+                                    .collect::<::tezos_michelson::Result<Vec<_>>>()?,
+                            ),
+                        ),
+                        IntoMichelson::into_michelson(callback)?,
+                    ]),
+                ),
+            ),
+        )
+    }
+}
+impl FromMichelson for BalanceOf {
+    fn from_michelson(data: Data) -> ::tezos_michelson::Result<Self> {
+        let mut values_0 = internal::pair_values(data, 2)?.into_iter();
+        let requests = internal::sequence_values(
+                values_0.next().ok_or(::tezos_michelson::Error::InvalidMichelsonData)?,
+            )?
+            .into_iter()
+            .map(FromMichelson::from_michelson)
+            .collect::<::tezos_michelson::Result<Vec<_>>>()?;
+        let callback = FromMichelson::from_michelson(
+            values_0.next().ok_or(::tezos_michelson::Error::InvalidMichelsonData)?,
+        )?;
+        Ok(Self { requests, callback })
+    }
+}
+#[derive(Debug, Clone, PartialEq)]
+pub struct RemoveAdministrator {
+    pub administrator_to_remove: Address,
+    pub token_id: Nat,
+}
+impl MichelsonType for RemoveAdministrator {
+    fn michelson_type() -> ::tezos_michelson::Result<Type> {
+        Ok(
+            Type::Pair(
+                types::Pair::new(
+                    Vec::from([
+                        types::address::<Type>()
+                            .with_field_annotation("administrator_to_remove".into()),
+                        types::nat::<Type>().with_field_annotation("token_id".into()),
+                    ]),
+                    None,
+                ),
+            ),
+        )
+    }
+}
+impl IntoMichelson for RemoveAdministrator {
+    fn into_michelson(self) -> ::tezos_michelson::Result<Data> {
+        let Self { administrator_to_remove, token_id } = self;
+        Ok(
+            Data::Pair(
+                data::Pair::new(
+                    Vec::from([
+                        IntoMichelson::into_michelson(administrator_to_remove)?,
+                        IntoMichelson::into_michelson(token_id)?,
+                    ]),
+                ),
+            ),
+        )
+    }
+}
+impl FromMichelson for RemoveAdministrator {
+    fn from_michelson(data: Data) -> ::tezos_michelson::Result<Self> {
+        let mut values_0 = internal::pair_values(data, 2)?.into_iter();
+        let administrator_to_remove = FromMichelson::from_michelson(
+            values_0.next().ok_or(::tezos_michelson::Error::InvalidMichelsonData)?,
+        )?;
+        let token_id = FromMichelson::from_michelson(
+            values_0.next().ok_or(::tezos_michelson::Error::InvalidMichelsonData)?,
+        )?;
+        Ok(Self {
+            administrator_to_remove,
+            token_id,
+        })
+    }
+}
+#[derive(Debug, Clone, PartialEq)]
+pub struct SetAdministrator {
+    pub administrator_to_set: Address,
+    pub token_id: Nat,
+}
+impl MichelsonType for SetAdministrator {
+    fn michelson_type() -> ::tezos_michelson::Result<Type> {
+        Ok(
+            Type::Pair(
+                types::Pair::new(
+                    Vec::from([
+                        types::address::<Type>()
+                            .with_field_annotation("administrator_to_set".into()),
+                        types::nat::<Type>().with_field_annotation("token_id".into()),
+                    ]),
+                    None,
+                ),
+            ),
+        )
+    }
+}
+impl IntoMichelson for SetAdministrator {
+    fn into_michelson(self) -> ::tezos_michelson::Result<Data> {
+        let Self { administrator_to_set, token_id } = self;
+        Ok(
+            Data::Pair(
+                data::Pair::new(
+                    Vec::from([
+                        IntoMichelson::into_michelson(administrator_to_set)?,
+                        IntoMichelson::into_michelson(token_id)?,
+                    ]),
+                ),
+            ),
+        )
+    }
+}
+impl FromMichelson for SetAdministrator {
+    fn from_michelson(data: Data) -> ::tezos_michelson::Result<Self> {
+        let mut values_0 = internal::pair_values(data, 2)?.into_iter();
+        let administrator_to_set = FromMichelson::from_michelson(
+            values_0.next().ok_or(::tezos_michelson::Error::InvalidMichelsonData)?,
+        )?;
+        let token_id = FromMichelson::from_michelson(
+            values_0.next().ok_or(::tezos_michelson::Error::InvalidMichelsonData)?,
+        )?;
+        Ok(Self {
+            administrator_to_set,
+            token_id,
+        })
+    }
+}
+/// The same type as [`TreasuryLedgerKey`].
+pub type SetTreasury = TreasuryLedgerKey;
+#[derive(Debug, Clone, PartialEq)]
+pub struct TxsItem {
+    pub to_: Address,
+    pub token_id: Nat,
+    pub amount: Nat,
+}
+impl MichelsonType for TxsItem {
+    fn michelson_type() -> ::tezos_michelson::Result<Type> {
+        Ok(
+            Type::Pair(
+                types::Pair::new(
+                    Vec::from([
+                        types::address::<Type>().with_field_annotation("to_".into()),
+                        Type::Pair(
+                            types::Pair::new(
+                                Vec::from([
+                                    types::nat::<Type>()
+                                        .with_field_annotation("token_id".into()),
+                                    types::nat::<Type>().with_field_annotation("amount".into()),
+                                ]),
+                                None,
+                            ),
+                        ),
+                    ]),
+                    None,
+                ),
+            ),
+        )
+    }
+}
+impl IntoMichelson for TxsItem {
+    fn into_michelson(self) -> ::tezos_michelson::Result<Data> {
+        let Self { to_, token_id, amount } = self;
+        Ok(
+            Data::Pair(
+                data::Pair::new(
+                    Vec::from([
+                        IntoMichelson::into_michelson(to_)?,
+                        Data::Pair(
+                            data::Pair::new(
+                                Vec::from([
+                                    IntoMichelson::into_michelson(token_id)?,
+                                    IntoMichelson::into_michelson(amount)?,
+                                ]),
+                            ),
+                        ),
+                    ]),
+                ),
+            ),
+        )
+    }
+}
+impl FromMichelson for TxsItem {
+    fn from_michelson(data: Data) -> ::tezos_michelson::Result<Self> {
+        let mut values_0 = internal::pair_values(data, 2)?.into_iter();
+        let to_ = FromMichelson::from_michelson(
+            values_0.next().ok_or(::tezos_michelson::Error::InvalidMichelsonData)?,
+        )?;
+        let mut values_1 = internal::pair_values(
+                values_0.next().ok_or(::tezos_michelson::Error::InvalidMichelsonData)?,
+                2,
+            )?
+            .into_iter();
+        let token_id = FromMichelson::from_michelson(
+            values_1.next().ok_or(::tezos_michelson::Error::InvalidMichelsonData)?,
+        )?;
+        let amount = FromMichelson::from_michelson(
+            values_1.next().ok_or(::tezos_michelson::Error::InvalidMichelsonData)?,
+        )?;
+        Ok(Self { to_, token_id, amount })
+    }
+}
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransferItem {
+    pub from_: Address,
+    pub txs: Vec<TxsItem>,
+}
+impl MichelsonType for TransferItem {
+    fn michelson_type() -> ::tezos_michelson::Result<Type> {
+        Ok(
+            Type::Pair(
+                types::Pair::new(
+                    Vec::from([
+                        types::address::<Type>().with_field_annotation("from_".into()),
+                        Type::List(
+                                types::List::new(
+                                    <TxsItem as MichelsonType>::michelson_type()?,
+                                    None,
+                                ),
+                            )
+                            .with_field_annotation("txs".into()),
+                    ]),
+                    None,
+                ),
+            ),
+        )
+    }
+}
+impl IntoMichelson for TransferItem {
+    fn into_michelson(self) -> ::tezos_michelson::Result<Data> {
+        let Self { from_, txs } = self;
+        Ok(
+            Data::Pair(
+                data::Pair::new(
+                    Vec::from([
+                        IntoMichelson::into_michelson(from_)?,
+                        Data::Sequence(
+                            data::Sequence::new(
+                                txs
+                                    .into_iter()
+                                    .map(IntoMichelson::into_michelson)
+                                    .collect::<::tezos_michelson::Result<Vec<_>>>()?,
+                            ),
+                        ),
+                    ]),
+                ),
+            ),
+        )
+    }
+}
+impl FromMichelson for TransferItem {
+    fn from_michelson(data: Data) -> ::tezos_michelson::Result<Self> {
+        let mut values_0 = internal::pair_values(data, 2)?.into_iter();
+        let from_ = FromMichelson::from_michelson(
+            values_0.next().ok_or(::tezos_michelson::Error::InvalidMichelsonData)?,
+        )?;
+        let txs = internal::sequence_values(
+                values_0.next().ok_or(::tezos_michelson::Error::InvalidMichelsonData)?,
+            )?
+            .into_iter()
+            .map(FromMichelson::from_michelson)
+            .collect::<::tezos_michelson::Result<Vec<_>>>()?;
+        Ok(Self { from_, txs })
+    }
+}
+#[derive(Debug, Clone, PartialEq)]
+pub struct AddOperator {
+    pub owner: Address,
+    pub operator: Address,
+    pub token_id: Nat,
+}
+impl MichelsonType for AddOperator {
+    fn michelson_type() -> ::tezos_michelson::Result<Type> {
+        Ok(
+            Type::Pair(
+                types::Pair::new(
+                    Vec::from([
+                        types::address::<Type>().with_field_annotation("owner".into()),
+                        Type::Pair(
+                            types::Pair::new(
+                                Vec::from([
+                                    types::address::<Type>()
+                                        .with_field_annotation("operator".into()),
+                                    types::nat::<Type>()
+                                        .with_field_annotation("token_id".into()),
+                                ]),
+                                None,
+                            ),
+                        ),
+                    ]),
+                    None,
+                ),
+            ),
+        )
+    }
+}
+impl IntoMichelson for AddOperator {
+    fn into_michelson(self) -> ::tezos_michelson::Result<Data> {
+        let Self { owner, operator, token_id } = self;
+        Ok(
+            Data::Pair(
+                data::Pair::new(
+                    Vec::from([
+                        IntoMichelson::into_michelson(owner)?,
+                        Data::Pair(
+                            data::Pair::new(
+                                Vec::from([
+                                    IntoMichelson::into_michelson(operator)?,
+                                    IntoMichelson::into_michelson(token_id)?,
+                                ]),
+                            ),
+                        ),
+                    ]),
+                ),
+            ),
+        )
+    }
+}
+impl FromMichelson for AddOperator {
+    fn from_michelson(data: Data) -> ::tezos_michelson::Result<Self> {
+        let mut values_0 = internal::pair_values(data, 2)?.into_iter();
+        let owner = FromMichelson::from_michelson(
+            values_0.next().ok_or(::tezos_michelson::Error::InvalidMichelsonData)?,
+        )?;
+        let mut values_1 = internal::pair_values(
+                values_0.next().ok_or(::tezos_michelson::Error::InvalidMichelsonData)?,
+                2,
+            )?
+            .into_iter();
+        let operator = FromMichelson::from_michelson(
+            values_1.next().ok_or(::tezos_michelson::Error::InvalidMichelsonData)?,
+        )?;
+        let token_id = FromMichelson::from_michelson(
+            values_1.next().ok_or(::tezos_michelson::Error::InvalidMichelsonData)?,
+        )?;
+        Ok(Self { owner, operator, token_id })
+    }
+}
+/// The same type as [`AddOperator`].
+pub type RemoveOperator = AddOperator;
+#[derive(Debug, Clone, PartialEq)]
+pub enum UpdateOperatorsItem {
+    AddOperator(AddOperator),
+    RemoveOperator(RemoveOperator),
+}
+impl MichelsonType for UpdateOperatorsItem {
+    fn michelson_type() -> ::tezos_michelson::Result<Type> {
+        Ok(
+            Type::Or(
+                types::Or::new(
+                    <AddOperator as MichelsonType>::michelson_type()?
+                        .with_field_annotation("add_operator".into()),
+                    <AddOperator as MichelsonType>::michelson_type()?
+                        .with_field_annotation("remove_operator".into()),
+                    None,
+                ),
+            ),
+        )
+    }
+}
+impl IntoMichelson for UpdateOperatorsItem {
+    fn into_michelson(self) -> ::tezos_michelson::Result<Data> {
+        Ok(
+            match self {
+                Self::AddOperator(value) => {
+                    Data::Left(data::Left::new(IntoMichelson::into_michelson(value)?))
+                }
+                Self::RemoveOperator(value) => {
+                    Data::Right(data::Right::new(IntoMichelson::into_michelson(value)?))
+                }
+            },
+        )
+    }
+}
+impl FromMichelson for UpdateOperatorsItem {
+    fn from_michelson(data: Data) -> ::tezos_michelson::Result<Self> {
+        Ok(
+            match internal::branch(data)? {
+                internal::Branch::Left(value) => {
+                    Self::AddOperator(FromMichelson::from_michelson(value)?)
+                }
+                internal::Branch::Right(value) => {
+                    Self::RemoveOperator(FromMichelson::from_michelson(value)?)
+                }
+            },
+        )
+    }
+}
+#[derive(Debug, Clone, PartialEq)]
+pub struct UpdateStake {
+    pub address: Address,
+    pub amount: Nat,
+}
+impl MichelsonType for UpdateStake {
+    fn michelson_type() -> ::tezos_michelson::Result<Type> {
+        Ok(
+            Type::Pair(
+                types::Pair::new(
+                    Vec::from([
+                        types::address::<Type>().with_field_annotation("address".into()),
+                        types::nat::<Type>().with_field_annotation("amount".into()),
+                    ]),
+                    None,
+                ),
+            ),
+        )
+    }
+}
+impl IntoMichelson for UpdateStake {
+    fn into_michelson(self) -> ::tezos_michelson::Result<Data> {
+        let Self { address, amount } = self;
+        Ok(
+            Data::Pair(
+                data::Pair::new(
+                    Vec::from([
+                        IntoMichelson::into_michelson(address)?,
+                        IntoMichelson::into_michelson(amount)?,
+                    ]),
+                ),
+            ),
+        )
+    }
+}
+impl FromMichelson for UpdateStake {
+    fn from_michelson(data: Data) -> ::tezos_michelson::Result<Self> {
+        let mut values_0 = internal::pair_values(data, 2)?.into_iter();
+        let address = FromMichelson::from_michelson(
+            values_0.next().ok_or(::tezos_michelson::Error::InvalidMichelsonData)?,
+        )?;
+        let amount = FromMichelson::from_michelson(
+            values_0.next().ok_or(::tezos_michelson::Error::InvalidMichelsonData)?,
+        )?;
+        Ok(Self { address, amount })
+    }
+}
+/// The same type as [`TreasuryLedgerKey`].
+pub type AdministratorsKey = TreasuryLedgerKey;
+/// The same type as [`TreasuryLedgerKey`].
+pub type LedgerKey = TreasuryLedgerKey;
+/// The same type as [`AddOperator`].
+pub type OperatorsKey = AddOperator;
+#[derive(Debug, Clone, PartialEq)]
+pub struct TokenMetadataValue {
+    pub token_id: Nat,
+    pub token_info: Vec<(String, Bytes)>,
+}
+impl MichelsonType for TokenMetadataValue {
+    fn michelson_type() -> ::tezos_michelson::Result<Type> {
+        Ok(
+            Type::Pair(
+                types::Pair::new(
+                    Vec::from([
+                        types::nat::<Type>().with_field_annotation("token_id".into()),
+                        Type::Map(
+                                types::Map::new(
+                                    types::string::<Type>(),
+                                    types::bytes::<Type>(),
+                                    None,
+                                ),
+                            )
+                            .with_field_annotation("token_info".into()),
+                    ]),
+                    None,
+                ),
+            ),
+        )
+    }
+}
+impl IntoMichelson for TokenMetadataValue {
+    fn into_michelson(self) -> ::tezos_michelson::Result<Data> {
+        let Self { token_id, token_info } = self;
+        Ok(
+            Data::Pair(
+                data::Pair::new(
+                    Vec::from([
+                        IntoMichelson::into_michelson(token_id)?,
+                        Data::Map(
+                            data::Map::new(
+                                token_info
+                                    .into_iter()
+                                    .map(|(key, value)| Ok(
+                                        data::Elt::new(
+                                            IntoMichelson::into_michelson(key)?,
+                                            IntoMichelson::into_michelson(value)?,
+                                        ),
+                                    ))
+                                    .collect::<::tezos_michelson::Result<Vec<_>>>()?,
+                            ),
+                        ),
+                    ]),
+                ),
+            ),
+        )
+    }
+}
+impl FromMichelson for TokenMetadataValue {
+    fn from_michelson(data: Data) -> ::tezos_michelson::Result<Self> {
+        let mut values_0 = internal::pair_values(data, 2)?.into_iter();
+        let token_id = FromMichelson::from_michelson(
+            values_0.next().ok_or(::tezos_michelson::Error::InvalidMichelsonData)?,
+        )?;
+        let token_info = internal::map_entries(
+                values_0.next().ok_or(::tezos_michelson::Error::InvalidMichelsonData)?,
+            )?
+            .into_iter()
+            .map(|(key, value)| Ok((
+                FromMichelson::from_michelson(key)?,
+                FromMichelson::from_michelson(value)?,
+            )))
+            .collect::<::tezos_michelson::Result<Vec<_>>>()?;
+        Ok(Self { token_id, token_info })
+    }
+}
@@ -0,0 +1,14 @@
+use std::result;
+
+use derive_more::{Display, Error as DError, From};
+
+#[derive(DError, Display, Debug, From)]
+pub enum Error {
+    Contract { source: tezos_contract::Error },
+    Michelson { source: tezos_michelson::Error },
+    Json { source: serde_json::Error },
+    Syntax { source: syn::Error },
+    InvalidContractScript,
+}
+
+pub type Result<T> = result::Result<T, Error>;
@@ -0,0 +1,885 @@
+use proc_macro2::{Ident, Literal as TokenLiteral, TokenStream};
+use quote::{format_ident, quote};
+use tezos_contract::MappedEntrypoints;
+use tezos_michelson::{
+    micheline::{literals::Literal, Micheline},
+    michelson::types::{ComparableType, Or, Pair, Parameter, Storage, Type},
+};
+use tezos_operation::operations::Entrypoint;
+use tezos_rpc::models::contract::ContractScript;
+
+use crate::{
+    naming::{field_ident, method_ident, type_ident},
+    Error, Result,
+};
+
+pub(crate) struct Generator {
+    items: Vec<TokenStream>,
+    /// The generated type names, along with their Michelson type stripped of its top level annotations.
+    generated: Vec<(String, Type)>,
+}
+
+struct Field {
+    ident: Ident,
+    rust_type: TokenStream,
+}
+
+struct Variant {
+    ident: Ident,
+    payload: Option<TokenStream>,
+    r#type: Type,
+    /// The branches leading to the variant, `true` standing for `Left`.
+    path: Vec<bool>,
+}
+
+impl Generator {
+    pub(crate) fn new() -> Self {
+        Self {
+            items: Vec::new(),
+            generated: Vec::new(),
+        }
+    }
+
+    pub(crate) fn generate(
+        mut self,
+        contract_name: &str,
+        script: &ContractScript,
+    ) -> Result<TokenStream> {
+        let mut sections = script.code.clone().normalized().into_values().into_iter();
+        let parameter: Parameter = sections
+            .next()
+            .ok_or(Error::InvalidContractScript)?
+            .try_into()?;
+        let storage: Storage = sections
+            .next()
+            .ok_or(Error::InvalidContractScript)?
+            .try_into()?;
+        let entrypoints = MappedEntrypoints::new(parameter)?;
+
+        let contract = format_ident!("{}", type_ident(contract_name));
+        let storage_type = *storage.r#type;
+        let storage_rust_type = self.rust_type(&storage_type, &format!("{}Storage", contract))?;
+
+        let mut methods = Vec::new();
+        let mut named_entrypoints = entrypoints.entrypoints();
+        if named_entrypoints.is_empty() {
+            named_entrypoints.push((&Entrypoint::Default, entrypoints.parameter_type()));
+        }
+        for (entrypoint, r#type) in named_entrypoints {
+            methods.push(self.entrypoint_method(entrypoint.to_str(), r#type)?);
+        }
+        for (index, (name, key_type, value_type)) in big_maps(&storage_type).into_iter().enumerate()
+        {
+            methods.push(self.big_map_method(index, name, key_type, value_type)?);
+        }
+
+        let items = &self.items;
+        let wrapper_doc = format!(" Typed bindings to the `{}` contract.", contract_name);
+
+        Ok(quote! {
+            #[allow(unused_imports)]
+            use ::tezos_contract::{Contract, ContractFetcher, Error, PartialTransaction, Result};
+            #[allow(unused_imports)]
+            use ::tezos_core::types::{
+                encoded::{Address, ChainId, ContractHash, ImplicitAddress, PublicKey, Signature},
+                mutez::Mutez,
+                number::{Int, Nat},
+                timestamp::Timestamp,
+            };
+            #[allow(unused_imports)]
+            use ::tezos_michelson::{
+                micheline::{self, Micheline},
+                michelson::{
+                    convert::{internal, FromMichelson, IntoMichelson, MichelsonType},
+                    data::{self, Bytes, Data},
+                    types::{self, Type},
+                    Michelson,
+                },
+            };
+            #[allow(unused_imports)]
+            use ::tezos_operation::operations::Parameters;
+            #[allow(unused_imports)]
+            use ::tezos_rpc::{client::TezosRpc, http::Http, models::block::BlockId};
+
+            #[doc = #wrapper_doc]
+            pub struct #contract<'a, HttpClient: Http> {
+                contract: Contract<'a, HttpClient>,
+            }
+
+            impl<'a, HttpClient: Http + Sync> #contract<'a, HttpClient> {
+                pub fn new(contract: Contract<'a, HttpClient>) -> Self {
+                    Self { contract }
+                }
+
+                /// Fetches the contract at `address`.
+                pub async fn at(
+                    client: &'a TezosRpc<HttpClient>,
+                    address: ContractHash,
+                    block_id: Option<&BlockId>,
+                ) -> Result<Self> {
+                    Ok(Self::new(client.contract_at(address, block_id).await?))
+                }
+
+                pub fn contract(&self) -> &Contract<'a, HttpClient> {
+                    &self.contract
+                }
+
+                /// Decodes the storage fetched along with the contract.
+                pub fn storage(&self) -> Result<#storage_rust_type> {
+                    self.contract.storage().decode_as()
+                }
+
+                #(#methods)*
+            }
+
+            #(#items)*
+        })
+    }
+
+    fn entrypoint_method(&mut self, entrypoint: &str, r#type: &Type) -> Result<TokenStream> {
+        let method = method_ident(entrypoint);
+        let hint = format!("{}Parameter", type_ident(entrypoint));
+        let (argument, value) = if is_unit(r#type) {
+            (quote!(), quote!(data::unit::<Data>()))
+        } else {
+            let rust_type = self.rust_type(r#type, &hint)?;
+            let value = self.encode(r#type, quote!(value));
+            (quote!(, value: #rust_type), value)
+        };
+        let doc = format!(" Creates a call to the `{}` entrypoint.", entrypoint);
+
+        Ok(quote! {
+            #[doc = #doc]
+            pub fn #method(&self #argument) -> Result<PartialTransaction> {
+                let value = Micheline::from(#value);
+                Ok(PartialTransaction::new(
+                    0u8.into(),
+                    self.contract.address().into(),
+                    Some(Parameters::new(#entrypoint.into(), value.normalized())),
+                ))
+            }
+        })
+    }
+
+    fn big_map_method(
+        &mut self,
+        index: usize,
+        name: Option<String>,
+        key_type: &Type,
+        value_type: &Type,
+    ) -> Result<TokenStream> {
+        let (method, big_map, hint) = match &name {
+            Some(name) => (
+                format_ident!(
+                    "get_{}",
+                    field_ident(name).to_string().trim_start_matches("r#")
+                ),
+                quote!(self.contract.storage().big_maps().get_by_name(#name)),
+                type_ident(name),
+            ),
+            None => (
+                format_ident!("get_big_map_{}", index),
+                {
+                    let index = TokenLiteral::usize_unsuffixed(index);
+                    quote!(self.contract.storage().big_maps().get_by_index(#index))
+                },
+                format!("BigMap{}", index),
+            ),
+        };
+        let name = name.unwrap_or_else(|| index.to_string());
+        let key_rust_type = self.rust_type(key_type, &format!("{}Key", hint))?;
+        let value_rust_type = self.rust_type(value_type, &format!("{}Value", hint))?;
+        let key = self.encode(key_type, quote!(key));
+        let value = self.decode(value_type, quote!(data));
+        let doc = format!(
+            " Retrieves the value stored at `key` in the `{}` big map.",
+            name
+        );
+        let not_found = format!("big map `{}` not found", name);
+
+        Ok(quote! {
+            #[doc = #doc]
+            pub async fn #method(
+                &self,
+                key: #key_rust_type,
+                block_id: Option<&BlockId>,
+            ) -> Result<#value_rust_type> {
+                let big_map = #big_map.ok_or(Error::Internal {
+                    description: #not_found.into(),
+                })?;
+                let data = Data::try_from(big_map.get_value(Michelson::from(#key), block_id).await?)?;
+                Ok(#value)
+            }
+        })
+    }
+
+    /// Returns the Rust type representing the values of `r#type`, generating structs for `pair`s and enums for `or`s.
+    fn rust_type(&mut self, r#type: &Type, hint: &str) -> Result<TokenStream> {
+        let hint = annotation(r#type)
+            .map(|name| type_ident(&name))
+            .unwrap_or_else(|| hint.into());
+        Ok(match r#type {
+            Type::Comparable(comparable) => match comparable {
+                ComparableType::Unit(_) => quote!(()),
+                ComparableType::Bool(_) => quote!(bool),
+                ComparableType::Int(_) => quote!(Int),
+                ComparableType::Nat(_) => quote!(Nat),
+                ComparableType::String(_) => quote!(String),
+                ComparableType::ChainId(_) => quote!(ChainId),
+                ComparableType::Bytes(_) => quote!(Bytes),
+                ComparableType::Mutez(_) => quote!(Mutez),
+                ComparableType::KeyHash(_) => quote!(ImplicitAddress),
+                ComparableType::Key(_) => quote!(PublicKey),
+                ComparableType::Signature(_) => quote!(Signature),
+                ComparableType::Timestamp(_) => quote!(Timestamp),
+                ComparableType::Address(_) => quote!(Address),
+                ComparableType::Option(_) | ComparableType::Or(_) | ComparableType::Pair(_) => {
+                    self.rust_type(&expanded(comparable)?, &hint)?
+                }
+                _ => quote!(Data),
+            },
+            Type::Option(option) => {
+                let inner = self.rust_type(&option.r#type, &hint)?;
+                quote!(Option<#inner>)
+            }
+            Type::List(list) => {
+                let inner = self.rust_type(&list.r#type, &format!("{}Item", hint))?;
+                quote!(Vec<#inner>)
+            }
+            Type::Set(set) => {
+                let inner = self.rust_type(&set.r#type.clone().into(), &format!("{}Item", hint))?;
+                quote!(Vec<#inner>)
+            }
+            Type::Map(map) => {
+                let key = self.rust_type(&map.key_type, &format!("{}Key", hint))?;
+                let value = self.rust_type(&map.value_type, &format!("{}Value", hint))?;
+                quote!(Vec<(#key, #value)>)
+            }
+            Type::BigMap(_) => quote!(Int),
+            Type::Contract(_) => quote!(Address),
+            Type::Pair(pair) => {
+                let ident = self.generate_struct(r#type, pair, &hint)?;
+                quote!(#ident)
+            }
+            Type::Or(or) => {
+                let ident = self.generate_enum(r#type, or, &hint)?;
+                quote!(#ident)
+            }
+            _ => quote!(Data),
+        })
+    }
+
+    /// Reserves a name for the type generated for `r#type`, returning `true` if it still has to be generated.
+    ///
+    /// A type already generated under another name is given an alias named after `hint`, so that every use of a
+    /// shared type keeps a name of its own.
+    fn reserve_name(&mut self, r#type: &Type, hint: &str) -> (Ident, bool) {
+        let stripped = without_annotations(r#type);
+        let is_reserved = self
+            .generated
+            .iter()
+            .any(|(name, r#type)| name == hint && r#type == &stripped);
+        if is_reserved {
+            return (format_ident!("{}", hint), false);
+        }
+        let generated = self.generated_name(r#type).map(String::from);
+
+        let name = unique_name(hint, |name| {
+            self.generated
+                .iter()
+                .any(|(generated, _)| generated == name)
+        });
+        self.generated.push((name.clone(), stripped));
+        let ident = format_ident!("{}", name);
+        match generated {
+            Some(generated) => {
+                let target = format_ident!("{}", generated);
+                let doc = format!(" The same type as [`{}`].", generated);
+                self.items.push(quote! {
+                    #[doc = #doc]
+                    pub type #ident = #target;
+                });
+                (ident, false)
+            }
+            None => (ident, true),
+        }
+    }
+
+    fn generate_struct(&mut self, r#type: &Type, pair: &Pair, hint: &str) -> Result<Ident> {
+        let (ident, is_new) = self.reserve_name(r#type, hint);
+        if !is_new {
+            return Ok(ident);
+        }
+
+        let mut leaves = Vec::new();
+        pair_leaves(pair, &mut leaves);
+        let mut fields = Vec::new();
+        for (index, leaf) in leaves.into_iter().enumerate() {
+            let name = annotation(leaf).unwrap_or_else(|| format!("arg{}", index));
+            let rust_type = self.rust_type(leaf, &format!("{}{}", ident, type_ident(&name)))?;
+            let name = field_ident(&name).to_string();
+            let name = unique_name(name.trim_start_matches("r#"), |name| {
+                fields
+                    .iter()
+                    .any(|field: &Field| field.ident.to_string().trim_start_matches("r#") == name)
+            });
+            fields.push(Field {
+                ident: field_ident(&name),
+                rust_type,
+            });
+        }
+
+        let definitions = fields
+            .iter()
+            .map(|Field { ident, rust_type }| quote!(pub #ident: #rust_type));
+        let bindings = fields.iter().map(|field| &field.ident);
+        let constructor = fields.iter().map(|field| &field.ident);
+        let into_michelson = self.encode_pair(pair, &mut fields.iter());
+        let mut statements = Vec::new();
+        self.decode_pair(
+            pair,
+            quote!(data),
+            &mut fields.iter(),
+            &mut statements,
+            &mut 0,
+        );
+        let michelson_type = self.type_expr(r#type, true);
+
+        self.items.push(quote! {
+            #[derive(Debug, Clone, PartialEq)]
+            pub struct #ident {
+                #(#definitions,)*
+            }
+
+            impl MichelsonType for #ident {
+                fn michelson_type() -> ::tezos_michelson::Result<Type> {
+                    Ok(#michelson_type)
+                }
+            }
+
+            impl IntoMichelson for #ident {
+                fn into_michelson(self) -> ::tezos_michelson::Result<Data> {
+                    let Self { #(#bindings),* } = self;
+                    Ok(#into_michelson)
+                }
+            }
+
+            impl FromMichelson for #ident {
+                fn from_michelson(data: Data) -> ::tezos_michelson::Result<Self> {
+                    #(#statements)*
+                    Ok(Self { #(#constructor),* })
+                }
+            }
+        });
+
+        Ok(ident)
+    }
+
+    fn encode_pair<'a>(
+        &self,
+        pair: &Pair,
+        fields: &mut impl Iterator<Item = &'a Field>,
+    ) -> TokenStream {
+        let values = pair
+            .types
+            .iter()
+            .map(|r#type| match r#type {
+                Type::Pair(pair) if annotation(r#type).is_none() => self.encode_pair(pair, fields),
+                _ => {
+                    let field = &fields.next().expect("a field per pair leaf").ident;
+                    self.encode(r#type, quote!(#field))
+                }
+            })
+            .collect::<Vec<_>>();
+
+        quote!(Data::Pair(data::Pair::new(Vec::from([#(#values),*]))))
+    }
+
+    fn decode_pair<'a>(
+        &self,
+        pair: &Pair,
+        value: TokenStream,
+        fields: &mut impl Iterator<Item = &'a Field>,
+        statements: &mut Vec<TokenStream>,
+        counter: &mut usize,
+    ) {
+        let values = format_ident!("values_{}", counter);
+        *counter += 1;
+        let count = TokenLiteral::usize_unsuffixed(pair.types.len());
+        statements.push(quote! {
+            let mut #values = internal::pair_values(#value, #count)?.into_iter();
+        });
+        for r#type in pair.types.iter() {
+            let next =
+                quote!(#values.next().ok_or(::tezos_michelson::Error::InvalidMichelsonData)?);
+            match r#type {
+                Type::Pair(pair) if annotation(r#type).is_none() => {
+                    self.decode_pair(pair, next, fields, statements, counter)
+                }
+                _ => {
+                    let field = &fields.next().expect("a field per pair leaf").ident;
+                    let decoded = self.decode(r#type, next);
+                    statements.push(quote!(let #field = #decoded;));
+                }
+            }
+        }
+    }
+
+    fn generate_enum(&mut self, r#type: &Type, or: &Or, hint: &str) -> Result<Ident> {
+        let (ident, is_new) = self.reserve_name(r#type, hint);
+        if !is_new {
+            return Ok(ident);
+        }
+
+        let mut leaves = Vec::new();
+        or_leaves(or, &mut vec![], &mut leaves);
+        let mut variants = Vec::new();
+        for (index, (leaf, path)) in leaves.into_iter().enumerate() {
+            let name = annotation(leaf)
+                .map(|name| type_ident(&name))
+                .unwrap_or_else(|| format!("Variant{}", index));
+            let name = unique_name(&name, |name| {
+                variants
+                    .iter()
+                    .any(|variant: &Variant| variant.ident == name)
+            });
+            let payload = if is_unit(leaf) {
+                None
+            } else {
+                Some(self.rust_type(leaf, &format!("{}{}", ident, name))?)
+            };
+            variants.push(Variant {
+                ident: format_ident!("{}", name),
+                payload,
+                r#type: leaf.clone(),
+                path,
+            });
+        }
+
+        let definitions = variants.iter().map(|variant| {
+            let ident = &variant.ident;
+            match &variant.payload {
+                Some(payload) => quote!(#ident(#payload)),
+                None => quote!(#ident),
+            }
+        });
+        let arms = variants.iter().map(|variant| {
+            let ident = &variant.ident;
+            let (pattern, value) = match &variant.payload {
+                Some(_) => (
+                    quote!(Self::#ident(value)),
+                    self.encode(&variant.r#type, quote!(value)),
+                ),
+                None => (quote!(Self::#ident), quote!(data::unit::<Data>())),
+            };
+            let value = variant.path.iter().rev().fold(value, |value, is_left| {
+                if *is_left {
+                    quote!(Data::Left(data::Left::new(#value)))
+                } else {
+                    quote!(Data::Right(data::Right::new(#value)))
+                }
+            });
+            quote!(#pattern => #value)
+        });
+        let from_michelson = self.decode_or(or, quote!(data), &variants, &mut vec![]);
+        let michelson_type = self.type_expr(r#type, true);
+
+        self.items.push(quote! {
+            #[derive(Debug, Clone, PartialEq)]
+            pub enum #ident {
+                #(#definitions,)*
+            }
+
+            impl MichelsonType for #ident {
+                fn michelson_type() -> ::tezos_michelson::Result<Type> {
+                    Ok(#michelson_type)
+                }
+            }
+
+            impl IntoMichelson for #ident {
+                fn into_michelson(self) -> ::tezos_michelson::Result<Data> {
+                    Ok(match self {
+                        #(#arms,)*
+                    })
+                }
+            }
+
+            impl FromMichelson for #ident {
+                fn from_michelson(data: Data) -> ::tezos_michelson::Result<Self> {
+                    Ok(#from_michelson)
+                }
+            }
+        });
+
+        Ok(ident)
+    }
+
+    fn decode_or(
+        &self,
+        or: &Or,
+        value: TokenStream,
+        variants: &[Variant],
+        path: &mut Vec<bool>,
+    ) -> TokenStream {
+        let mut branch = |r#type: &Type, is_left: bool| {
+            path.push(is_left);
+            let result = match r#type {
+                Type::Or(or) if annotation(r#type).is_none() => {
+                    self.decode_or(or, quote!(value), variants, path)
+                }
+                _ => {
+                    let variant = variants
+                        .iter()
+                        .find(|variant| variant.path.as_slice() == path.as_slice())
+                        .expect("a variant per or leaf");
+                    let ident = &variant.ident;
+                    match &variant.payload {
+                        Some(_) => {
+                            let decoded = self.decode(&variant.r#type, quote!(value));
+                            quote!(Self::#ident(#decoded))
+                        }
+                        None => quote!({
+                            <()>::from_michelson(value)?;
+                            Self::#ident
+                        }),
+                    }
+                }
+            };
+            path.pop();
+            result
+        };
+        let lhs = branch(&or.lhs, true);
+        let rhs = branch(&or.rhs, false);
+
+        quote! {
+            match internal::branch(#value)? {
+                internal::Branch::Left(value) => #lhs,
+                internal::Branch::Right(value) => #rhs,
+            }
+        }
+    }
+
+    /// The name of the type generated for `r#type`, if any.
+    fn generated_name(&self, r#type: &Type) -> Option<&str> {
+        let stripped = without_annotations(r#type);
+        self.generated
+            .iter()
+            .find(|(_, generated)| generated == &stripped)
+            .map(|(name, _)| name.as_str())
+    }
+
+    /// An expression creating `r#type`, referring to the types generated for its nested `pair`s and `or`s, to be used
+    /// in a function returning a `tezos_michelson::Result`.
+    fn type_expr(&self, r#type: &Type, is_root: bool) -> TokenStream {
+        let expr = match r#type {
+            Type::Comparable(
+                comparable @ (ComparableType::Option(_)
+                | ComparableType::Or(_)
+                | ComparableType::Pair(_)),
+            ) => match expanded(comparable) {
+                Ok(expanded) => return self.type_expr(&expanded, is_root),
+                Err(_) => micheline_type_expr(r#type),
+            },
+            Type::Comparable(_) | Type::Operation(_) => match Micheline::from(r#type) {
+                Micheline::PrimitiveApplication(primitive_application) => {
+                    let helper = format_ident!("{}", primitive_application.prim());
+                    quote!(types::#helper::<Type>())
+                }
+                _ => micheline_type_expr(r#type),
+            },
+            Type::Pair(_) | Type::Or(_) if !is_root && self.generated_name(r#type).is_some() => {
+                let ident = format_ident!("{}", self.generated_name(r#type).unwrap_or_default());
+                quote!(<#ident as MichelsonType>::michelson_type()?)
+            }
+            Type::Pair(pair) => {
+                let types = pair.types.iter().map(|r#type| match r#type {
+                    Type::Pair(_) if annotation(r#type).is_none() => self.type_expr(r#type, true),
+                    _ => self.type_expr(r#type, false),
+                });
+                quote!(Type::Pair(types::Pair::new(Vec::from([#(#types),*]), None)))
+            }
+            Type::Or(or) => {
+                let branch = |r#type: &Type| match r#type {
+                    Type::Or(_) if annotation(r#type).is_none() => self.type_expr(r#type, true),
+                    _ => self.type_expr(r#type, false),
+                };
+                let (lhs, rhs) = (branch(&or.lhs), branch(&or.rhs));
+                quote!(Type::Or(types::Or::new(#lhs, #rhs, None)))
+            }
+            Type::Option(option) => {
+                let inner = self.type_expr(&option.r#type, false);
+                quote!(Type::Option(types::Option::new(#inner, None)))
+            }
+            Type::List(list) => {
+                let inner = self.type_expr(&list.r#type, false);
+                quote!(Type::List(types::List::new(#inner, None)))
+            }
+            Type::Contract(contract) => {
+                let inner = self.type_expr(&contract.r#type, false);
+                quote!(Type::Contract(types::Contract::new(#inner, None)))
+            }
+            Type::Ticket(ticket) => {
+                let inner = self.type_expr(&ticket.r#type, false);
+                quote!(Type::Ticket(types::Ticket::new(#inner, None)))
+            }
+            Type::Map(map) => {
+                let key = self.type_expr(&map.key_type, false);
+                let value = self.type_expr(&map.value_type, false);
+                quote!(Type::Map(types::Map::new(#key, #value, None)))
+            }
+            Type::BigMap(big_map) => {
+                let key = self.type_expr(&big_map.key_type, false);
+                let value = self.type_expr(&big_map.value_type, false);
+                quote!(Type::BigMap(types::BigMap::new(#key, #value, None)))
+            }
+            Type::Lambda(lambda) => {
+                let parameter = self.type_expr(&lambda.parameter_type, false);
+                let r#return = self.type_expr(&lambda.return_type, false);
+                quote!(Type::Lambda(types::Lambda::new(#parameter, #r#return, None)))
+            }
+            _ => micheline_type_expr(r#type),
+        };
+
+        match annotation(r#type) {
+            Some(name) if !is_root => quote!(#expr.with_field_annotation(#name.into())),
+            _ => expr,
+        }
+    }
+
+    /// An expression converting `value`, of the Rust type representing `r#type`, into [Data], to be used in a function
+    /// returning a `Result` whose error can be converted from `tezos_michelson::Error`.
+    fn encode(&self, r#type: &Type, value: TokenStream) -> TokenStream {
+        match r#type {
+            Type::Comparable(
+                comparable @ (ComparableType::Option(_)
+                | ComparableType::Or(_)
+                | ComparableType::Pair(_)),
+            ) => match expanded(comparable) {
+                Ok(r#type) => self.encode(&r#type, value),
+                Err(_) => value,
+            },
+            Type::Comparable(ComparableType::Never(_)) => value,
+            Type::Comparable(_)
+            | Type::BigMap(_)
+            | Type::Contract(_)
+            | Type::Pair(_)
+            | Type::Or(_) => {
+                quote!(IntoMichelson::into_michelson(#value)?)
+            }
+            Type::Option(option) => {
+                let inner = self.encode(&option.r#type, quote!(value));
+                quote!(match #value {
+                    Some(value) => Data::Some(data::Some::new(#inner)),
+                    None => data::none::<Data>(),
+                })
+            }
+            Type::List(list) => self.encode_sequence(&list.r#type, value),
+            Type::Set(set) => self.encode_sequence(&set.r#type.clone().into(), value),
+            Type::Map(map) => {
+                let key = self.encode(&map.key_type, quote!(key));
+                let element = self.encode(&map.value_type, quote!(value));
+                quote!(Data::Map(data::Map::new(
+                    #value
+                        .into_iter()
+                        .map(|(key, value)| Ok(data::Elt::new(#key, #element)))
+                        .collect::<::tezos_michelson::Result<Vec<_>>>()?
+                )))
+            }
+            _ => value,
+        }
+    }
+
+    fn encode_sequence(&self, r#type: &Type, value: TokenStream) -> TokenStream {
+        let element = self.encode(r#type, quote!(value));
+        let function =
+            if element.to_string() == quote!(IntoMichelson::into_michelson(value)?).to_string() {
+                quote!(IntoMichelson::into_michelson)
+            } else {
+                quote!(|value| Ok(#element))
+            };
+        quote!(Data::Sequence(data::Sequence::new(
+            #value
+                .into_iter()
+                .map(#function)
+                .collect::<::tezos_michelson::Result<Vec<_>>>()?
+        )))
+    }
+
+    /// An expression converting the [Data] `value` into the Rust type representing `r#type`, to be used in a function
+    /// returning a `Result` whose error can be converted from `tezos_michelson::Error`.
+    fn decode(&self, r#type: &Type, value: TokenStream) -> TokenStream {
+        match r#type {
+            Type::Comparable(
+                comparable @ (ComparableType::Option(_)
+                | ComparableType::Or(_)
+                | ComparableType::Pair(_)),
+            ) => match expanded(comparable) {
+                Ok(r#type) => self.decode(&r#type, value),
+                Err(_) => value,
+            },
+            Type::Comparable(ComparableType::Never(_)) => value,
+            Type::Comparable(_)
+            | Type::BigMap(_)
+            | Type::Contract(_)
+            | Type::Pair(_)
+            | Type::Or(_) => {
+                quote!(FromMichelson::from_michelson(#value)?)
+            }
+            Type::Option(option) => {
+                let inner = self.decode(&option.r#type, quote!(value));
+                quote!(match internal::option_value(#value)? {
+                    Some(value) => Some(#inner),
+                    None => None,
+                })
+            }
+            Type::List(list) => self.decode_sequence(&list.r#type, value),
+            Type::Set(set) => self.decode_sequence(&set.r#type.clone().into(), value),
+            Type::Map(map) => {
+                let key = self.decode(&map.key_type, quote!(key));
+                let element = self.decode(&map.value_type, quote!(value));
+                quote!(internal::map_entries(#value)?
+                    .into_iter()
+                    .map(|(key, value)| Ok((#key, #element)))
+                    .collect::<::tezos_michelson::Result<Vec<_>>>()?)
+            }
+            _ => value,
+        }
+    }
+
+    fn decode_sequence(&self, r#type: &Type, value: TokenStream) -> TokenStream {
+        let element = self.decode(r#type, quote!(value));
+        let function =
+            if element.to_string() == quote!(FromMichelson::from_michelson(value)?).to_string() {
+                quote!(FromMichelson::from_michelson)
+            } else {
+                quote!(|value| Ok(#element))
+            };
+        quote!(internal::sequence_values(#value)?
+            .into_iter()
+            .map(#function)
+            .collect::<::tezos_michelson::Result<Vec<_>>>()?)
+    }
+}
+
+/// An expression creating `r#type` out of its Micheline representation.
+fn micheline_type_expr(r#type: &Type) -> TokenStream {
+    let micheline = micheline_expr(&without_annotations(r#type).into());
+    quote!(Type::try_from(#micheline)?)
+}
+
+fn micheline_expr(value: &Micheline) -> TokenStream {
+    match value {
+        Micheline::PrimitiveApplication(primitive_application) => {
+            let prim = primitive_application.prim();
+            let mut expr = quote!(micheline::primitive_application(#prim));
+            if let Some(args) = primitive_application.args() {
+                let args = args.iter().map(micheline_expr);
+                expr = quote!(#expr.with_args(Vec::from([#(#args),*])));
+            }
+            if let Some(annots) = primitive_application
+                .annots()
+                .as_ref()
+                .filter(|annots| !annots.is_empty())
+            {
+                expr = quote!(#expr.with_annots(Vec::from([#(#annots.into()),*])));
+            }
+            quote!(Micheline::from(#expr))
+        }
+        Micheline::Literal(Literal::Int(value)) => {
+            let value = value.to_string();
+            quote!(micheline::try_int::<_, Micheline, _>(#value)?)
+        }
+        Micheline::Literal(_) => quote!(unreachable!("types only contain int literals")),
+        Micheline::Sequence(sequence) => {
+            let values = sequence.values().iter().map(micheline_expr);
+            quote!(micheline::sequence::<_, Micheline>(Vec::from([#(#values),*])))
+        }
+    }
+}
+
+/// The full [Type] corresponding to a comparable `option`, `or` or `pair`.
+fn expanded(r#type: &ComparableType) -> Result<Type> {
+    let micheline: Micheline = r#type.into();
+
+    Ok(micheline.try_into()?)
+}
+
+fn annotation(r#type: &Type) -> Option<String> {
+    r#type
+        .metadata()
+        .field_name()
+        .as_ref()
+        .map(|name| name.value_without_prefix().to_string())
+}
+
+fn is_unit(r#type: &Type) -> bool {
+    matches!(r#type, Type::Comparable(ComparableType::Unit(_)))
+}
+
+/// Collects the components of a pair, flattening its unannotated nested pairs.
+fn pair_leaves<'a>(pair: &'a Pair, leaves: &mut Vec<&'a Type>) {
+    for r#type in pair.types.iter() {
+        match r#type {
+            Type::Pair(pair) if annotation(r#type).is_none() => pair_leaves(pair, leaves),
+            _ => leaves.push(r#type),
+        }
+    }
+}
+
+/// Collects the branches of an or, flattening its unannotated nested ors, along with their path.
+fn or_leaves<'a>(or: &'a Or, path: &mut Vec<bool>, leaves: &mut Vec<(&'a Type, Vec<bool>)>) {
+    for (r#type, is_left) in [(&*or.lhs, true), (&*or.rhs, false)] {
+        path.push(is_left);
+        match r#type {
+            Type::Or(or) if annotation(r#type).is_none() => or_leaves(or, path, leaves),
+            _ => leaves.push((r#type, path.clone())),
+        }
+        path.pop();
+    }
+}
+
+/// Returns `hint`, suffixed with the first number that makes it unique if it is already taken.
+fn unique_name(hint: &str, is_taken: impl Fn(&str) -> bool) -> String {
+    let mut name = hint.to_string();
+    let mut suffix = 1;
+    while is_taken(&name) {
+        suffix += 1;
+        name = format!("{}{}", hint, suffix);
+    }
+
+    name
+}
+
+/// Returns the big maps found in the storage type, in the order in which [tezos_contract::Storage] indexes them.
+fn big_maps(r#type: &Type) -> Vec<(Option<String>, &Type, &Type)> {
+    let mut big_maps = Vec::new();
+    let mut to_visit = vec![r#type];
+    while let Some(r#type) = to_visit.pop() {
+        match r#type {
+            Type::BigMap(big_map) => {
+                let metadata = r#type.metadata();
+                let name = metadata
+                    .field_name()
+                    .as_ref()
+                    .or(metadata.type_name().as_ref())
+                    .map(|name| name.value_without_prefix().to_string());
+                big_maps.push((name, &*big_map.key_type, &*big_map.value_type));
+            }
+            Type::Pair(pair) => to_visit.extend(pair.types.iter().rev()),
+            _ => {}
+        }
+    }
+
+    big_maps
+}
+
+/// Returns `r#type` without its top level annotations.
+fn without_annotations(r#type: &Type) -> Type {
+    match Micheline::from(r#type) {
+        Micheline::PrimitiveApplication(primitive_application) => {
+            let micheline = Micheline::from(primitive_application.with_annots(vec![]));
+            micheline.try_into().unwrap_or_else(|_| r#type.clone())
+        }
+        _ => r#type.clone(),
+    }
+}
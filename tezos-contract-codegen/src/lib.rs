@@ -0,0 +1,82 @@
+//! The `tezos-contract-codegen` crate generates typed Rust bindings out of a contract's script: a wrapper around
+//! [Contract](tezos_contract::Contract) exposing one method per entrypoint, the decoded storage and typed getters
+//! for the storage's big maps.
+//!
+//! The `pair`s and `or`s found in the parameter and storage types are turned into structs and enums implementing
+//! `IntoMichelson` and `FromMichelson`, following the exact layout of the Michelson types. A type used in several
+//! places is generated once, under the name of its first use, and aliased under the name of each other use.
+//!
+//! ## Generate the Bindings
+//!
+//! The bindings can be generated from a `build.rs` script, out of the script returned by the
+//! `/chains/main/blocks/head/context/contracts/<address>/script` RPC endpoint:
+//!
+//! ```rust,no_run
+//! // build.rs
+//! let script = std::fs::read_to_string("contracts/token.json").unwrap();
+//! let bindings = tezos_contract_codegen::generate_from_json("Token", &script).unwrap();
+//! let out_dir = std::env::var("OUT_DIR").unwrap();
+//! std::fs::write(format!("{}/token.rs", out_dir), bindings).unwrap();
+//! ```
+//!
+//! and included in the crate, which needs to depend on `tezos-core`, `tezos-michelson`, `tezos-operation`,
+//! `tezos-rpc` and `tezos-contract`:
+//!
+//! ```rust,ignore
+//! mod token {
+//!     include!(concat!(env!("OUT_DIR"), "/token.rs"));
+//! }
+//! ```
+//!
+//! The same can be done with the `tezos-contract-codegen` binary:
+//!
+//! ```shell
+//! tezos-contract-codegen contracts/token.json Token src/token.rs
+//! ```
+
+mod error;
+mod generator;
+mod naming;
+
+use tezos_rpc::models::contract::ContractScript;
+
+pub use error::{Error, Result};
+
+/// Generates the bindings to a contract, named `contract_name`, out of its script.
+pub fn generate(contract_name: &str, script: &ContractScript) -> Result<String> {
+    let tokens = generator::Generator::new().generate(contract_name, script)?;
+    let file: syn::File = syn::parse2(tokens)?;
+
+    Ok(prettyplease::unparse(&file))
+}
+
+/// Generates the bindings to a contract, named `contract_name`, out of its JSON encoded script.
+pub fn generate_from_json(contract_name: &str, script: &str) -> Result<String> {
+    let script: ContractScript = serde_json::from_str(script)?;
+
+    generate(contract_name, &script)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_generate() -> Result<()> {
+        let script = include_str!("__TEST_DATA__/contract.json");
+        let bindings = generate_from_json("Token", script)?;
+        assert_eq!(include_str!("__TEST_DATA__/token.rs"), bindings);
+
+        let script = include_str!("__TEST_DATA__/naming.json");
+        let bindings = generate_from_json("Naming", script)?;
+        assert_eq!(include_str!("__TEST_DATA__/naming.rs"), bindings);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_generate_invalid_script() {
+        let result = generate_from_json("Token", r#"{ "code": [], "storage": { "int": "0" } }"#);
+        assert!(matches!(result, Err(Error::InvalidContractScript)));
+    }
+}
@@ -0,0 +1,27 @@
+use std::{env, fs, process};
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    if args.len() < 3 || args.len() > 4 {
+        eprintln!("Usage: tezos-contract-codegen <script.json> <ContractName> [output.rs]");
+        process::exit(1);
+    }
+
+    let script = fs::read_to_string(&args[1]).unwrap_or_else(|error| {
+        eprintln!("Failed to read {}: {}", args[1], error);
+        process::exit(1);
+    });
+    let bindings =
+        tezos_contract_codegen::generate_from_json(&args[2], &script).unwrap_or_else(|error| {
+            eprintln!("Failed to generate the bindings: {}", error);
+            process::exit(1);
+        });
+
+    match args.get(3) {
+        Some(output) => fs::write(output, bindings).unwrap_or_else(|error| {
+            eprintln!("Failed to write {}: {}", output, error);
+            process::exit(1);
+        }),
+        None => print!("{}", bindings),
+    }
+}
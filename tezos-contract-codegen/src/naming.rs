@@ -0,0 +1,85 @@
+use proc_macro2::{Ident, Span};
+
+/// Converts an annotation (e.g. `update_operators`) into a type name (e.g. `UpdateOperators`).
+pub(crate) fn type_ident(name: &str) -> String {
+    let mut result = String::new();
+    let mut uppercase_next = true;
+    for character in name.chars() {
+        if !character.is_ascii_alphanumeric() {
+            uppercase_next = true;
+            continue;
+        }
+        if uppercase_next {
+            result.extend(character.to_uppercase());
+            uppercase_next = false;
+        } else {
+            result.push(character);
+        }
+    }
+    if result.is_empty() || result.starts_with(|character: char| character.is_ascii_digit()) {
+        result.insert(0, 'T');
+    }
+
+    result
+}
+
+/// Converts an annotation (e.g. `tokenId`) into a field name (e.g. `token_id`).
+pub(crate) fn field_ident(name: &str) -> Ident {
+    let mut result = String::new();
+    let mut previous_lowercase = false;
+    for character in name.chars() {
+        if character.is_ascii_uppercase() {
+            if previous_lowercase {
+                result.push('_');
+            }
+            result.push(character.to_ascii_lowercase());
+            previous_lowercase = false;
+        } else if character.is_ascii_alphanumeric() || character == '_' {
+            result.push(character);
+            previous_lowercase = character.is_ascii_lowercase() || character.is_ascii_digit();
+        } else {
+            result.push('_');
+            previous_lowercase = false;
+        }
+    }
+    if result.is_empty() || result.starts_with(|character: char| character.is_ascii_digit()) {
+        result.insert(0, '_');
+    }
+
+    match syn::parse_str::<Ident>(&result) {
+        Ok(_) if result != "_" => Ident::new(&result, Span::call_site()),
+        _ => match result.as_str() {
+            "self" | "Self" | "super" | "crate" | "_" => {
+                Ident::new(&format!("{}_", result), Span::call_site())
+            }
+            _ => Ident::new_raw(&result, Span::call_site()),
+        },
+    }
+}
+
+/// Converts an entrypoint name into a method name, avoiding the names used by the wrapper itself.
+pub(crate) fn method_ident(entrypoint: &str) -> Ident {
+    let ident = field_ident(entrypoint);
+    match ident.to_string().as_str() {
+        "new" | "at" | "contract" | "storage" => {
+            Ident::new(&format!("call_{}", ident), Span::call_site())
+        }
+        _ => ident,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_names() {
+        assert_eq!("UpdateOperators", type_ident("update_operators"));
+        assert_eq!("T0", type_ident("0"));
+        assert_eq!("token_id", field_ident("tokenId").to_string());
+        assert_eq!("from_", field_ident("from_").to_string());
+        assert_eq!("r#type", field_ident("type").to_string());
+        assert_eq!("self_", field_ident("self").to_string());
+        assert_eq!("call_storage", method_ident("storage").to_string());
+    }
+}
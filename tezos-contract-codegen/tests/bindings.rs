@@ -0,0 +1,193 @@
+#[allow(dead_code)]
+mod token {
+    include!("../src/__TEST_DATA__/token.rs");
+}
+
+#[allow(dead_code)]
+mod naming {
+    include!("../src/__TEST_DATA__/naming.rs");
+}
+
+use httpmock::{Method::POST, MockServer};
+use tezos_contract::Result;
+use tezos_core::types::{
+    encoded::{Address, ContractHash, Encoded},
+    timestamp::Timestamp,
+};
+use tezos_michelson::{
+    micheline::Micheline,
+    michelson::{
+        convert::{FromMichelson, IntoMichelson, MichelsonType},
+        data::{self, Data},
+    },
+};
+use tezos_rpc::client::TezosRpc;
+
+use naming::{Naming, NamingStorage, State};
+use token::{LedgerKey, Token, TransferItem, TreasuryLedgerKey, TxsItem, UpdateOperatorsItem};
+
+fn address(value: &str) -> Address {
+    value.try_into().unwrap()
+}
+
+fn mock_contract(server: &MockServer, contract_address: &ContractHash, script: &str) {
+    server.mock(|when, then| {
+        when.method(POST).path(format!(
+            "/chains/main/blocks/head/context/contracts/{}/script/normalized",
+            contract_address.value(),
+        ));
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(script);
+    });
+}
+
+#[tokio::test]
+async fn test_storage() -> Result<()> {
+    let server = MockServer::start();
+    let contract_address: ContractHash = "KT1J4CiyWPmtFPXAjpgBezM5hoVHXHNzWBHK".try_into()?;
+    mock_contract(
+        &server,
+        &contract_address,
+        include_str!("../src/__TEST_DATA__/contract.json"),
+    );
+    let rpc = TezosRpc::new(server.base_url());
+    let token = Token::at(&rpc, contract_address, None).await?;
+
+    let storage = token.storage()?;
+    assert_eq!(108614u32, storage.ledger.to_integer::<u32>()?);
+    assert_eq!("40534998837", storage.dist_factor.to_string());
+    assert_eq!(
+        Timestamp::from(1654090529i64),
+        storage.epoch_start_timestamp
+    );
+    assert_eq!(
+        TreasuryLedgerKey {
+            owner: address("tz1YY1LvD6TFH4z74pvxPQXBjAKHE5tB5Q8f"),
+            token_id: 0u8.into(),
+        },
+        storage.treasury_ledger_key
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_colliding_names() -> Result<()> {
+    let server = MockServer::start();
+    let contract_address: ContractHash = "KT1J4CiyWPmtFPXAjpgBezM5hoVHXHNzWBHK".try_into()?;
+    mock_contract(
+        &server,
+        &contract_address,
+        include_str!("../src/__TEST_DATA__/naming.json"),
+    );
+    let rpc = TezosRpc::new(server.base_url());
+    let naming = Naming::at(&rpc, contract_address, None).await?;
+
+    assert_eq!(
+        NamingStorage {
+            token_id: 1u8.into(),
+            arg1: 2u8.into(),
+            token_id2: 3u8.into(),
+            arg12: 4u8.into(),
+            state: State::DoIt2,
+        },
+        naming.storage()?
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_big_map_getter() -> Result<()> {
+    let server = MockServer::start();
+    let contract_address: ContractHash = "KT1J4CiyWPmtFPXAjpgBezM5hoVHXHNzWBHK".try_into()?;
+    mock_contract(
+        &server,
+        &contract_address,
+        include_str!("../src/__TEST_DATA__/contract.json"),
+    );
+    server.mock(|when, then| {
+        when.method(POST).path(
+            "/chains/main/blocks/head/context/big_maps/108614/exprua97oRxvyAVsw5QEcWaELYNdeqsZUoFRMXTBuKUoG6MHtbV7C3/normalized",
+        );
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(include_str!("../src/__TEST_DATA__/big_map_value.json"));
+    });
+    let rpc = TezosRpc::new(server.base_url());
+    let token = Token::at(&rpc, contract_address, None).await?;
+
+    let balance = token
+        .get_ledger(
+            LedgerKey {
+                owner: address("tz1YY1LvD6TFH4z74pvxPQXBjAKHE5tB5Q8f"),
+                token_id: 0u8.into(),
+            },
+            None,
+        )
+        .await?;
+    assert_eq!("164748675300576703", balance.to_string());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_entrypoints() -> Result<()> {
+    let server = MockServer::start();
+    let contract_address: ContractHash = "KT1J4CiyWPmtFPXAjpgBezM5hoVHXHNzWBHK".try_into()?;
+    mock_contract(
+        &server,
+        &contract_address,
+        include_str!("../src/__TEST_DATA__/contract.json"),
+    );
+    let rpc = TezosRpc::new(server.base_url());
+    let token = Token::at(&rpc, contract_address, None).await?;
+
+    let transfer = vec![TransferItem {
+        from_: address("tz1YY1LvD6TFH4z74pvxPQXBjAKHE5tB5Q8f"),
+        txs: vec![TxsItem {
+            to_: address("tz1agAtczEzZS8tV67KGF4urNqxfNCSPksiW"),
+            token_id: 0u8.into(),
+            amount: 100u8.into(),
+        }],
+    }];
+    let partial_transaction = token.transfer(transfer)?;
+    let destination: ContractHash = partial_transaction.destination.try_into()?;
+    assert_eq!(token.contract().address(), &destination);
+    let parameters = partial_transaction.parameters.unwrap();
+    assert_eq!("transfer", parameters.entrypoint.to_str());
+    let expected: Micheline = serde_json::from_str(
+        r#"[{
+            "prim": "Pair",
+            "args": [
+                { "string": "tz1YY1LvD6TFH4z74pvxPQXBjAKHE5tB5Q8f" },
+                [{
+                    "prim": "Pair",
+                    "args": [
+                        { "string": "tz1agAtczEzZS8tV67KGF4urNqxfNCSPksiW" },
+                        { "prim": "Pair", "args": [{ "int": "0" }, { "int": "100" }] }
+                    ]
+                }]
+            ]
+        }]"#,
+    )
+    .unwrap();
+    assert_eq!(expected, parameters.value);
+
+    let parameters = token.claim()?.parameters.unwrap();
+    assert_eq!("claim", parameters.entrypoint.to_str());
+    assert_eq!(Micheline::from(data::unit::<Data>()), parameters.value);
+
+    let update = UpdateOperatorsItem::RemoveOperator(token::RemoveOperator {
+        owner: address("tz1YY1LvD6TFH4z74pvxPQXBjAKHE5tB5Q8f"),
+        operator: address("KT1J4CiyWPmtFPXAjpgBezM5hoVHXHNzWBHK"),
+        token_id: 1u8.into(),
+    });
+    let data = update.clone().into_michelson()?;
+    let micheline: Micheline = data.clone().into();
+    micheline.pack(Some(&UpdateOperatorsItem::michelson_type()?.into()))?;
+    assert_eq!(update, UpdateOperatorsItem::from_michelson(data)?);
+
+    Ok(())
+}
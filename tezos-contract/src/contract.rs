@@ -1,6 +1,13 @@
 mod big_map;
+mod call;
 mod entrypoints;
+mod json;
+mod originator;
 mod storage;
+mod value;
+mod views;
+
+use std::collections::HashMap;
 
 use async_trait::async_trait;
 
@@ -12,6 +19,7 @@ use tezos_core::types::{
 use tezos_michelson::{
     micheline::{primitive_application::PrimitiveApplication, Micheline},
     michelson::{
+        convert::IntoMichelson,
         data::{
             Bytes, Data, Instruction, Left, Map, Pair, Right, Sequence as DataSequence,
             Some as DataSome, String as MichelsonString,
@@ -28,13 +36,16 @@ use tezos_rpc::{
     models::{block::BlockId, contract::UnparsingMode},
 };
 
-use crate::{utils::AnyAnnotationValue, Error, Result};
+use crate::{token::same_type, utils::AnyAnnotationValue, Error, Result};
 
-use self::entrypoints::MappedEntrypoints;
 pub use self::{
-    big_map::{BigMap, BigMapContainer},
-    entrypoints::EntrypointPathComponent,
+    big_map::{BigMap, BigMapContainer, BigMapEntry, BigMapMirror},
+    call::ContractCallBuilder,
+    entrypoints::{EntrypointPathComponent, MappedEntrypoints},
+    originator::{contract_address, OriginatedContract, Originator},
     storage::Storage,
+    value::AnnotatedValue,
+    views::{ContractView, ViewCallBuilder},
 };
 
 /// The [Contract] structure represents a tezos contract.
@@ -67,6 +78,8 @@ pub struct Contract<'a, HttpClient: Http> {
     client: &'a TezosRpc<HttpClient>,
 
     entrypoints: MappedEntrypoints,
+    views: Vec<ContractView>,
+    invalid_views: HashMap<String, String>,
 }
 
 impl<'a, HttpClient: Http> Contract<'a, HttpClient> {
@@ -82,6 +95,15 @@ impl<'a, HttpClient: Http> Contract<'a, HttpClient> {
         self.client
     }
 
+    pub fn entrypoints(&self) -> &MappedEntrypoints {
+        &self.entrypoints
+    }
+
+    /// The Michelson views declared in the contract's script.
+    pub fn views(&self) -> &[ContractView] {
+        &self.views
+    }
+
     pub(crate) async fn new(
         address: ContractHash,
         client: &'a TezosRpc<HttpClient>,
@@ -104,11 +126,14 @@ impl<'a, HttpClient: Http> Contract<'a, HttpClient> {
             .clone()
             .try_into()?;
         let entrypoints = MappedEntrypoints::new(parameter)?;
+        let (views, invalid_views) = ContractView::parse_all(script.code.values());
         return Ok(Contract {
             address,
             storage: Storage::new(script, client)?,
             client,
             entrypoints,
+            views,
+            invalid_views,
         });
     }
 
@@ -135,6 +160,68 @@ impl<'a, HttpClient: Http> Contract<'a, HttpClient> {
         Err(Error::EntrypointNotFound)
     }
 
+    /// Creates a call to `entrypoint` with a value implementing [IntoMichelson], e.g. a type deriving it.
+    pub fn call_with<T: IntoMichelson>(
+        &self,
+        entrypoint: Entrypoint,
+        value: T,
+    ) -> Result<PartialTransaction> {
+        let r#type = self
+            .entrypoints
+            .get(&entrypoint)
+            .ok_or(Error::EntrypointNotFound)?;
+        let value = value.into_michelson()?.normalized();
+        if !same_type(&T::michelson_type()?, r#type) || !value.is_compatible_with(r#type) {
+            return Err(Error::IncompatibleEntrypoint {
+                name: entrypoint.to_str().into(),
+            });
+        }
+        let value: Micheline = value.into();
+
+        Ok(PartialTransaction::new(
+            0u8.into(),
+            (&self.address).into(),
+            Some(Parameters::new(entrypoint, value)),
+        ))
+    }
+
+    /// Creates a [ContractCallBuilder] to construct a call to `entrypoint` from JSON-like arguments.
+    pub fn call_builder(&self, entrypoint: Entrypoint) -> Result<ContractCallBuilder> {
+        let entrypoint_type = self
+            .entrypoints
+            .get(&entrypoint)
+            .ok_or(Error::EntrypointNotFound)?;
+
+        Ok(ContractCallBuilder::new(
+            (&self.address).into(),
+            entrypoint,
+            entrypoint_type.clone(),
+        ))
+    }
+
+    /// Creates a [ViewCallBuilder] to run the Michelson view `name` with `input`.
+    ///
+    /// Fails with [Error::InvalidView] if the view is declared in the script but its types can't be parsed.
+    pub fn call_view(
+        &self,
+        name: &str,
+        input: Data,
+    ) -> Result<ViewCallBuilder<'_, 'a, HttpClient>> {
+        if let Some(description) = self.invalid_views.get(name) {
+            return Err(Error::InvalidView {
+                name: name.into(),
+                description: description.clone(),
+            });
+        }
+        let view = self
+            .views
+            .iter()
+            .find(|view| view.name == name)
+            .ok_or_else(|| Error::ViewNotFound { name: name.into() })?;
+
+        ViewCallBuilder::new(self, view, input)
+    }
+
     pub fn get_entrypoint_at_path(&self, path: &[EntrypointPathComponent]) -> Option<Entrypoint> {
         self.entrypoints.get_entrypoint_at_path(path)
     }
@@ -275,7 +362,10 @@ trait StaticCompatibleWith<T> {
 impl CompatibleWith<Type> for Data {
     fn is_compatible_with(&self, value: &Type) -> bool {
         match self {
-            Self::Int(_) => Int::is_compatible_with(value),
+            Self::Int(int) => {
+                Int::is_compatible_with(value)
+                    || (!int.to_string().starts_with('-') && Nat::is_compatible_with(value))
+            }
             Self::Nat(_) => Nat::is_compatible_with(value),
             Self::String(_) => MichelsonString::is_compatible_with(value),
             Self::Bytes(_) => Bytes::is_compatible_with(value),
@@ -365,6 +455,7 @@ impl CompatibleWith<Type> for DataSequence {
                     .iter()
                     .all(|item| item.is_compatible_with(&element_type.clone().into()))
             }
+            Type::Map(_) | Type::BigMap(_) => self.values().is_empty(),
             Type::Pair(_) => {
                 let pair: Data = Pair::new(self.clone().into_values()).into();
                 pair.is_compatible_with(value)
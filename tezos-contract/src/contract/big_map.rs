@@ -1,4 +1,6 @@
-use tezos_core::{types::encoded::ScriptExprHash, Tezos};
+mod mirror;
+
+use tezos_core::{hash, types::encoded::ScriptExprHash};
 use tezos_michelson::{
     micheline::Micheline,
     michelson::{
@@ -13,7 +15,9 @@ use tezos_rpc::{
     models::{block::BlockId, contract::UnparsingMode},
 };
 
-use crate::{utils::AnyAnnotationValue, Result};
+use crate::{utils::AnyAnnotationValue, Error, Result};
+
+pub use self::mirror::{BigMapEntry, BigMapMirror};
 
 #[derive(Debug, Clone)]
 pub struct BigMap<'a, HttpClient: Http> {
@@ -40,9 +44,7 @@ impl<'a, HttpClient: Http> BigMap<'a, HttpClient> {
     }
 
     pub async fn get_value(&self, key: Michelson, block_id: Option<&BlockId>) -> Result<Micheline> {
-        let packed_key = key.pack(Some(&self.key_type))?;
-        let hashed = Tezos::default().get_crypto().blake2b(&packed_key, 32)?;
-        let script_expr: ScriptExprHash = (&hashed).try_into()?;
+        let script_expr = key_hash(key, &self.key_type)?;
         let mut request = self
             .client
             .get_big_map_value(self.id, &script_expr)
@@ -55,6 +57,67 @@ impl<'a, HttpClient: Http> BigMap<'a, HttpClient> {
 
         Ok(MichelinePacker::post_unpack(value, &schema)?)
     }
+
+    /// Lists the values of the big map, optionally paginated with `offset` and `length`.
+    ///
+    /// The node only returns the values, in an unspecified but consistent order, as the keys themselves are not
+    /// kept in the context. Use a [BigMapMirror] to follow the keys as well.
+    pub async fn values(
+        &self,
+        offset: Option<u32>,
+        length: Option<u32>,
+        block_id: Option<&BlockId>,
+    ) -> Result<Vec<Micheline>> {
+        let mut request = self.client.get_big_map(self.id);
+        if let Some(offset) = offset {
+            request = request.offset(offset);
+        }
+        if let Some(length) = length {
+            request = request.length(length);
+        }
+        if let Some(block_id) = block_id {
+            request = request.block_id(block_id);
+        }
+        let values = request
+            .send()
+            .await?
+            .into_sequence()
+            .ok_or(Error::InvalidBigMapValue)?;
+        let schema: Micheline = self.value_type.clone().into();
+
+        values
+            .into_values()
+            .into_iter()
+            .map(|value| Ok(MichelinePacker::post_unpack(value, &schema)?))
+            .collect()
+    }
+
+    /// Creates a [BigMapMirror] of the big map at `level`, out of the values of the already known `keys`.
+    pub async fn mirror(&self, keys: Vec<Michelson>, level: i32) -> Result<BigMapMirror> {
+        let block_id = BlockId::Level(level);
+        let mut entries = Vec::with_capacity(keys.len());
+        for key in keys {
+            let key_hash = key_hash(key.clone(), &self.key_type)?;
+            let value = self.get_value(key.clone(), Some(&block_id)).await?;
+            entries.push((
+                key_hash,
+                BigMapEntry::decode(key.into(), value, &self.key_type, &self.value_type)?,
+            ));
+        }
+
+        Ok(BigMapMirror::new(
+            self.id,
+            self.key_type.clone(),
+            self.value_type.clone(),
+            level,
+        )
+        .with_entries(entries))
+    }
+}
+
+/// Computes the [ScriptExprHash] of a big map key of type `key_type`.
+pub(crate) fn key_hash(key: Michelson, key_type: &Type) -> Result<ScriptExprHash> {
+    Ok(hash::script_expr_hash(&key.pack(Some(key_type))?)?)
 }
 
 #[derive(Debug, Clone)]
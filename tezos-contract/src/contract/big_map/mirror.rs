@@ -0,0 +1,465 @@
+use std::collections::HashMap;
+
+use tezos_core::types::encoded::ScriptExprHash;
+use tezos_michelson::{
+    micheline::Micheline,
+    michelson::{types::Type, Michelson},
+    MichelinePacker,
+};
+use tezos_rpc::{
+    client::TezosRpc,
+    http::Http,
+    models::{
+        block::{Block, BlockId},
+        operation::{
+            operation_result::{
+                big_map_diff::BigMapDiff,
+                lazy_storage_diff::{big_map::Update, LazyStorageDiff},
+                operations::{
+                    origination::OriginationOperationResult,
+                    transaction::TransactionOperationResult, InternalOperationResult,
+                },
+                DiffAction, OperationResultStatus,
+            },
+            OperationContent,
+        },
+    },
+};
+
+use crate::{token::same_type, Error, Result};
+
+use super::key_hash;
+
+/// A key of a big map along with its value.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BigMapEntry {
+    pub key: Micheline,
+    pub value: Micheline,
+}
+
+impl BigMapEntry {
+    pub fn new(key: Micheline, value: Micheline) -> Self {
+        Self { key, value }
+    }
+
+    /// Creates an entry out of a `key` and a `value` in any representation, decoded according to the big map types,
+    /// so that entries are comparable whether they come from a snapshot or from a diff.
+    pub(crate) fn decode(
+        key: Micheline,
+        value: Micheline,
+        key_type: &Type,
+        value_type: &Type,
+    ) -> Result<Self> {
+        let key_schema: Micheline = key_type.clone().into();
+        let value_schema: Micheline = value_type.clone().into();
+
+        Ok(Self::new(
+            MichelinePacker::post_unpack(key.normalized(), &key_schema)?,
+            MichelinePacker::post_unpack(value.normalized(), &value_schema)?,
+        ))
+    }
+}
+
+type Entries = HashMap<ScriptExprHash, BigMapEntry>;
+
+/// A local copy of the content of a big map, kept in sync by applying the big map diffs of the subsequent blocks.
+///
+/// The mirror starts either empty, e.g. from the level preceding the origination of the contract holding the big map,
+/// or from a snapshot of known keys created with [BigMap::mirror](crate::BigMap::mirror). The keys and values are
+/// decoded according to the big map types.
+///
+/// The diffs of temporary big maps with the same types are followed within an operation, so that copies of them are
+/// reflected in the mirror, but copies of other persistent big maps into the mirrored one can't be resolved and
+/// result in [Error::BigMapNotMirrored]. The diffs of any other big map are ignored without being decoded.
+#[derive(Debug, Clone)]
+pub struct BigMapMirror {
+    id: u32,
+    key_type: Type,
+    value_type: Type,
+    level: i32,
+    entries: Entries,
+    removed: bool,
+}
+
+impl BigMapMirror {
+    /// Creates an empty mirror of the big map `id` at `level`.
+    pub fn new(id: u32, key_type: Type, value_type: Type, level: i32) -> Self {
+        Self {
+            id,
+            key_type,
+            value_type,
+            level,
+            entries: HashMap::new(),
+            removed: false,
+        }
+    }
+
+    /// Sets the entries of the mirror, keyed by the [ScriptExprHash] of their key.
+    pub fn with_entries<I: IntoIterator<Item = (ScriptExprHash, BigMapEntry)>>(
+        mut self,
+        entries: I,
+    ) -> Self {
+        self.entries = entries.into_iter().collect();
+
+        self
+    }
+
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    pub fn key_type(&self) -> &Type {
+        &self.key_type
+    }
+
+    pub fn value_type(&self) -> &Type {
+        &self.value_type
+    }
+
+    /// The level of the last block applied to the mirror.
+    pub fn level(&self) -> i32 {
+        self.level
+    }
+
+    /// Whether the big map has been removed, e.g. because the value holding it was dropped.
+    pub fn is_removed(&self) -> bool {
+        self.removed
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn entries(&self) -> impl Iterator<Item = (&ScriptExprHash, &BigMapEntry)> {
+        self.entries.iter()
+    }
+
+    pub fn get_by_hash(&self, key_hash: &ScriptExprHash) -> Option<&BigMapEntry> {
+        self.entries.get(key_hash)
+    }
+
+    pub fn get(&self, key: Michelson) -> Result<Option<&BigMapEntry>> {
+        Ok(self.get_by_hash(&key_hash(key, &self.key_type)?))
+    }
+
+    /// Fetches and applies the blocks following the mirror's level, up to `block_id` or the head of the chain.
+    pub async fn sync<HttpClient: Http>(
+        &mut self,
+        client: &TezosRpc<HttpClient>,
+        block_id: Option<&BlockId>,
+    ) -> Result<()> {
+        let mut request = client.get_block();
+        if let Some(block_id) = block_id {
+            request = request.block_id(block_id);
+        }
+        let target = request.send().await?;
+        for level in (self.level + 1)..target.header.level {
+            let block = client
+                .get_block()
+                .block_id(&BlockId::Level(level))
+                .send()
+                .await?;
+            self.apply_block(&block)?;
+        }
+        if target.header.level > self.level {
+            self.apply_block(&target)?;
+        }
+
+        Ok(())
+    }
+
+    /// Applies the big map diffs of the applied operations of `block`, which must follow the mirror's level.
+    pub fn apply_block(&mut self, block: &Block) -> Result<()> {
+        if block.header.level != self.level + 1 {
+            return Err(Error::UnexpectedBlockLevel {
+                expected: self.level + 1,
+                level: block.header.level,
+            });
+        }
+        for content in block
+            .operations
+            .iter()
+            .flatten()
+            .flat_map(|operation| operation.contents.iter())
+        {
+            self.apply_operation_content(content)?;
+        }
+        self.level = block.header.level;
+
+        Ok(())
+    }
+
+    fn apply_operation_content(&mut self, content: &OperationContent) -> Result<()> {
+        let mut temporaries = HashMap::new();
+        match content {
+            OperationContent::Transaction(transaction) => match &transaction.metadata {
+                Some(metadata) => {
+                    self.apply_transaction_result(&metadata.operation_result, &mut temporaries)?;
+                    self.apply_internal_operation_results(
+                        &metadata.internal_operation_results,
+                        &mut temporaries,
+                    )
+                }
+                None => Ok(()),
+            },
+            OperationContent::Origination(origination) => match &origination.metadata {
+                Some(metadata) => {
+                    self.apply_origination_result(&metadata.operation_result, &mut temporaries)
+                }
+                None => Ok(()),
+            },
+            OperationContent::TransferTicket(transfer_ticket) => match &transfer_ticket.metadata {
+                Some(metadata) => self.apply_internal_operation_results(
+                    &metadata.internal_operation_results,
+                    &mut temporaries,
+                ),
+                None => Ok(()),
+            },
+            _ => Ok(()),
+        }
+    }
+
+    fn apply_internal_operation_results(
+        &mut self,
+        results: &[InternalOperationResult],
+        temporaries: &mut HashMap<String, Entries>,
+    ) -> Result<()> {
+        for result in results {
+            match result {
+                InternalOperationResult::Transaction(transaction) => {
+                    if let Some(result) = &transaction.result {
+                        self.apply_transaction_result(result, temporaries)?;
+                    }
+                }
+                InternalOperationResult::Origination(origination) => {
+                    self.apply_origination_result(&origination.result, temporaries)?;
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    fn apply_transaction_result(
+        &mut self,
+        result: &TransactionOperationResult,
+        temporaries: &mut HashMap<String, Entries>,
+    ) -> Result<()> {
+        if result.status != OperationResultStatus::Applied {
+            return Ok(());
+        }
+        match (&result.lazy_storage_diff, &result.big_map_diff) {
+            (Some(diffs), _) => self.apply_lazy_storage_diff_with(diffs, temporaries),
+            (None, Some(diffs)) => self.apply_big_map_diff_with(diffs, temporaries),
+            (None, None) => Ok(()),
+        }
+    }
+
+    fn apply_origination_result(
+        &mut self,
+        result: &OriginationOperationResult,
+        temporaries: &mut HashMap<String, Entries>,
+    ) -> Result<()> {
+        if result.status != OperationResultStatus::Applied {
+            return Ok(());
+        }
+        match (&result.lazy_storage_diff, &result.big_map_diff) {
+            (Some(diffs), _) => self.apply_lazy_storage_diff_with(diffs, temporaries),
+            (None, Some(diffs)) => self.apply_big_map_diff_with(diffs, temporaries),
+            (None, None) => Ok(()),
+        }
+    }
+
+    /// Applies the `lazy_storage_diff` of an operation result.
+    pub fn apply_lazy_storage_diff(&mut self, diffs: &[LazyStorageDiff]) -> Result<()> {
+        self.apply_lazy_storage_diff_with(diffs, &mut HashMap::new())
+    }
+
+    /// Applies the legacy `big_map_diff` of an operation result.
+    pub fn apply_big_map_diff(&mut self, diffs: &[BigMapDiff]) -> Result<()> {
+        self.apply_big_map_diff_with(diffs, &mut HashMap::new())
+    }
+
+    /// Applies `diffs`, where `temporaries` holds the content of the temporary big maps with the types of the
+    /// mirrored one allocated so far by the operation.
+    fn apply_lazy_storage_diff_with(
+        &mut self,
+        diffs: &[LazyStorageDiff],
+        temporaries: &mut HashMap<String, Entries>,
+    ) -> Result<()> {
+        for diff in diffs {
+            let big_map = match diff {
+                LazyStorageDiff::BigMap(big_map) => big_map,
+                _ => continue,
+            };
+            if !self.is_tracked(
+                &big_map.id,
+                big_map.diff.action,
+                big_map.diff.source.as_deref(),
+                big_map.diff.key_type.as_ref(),
+                big_map.diff.value_type.as_ref(),
+                temporaries,
+            ) {
+                continue;
+            }
+            let updates = big_map
+                .diff
+                .updates
+                .iter()
+                .map(|update| self.decode_update(update))
+                .collect::<Result<Vec<_>>>()?;
+            self.apply_diff(
+                &big_map.id,
+                big_map.diff.action,
+                big_map.diff.source.as_deref(),
+                updates,
+                temporaries,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    fn apply_big_map_diff_with(
+        &mut self,
+        diffs: &[BigMapDiff],
+        temporaries: &mut HashMap<String, Entries>,
+    ) -> Result<()> {
+        for diff in diffs {
+            let (id, source) = match diff.action {
+                DiffAction::Copy => (&diff.destination_big_map, diff.source_big_map.as_deref()),
+                _ => (&diff.big_map, None),
+            };
+            let id = match id {
+                Some(id) => id,
+                None => continue,
+            };
+            if !self.is_tracked(
+                id,
+                diff.action,
+                source,
+                diff.key_type.as_ref(),
+                diff.value_type.as_ref(),
+                temporaries,
+            ) {
+                continue;
+            }
+            let updates = match (&diff.key_hash, &diff.key) {
+                (Some(key_hash), Some(key)) if diff.action == DiffAction::Update => {
+                    vec![self.decode_update(&Update {
+                        key_hash: key_hash.clone(),
+                        key: key.clone(),
+                        value: diff.value.clone(),
+                    })?]
+                }
+                _ => vec![],
+            };
+            self.apply_diff(id, diff.action, source, updates, temporaries)?;
+        }
+
+        Ok(())
+    }
+
+    /// Checks whether a diff of the big map `id` is relevant to the mirror, i.e. whether `id` is the mirrored big map
+    /// or a temporary big map with the same types, so that the diffs of other big maps are never decoded.
+    fn is_tracked(
+        &self,
+        id: &str,
+        action: DiffAction,
+        source: Option<&str>,
+        key_type: Option<&Micheline>,
+        value_type: Option<&Micheline>,
+        temporaries: &HashMap<String, Entries>,
+    ) -> bool {
+        if id == self.id.to_string() {
+            return !self.removed;
+        }
+        if !id.starts_with('-') {
+            return false;
+        }
+        match (action, source) {
+            (DiffAction::Alloc, _) => {
+                self.has_type(key_type, &self.key_type)
+                    && self.has_type(value_type, &self.value_type)
+            }
+            (DiffAction::Copy, Some(source)) => {
+                source == self.id.to_string() || temporaries.contains_key(source)
+            }
+            _ => temporaries.contains_key(id),
+        }
+    }
+
+    fn has_type(&self, r#type: Option<&Micheline>, expected: &Type) -> bool {
+        r#type
+            .and_then(|r#type| Type::try_from(r#type.clone()).ok())
+            .map_or(false, |r#type| same_type(&r#type, expected))
+    }
+
+    /// Applies the diff of a tracked big map, see [BigMapMirror::is_tracked].
+    fn apply_diff(
+        &mut self,
+        id: &str,
+        action: DiffAction,
+        source: Option<&str>,
+        updates: Vec<(ScriptExprHash, Option<BigMapEntry>)>,
+        temporaries: &mut HashMap<String, Entries>,
+    ) -> Result<()> {
+        let is_mirrored = id == self.id.to_string();
+        if action == DiffAction::Remove {
+            if is_mirrored {
+                self.entries.clear();
+                self.removed = true;
+            } else {
+                temporaries.remove(id);
+            }
+            return Ok(());
+        }
+        let copied = match (action, source) {
+            (DiffAction::Copy, Some(source)) if source == self.id.to_string() => {
+                self.entries.clone()
+            }
+            (DiffAction::Copy, source) => source
+                .and_then(|source| temporaries.get(source))
+                .cloned()
+                .ok_or_else(|| Error::BigMapNotMirrored {
+                    id: source.unwrap_or_default().into(),
+                })?,
+            _ => HashMap::new(),
+        };
+        let entries = if is_mirrored {
+            &mut self.entries
+        } else {
+            temporaries.entry(id.into()).or_default()
+        };
+        if action != DiffAction::Update {
+            *entries = copied;
+        }
+        for (key_hash, entry) in updates {
+            match entry {
+                Some(entry) => entries.insert(key_hash, entry),
+                None => entries.remove(&key_hash),
+            };
+        }
+
+        Ok(())
+    }
+
+    fn decode_update(&self, update: &Update) -> Result<(ScriptExprHash, Option<BigMapEntry>)> {
+        let entry = match &update.value {
+            Some(value) => Some(BigMapEntry::decode(
+                update.key.clone(),
+                value.clone(),
+                &self.key_type,
+                &self.value_type,
+            )?),
+            None => None,
+        };
+
+        Ok((update.key_hash.clone(), entry))
+    }
+}
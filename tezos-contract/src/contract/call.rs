@@ -0,0 +1,130 @@
+use serde_json::{Map, Value};
+use tezos_core::types::{encoded::Address, mutez::Mutez};
+use tezos_michelson::michelson::types::Type;
+use tezos_operation::operations::{Entrypoint, Parameters};
+
+use crate::{utils::AnyAnnotationValue, Error, Result};
+
+use super::{
+    json::{field_path, JsonValueConstructor},
+    PartialTransaction,
+};
+
+/// A builder to construct a contract call out of JSON-like arguments.
+///
+/// The arguments are matched against the annotations of the entrypoint parameter type, so that records
+/// can be provided field by field instead of as right-combed pairs.
+///
+/// ```rust
+/// use tezos_contract::{Contract, Result};
+/// use tezos_rpc::http::Http;
+/// use serde_json::json;
+///
+/// fn transfer<HttpClient: Http>(contract: &Contract<HttpClient>) -> Result<()> {
+///     let partial_transaction = contract
+///         .call_builder("transfer".into())?
+///         .args(json!([{
+///             "from_": "tz1YY1LvD6TFH4z74pvxPQXBjAKHE5tB5Q8f",
+///             "txs": [{ "to_": "tz1agAtczEzZS8tV67KGF4urNqxfNCSPksiW", "token_id": 0, "amount": "100" }]
+///         }]))
+///         .build()?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ContractCallBuilder {
+    destination: Address,
+    entrypoint: Entrypoint,
+    parameter_type: Type,
+    amount: Mutez,
+    value: Option<Value>,
+    arguments: Map<String, Value>,
+}
+
+impl ContractCallBuilder {
+    pub(crate) fn new(destination: Address, entrypoint: Entrypoint, parameter_type: Type) -> Self {
+        Self {
+            destination,
+            entrypoint,
+            parameter_type,
+            amount: 0u8.into(),
+            value: None,
+            arguments: Map::new(),
+        }
+    }
+
+    /// Sets the amount transferred with the call.
+    pub fn amount(mut self, amount: Mutez) -> Self {
+        self.amount = amount;
+
+        self
+    }
+
+    /// Sets the value of the named argument.
+    pub fn arg<S: Into<String>, V: Into<Value>>(mut self, name: S, value: V) -> Self {
+        self.arguments.insert(name.into(), value.into());
+
+        self
+    }
+
+    /// Sets the arguments of the call. An object is merged with the named arguments already set,
+    /// any other value is used as the whole entrypoint parameter.
+    pub fn args(mut self, value: Value) -> Self {
+        match value {
+            Value::Object(arguments) => self.arguments.extend(arguments),
+            value => self.value = Some(value),
+        }
+
+        self
+    }
+
+    pub fn build(&self) -> Result<PartialTransaction> {
+        let path = self.entrypoint.to_str();
+        let value = self
+            .parameter_type
+            .construct_json_value(&self.parameter_value()?, path)?;
+
+        Ok(PartialTransaction::new(
+            self.amount,
+            self.destination.clone(),
+            Some(Parameters::new(self.entrypoint.clone(), value)),
+        ))
+    }
+
+    fn parameter_value(&self) -> Result<Value> {
+        if let Some(value) = &self.value {
+            if !self.arguments.is_empty() {
+                return Err(Error::InvalidArgument {
+                    name: self.entrypoint.to_str().into(),
+                    description: "named arguments cannot be combined with a positional value"
+                        .into(),
+                });
+            }
+            return Ok(value.clone());
+        }
+        match &self.parameter_type {
+            Type::Pair(_) | Type::Or(_) => Ok(Value::Object(self.arguments.clone())),
+            _ if self.arguments.is_empty() => Ok(Value::Null),
+            _ if self.arguments.len() == 1 => {
+                let (name, value) = self.arguments.iter().next().unwrap();
+                // The parameter of an entrypoint is annotated with the entrypoint name.
+                let expected = self
+                    .parameter_type
+                    .metadata()
+                    .any_annotation_value()
+                    .unwrap_or_else(|| self.entrypoint.to_str());
+                if name != expected {
+                    return Err(Error::InvalidArgument {
+                        name: field_path(self.entrypoint.to_str(), name),
+                        description: format!("unexpected argument, expected `{}`", expected),
+                    });
+                }
+                Ok(value.clone())
+            }
+            _ => Err(Error::InvalidArgument {
+                name: self.entrypoint.to_str().into(),
+                description: "expected a single argument".into(),
+            }),
+        }
+    }
+}
@@ -29,6 +29,20 @@ impl MappedEntrypoints {
         });
     }
 
+    pub fn parameter_type(&self) -> &Type {
+        &self.parameters_type
+    }
+
+    /// Returns the named entrypoints along with their parameter type.
+    pub fn entrypoints(&self) -> Vec<(&Entrypoint, &Type)> {
+        self.entrypoint_paths
+            .iter()
+            .filter_map(|(entrypoint, path)| {
+                get_at_path(&self.parameters_type, path).map(|r#type| (entrypoint, r#type))
+            })
+            .collect()
+    }
+
     pub fn get(&self, entrypoint: &Entrypoint) -> Option<&Type> {
         if &Entrypoint::default() == entrypoint {
             return Some(&self.parameters_type);
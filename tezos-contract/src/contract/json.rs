@@ -0,0 +1,519 @@
+use serde_json::{Map, Value};
+use tezos_core::types::{
+    encoded::{Address, ChainId, ImplicitAddress, PublicKey, Signature},
+    mutez::Mutez,
+    number::{Int, Nat},
+    timestamp::Timestamp,
+};
+use tezos_michelson::{
+    micheline::{self, literals::Bytes, primitive_application, Micheline},
+    michelson::{
+        types::{ComparableType, Or, Pair, Type},
+        DataPrimitive,
+    },
+    MichelineComparator,
+};
+
+use crate::{utils::AnyAnnotationValue, Error, Result};
+
+/// Builds Michelson values out of JSON values, using the field annotations of the expected [Type]
+/// to resolve named fields.
+///
+/// * records (`pair`) accept an object keyed by the field annotations or an array of the components,
+///   a repeated annotation being suffixed with its occurrence number (e.g. `token_id`, `token_id2`),
+/// * variants (`or`) accept an object with a single key matching one of the branch annotations,
+///   or a string for a `unit` branch,
+/// * `option` accepts `null` for `None`; when the optional value is itself a `unit` or an `option`,
+///   `Some` must be wrapped as `{ "Some": value }` to be distinguished from `None`,
+/// * `list` and `set` accept an array, `map` and `big_map` accept an object or an array of `[key, value]` entries,
+///   set elements and map keys are sorted in the Michelson order and must be unique,
+/// * numbers accept JSON numbers or numeric strings, `bytes` accept hex strings,
+/// * addresses, keys, signatures and chain ids accept their base58 encoded representation.
+pub(crate) trait JsonValueConstructor {
+    fn construct_json_value(&self, value: &Value, path: &str) -> Result<Micheline>;
+}
+
+impl JsonValueConstructor for Type {
+    fn construct_json_value(&self, value: &Value, path: &str) -> Result<Micheline> {
+        match self {
+            Self::Comparable(comparable) => comparable.construct_json_value(value, path),
+            Self::Pair(pair) => construct_pair(pair, value, path),
+            Self::Or(or) => construct_or(or, value, path),
+            Self::Option(option) => {
+                let value = match value {
+                    Value::Null => return Ok(primitive_application(DataPrimitive::None).into()),
+                    Value::Object(object) if object.len() == 1 && wraps_some(&option.r#type) => {
+                        object.get("Some").unwrap_or(value)
+                    }
+                    _ => value,
+                };
+                Ok(primitive_application(DataPrimitive::Some)
+                    .with_args(vec![option.r#type.construct_json_value(value, path)?])
+                    .into())
+            }
+            Self::List(list) => construct_sequence(&list.r#type, value, path),
+            Self::Set(set) => construct_set(&set.r#type, value, path),
+            Self::Map(map) => construct_map(&map.key_type, &map.value_type, value, path),
+            Self::BigMap(big_map) => {
+                if let Value::Number(_) = value {
+                    return construct_int(value, path);
+                }
+                construct_map(&big_map.key_type, &big_map.value_type, value, path)
+            }
+            Self::Contract(_) => construct_address(value, path),
+            Self::Lambda(_)
+            | Self::Ticket(_)
+            | Self::Bls12_381G1(_)
+            | Self::Bls12_381G2(_)
+            | Self::Bls12_381Fr(_)
+            | Self::SaplingTransaction(_)
+            | Self::SaplingState(_)
+            | Self::Chest(_)
+            | Self::ChestKey(_) => construct_micheline(value, path),
+            _ => Err(invalid_argument(
+                path,
+                format!("values of type {:?} cannot be built from JSON", self),
+            )),
+        }
+    }
+}
+
+impl JsonValueConstructor for ComparableType {
+    fn construct_json_value(&self, value: &Value, path: &str) -> Result<Micheline> {
+        match self {
+            Self::Unit(_) => match value {
+                Value::Null => Ok(primitive_application(DataPrimitive::Unit).into()),
+                Value::Object(object) if object.is_empty() => {
+                    Ok(primitive_application(DataPrimitive::Unit).into())
+                }
+                _ => Err(invalid_argument(path, "expected `null` for a unit value")),
+            },
+            Self::Bool(_) => match value {
+                Value::Bool(true) => Ok(primitive_application(DataPrimitive::True).into()),
+                Value::Bool(false) => Ok(primitive_application(DataPrimitive::False).into()),
+                _ => Err(invalid_argument(path, "expected a boolean")),
+            },
+            Self::Int(_) => construct_int(value, path),
+            Self::Nat(_) => {
+                let nat: Nat = number_string(value, path)?
+                    .as_str()
+                    .try_into()
+                    .map_err(|_| invalid_argument(path, "expected a natural number"))?;
+                Ok(nat.into())
+            }
+            Self::Mutez(_) => {
+                Mutez::try_from(number_string(value, path)?)
+                    .map_err(|_| invalid_argument(path, "expected an amount in mutez"))?;
+                construct_int(value, path)
+            }
+            Self::Timestamp(_) => match value {
+                Value::Number(_) => construct_int(value, path),
+                Value::String(value) => match value.parse::<Timestamp>() {
+                    Ok(timestamp) if Int::is_valid(value) => {
+                        let seconds: Int = timestamp.into();
+                        Ok(seconds.into())
+                    }
+                    Ok(_) => construct_string(value, path),
+                    Err(_) => Err(invalid_argument(
+                        path,
+                        "expected an RFC3339 timestamp or a number of seconds",
+                    )),
+                },
+                _ => Err(invalid_argument(
+                    path,
+                    "expected an RFC3339 timestamp or a number of seconds",
+                )),
+            },
+            Self::String(_) => construct_string(string(value, path)?, path),
+            Self::Bytes(_) => {
+                let bytes: Bytes = string(value, path)?
+                    .try_into()
+                    .map_err(|_| invalid_argument(path, "expected an hex encoded string"))?;
+                Ok(bytes.into())
+            }
+            Self::Address(_) => construct_address(value, path),
+            Self::KeyHash(_) => {
+                let value = string(value, path)?;
+                ImplicitAddress::try_from(value)
+                    .map_err(|_| invalid_argument(path, "expected a public key hash"))?;
+                construct_string(value, path)
+            }
+            Self::Key(_) => {
+                let value = string(value, path)?;
+                PublicKey::try_from(value)
+                    .map_err(|_| invalid_argument(path, "expected a public key"))?;
+                construct_string(value, path)
+            }
+            Self::Signature(_) => {
+                let value = string(value, path)?;
+                Signature::try_from(value)
+                    .map_err(|_| invalid_argument(path, "expected a signature"))?;
+                construct_string(value, path)
+            }
+            Self::ChainId(_) => {
+                let value = string(value, path)?;
+                ChainId::try_from(value)
+                    .map_err(|_| invalid_argument(path, "expected a chain id"))?;
+                construct_string(value, path)
+            }
+            Self::Option(_) | Self::Or(_) | Self::Pair(_) => {
+                let r#type: Type = Micheline::from(self).try_into()?;
+                r#type.construct_json_value(value, path)
+            }
+            Self::Never(_) => Err(invalid_argument(path, "values of type never cannot exist")),
+        }
+    }
+}
+
+/// Returns whether `Some` values of an `option` of `r#type` are wrapped, as their value may be `null`.
+fn wraps_some(r#type: &Type) -> bool {
+    matches!(
+        r#type,
+        Type::Option(_) | Type::Comparable(ComparableType::Unit(_) | ComparableType::Option(_))
+    )
+}
+
+fn construct_pair(pair: &Pair, value: &Value, path: &str) -> Result<Micheline> {
+    let args = match value {
+        Value::Array(values) => construct_pair_from_array(&pair.types, values, path)?,
+        Value::Object(object) => {
+            let mut expected_fields = Vec::new();
+            pair.types
+                .iter()
+                .for_each(|r#type| collect_fields(r#type, &mut expected_fields));
+            if let Some(unexpected) = object.keys().find(|key| !expected_fields.contains(key)) {
+                return Err(invalid_argument(
+                    &field_path(path, unexpected),
+                    "unexpected argument",
+                ));
+            }
+            construct_pair_fields(pair, object, path, &mut Vec::new())?
+        }
+        _ => {
+            return Err(invalid_argument(
+                path,
+                "expected an object or an array for a pair value",
+            ))
+        }
+    };
+
+    Ok(primitive_application(DataPrimitive::Pair)
+        .with_args(args)
+        .into())
+}
+
+/// Constructs the components of a record, `fields` holding the names of the fields already constructed.
+fn construct_pair_fields(
+    pair: &Pair,
+    object: &Map<String, Value>,
+    path: &str,
+    fields: &mut Vec<String>,
+) -> Result<Vec<Micheline>> {
+    pair.types
+        .iter()
+        .enumerate()
+        .map(|(index, r#type)| construct_field(r#type, index, object, path, fields))
+        .collect()
+}
+
+fn construct_pair_from_array(
+    types: &[Type],
+    values: &[Value],
+    path: &str,
+) -> Result<Vec<Micheline>> {
+    if values.len() == types.len() {
+        return types
+            .iter()
+            .zip(values.iter())
+            .enumerate()
+            .map(|(index, (r#type, value))| {
+                r#type.construct_json_value(value, &index_path(path, index))
+            })
+            .collect();
+    }
+    // Right combs can be provided as a flat array, e.g. `pair a (pair b c)` as `[a, b, c]`.
+    if let Some((Type::Pair(last), types)) = types.split_last() {
+        if values.len() > types.len() + 1 {
+            let (values, rest) = values.split_at(types.len());
+            let mut args = construct_pair_from_array(types, values, path)?;
+            args.push(
+                primitive_application(DataPrimitive::Pair)
+                    .with_args(construct_pair_from_array(&last.types, rest, path)?)
+                    .into(),
+            );
+            return Ok(args);
+        }
+    }
+
+    Err(invalid_argument(
+        path,
+        format!(
+            "expected {} pair components, got {}",
+            types.len(),
+            values.len()
+        ),
+    ))
+}
+
+fn construct_field(
+    r#type: &Type,
+    index: usize,
+    object: &Map<String, Value>,
+    path: &str,
+    fields: &mut Vec<String>,
+) -> Result<Micheline> {
+    if let Some(name) = r#type.metadata().any_annotation_value() {
+        let name = field_name(name, |name| fields.iter().any(|field| field == name));
+        let path = field_path(path, &name);
+        let value = object.get(&name);
+        fields.push(name);
+        return match value {
+            Some(value) => r#type.construct_json_value(value, &path),
+            None if matches!(r#type, Type::Option(_)) => {
+                Ok(primitive_application(DataPrimitive::None).into())
+            }
+            None => Err(Error::MissingArgument { name: path }),
+        };
+    }
+    match r#type {
+        // Nested records without annotation share the fields of their parent.
+        Type::Pair(pair) => Ok(primitive_application(DataPrimitive::Pair)
+            .with_args(construct_pair_fields(pair, object, path, fields)?)
+            .into()),
+        Type::Or(or) => construct_or(or, &Value::Object(object.clone()), path),
+        _ => Err(invalid_argument(
+            &index_path(path, index),
+            "the field has no annotation, the value must be provided as an array",
+        )),
+    }
+}
+
+fn collect_fields(r#type: &Type, fields: &mut Vec<String>) {
+    if let Some(name) = r#type.metadata().any_annotation_value() {
+        fields.push(field_name(name, |name| {
+            fields.iter().any(|field| field == name)
+        }));
+        return;
+    }
+    match r#type {
+        Type::Pair(pair) => pair
+            .types
+            .iter()
+            .for_each(|r#type| collect_fields(r#type, fields)),
+        Type::Or(or) => {
+            collect_fields(&or.lhs, fields);
+            collect_fields(&or.rhs, fields);
+        }
+        _ => {}
+    }
+}
+
+fn construct_or(or: &Or, value: &Value, path: &str) -> Result<Micheline> {
+    let (name, value) = match value {
+        Value::Object(object) if object.len() == 1 => {
+            let (name, value) = object.iter().next().unwrap();
+            (name.as_str(), value)
+        }
+        Value::String(name) => (name.as_str(), &Value::Null),
+        _ => {
+            return Err(invalid_argument(
+                path,
+                "expected an object with a single key naming the variant",
+            ))
+        }
+    };
+
+    construct_or_branch(or, name, value, path)?
+        .ok_or_else(|| invalid_argument(&field_path(path, name), "unknown variant"))
+}
+
+fn construct_or_branch(
+    or: &Or,
+    name: &str,
+    value: &Value,
+    path: &str,
+) -> Result<Option<Micheline>> {
+    let branches = [
+        (&*or.lhs, DataPrimitive::Left, "Left"),
+        (&*or.rhs, DataPrimitive::Right, "Right"),
+    ];
+    for (r#type, primitive, primitive_name) in branches {
+        let branch = if r#type.metadata().any_annotation_value() == Some(name)
+            || name == primitive_name
+        {
+            Some(r#type.construct_json_value(value, &field_path(path, name))?)
+        } else if let (Type::Or(or), None) = (r#type, r#type.metadata().any_annotation_value()) {
+            construct_or_branch(or, name, value, path)?
+        } else {
+            None
+        };
+        if let Some(branch) = branch {
+            return Ok(Some(
+                primitive_application(primitive)
+                    .with_args(vec![branch])
+                    .into(),
+            ));
+        }
+    }
+
+    Ok(None)
+}
+
+fn construct_sequence(r#type: &Type, value: &Value, path: &str) -> Result<Micheline> {
+    Ok(micheline::sequence(construct_elements(
+        r#type, value, path,
+    )?))
+}
+
+/// The elements are sorted in the Michelson order, duplicates are rejected.
+fn construct_set(r#type: &ComparableType, value: &Value, path: &str) -> Result<Micheline> {
+    let elements = construct_elements(&r#type.clone().into(), value, path)?;
+    let elements = MichelineComparator::sort(elements, &r#type.into(), |element| element)
+        .map_err(|error| sort_error(error, path, "duplicate set element"))?;
+
+    Ok(micheline::sequence(elements))
+}
+
+fn construct_elements(r#type: &Type, value: &Value, path: &str) -> Result<Vec<Micheline>> {
+    value
+        .as_array()
+        .ok_or_else(|| invalid_argument(path, "expected an array"))?
+        .iter()
+        .enumerate()
+        .map(|(index, value)| r#type.construct_json_value(value, &index_path(path, index)))
+        .collect()
+}
+
+/// The entries are sorted by key in the Michelson order, duplicate keys are rejected.
+fn construct_map(
+    key_type: &Type,
+    value_type: &Type,
+    value: &Value,
+    path: &str,
+) -> Result<Micheline> {
+    let entries: Vec<(Value, &Value)> = match value {
+        Value::Object(object) => object
+            .iter()
+            .map(|(key, value)| (Value::String(key.clone()), value))
+            .collect(),
+        Value::Array(values) => values
+            .iter()
+            .enumerate()
+            .map(|(index, entry)| match entry {
+                Value::Array(entry) if entry.len() == 2 => Ok((entry[0].clone(), &entry[1])),
+                _ => Err(invalid_argument(
+                    &index_path(path, index),
+                    "expected a `[key, value]` entry",
+                )),
+            })
+            .collect::<Result<Vec<_>>>()?,
+        _ => {
+            return Err(invalid_argument(
+                path,
+                "expected an object or an array of entries",
+            ))
+        }
+    };
+
+    let entries = entries
+        .into_iter()
+        .map(|(key, value)| {
+            let path = field_path(path, &key.as_str().map_or(key.to_string(), Into::into));
+            Ok((
+                key_type.construct_json_value(&key, &path)?,
+                value_type.construct_json_value(value, &path)?,
+            ))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let entries = MichelineComparator::sort(entries, &key_type.into(), |(key, _)| key)
+        .map_err(|error| sort_error(error, path, "duplicate map key"))?;
+
+    Ok(micheline::sequence(
+        entries
+            .into_iter()
+            .map(|(key, value)| {
+                primitive_application(DataPrimitive::Elt)
+                    .with_args(vec![key, value])
+                    .into()
+            })
+            .collect::<Vec<Micheline>>(),
+    ))
+}
+
+fn sort_error(error: tezos_michelson::Error, path: &str, description: &str) -> Error {
+    match error {
+        tezos_michelson::Error::DuplicateComparableValue => invalid_argument(path, description),
+        error => error.into(),
+    }
+}
+
+fn construct_int(value: &Value, path: &str) -> Result<Micheline> {
+    let int: Int = number_string(value, path)?
+        .as_str()
+        .try_into()
+        .map_err(|_| invalid_argument(path, "expected an integer"))?;
+    Ok(int.into())
+}
+
+fn construct_string(value: &str, path: &str) -> Result<Micheline> {
+    micheline::try_string(value).map_err(|_| invalid_argument(path, "invalid string"))
+}
+
+fn construct_address(value: &Value, path: &str) -> Result<Micheline> {
+    let value = string(value, path)?;
+    Address::try_from(value).map_err(|_| invalid_argument(path, "expected an address"))?;
+    construct_string(value, path)
+}
+
+fn construct_micheline(value: &Value, path: &str) -> Result<Micheline> {
+    serde_json::from_value(value.clone())
+        .map_err(|_| invalid_argument(path, "expected a Micheline JSON value"))
+}
+
+fn string<'a>(value: &'a Value, path: &str) -> Result<&'a str> {
+    value
+        .as_str()
+        .ok_or_else(|| invalid_argument(path, "expected a string"))
+}
+
+fn number_string(value: &Value, path: &str) -> Result<String> {
+    match value {
+        Value::Number(number) if number.is_i64() || number.is_u64() => Ok(number.to_string()),
+        Value::String(value) => Ok(value.clone()),
+        _ => Err(invalid_argument(
+            path,
+            "expected an integer number or string",
+        )),
+    }
+}
+
+pub(super) fn field_path(path: &str, name: &str) -> String {
+    if path.is_empty() {
+        return name.into();
+    }
+    format!("{}.{}", path, name)
+}
+
+/// Names a record field after its annotation, suffixed with the first number from 2 that makes it unique if the
+/// annotation is already taken by a previous field of the record.
+pub(super) fn field_name(annotation: &str, is_taken: impl Fn(&str) -> bool) -> String {
+    let mut name = annotation.to_string();
+    let mut suffix = 1;
+    while is_taken(&name) {
+        suffix += 1;
+        name = format!("{}{}", annotation, suffix);
+    }
+
+    name
+}
+
+pub(super) fn index_path(path: &str, index: usize) -> String {
+    format!("{}[{}]", path, index)
+}
+
+fn invalid_argument<S: Into<String>>(path: &str, description: S) -> Error {
+    Error::InvalidArgument {
+        name: path.into(),
+        description: description.into(),
+    }
+}
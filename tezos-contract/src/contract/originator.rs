@@ -0,0 +1,202 @@
+use tezos_core::{
+    hash,
+    types::{
+        encoded::{Address, ContractHash, Encoded, ImplicitAddress, OperationHash, SecretKey},
+        mutez::Mutez,
+    },
+};
+use tezos_michelson::{
+    micheline::{sequence::Sequence, Micheline},
+    michelson::{data::Data, types::Storage as TypeStorage},
+};
+use tezos_operation::operations::{Origination, Script, SignedOperation, UnsignedOperation};
+use tezos_rpc::{client::TezosRpc, http::Http, models::limits::Limits};
+
+use crate::{Error, Result};
+
+use super::CompatibleWith;
+
+/// The primitive declaring the storage type in a contract's script.
+const STORAGE_PRIMITIVE: &str = "storage";
+
+/// A builder to originate a contract out of its code and initial storage.
+///
+/// The initial storage is checked against the storage type declared in the code, and the address of the
+/// originated contract is derived from the hash of the signed operation, so it is known before the operation
+/// is included in a block.
+///
+/// ```rust
+/// use tezos_contract::{Originator, Result};
+/// use tezos_core::types::encoded::{Encoded, SecretKey};
+/// use tezos_rpc::client::TezosRpc;
+///
+/// async fn originate() -> Result<()> {
+///     let rpc = TezosRpc::new("https://testnet-tezos.giganode.io".into());
+///     let originator = Originator::from_json(
+///         &rpc,
+///         r#"[
+///             { "prim": "parameter", "args": [{ "prim": "nat" }] },
+///             { "prim": "storage", "args": [{ "prim": "nat" }] },
+///             { "prim": "code", "args": [[{ "prim": "CAR" }, { "prim": "NIL", "args": [{ "prim": "operation" }] }, { "prim": "PAIR" }]] }
+///         ]"#,
+///         r#"{ "int": "0" }"#,
+///     )?;
+///     let secret_key: SecretKey = "edskRv7VyXGVZb8EsrR7D9XKUbbAQNQGtALP6QeB16ZCD7SmmJpzyeneJVg3Mq56YLbxRA1kSdAXiswwPiaVfR3NHGMCXCziuZ".try_into()?;
+///     let originated = originator
+///         .send(&"tz1YY1LvD6TFH4z74pvxPQXBjAKHE5tB5Q8f".try_into()?, &secret_key)
+///         .await?;
+///     println!("{} originated by {}", originated.address.value(), originated.operation_hash.value());
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Originator<'a, HttpClient: Http> {
+    client: &'a TezosRpc<HttpClient>,
+    script: Script,
+    balance: Mutez,
+    delegate: Option<ImplicitAddress>,
+    limits: Option<Limits>,
+}
+
+impl<'a, HttpClient: Http> Originator<'a, HttpClient> {
+    /// Creates an [Originator] for `code`, a sequence of the script sections, and the initial `storage`.
+    pub fn new(
+        client: &'a TezosRpc<HttpClient>,
+        code: Micheline,
+        storage: Micheline,
+    ) -> Result<Self> {
+        let code: Sequence = code.try_into()?;
+        let storage_type: TypeStorage = code
+            .values()
+            .iter()
+            .find(|section| match section {
+                Micheline::PrimitiveApplication(section) => section.prim() == STORAGE_PRIMITIVE,
+                _ => false,
+            })
+            .ok_or(Error::InvalidContractScript)?
+            .clone()
+            .normalized()
+            .try_into()?;
+        let storage_value: Data = storage.clone().normalized().try_into()?;
+        if !storage_value.is_compatible_with(&storage_type.r#type) {
+            return Err(Error::IncompatibleValue {
+                description: format!(
+                    "{:?} is incompatible with the storage type of the contract",
+                    storage_value
+                ),
+            });
+        }
+
+        Ok(Self {
+            client,
+            script: Script::new(code, storage),
+            balance: 0u8.into(),
+            delegate: None,
+            limits: None,
+        })
+    }
+
+    /// Creates an [Originator] out of the Micheline JSON representation of `code` and of the initial `storage`.
+    pub fn from_json(client: &'a TezosRpc<HttpClient>, code: &str, storage: &str) -> Result<Self> {
+        Self::new(
+            client,
+            serde_json::from_str(code)?,
+            serde_json::from_str(storage)?,
+        )
+    }
+
+    pub fn script(&self) -> &Script {
+        &self.script
+    }
+
+    /// Sets the initial balance of the contract, transferred from the source.
+    pub fn balance(mut self, balance: Mutez) -> Self {
+        self.balance = balance;
+
+        self
+    }
+
+    /// Sets the delegate of the contract.
+    pub fn delegate(mut self, delegate: ImplicitAddress) -> Self {
+        self.delegate = Some(delegate);
+
+        self
+    }
+
+    /// Sets the limits used to estimate the fee of the operation.
+    pub fn limits(mut self, limits: Limits) -> Self {
+        self.limits = Some(limits);
+
+        self
+    }
+}
+
+impl<'a, HttpClient: Http + Send + Sync> Originator<'a, HttpClient> {
+    /// Creates the origination operation of `source`, with its fee, gas and storage limits estimated
+    /// with [TezosRpc::min_fee].
+    ///
+    /// `source` must already be revealed.
+    pub async fn estimate(&self, source: &ImplicitAddress) -> Result<UnsignedOperation> {
+        let branch = self.client.get_block_hash().send().await?;
+        let address: Address = source.clone().into();
+        let counter = self.client.get_contract_counter(&address).send().await?;
+        let origination = Origination::new(
+            source.clone(),
+            0u8.into(),
+            (counter + 1u8).into(),
+            0u8.into(),
+            0u8.into(),
+            self.balance,
+            self.delegate.clone(),
+            self.script.clone(),
+        );
+        let operation = UnsignedOperation::new(branch, vec![origination.into()]);
+
+        Ok(self.client.min_fee(operation, self.limits.as_ref()).await?)
+    }
+
+    /// Estimates, signs with `key` and injects the origination operation of `source`.
+    pub async fn send(
+        &self,
+        source: &ImplicitAddress,
+        key: &SecretKey,
+    ) -> Result<OriginatedContract> {
+        let operation = self.estimate(source).await?.into_signed_operation(key)?;
+        let operation_hash = operation.hash()?;
+        let address = contract_address(&operation_hash, 0)?;
+        let injected_hash = self
+            .client
+            .inject_operation(&operation.to_injectable_string()?)
+            .send()
+            .await?;
+        if injected_hash != operation_hash {
+            return Err(Error::Internal {
+                description: format!(
+                    "the node injected {} instead of {}",
+                    injected_hash.value(),
+                    operation_hash.value()
+                ),
+            });
+        }
+
+        Ok(OriginatedContract {
+            address,
+            operation_hash,
+            operation,
+        })
+    }
+}
+
+/// A contract whose origination has been injected.
+#[derive(Debug, Clone)]
+pub struct OriginatedContract {
+    pub address: ContractHash,
+    pub operation_hash: OperationHash,
+    pub operation: SignedOperation,
+}
+
+/// Derives the address of a contract originated by the operation `operation_hash`, where `index` is the number
+/// of contracts originated before it by the same operation, internal originations included.
+pub fn contract_address(operation_hash: &OperationHash, index: u32) -> Result<ContractHash> {
+    Ok(hash::contract_address(operation_hash, index)?)
+}
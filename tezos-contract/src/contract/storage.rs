@@ -4,6 +4,7 @@ use tezos_core::{internal::traits::InnerValueRef, types::number::Int};
 use tezos_michelson::{
     micheline::Micheline,
     michelson::{
+        convert::FromMichelson,
         data::{Data, Pair as DataPair},
         types::{BigMap as TypeBigMap, Pair as TypePair, Storage as TypeStorage, Type},
     },
@@ -13,7 +14,10 @@ use tezos_rpc::{client::TezosRpc, http::Http, models::contract::ContractScript};
 
 use crate::{utils::AnyAnnotationValue, Error, Result};
 
-use super::big_map::{BigMap, BigMapContainer};
+use super::{
+    big_map::{BigMap, BigMapContainer},
+    value::AnnotatedValue,
+};
 
 #[derive(Debug, Clone)]
 pub struct Storage<'a, HttpClient: Http> {
@@ -54,6 +58,29 @@ impl<'a, HttpClient: Http> Storage<'a, HttpClient> {
     pub fn get_by_name(&self, name: &str) -> Option<&Data> {
         self.mapped.get_by_name(name)
     }
+
+    pub fn r#type(&self) -> &Type {
+        &self.mapped.r#type
+    }
+
+    pub fn value(&self) -> &Data {
+        &self.mapped.value
+    }
+
+    /// Decodes the whole storage into an [AnnotatedValue], keyed by the storage type annotations.
+    pub fn decode(&self) -> Result<AnnotatedValue> {
+        AnnotatedValue::from_micheline(&(&self.mapped.value).into(), &self.mapped.r#type)
+    }
+
+    /// Decodes the whole storage into a value implementing [FromMichelson], e.g. a type deriving it.
+    pub fn decode_as<T: FromMichelson>(&self) -> Result<T> {
+        Ok(T::from_michelson(self.mapped.value.clone())?)
+    }
+
+    /// Decodes the whole storage into a JSON value, keyed by the storage type annotations.
+    pub fn to_json(&self) -> Result<serde_json::Value> {
+        Ok(self.decode()?.to_json())
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -0,0 +1,464 @@
+use serde_json::{Map, Value};
+use tezos_core::types::{
+    encoded::{Address, ChainId, Encoded, ImplicitAddress, PublicKey, Signature},
+    mutez::Mutez,
+    number::{Int, Nat},
+    timestamp::Timestamp,
+};
+use tezos_michelson::{
+    micheline::{literals::Literal, primitive_application, Micheline},
+    michelson::{
+        types::{ComparableType, Or, Pair, Type},
+        DataPrimitive,
+    },
+    MichelinePacker,
+};
+
+use crate::{utils::AnyAnnotationValue, Error, Result};
+
+use super::json::{field_name, field_path, index_path, JsonValueConstructor};
+
+/// A Michelson value decoded alongside its [Type], resolving field and variant names
+/// from the type annotations.
+///
+/// * annotated `pair`s are decoded as [AnnotatedValue::Record], nested unannotated pairs sharing
+///   the fields of their parent and repeated annotations being suffixed with their occurrence number
+///   (e.g. `token_id`, `token_id2`), other pairs are decoded as [AnnotatedValue::Tuple],
+/// * `or` values are decoded as [AnnotatedValue::Variant], named after the annotation of the branch
+///   (or `Left`/`Right` when the branch is not annotated),
+/// * `big_map` values are decoded as their id when stored on chain.
+///
+/// It can be exported to a [serde_json::Value], in the format accepted by [AnnotatedValue::from_json]
+/// and by [crate::ContractCallBuilder], so that initial storages can be built out of JSON values.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AnnotatedValue {
+    Unit,
+    Bool(bool),
+    Int(Int),
+    Nat(Nat),
+    Mutez(Mutez),
+    String(String),
+    /// Hex encoded bytes, without the `0x` prefix.
+    Bytes(String),
+    Timestamp(Timestamp),
+    Address(Address),
+    KeyHash(ImplicitAddress),
+    Key(PublicKey),
+    Signature(Signature),
+    ChainId(ChainId),
+    /// An address, optionally followed by an entrypoint (`KT1...%entrypoint`).
+    Contract(String),
+    Option(Option<Box<AnnotatedValue>>),
+    Record(Vec<(String, AnnotatedValue)>),
+    Tuple(Vec<AnnotatedValue>),
+    Variant {
+        name: String,
+        value: Box<AnnotatedValue>,
+    },
+    List(Vec<AnnotatedValue>),
+    Set(Vec<AnnotatedValue>),
+    Map(Vec<(AnnotatedValue, AnnotatedValue)>),
+    BigMap(Int),
+    /// Values without a structured representation (lambdas, tickets, BLS12-381 points, sapling states...).
+    Micheline(Micheline),
+}
+
+impl AnnotatedValue {
+    /// Decodes a Michelson value of the given [Type].
+    pub fn from_micheline(value: &Micheline, r#type: &Type) -> Result<Self> {
+        decode(value, r#type, "")
+    }
+
+    /// Builds a value of the given [Type] out of a JSON value.
+    pub fn from_json(value: &Value, r#type: &Type) -> Result<Self> {
+        Self::from_micheline(&r#type.construct_json_value(value, "")?, r#type)
+    }
+
+    /// Encodes the value back to Michelson.
+    pub fn to_micheline(&self, r#type: &Type) -> Result<Micheline> {
+        r#type.construct_json_value(&self.to_json(), "")
+    }
+
+    /// Returns the value of the record field with the given name.
+    pub fn get(&self, name: &str) -> Option<&AnnotatedValue> {
+        match self {
+            Self::Record(fields) => fields
+                .iter()
+                .find(|(field, _)| field == name)
+                .map(|(_, value)| value),
+            _ => None,
+        }
+    }
+
+    pub fn to_json(&self) -> Value {
+        match self {
+            Self::Unit => Value::Null,
+            Self::Bool(value) => Value::Bool(*value),
+            Self::Int(value) => Value::String(value.to_string()),
+            Self::Nat(value) => Value::String(value.to_string()),
+            Self::Mutez(value) => Value::String((*value).into()),
+            Self::String(value) | Self::Bytes(value) => Value::String(value.clone()),
+            Self::Timestamp(value) => Value::String(value.to_string()),
+            Self::Address(value) => Value::String(value.value().into()),
+            Self::KeyHash(value) => Value::String(value.value().into()),
+            Self::Key(value) => Value::String(value.value().into()),
+            Self::Signature(value) => Value::String(value.value().into()),
+            Self::ChainId(value) => Value::String(value.value().into()),
+            Self::Contract(value) => Value::String(value.clone()),
+            Self::Option(None) => Value::Null,
+            // `Some` is wrapped when its value would otherwise be read back as `None`.
+            Self::Option(Some(value)) if matches!(**value, Self::Unit | Self::Option(_)) => {
+                let mut object = Map::new();
+                object.insert("Some".into(), value.to_json());
+                Value::Object(object)
+            }
+            Self::Option(Some(value)) => value.to_json(),
+            Self::Record(fields) => Value::Object(
+                fields
+                    .iter()
+                    .map(|(name, value)| (name.clone(), value.to_json()))
+                    .collect(),
+            ),
+            Self::Tuple(values) | Self::List(values) | Self::Set(values) => {
+                Value::Array(values.iter().map(|value| value.to_json()).collect())
+            }
+            Self::Variant { name, value } => {
+                let mut object = Map::new();
+                object.insert(name.clone(), value.to_json());
+                Value::Object(object)
+            }
+            Self::Map(entries) => {
+                let entries = entries
+                    .iter()
+                    .map(|(key, value)| (key.to_json(), value.to_json()))
+                    .collect::<Vec<_>>();
+                if entries.iter().all(|(key, _)| key.is_string()) {
+                    return Value::Object(
+                        entries
+                            .into_iter()
+                            .map(|(key, value)| (key.as_str().unwrap().into(), value))
+                            .collect(),
+                    );
+                }
+                Value::Array(
+                    entries
+                        .into_iter()
+                        .map(|(key, value)| Value::Array(vec![key, value]))
+                        .collect(),
+                )
+            }
+            Self::BigMap(id) => id
+                .to_integer::<u64>()
+                .map_or(Value::String(id.to_string()), Value::from),
+            Self::Micheline(value) => serde_json::to_value(value).unwrap_or(Value::Null),
+        }
+    }
+}
+
+impl From<AnnotatedValue> for Value {
+    fn from(value: AnnotatedValue) -> Self {
+        value.to_json()
+    }
+}
+
+impl From<&AnnotatedValue> for Value {
+    fn from(value: &AnnotatedValue) -> Self {
+        value.to_json()
+    }
+}
+
+fn decode(value: &Micheline, r#type: &Type, path: &str) -> Result<AnnotatedValue> {
+    match r#type {
+        Type::Comparable(comparable) => decode_comparable(value, comparable, path),
+        Type::Pair(pair) => decode_pair(value, pair, path),
+        Type::Or(or) => decode_or(value, or, path),
+        Type::Option(option) => match data_primitive(value) {
+            Some((DataPrimitive::None, _)) => Ok(AnnotatedValue::Option(None)),
+            Some((DataPrimitive::Some, [value])) => Ok(AnnotatedValue::Option(Some(Box::new(
+                decode(value, &option.r#type, path)?,
+            )))),
+            _ => Err(incompatible_value(path, "an option")),
+        },
+        Type::List(list) => Ok(AnnotatedValue::List(decode_sequence(
+            value,
+            &list.r#type,
+            path,
+        )?)),
+        Type::Set(set) => Ok(AnnotatedValue::Set(decode_sequence(
+            value,
+            &set.r#type.clone().into(),
+            path,
+        )?)),
+        Type::Map(map) => decode_map(value, &map.key_type, &map.value_type, path),
+        Type::BigMap(big_map) => match value {
+            Micheline::Literal(Literal::Int(id)) => Ok(AnnotatedValue::BigMap(id.clone())),
+            _ => decode_map(value, &big_map.key_type, &big_map.value_type, path),
+        },
+        Type::Contract(_) => Ok(AnnotatedValue::Contract(unpacked_string(
+            value, r#type, path,
+        )?)),
+        _ => Ok(AnnotatedValue::Micheline(value.clone())),
+    }
+}
+
+fn decode_comparable(
+    value: &Micheline,
+    r#type: &ComparableType,
+    path: &str,
+) -> Result<AnnotatedValue> {
+    match r#type {
+        ComparableType::Unit(_) => match data_primitive(value) {
+            Some((DataPrimitive::Unit, _)) => Ok(AnnotatedValue::Unit),
+            _ => Err(incompatible_value(path, "a unit")),
+        },
+        ComparableType::Bool(_) => match data_primitive(value) {
+            Some((DataPrimitive::True, _)) => Ok(AnnotatedValue::Bool(true)),
+            Some((DataPrimitive::False, _)) => Ok(AnnotatedValue::Bool(false)),
+            _ => Err(incompatible_value(path, "a boolean")),
+        },
+        ComparableType::Int(_) => Ok(AnnotatedValue::Int(int(value, path)?)),
+        ComparableType::Nat(_) => Ok(AnnotatedValue::Nat(
+            int(value, path)?
+                .to_string()
+                .try_into()
+                .map_err(|_| incompatible_value(path, "a natural number"))?,
+        )),
+        ComparableType::Mutez(_) => Ok(AnnotatedValue::Mutez(
+            int(value, path)?
+                .to_string()
+                .try_into()
+                .map_err(|_| incompatible_value(path, "an amount in mutez"))?,
+        )),
+        ComparableType::String(_) => match value {
+            Micheline::Literal(Literal::String(value)) => {
+                Ok(AnnotatedValue::String(value.to_str().into()))
+            }
+            _ => Err(incompatible_value(path, "a string")),
+        },
+        ComparableType::Bytes(_) => match value {
+            Micheline::Literal(Literal::Bytes(value)) => Ok(AnnotatedValue::Bytes(
+                value.value().trim_start_matches("0x").into(),
+            )),
+            _ => Err(incompatible_value(path, "bytes")),
+        },
+        ComparableType::Timestamp(_) => match value {
+            Micheline::Literal(Literal::Int(value)) => {
+                Ok(AnnotatedValue::Timestamp(value.clone().into()))
+            }
+            Micheline::Literal(Literal::String(value)) => Ok(AnnotatedValue::Timestamp(
+                Timestamp::from_rfc3339(value.to_str())
+                    .map_err(|_| incompatible_value(path, "a timestamp"))?,
+            )),
+            _ => Err(incompatible_value(path, "a timestamp")),
+        },
+        ComparableType::Address(_) => Ok(AnnotatedValue::Address(
+            unpacked_string(value, &r#type.clone().into(), path)?
+                .try_into()
+                .map_err(|_| incompatible_value(path, "an address"))?,
+        )),
+        ComparableType::KeyHash(_) => Ok(AnnotatedValue::KeyHash(
+            unpacked_string(value, &r#type.clone().into(), path)?
+                .try_into()
+                .map_err(|_| incompatible_value(path, "a public key hash"))?,
+        )),
+        ComparableType::Key(_) => Ok(AnnotatedValue::Key(
+            unpacked_string(value, &r#type.clone().into(), path)?
+                .try_into()
+                .map_err(|_| incompatible_value(path, "a public key"))?,
+        )),
+        ComparableType::Signature(_) => Ok(AnnotatedValue::Signature(
+            unpacked_string(value, &r#type.clone().into(), path)?
+                .try_into()
+                .map_err(|_| incompatible_value(path, "a signature"))?,
+        )),
+        ComparableType::ChainId(_) => Ok(AnnotatedValue::ChainId(
+            unpacked_string(value, &r#type.clone().into(), path)?
+                .try_into()
+                .map_err(|_| incompatible_value(path, "a chain id"))?,
+        )),
+        ComparableType::Option(_) | ComparableType::Or(_) | ComparableType::Pair(_) => {
+            let r#type: Type = Micheline::from(r#type).try_into()?;
+            decode(value, &r#type, path)
+        }
+        ComparableType::Never(_) => Err(incompatible_value(path, "a never value")),
+    }
+}
+
+fn decode_pair(value: &Micheline, pair: &Pair, path: &str) -> Result<AnnotatedValue> {
+    let values = pair_components(value, pair.types.len(), path)?;
+    if pair.types.iter().all(is_record_field) {
+        let mut fields = Vec::new();
+        decode_record_fields(&values, pair, path, &mut fields)?;
+        return Ok(AnnotatedValue::Record(fields));
+    }
+
+    Ok(AnnotatedValue::Tuple(
+        pair.types
+            .iter()
+            .zip(values.iter())
+            .enumerate()
+            .map(|(index, (r#type, value))| decode(value, r#type, &index_path(path, index)))
+            .collect::<Result<Vec<_>>>()?,
+    ))
+}
+
+fn decode_record_fields(
+    values: &[Micheline],
+    pair: &Pair,
+    path: &str,
+    fields: &mut Vec<(String, AnnotatedValue)>,
+) -> Result<()> {
+    for (r#type, value) in pair.types.iter().zip(values.iter()) {
+        match (r#type.metadata().any_annotation_value(), r#type) {
+            (Some(name), _) => {
+                let name = field_name(name, |name| fields.iter().any(|(field, _)| field == name));
+                let value = decode(value, r#type, &field_path(path, &name))?;
+                fields.push((name, value));
+            }
+            (None, Type::Pair(pair)) => {
+                let values = pair_components(value, pair.types.len(), path)?;
+                decode_record_fields(&values, pair, path, fields)?;
+            }
+            _ => return Err(incompatible_value(path, "an annotated field")),
+        }
+    }
+
+    Ok(())
+}
+
+fn is_record_field(r#type: &Type) -> bool {
+    if r#type.metadata().any_annotation_value().is_some() {
+        return true;
+    }
+    match r#type {
+        Type::Pair(pair) => pair.types.iter().all(is_record_field),
+        _ => false,
+    }
+}
+
+/// Returns exactly `count` components of a (possibly combed) pair value.
+fn pair_components(value: &Micheline, count: usize, path: &str) -> Result<Vec<Micheline>> {
+    let mut values = match (data_primitive(value), value) {
+        (Some((DataPrimitive::Pair, args)), _) if args.len() >= 2 => args.to_vec(),
+        (_, Micheline::Sequence(sequence)) if sequence.values().len() >= 2 => {
+            sequence.values().to_vec()
+        }
+        _ => return Err(incompatible_value(path, "a pair")),
+    };
+    if values.len() > count {
+        let rest = values.split_off(count - 1);
+        values.push(
+            primitive_application(DataPrimitive::Pair)
+                .with_args(rest)
+                .into(),
+        );
+    } else if values.len() < count {
+        let last = values.pop().unwrap();
+        let missing = count - values.len();
+        values.extend(pair_components(&last, missing, path)?);
+    }
+
+    Ok(values)
+}
+
+fn decode_or(value: &Micheline, or: &Or, path: &str) -> Result<AnnotatedValue> {
+    let (r#type, value, default_name) = match data_primitive(value) {
+        Some((DataPrimitive::Left, [value])) => (&*or.lhs, value, "Left"),
+        Some((DataPrimitive::Right, [value])) => (&*or.rhs, value, "Right"),
+        _ => return Err(incompatible_value(path, "an or")),
+    };
+    match (r#type.metadata().any_annotation_value(), r#type) {
+        (Some(name), _) => Ok(AnnotatedValue::Variant {
+            name: name.into(),
+            value: Box::new(decode(value, r#type, &field_path(path, name))?),
+        }),
+        // Nested unannotated variants are flattened.
+        (None, Type::Or(or)) => decode_or(value, or, path),
+        (None, _) => Ok(AnnotatedValue::Variant {
+            name: default_name.into(),
+            value: Box::new(decode(value, r#type, &field_path(path, default_name))?),
+        }),
+    }
+}
+
+fn decode_sequence(value: &Micheline, r#type: &Type, path: &str) -> Result<Vec<AnnotatedValue>> {
+    match value {
+        Micheline::Sequence(sequence) => sequence
+            .values()
+            .iter()
+            .enumerate()
+            .map(|(index, value)| decode(value, r#type, &index_path(path, index)))
+            .collect(),
+        _ => Err(incompatible_value(path, "a sequence")),
+    }
+}
+
+fn decode_map(
+    value: &Micheline,
+    key_type: &Type,
+    value_type: &Type,
+    path: &str,
+) -> Result<AnnotatedValue> {
+    let elements = match value {
+        Micheline::Sequence(sequence) => sequence.values(),
+        _ => return Err(incompatible_value(path, "a map")),
+    };
+
+    Ok(AnnotatedValue::Map(
+        elements
+            .iter()
+            .enumerate()
+            .map(|(index, element)| match data_primitive(element) {
+                Some((DataPrimitive::Elt, [key, value])) => {
+                    let path = index_path(path, index);
+                    Ok((
+                        decode(key, key_type, &path)?,
+                        decode(value, value_type, &path)?,
+                    ))
+                }
+                _ => Err(incompatible_value(&index_path(path, index), "a map entry")),
+            })
+            .collect::<Result<Vec<_>>>()?,
+    ))
+}
+
+fn data_primitive(value: &Micheline) -> Option<(DataPrimitive, &[Micheline])> {
+    match value {
+        Micheline::PrimitiveApplication(primitive_application) => {
+            let primitive = primitive_application.prim().parse::<DataPrimitive>().ok()?;
+            Some((
+                primitive,
+                primitive_application
+                    .args()
+                    .as_ref()
+                    .map_or(&[], |args| args.as_slice()),
+            ))
+        }
+        _ => None,
+    }
+}
+
+fn int(value: &Micheline, path: &str) -> Result<Int> {
+    match value {
+        Micheline::Literal(Literal::Int(value)) => Ok(value.clone()),
+        _ => Err(incompatible_value(path, "an integer")),
+    }
+}
+
+/// Returns the readable (string) representation of values which may be optimized as bytes.
+fn unpacked_string(value: &Micheline, r#type: &Type, path: &str) -> Result<String> {
+    match MichelinePacker::post_unpack(value.clone(), &r#type.into()) {
+        Ok(Micheline::Literal(Literal::String(value))) => Ok(value.into_string()),
+        _ => Err(incompatible_value(path, "a string")),
+    }
+}
+
+fn incompatible_value(path: &str, expected: &str) -> Error {
+    Error::IncompatibleValue {
+        description: if path.is_empty() {
+            format!("expected {}", expected)
+        } else {
+            format!("expected {} at `{}`", expected, path)
+        },
+    }
+}
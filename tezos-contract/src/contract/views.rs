@@ -0,0 +1,162 @@
+use std::collections::HashMap;
+
+use tezos_core::types::encoded::{Address, ImplicitAddress};
+use tezos_michelson::{
+    micheline::{literals::Literal, Micheline},
+    michelson::{data::Data, types::Type},
+    MichelinePacker,
+};
+use tezos_rpc::{
+    http::Http,
+    models::{block::BlockId, contract::UnparsingMode},
+};
+
+use crate::{Error, Result};
+
+use super::{CompatibleWith, Contract};
+
+/// The primitive declaring a view in a contract's script.
+const VIEW_PRIMITIVE: &str = "view";
+
+/// A Michelson view declared in a contract's script.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContractView {
+    pub name: String,
+    pub input_type: Type,
+    pub output_type: Type,
+}
+
+impl ContractView {
+    /// Parses the `view "<name>" <input type> <output type> <code>` sections of a script.
+    ///
+    /// The views which can't be parsed are not returned, the description of their error is returned by name instead
+    /// so that it can be reported when they are called.
+    pub(crate) fn parse_all(code: &[Micheline]) -> (Vec<Self>, HashMap<String, String>) {
+        let mut views = vec![];
+        let mut invalid_views = HashMap::new();
+        for section in code {
+            let args = match section {
+                Micheline::PrimitiveApplication(primitive_application)
+                    if primitive_application.prim() == VIEW_PRIMITIVE =>
+                {
+                    primitive_application.args().as_ref()
+                }
+                _ => continue,
+            };
+            match Self::parse(args) {
+                Ok(view) => views.push(view),
+                Err(error) => {
+                    if let Some(Micheline::Literal(Literal::String(name))) =
+                        args.and_then(|args| args.first())
+                    {
+                        invalid_views.insert(name.to_str().into(), error.to_string());
+                    }
+                }
+            }
+        }
+
+        (views, invalid_views)
+    }
+
+    fn parse(args: Option<&Vec<Micheline>>) -> Result<Self> {
+        match args.map(|args| args.as_slice()) {
+            Some([Micheline::Literal(Literal::String(name)), input_type, output_type, _]) => {
+                Ok(Self {
+                    name: name.to_str().into(),
+                    input_type: input_type.clone().try_into()?,
+                    output_type: output_type.clone().try_into()?,
+                })
+            }
+            _ => Err(Error::InvalidContractScript),
+        }
+    }
+}
+
+/// A builder to construct and run a call to a Michelson view of a contract.
+///
+/// The view is run without gas limit with the `run_script_view` RPC, and its result is unpacked
+/// according to the view's output type.
+#[derive(Debug, Clone)]
+pub struct ViewCallBuilder<'b, 'a, HttpClient: Http> {
+    contract: &'b Contract<'a, HttpClient>,
+    view: &'b ContractView,
+    input: Micheline,
+    source: Option<Address>,
+    payer: Option<ImplicitAddress>,
+    block_id: Option<&'b BlockId>,
+}
+
+impl<'b, 'a, HttpClient: Http> ViewCallBuilder<'b, 'a, HttpClient> {
+    pub(crate) fn new(
+        contract: &'b Contract<'a, HttpClient>,
+        view: &'b ContractView,
+        input: Data,
+    ) -> Result<Self> {
+        let input = input.normalized();
+        if !input.is_compatible_with(&view.input_type) {
+            return Err(Error::IncompatibleValue {
+                description: format!(
+                    "{:?} is incompatible with the input of view `{}`",
+                    input, view.name
+                ),
+            });
+        }
+        let schema: Micheline = (&view.input_type).into();
+
+        Ok(Self {
+            contract,
+            view,
+            input: MichelinePacker::pre_pack(input.into(), &schema)?,
+            source: None,
+            payer: None,
+            block_id: None,
+        })
+    }
+
+    /// Sets the address returned by the `SENDER` instruction, the contract itself by default.
+    pub fn source(mut self, source: Address) -> Self {
+        self.source = Some(source);
+
+        self
+    }
+
+    /// Sets the address returned by the `SOURCE` instruction, the `source` by default if it is an implicit account.
+    pub fn payer(mut self, payer: ImplicitAddress) -> Self {
+        self.payer = Some(payer);
+
+        self
+    }
+
+    /// Sets the block whose context the view is run in.
+    pub fn block_id(mut self, block_id: &'b BlockId) -> Self {
+        self.block_id = Some(block_id);
+
+        self
+    }
+
+    pub async fn send(&self) -> Result<Micheline> {
+        let contract_address: Address = self.contract.address().into();
+        let source = self.source.as_ref().unwrap_or(&contract_address);
+        let payer = self.payer.as_ref().or(match source {
+            Address::Implicit(source) => Some(source),
+            Address::Originated(_) => None,
+        });
+        let mut request = self
+            .contract
+            .client()
+            .run_script_view(self.contract.address(), &self.view.name, &self.input)
+            .unlimited_gas(true)
+            .source(source)
+            .unparsing_mode(UnparsingMode::Optimized_legacy);
+        if let Some(payer) = payer {
+            request = request.payer(payer);
+        }
+        if let Some(block_id) = self.block_id {
+            request = request.block_id(block_id);
+        }
+        let result = request.send().await?;
+        let schema: Micheline = (&self.view.output_type).into();
+
+        Ok(MichelinePacker::post_unpack(result.data, &schema)?)
+    }
+}
@@ -13,15 +13,85 @@ pub enum Error {
     Michelson {
         source: tezos_michelson::Error,
     },
+    Operation {
+        source: tezos_operation::Error,
+    },
     Rpc {
         source: tezos_rpc::Error,
     },
+    Json {
+        source: serde_json::Error,
+    },
     InvalidContractScript,
     EntrypointNotFound,
+    #[display(fmt = "entrypoint `{}` does not match the expected type", name)]
+    #[from(ignore)]
+    IncompatibleEntrypoint {
+        name: String,
+    },
+    LedgerNotFound,
+    InvalidBigMapValue,
+    #[display(fmt = "big map {} is not mirrored", id)]
+    #[from(ignore)]
+    BigMapNotMirrored {
+        id: String,
+    },
+    #[display(fmt = "expected block at level {}, got {}", expected, level)]
+    #[from(ignore)]
+    UnexpectedBlockLevel {
+        expected: i32,
+        level: i32,
+    },
+    MetadataNotFound,
+    #[display(fmt = "invalid metadata URI `{}`", uri)]
+    #[from(ignore)]
+    InvalidMetadataUri {
+        uri: String,
+    },
+    #[display(fmt = "failed to fetch `{}`: {}", uri, description)]
+    #[from(ignore)]
+    MetadataFetch {
+        uri: String,
+        description: String,
+    },
+    #[display(fmt = "`{}` does not point to the chain `{}`", uri, chain_id)]
+    #[from(ignore)]
+    MetadataNetworkMismatch {
+        uri: String,
+        chain_id: String,
+    },
+    #[display(fmt = "the content of `{}` does not match its hash", uri)]
+    #[from(ignore)]
+    MetadataHashMismatch {
+        uri: String,
+    },
+    #[display(fmt = "view `{}` not found", name)]
+    #[from(ignore)]
+    ViewNotFound {
+        name: String,
+    },
+    #[display(fmt = "view `{}` can't be parsed: {}", name, description)]
+    #[from(ignore)]
+    InvalidView {
+        name: String,
+        description: String,
+    },
+    InvalidViewResult,
     #[from(ignore)]
     IncompatibleValue {
         description: String,
     },
+    #[display(fmt = "missing argument `{}`", name)]
+    #[from(ignore)]
+    MissingArgument {
+        name: String,
+    },
+    #[display(fmt = "invalid argument `{}`: {}", name, description)]
+    #[from(ignore)]
+    InvalidArgument {
+        name: String,
+        description: String,
+    },
 }
 
 pub type Result<T> = result::Result<T, Error>;
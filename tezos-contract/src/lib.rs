@@ -157,6 +157,9 @@
 //! }
 //! ```
 //!
+//! [BigMap::values](crate::BigMap::values) lists the values of a big map, while a [BigMapMirror](crate::BigMapMirror)
+//! keeps a local copy of its keys and values in sync with the big map diffs of the following blocks.
+//!
 //! ## Prepare a Contract Call
 //!
 //! To prepare a contract call with the contract handler:
@@ -202,31 +205,119 @@
 //!     Ok(())
 //! }
 //! ```
+//!
+//! ## Call an On-chain View
+//!
+//! The views declared in the contract's script are listed by [Contract::views](crate::Contract::views) and run
+//! with [Contract::call_view](crate::Contract::call_view):
+//!
+//! ```rust
+//! use tezos_rpc::client::TezosRpc;
+//! use tezos_contract::{ContractFetcher, Result};
+//!
+//! async fn example() -> Result<()> {
+//!     let rpc = TezosRpc::new("https://testnet-tezos.giganode.io".into());
+//!     let contract = rpc.contract_at("KT1HNqxFJxnmUcX8wF915wxxaAAU4ixDwWQ7".try_into()?, None).await?;
+//!     let result = contract
+//!         .call_view("multiply", 2u8.into())?
+//!         .source("tz1YY1LvD6TFH4z74pvxPQXBjAKHE5tB5Q8f".try_into()?)
+//!         .send()
+//!         .await?;
+//!     Ok(())
+//! }
+//! ```
+//!
+//! ## Originate a Contract
+//!
+//! [Originator](crate::Originator) checks the initial storage against the script, estimates, signs and injects the
+//! origination, and derives the address of the new contract from the operation hash before it is included.
+//!
+//! ## Token Standards
+//!
+//! [Fa12](crate::Fa12) and [Fa2](crate::Fa2) wrap a contract handler implementing the FA1.2 or FA2 standard, checking its
+//! entrypoints and exposing typed calls and balance reads:
+//!
+//! ```rust
+//! use tezos_rpc::client::TezosRpc;
+//! use tezos_contract::{ContractFetcher, Fa2, Fa2Transfer, Fa2TransferDestination, Result};
+//!
+//! async fn example() -> Result<()> {
+//!     let rpc = TezosRpc::new("https://testnet-tezos.giganode.io".into());
+//!     let contract = rpc.contract_at("KT1HNqxFJxnmUcX8wF915wxxaAAU4ixDwWQ7".try_into()?, None).await?;
+//!     let token = Fa2::new(contract)?;
+//!     let owner = "tz1fJGtrdmckD3VkiDxqUEci5h4gGcvocw6e".try_into()?;
+//!     let balance = token.balance(&owner, &0u8.into(), None).await?;
+//!     let partial_transaction = token.transfer(vec![Fa2Transfer {
+//!         from: owner,
+//!         txs: vec![Fa2TransferDestination {
+//!             to: "tz1PwXjsrgYBi9wpe3tFhazJpt7JMTVzBp5c".try_into()?,
+//!             token_id: 0u8.into(),
+//!             amount: balance,
+//!         }],
+//!     }])?;
+//!     Ok(())
+//! }
+//! ```
+//!
+//! ## Contract Metadata
+//!
+//! The [TZIP-16](https://gitlab.com/tezos/tzip/-/blob/master/proposals/tzip-16/tzip-16.md) metadata of a contract is
+//! read from its `%metadata` big map with [Contract::metadata](crate::Contract::metadata). Off-chain documents are
+//! downloaded by a [MetadataFetcher](crate::MetadataFetcher), which can be replaced to serve documents offline, and the
+//! Michelson storage views it declares can be run against the contract's storage:
+//!
+//! ```rust
+//! use tezos_rpc::client::TezosRpc;
+//! use tezos_contract::{ContractFetcher, HttpMetadataFetcher, Result};
+//!
+//! async fn example() -> Result<()> {
+//!     let rpc = TezosRpc::new("https://testnet-tezos.giganode.io".into());
+//!     let contract = rpc.contract_at("KT1HNqxFJxnmUcX8wF915wxxaAAU4ixDwWQ7".try_into()?, None).await?;
+//!     let metadata = contract.metadata(&HttpMetadataFetcher::new(), None).await?;
+//!     if let Some(view) = metadata.view("get_balance") {
+//!         let balance = view.run(&contract, Some(0u8.into()), None).await?;
+//!     }
+//!     Ok(())
+//! }
+//! ```
+//!
+//! The metadata of the tokens of FA2 contracts is resolved the same way, from their `%token_metadata` big map or
+//! their `token_metadata` off-chain view, with [Contract::token_metadata](crate::Contract::token_metadata).
 
 mod contract;
 mod error;
+mod metadata;
+mod token;
 mod utils;
 
 pub use contract::*;
 pub use error::{Error, Result};
+pub use metadata::*;
+pub use token::*;
 
 #[cfg(test)]
 mod test {
-    use httpmock::{Method::POST, MockServer};
+    use httpmock::{
+        Method::{GET, POST},
+        MockServer,
+    };
+    use sha2::Digest;
     use tezos_core::types::{
-        encoded::{Address, ContractHash, Encoded},
+        encoded::{Address, ContractHash, Encoded, ImplicitAddress, OperationHash, SecretKey},
         number::Nat,
     };
     use tezos_michelson::{
         micheline::{self, Micheline},
         michelson::{
-            data::{pair, sequence, try_string},
+            convert::IntoMichelson,
+            data::{bytes, pair, sequence, try_string, Data},
+            types::{self, Type},
             ComparableTypePrimitive, DataPrimitive, Primitive, TypePrimitive,
         },
         MichelinePacker,
     };
-    use tezos_operation::operations::Parameters;
-    use tezos_rpc::client::TezosRpc;
+    use tezos_operation::operations::{OperationContent, Parameters};
+    use tezos_rpc::{client::TezosRpc, models::block::BlockId};
 
     use super::*;
 
@@ -276,7 +367,7 @@ mod test {
             .await?
             .try_into()?;
 
-        assert_eq!("164748675300576703", balance.to_str());
+        assert_eq!("164748675300576703", balance.to_string());
         Ok(())
     }
 
@@ -365,4 +456,1442 @@ mod test {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_contract_call_builder() -> Result<()> {
+        use serde_json::json;
+
+        let server = MockServer::start();
+        let rpc_url = server.base_url();
+
+        let contract_address: ContractHash = "KT1J4CiyWPmtFPXAjpgBezM5hoVHXHNzWBHK".try_into()?;
+        server.mock(|when, then| {
+            when.method(POST).path(format!(
+                "/chains/main/blocks/head/context/contracts/{}/script/normalized",
+                contract_address.value(),
+            ));
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(include_str!("__TEST_DATA__/contract.json"));
+        });
+        let rpc = TezosRpc::new(rpc_url);
+        let contract = rpc.contract_at(contract_address, None).await?;
+
+        let partial_transaction = contract
+            .call_builder("transfer".into())?
+            .args(json!([{
+                "from_": "tz1YY1LvD6TFH4z74pvxPQXBjAKHE5tB5Q8f",
+                "txs": [{ "to_": "tz1agAtczEzZS8tV67KGF4urNqxfNCSPksiW", "token_id": 0, "amount": "100" }]
+            }]))
+            .build()?;
+        let expected: Micheline = serde_json::from_value(json!([{
+            "prim": "Pair",
+            "args": [
+                { "string": "tz1YY1LvD6TFH4z74pvxPQXBjAKHE5tB5Q8f" },
+                [{
+                    "prim": "Pair",
+                    "args": [
+                        { "string": "tz1agAtczEzZS8tV67KGF4urNqxfNCSPksiW" },
+                        { "prim": "Pair", "args": [{ "int": "0" }, { "int": "100" }] }
+                    ]
+                }]
+            ]
+        }]))
+        .unwrap();
+        assert_eq!(
+            Some(Parameters::new("transfer".into(), expected)),
+            partial_transaction.parameters
+        );
+
+        let partial_transaction = contract
+            .call_builder("set_administrator".into())?
+            .arg(
+                "administrator_to_set",
+                "tz1YY1LvD6TFH4z74pvxPQXBjAKHE5tB5Q8f",
+            )
+            .arg("token_id", 1)
+            .build()?;
+        let expected: Micheline = serde_json::from_value(json!({
+            "prim": "Pair",
+            "args": [{ "string": "tz1YY1LvD6TFH4z74pvxPQXBjAKHE5tB5Q8f" }, { "int": "1" }]
+        }))
+        .unwrap();
+        assert_eq!(
+            Some(Parameters::new("set_administrator".into(), expected)),
+            partial_transaction.parameters
+        );
+
+        let partial_transaction = contract
+            .call_builder("update_operators".into())?
+            .args(json!([{
+                "remove_operator": {
+                    "owner": "tz1YY1LvD6TFH4z74pvxPQXBjAKHE5tB5Q8f",
+                    "operator": "KT1J4CiyWPmtFPXAjpgBezM5hoVHXHNzWBHK",
+                    "token_id": 0
+                }
+            }]))
+            .build()?;
+        let expected: Micheline = serde_json::from_value(json!([{
+            "prim": "Right",
+            "args": [{
+                "prim": "Pair",
+                "args": [
+                    { "string": "tz1YY1LvD6TFH4z74pvxPQXBjAKHE5tB5Q8f" },
+                    {
+                        "prim": "Pair",
+                        "args": [{ "string": "KT1J4CiyWPmtFPXAjpgBezM5hoVHXHNzWBHK" }, { "int": "0" }]
+                    }
+                ]
+            }]
+        }]))
+        .unwrap();
+        assert_eq!(
+            Some(Parameters::new("update_operators".into(), expected)),
+            partial_transaction.parameters
+        );
+
+        #[derive(IntoMichelson)]
+        struct Transfer {
+            from_: Address,
+            txs: Vec<Destination>,
+        }
+        #[derive(IntoMichelson)]
+        struct Destination {
+            to_: Address,
+            token_id: Nat,
+            amount: Nat,
+        }
+        let partial_transaction = contract.call_with(
+            "transfer".into(),
+            vec![Transfer {
+                from_: "tz1YY1LvD6TFH4z74pvxPQXBjAKHE5tB5Q8f".try_into()?,
+                txs: vec![Destination {
+                    to_: "tz1agAtczEzZS8tV67KGF4urNqxfNCSPksiW".try_into()?,
+                    token_id: 0u8.into(),
+                    amount: 100u8.into(),
+                }],
+            }],
+        )?;
+        assert_eq!(
+            contract
+                .call_builder("transfer".into())?
+                .args(json!([{
+                    "from_": "tz1YY1LvD6TFH4z74pvxPQXBjAKHE5tB5Q8f",
+                    "txs": [{ "to_": "tz1agAtczEzZS8tV67KGF4urNqxfNCSPksiW", "token_id": 0, "amount": 100 }]
+                }]))
+                .build()?
+                .parameters,
+            partial_transaction.parameters
+        );
+        assert!(matches!(
+            contract.call_with("claim".into(), vec![Destination {
+                to_: "tz1agAtczEzZS8tV67KGF4urNqxfNCSPksiW".try_into()?,
+                token_id: 0u8.into(),
+                amount: 100u8.into(),
+            }]),
+            Err(Error::IncompatibleEntrypoint { name }) if name == "claim"
+        ));
+        assert!(matches!(
+            contract.call_with("transfer".into(), Vec::<Destination>::new()),
+            Err(Error::IncompatibleEntrypoint { name }) if name == "transfer"
+        ));
+
+        let partial_transaction = contract
+            .call_builder("transfer".into())?
+            .arg("transfer", json!([]))
+            .build()?;
+        assert_eq!(
+            Some(Parameters::new(
+                "transfer".into(),
+                serde_json::from_value(json!([])).unwrap()
+            )),
+            partial_transaction.parameters
+        );
+
+        let partial_transaction = contract.call_builder("claim".into())?.build()?;
+        assert_eq!(
+            Some(Parameters::new(
+                "claim".into(),
+                micheline::primitive_application(DataPrimitive::Unit).into()
+            )),
+            partial_transaction.parameters
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_contract_call_builder_errors() -> Result<()> {
+        use serde_json::json;
+
+        let server = MockServer::start();
+        let rpc_url = server.base_url();
+
+        let contract_address: ContractHash = "KT1J4CiyWPmtFPXAjpgBezM5hoVHXHNzWBHK".try_into()?;
+        server.mock(|when, then| {
+            when.method(POST).path(format!(
+                "/chains/main/blocks/head/context/contracts/{}/script/normalized",
+                contract_address.value(),
+            ));
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(include_str!("__TEST_DATA__/contract.json"));
+        });
+        let rpc = TezosRpc::new(rpc_url);
+        let contract = rpc.contract_at(contract_address, None).await?;
+
+        let result = contract
+            .call_builder("transfer".into())?
+            .args(json!([{ "from_": "tz1YY1LvD6TFH4z74pvxPQXBjAKHE5tB5Q8f", "txs": [{ "to_": "tz1agAtczEzZS8tV67KGF4urNqxfNCSPksiW", "token_id": 0 }] }]))
+            .build();
+        assert!(matches!(
+            result,
+            Err(Error::MissingArgument { name }) if name == "transfer[0].txs[0].amount"
+        ));
+
+        let result = contract
+            .call_builder("set_administrator".into())?
+            .arg("administrator_to_set", "tz1invalid")
+            .arg("token_id", 1)
+            .build();
+        assert!(matches!(
+            result,
+            Err(Error::InvalidArgument { name, .. }) if name == "set_administrator.administrator_to_set"
+        ));
+
+        let result = contract
+            .call_builder("set_administrator".into())?
+            .arg(
+                "administrator_to_set",
+                "tz1YY1LvD6TFH4z74pvxPQXBjAKHE5tB5Q8f",
+            )
+            .arg("token_id", -1)
+            .build();
+        assert!(matches!(
+            result,
+            Err(Error::InvalidArgument { name, .. }) if name == "set_administrator.token_id"
+        ));
+
+        let result = contract
+            .call_builder("set_administrator".into())?
+            .arg(
+                "administrator_to_set",
+                "tz1YY1LvD6TFH4z74pvxPQXBjAKHE5tB5Q8f",
+            )
+            .arg("token_id", 1)
+            .arg("amount", 1)
+            .build();
+        assert!(matches!(
+            result,
+            Err(Error::InvalidArgument { name, .. }) if name == "set_administrator.amount"
+        ));
+
+        let result = contract
+            .call_builder("transfer".into())?
+            .arg("tranfer", json!([]))
+            .build();
+        assert!(matches!(
+            result,
+            Err(Error::InvalidArgument { name, .. }) if name == "transfer.tranfer"
+        ));
+
+        assert!(matches!(
+            contract.call_builder("mint".into()),
+            Err(Error::EntrypointNotFound)
+        ));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_contract_storage_decode() -> Result<()> {
+        use serde_json::json;
+
+        let server = MockServer::start();
+        let rpc_url = server.base_url();
+
+        let contract_address: ContractHash = "KT1J4CiyWPmtFPXAjpgBezM5hoVHXHNzWBHK".try_into()?;
+        server.mock(|when, then| {
+            when.method(POST).path(format!(
+                "/chains/main/blocks/head/context/contracts/{}/script/normalized",
+                contract_address.value(),
+            ));
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(include_str!("__TEST_DATA__/contract.json"));
+        });
+        let rpc = TezosRpc::new(rpc_url);
+        let contract = rpc.contract_at(contract_address, None).await?;
+
+        let storage = contract.storage().decode()?;
+        let expected = json!({
+            "administrators": 108612,
+            "dist_factor": "40534998837",
+            "dist_factors": 108613,
+            "epoch_start_timestamp": "2022-06-01T13:35:29Z",
+            "last_update_timestamp": "2022-07-18T14:15:10Z",
+            "ledger": 108614,
+            "operators": 108615,
+            "stakes": 108616,
+            "token_metadata": 108617,
+            "total_stake": "1893266155659631302676",
+            "total_supply": 108618,
+            "treasury_ledger_key": {
+                "owner": "tz1YY1LvD6TFH4z74pvxPQXBjAKHE5tB5Q8f",
+                "token_id": "0"
+            }
+        });
+        assert_eq!(expected, storage.to_json());
+        assert_eq!(
+            Some(&AnnotatedValue::Nat(0u8.into())),
+            storage
+                .get("treasury_ledger_key")
+                .and_then(|key| key.get("token_id"))
+        );
+        assert_eq!(
+            storage,
+            AnnotatedValue::from_json(&expected, contract.storage().r#type())?
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_annotated_value_initial_storage() -> Result<()> {
+        use serde_json::json;
+        use tezos_michelson::michelson::types::Type;
+
+        let storage_type: Micheline = serde_json::from_value(json!({
+            "prim": "pair",
+            "args": [
+                { "prim": "address", "annots": ["%admin"] },
+                {
+                    "prim": "or",
+                    "args": [{ "prim": "unit", "annots": ["%active"] }, { "prim": "timestamp", "annots": ["%paused_until"] }],
+                    "annots": ["%status"]
+                },
+                { "prim": "option", "args": [{ "prim": "nat" }], "annots": ["%limit"] },
+                { "prim": "big_map", "args": [{ "prim": "string" }, { "prim": "bytes" }], "annots": ["%metadata"] }
+            ]
+        }))
+        .unwrap();
+        let storage_type: Type = storage_type.try_into()?;
+
+        let value = AnnotatedValue::from_json(
+            &json!({
+                "admin": "tz1YY1LvD6TFH4z74pvxPQXBjAKHE5tB5Q8f",
+                "status": { "paused_until": "2022-06-01T13:35:29Z" },
+                "metadata": { "": "74657a6f732d73746f726167653a64617461" }
+            }),
+            &storage_type,
+        )?;
+        assert_eq!(
+            AnnotatedValue::Record(vec![
+                (
+                    "admin".into(),
+                    AnnotatedValue::Address("tz1YY1LvD6TFH4z74pvxPQXBjAKHE5tB5Q8f".try_into()?)
+                ),
+                (
+                    "status".into(),
+                    AnnotatedValue::Variant {
+                        name: "paused_until".into(),
+                        value: Box::new(AnnotatedValue::Timestamp(
+                            "2022-06-01T13:35:29Z".try_into()?
+                        ))
+                    }
+                ),
+                ("limit".into(), AnnotatedValue::Option(None)),
+                (
+                    "metadata".into(),
+                    AnnotatedValue::Map(vec![(
+                        AnnotatedValue::String("".into()),
+                        AnnotatedValue::Bytes("74657a6f732d73746f726167653a64617461".into())
+                    )])
+                ),
+            ]),
+            value
+        );
+
+        let expected: Micheline = serde_json::from_value(json!({
+            "prim": "Pair",
+            "args": [
+                { "string": "tz1YY1LvD6TFH4z74pvxPQXBjAKHE5tB5Q8f" },
+                { "prim": "Right", "args": [{ "string": "2022-06-01T13:35:29Z" }] },
+                { "prim": "None" },
+                [{ "prim": "Elt", "args": [{ "string": "" }, { "bytes": "74657a6f732d73746f726167653a64617461" }] }]
+            ]
+        }))
+        .unwrap();
+        assert_eq!(expected, value.to_micheline(&storage_type)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_annotated_value_duplicate_fields() -> Result<()> {
+        use serde_json::json;
+        use tezos_michelson::michelson::types::Type;
+
+        let r#type: Micheline = serde_json::from_value(json!({
+            "prim": "pair",
+            "args": [
+                { "prim": "address", "annots": ["%owner"] },
+                { "prim": "nat", "annots": ["%token_id"] },
+                { "prim": "pair", "args": [{ "prim": "nat", "annots": ["%token_id"] }, { "prim": "nat", "annots": ["%token_id"] }] }
+            ]
+        }))
+        .unwrap();
+        let r#type: Type = r#type.try_into()?;
+        let micheline: Micheline = serde_json::from_value(json!({
+            "prim": "Pair",
+            "args": [
+                { "string": "tz1YY1LvD6TFH4z74pvxPQXBjAKHE5tB5Q8f" },
+                { "int": "1" },
+                { "prim": "Pair", "args": [{ "int": "2" }, { "int": "3" }] }
+            ]
+        }))
+        .unwrap();
+
+        let value = AnnotatedValue::from_micheline(&micheline, &r#type)?;
+        assert_eq!(
+            json!({
+                "owner": "tz1YY1LvD6TFH4z74pvxPQXBjAKHE5tB5Q8f",
+                "token_id": "1",
+                "token_id2": "2",
+                "token_id3": "3"
+            }),
+            value.to_json()
+        );
+        assert_eq!(micheline, value.to_micheline(&r#type)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_annotated_value_sets_and_maps() -> Result<()> {
+        use serde_json::json;
+        use tezos_michelson::michelson::types::Type;
+
+        let storage_type: Micheline = serde_json::from_value(json!({
+            "prim": "pair",
+            "args": [
+                { "prim": "set", "args": [{ "prim": "address" }], "annots": ["%admins"] },
+                { "prim": "map", "args": [{ "prim": "nat" }, { "prim": "string" }], "annots": ["%names"] }
+            ]
+        }))
+        .unwrap();
+        let storage_type: Type = storage_type.try_into()?;
+
+        let value = AnnotatedValue::from_json(
+            &json!({
+                "admins": ["KT1J4CiyWPmtFPXAjpgBezM5hoVHXHNzWBHK", "tz1YY1LvD6TFH4z74pvxPQXBjAKHE5tB5Q8f"],
+                "names": { "10": "ten", "9": "nine" }
+            }),
+            &storage_type,
+        )?;
+        let expected: Micheline = serde_json::from_value(json!({
+            "prim": "Pair",
+            "args": [
+                [{ "string": "tz1YY1LvD6TFH4z74pvxPQXBjAKHE5tB5Q8f" }, { "string": "KT1J4CiyWPmtFPXAjpgBezM5hoVHXHNzWBHK" }],
+                [
+                    { "prim": "Elt", "args": [{ "int": "9" }, { "string": "nine" }] },
+                    { "prim": "Elt", "args": [{ "int": "10" }, { "string": "ten" }] }
+                ]
+            ]
+        }))
+        .unwrap();
+        assert_eq!(expected, value.to_micheline(&storage_type)?);
+
+        assert!(matches!(
+            AnnotatedValue::from_json(
+                &json!({
+                    "admins": ["tz1YY1LvD6TFH4z74pvxPQXBjAKHE5tB5Q8f", "tz1YY1LvD6TFH4z74pvxPQXBjAKHE5tB5Q8f"],
+                    "names": {}
+                }),
+                &storage_type,
+            ),
+            Err(Error::InvalidArgument { name, .. }) if name == "admins"
+        ));
+        assert!(matches!(
+            AnnotatedValue::from_json(
+                &json!({ "admins": [], "names": [[1, "one"], ["01", "one again"]] }),
+                &storage_type,
+            ),
+            Err(Error::InvalidArgument { name, .. }) if name == "names"
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_annotated_value_nested_options() -> Result<()> {
+        use serde_json::json;
+        use tezos_michelson::michelson::types::Type;
+
+        let option_type = |r#type: serde_json::Value| -> Result<Type> {
+            let r#type: Micheline =
+                serde_json::from_value(json!({ "prim": "option", "args": [r#type] })).unwrap();
+            Ok(r#type.try_into()?)
+        };
+        let unit_option = option_type(json!({ "prim": "unit" }))?;
+        let nat_option_option =
+            option_type(json!({ "prim": "option", "args": [{ "prim": "nat" }] }))?;
+
+        let values = [
+            (AnnotatedValue::Option(None), &unit_option, json!(null)),
+            (
+                AnnotatedValue::Option(Some(Box::new(AnnotatedValue::Unit))),
+                &unit_option,
+                json!({ "Some": null }),
+            ),
+            (
+                AnnotatedValue::Option(None),
+                &nat_option_option,
+                json!(null),
+            ),
+            (
+                AnnotatedValue::Option(Some(Box::new(AnnotatedValue::Option(None)))),
+                &nat_option_option,
+                json!({ "Some": null }),
+            ),
+            (
+                AnnotatedValue::Option(Some(Box::new(AnnotatedValue::Option(Some(Box::new(
+                    AnnotatedValue::Nat(1u8.into()),
+                )))))),
+                &nat_option_option,
+                json!({ "Some": "1" }),
+            ),
+        ];
+        for (value, r#type, json) in values {
+            assert_eq!(json, value.to_json());
+            assert_eq!(value, AnnotatedValue::from_json(&json, r#type)?);
+            assert_eq!(
+                value,
+                AnnotatedValue::from_micheline(&value.to_micheline(r#type)?, r#type)?
+            );
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_fa12_client() -> Result<()> {
+        let server = MockServer::start();
+        let rpc_url = server.base_url();
+
+        let contract_address: ContractHash = "KT1HNqxFJxnmUcX8wF915wxxaAAU4ixDwWQ7".try_into()?;
+        server.mock(|when, then| {
+            when.method(POST).path(format!(
+                "/chains/main/blocks/head/context/contracts/{}/script/normalized",
+                contract_address.value(),
+            ));
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(include_str!("__TEST_DATA__/fa12_contract.json"));
+        });
+        server.mock(|when, then| {
+            when.method(POST)
+                .path_contains("/chains/main/blocks/head/context/big_maps/1/");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(r#"{ "prim": "Pair", "args": [[], { "int": "500" }] }"#);
+        });
+        let rpc = TezosRpc::new(rpc_url);
+        assert!(matches!(
+            Fa2::new(rpc.contract_at(contract_address.clone(), None).await?),
+            Err(Error::IncompatibleEntrypoint { name }) if name == "transfer"
+        ));
+
+        let fa12 = Fa12::new(rpc.contract_at(contract_address, None).await?)?;
+        let owner: Address = "tz1YY1LvD6TFH4z74pvxPQXBjAKHE5tB5Q8f".try_into()?;
+        let spender: Address = "tz1agAtczEzZS8tV67KGF4urNqxfNCSPksiW".try_into()?;
+        let partial_transaction = fa12.transfer(owner.clone(), spender.clone(), 10u8.into())?;
+        let expected: Micheline = micheline::primitive_application(DataPrimitive::Pair)
+            .with_args(vec![
+                micheline::try_string(owner.value())?,
+                micheline::primitive_application(DataPrimitive::Pair)
+                    .with_args(vec![
+                        micheline::try_string(spender.value())?,
+                        micheline::int(10),
+                    ])
+                    .into(),
+            ])
+            .into();
+        let parameters = partial_transaction.parameters.unwrap();
+        assert_eq!("transfer", parameters.entrypoint.to_str());
+        assert_eq!(expected, parameters.value);
+        let callback: Address = "KT1J4CiyWPmtFPXAjpgBezM5hoVHXHNzWBHK".try_into()?;
+        match fa12
+            .get_balance(owner.clone(), callback.clone(), None)
+            .await?
+        {
+            Fa12Response::Callback(partial_transaction) => assert_eq!(
+                "getBalance",
+                partial_transaction.parameters.unwrap().entrypoint.to_str()
+            ),
+            response => panic!("expected a callback, got {:?}", response),
+        }
+        match fa12
+            .get_allowance(owner.clone(), spender.clone(), callback, None)
+            .await?
+        {
+            Fa12Response::Callback(partial_transaction) => assert_eq!(
+                "getAllowance",
+                partial_transaction.parameters.unwrap().entrypoint.to_str()
+            ),
+            response => panic!("expected a callback, got {:?}", response),
+        }
+
+        assert_eq!(LedgerLayout::BalanceWithApprovals, fa12.ledger()?.layout());
+        assert_eq!("500", fa12.balance(&owner, None).await?.to_string());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_fa12_views() -> Result<()> {
+        let server = MockServer::start();
+        let mut script: serde_json::Value =
+            serde_json::from_str(include_str!("__TEST_DATA__/fa12_contract.json"))?;
+        let code = script["code"].as_array_mut().unwrap();
+        code.push(serde_json::json!({
+            "prim": "view",
+            "args": [{ "string": "getBalance" }, { "prim": "address" }, { "prim": "nat" }, [{ "prim": "FAILWITH" }]]
+        }));
+        code.push(serde_json::json!({
+            "prim": "view",
+            "args": [
+                { "string": "get_allowance" },
+                { "prim": "pair", "args": [{ "prim": "address" }, { "prim": "address" }] },
+                { "prim": "nat" },
+                [{ "prim": "FAILWITH" }]
+            ]
+        }));
+        server.mock(|when, then| {
+            when.method(POST).path(
+                "/chains/main/blocks/head/context/contracts/KT1HNqxFJxnmUcX8wF915wxxaAAU4ixDwWQ7/script/normalized",
+            );
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(script.to_string());
+        });
+        server.mock(|when, then| {
+            when.method(POST)
+                .path("/chains/main/blocks/head/helpers/scripts/run_script_view")
+                .json_body_partial(r#"{ "view": "getBalance" }"#);
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(r#"{ "data": { "int": "42" } }"#);
+        });
+        server.mock(|when, then| {
+            when.method(POST)
+                .path("/chains/main/blocks/head/helpers/scripts/run_script_view")
+                .json_body_partial(r#"{ "view": "get_allowance" }"#);
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(r#"{ "data": { "int": "7" } }"#);
+        });
+        let rpc = TezosRpc::new(server.base_url());
+        let fa12 = Fa12::new(
+            rpc.contract_at("KT1HNqxFJxnmUcX8wF915wxxaAAU4ixDwWQ7".try_into()?, None)
+                .await?,
+        )?;
+        let owner: Address = "tz1YY1LvD6TFH4z74pvxPQXBjAKHE5tB5Q8f".try_into()?;
+        let spender: Address = "tz1agAtczEzZS8tV67KGF4urNqxfNCSPksiW".try_into()?;
+        let callback: Address = "KT1J4CiyWPmtFPXAjpgBezM5hoVHXHNzWBHK".try_into()?;
+
+        assert!(matches!(
+            fa12.get_balance(owner.clone(), callback.clone(), None).await?,
+            Fa12Response::Value(balance) if balance.to_string() == "42"
+        ));
+        assert!(matches!(
+            fa12.get_allowance(owner, spender, callback, None).await?,
+            Fa12Response::Value(allowance) if allowance.to_string() == "7"
+        ));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_fa2_client() -> Result<()> {
+        let server = MockServer::start();
+        let rpc_url = server.base_url();
+
+        let contract_address: ContractHash = "KT1J4CiyWPmtFPXAjpgBezM5hoVHXHNzWBHK".try_into()?;
+        server.mock(|when, then| {
+            when.method(POST).path(format!(
+                "/chains/main/blocks/head/context/contracts/{}/script/normalized",
+                contract_address.value(),
+            ));
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(include_str!("__TEST_DATA__/contract.json"));
+        });
+        server.mock(|when, then| {
+            when.method(POST).path(
+                "/chains/main/blocks/head/context/big_maps/108614/exprua97oRxvyAVsw5QEcWaELYNdeqsZUoFRMXTBuKUoG6MHtbV7C3/normalized",
+            );
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(include_str!("__TEST_DATA__/big_map_value.json"));
+        });
+        let rpc = TezosRpc::new(rpc_url);
+        assert!(matches!(
+            Fa12::new(rpc.contract_at(contract_address.clone(), None).await?),
+            Err(Error::IncompatibleEntrypoint { name }) if name == "transfer"
+        ));
+
+        let fa2 = Fa2::new(rpc.contract_at(contract_address, None).await?)?;
+        let owner: Address = "tz1YY1LvD6TFH4z74pvxPQXBjAKHE5tB5Q8f".try_into()?;
+        let operator: Address = "KT1J4CiyWPmtFPXAjpgBezM5hoVHXHNzWBHK".try_into()?;
+        let partial_transaction = fa2.transfer(vec![Fa2Transfer {
+            from: owner.clone(),
+            txs: vec![Fa2TransferDestination {
+                to: "tz1agAtczEzZS8tV67KGF4urNqxfNCSPksiW".try_into()?,
+                token_id: 0u8.into(),
+                amount: 100u8.into(),
+            }],
+        }])?;
+        let expected: Micheline =
+            micheline::sequence(vec![micheline::primitive_application(DataPrimitive::Pair)
+                .with_args(vec![
+                    micheline::try_string(owner.value())?,
+                    micheline::sequence(vec![micheline::primitive_application(
+                        DataPrimitive::Pair,
+                    )
+                    .with_args(vec![
+                        micheline::try_string("tz1agAtczEzZS8tV67KGF4urNqxfNCSPksiW")?,
+                        micheline::primitive_application(DataPrimitive::Pair)
+                            .with_args(vec![micheline::int(0), micheline::int(100)])
+                            .into(),
+                    ])
+                    .into()]),
+                ])
+                .into()]);
+        assert_eq!(expected, partial_transaction.parameters.unwrap().value);
+
+        let partial_transaction = fa2.update_operators(vec![OperatorUpdate::Remove {
+            owner: owner.clone(),
+            operator: operator.clone(),
+            token_id: 1u8.into(),
+        }])?;
+        let expected: Micheline =
+            micheline::sequence(vec![micheline::primitive_application(DataPrimitive::Right)
+                .with_args(vec![micheline::primitive_application(DataPrimitive::Pair)
+                    .with_args(vec![
+                        micheline::try_string(owner.value())?,
+                        micheline::primitive_application(DataPrimitive::Pair)
+                            .with_args(vec![
+                                micheline::try_string(operator.value())?,
+                                micheline::int(1),
+                            ])
+                            .into(),
+                    ])
+                    .into()])
+                .into()]);
+        assert_eq!(expected, partial_transaction.parameters.unwrap().value);
+
+        let partial_transaction = fa2.balance_of(
+            vec![BalanceRequest {
+                owner: owner.clone(),
+                token_id: 0u8.into(),
+            }],
+            operator,
+        )?;
+        assert_eq!(
+            "balance_of",
+            partial_transaction.parameters.unwrap().entrypoint.to_str()
+        );
+
+        assert_eq!(LedgerLayout::MultiAsset, fa2.ledger()?.layout());
+        assert_eq!(
+            "164748675300576703",
+            fa2.balance(&owner, &0u8.into(), None).await?.to_string()
+        );
+        server.mock(|when, then| {
+            when.method(POST)
+                .path_contains("/chains/main/blocks/head/context/big_maps/108614/");
+            then.status(404);
+        });
+        let owner: Address = "tz1agAtczEzZS8tV67KGF4urNqxfNCSPksiW".try_into()?;
+        assert_eq!(
+            "0",
+            fa2.balance(&owner, &0u8.into(), None).await?.to_string()
+        );
+
+        Ok(())
+    }
+
+    /// Serves the same document for any `ipfs://` URI.
+    struct StaticFetcher(&'static [u8]);
+
+    #[async_trait::async_trait]
+    impl MetadataFetcher for StaticFetcher {
+        async fn fetch(&self, uri: &MetadataUri) -> Result<Vec<u8>> {
+            assert!(matches!(uri, MetadataUri::Ipfs(_)));
+            Ok(self.0.to_vec())
+        }
+    }
+
+    fn metadata_server(metadata_uri: &str) -> MockServer {
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(POST).path(
+                "/chains/main/blocks/head/context/contracts/KT1HNqxFJxnmUcX8wF915wxxaAAU4ixDwWQ7/script/normalized",
+            );
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(include_str!("__TEST_DATA__/metadata_contract.json"));
+        });
+        server.mock(|when, then| {
+            when.method(GET).path(
+                "/chains/main/blocks/head/context/contracts/KT1HNqxFJxnmUcX8wF915wxxaAAU4ixDwWQ7/balance",
+            );
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(r#""1000""#);
+        });
+        server.mock(|when, then| {
+            when.method(POST).path(
+                "/chains/main/blocks/head/context/big_maps/7/expru5X1yxJG6ezR2uHMotwMLNmSzQyh5t1vUnhjx4cS6Pv9qE1Sdo/normalized",
+            );
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(format!(r#"{{ "bytes": "{}" }}"#, hex::encode(metadata_uri)));
+        });
+
+        server
+    }
+
+    #[test]
+    fn test_metadata_uri() -> Result<()> {
+        assert_eq!(
+            MetadataUri::TezosStorage {
+                address: None,
+                network: None,
+                key: "here".into()
+            },
+            "tezos-storage:here".parse()?
+        );
+        assert_eq!(
+            MetadataUri::TezosStorage {
+                address: Some("KT1HNqxFJxnmUcX8wF915wxxaAAU4ixDwWQ7".try_into()?),
+                network: Some("mainnet".into()),
+                key: "%foo".into()
+            },
+            "tezos-storage://KT1HNqxFJxnmUcX8wF915wxxaAAU4ixDwWQ7.mainnet/%25foo".parse()?
+        );
+        let uri = "sha256://0xeaa42ea06b95d7917d22135a630e65352cfd0a721ae88155a1512468a95cb750/https%3A%2F%2Ftezos.com";
+        let parsed: MetadataUri = uri.parse()?;
+        assert!(matches!(
+            &parsed,
+            MetadataUri::Sha256 { uri, .. } if **uri == MetadataUri::Http("https://tezos.com".into())
+        ));
+        assert_eq!(uri, parsed.to_string());
+        assert_eq!(
+            MetadataUri::Ipfs("QmWDcp3BpBjvu8uJYxVqb7JLfr1pcyXsL97Cfkt3y1758o".into()),
+            "ipfs://QmWDcp3BpBjvu8uJYxVqb7JLfr1pcyXsL97Cfkt3y1758o".parse()?
+        );
+        for invalid in [
+            "tezos-storage:",
+            "tezos-storage://KT1HNqxFJxnmUcX8wF915wxxaAAU4ixDwWQ7",
+            "sha256://0x00/https%3A%2F%2Ftezos.com",
+            "ftp://tezos.com",
+        ] {
+            assert!(matches!(
+                invalid.parse::<MetadataUri>(),
+                Err(Error::InvalidMetadataUri { .. })
+            ));
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_metadata() -> Result<()> {
+        let server = metadata_server("tezos-storage:here");
+        server.mock(|when, then| {
+            when.method(POST).path(
+                "/chains/main/blocks/head/context/big_maps/7/expruaHzyjwFcmFKHqR49qdxwJupAna6ygSKo2mFJQtqZQjid5t8GK/normalized",
+            );
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(format!(
+                    r#"{{ "bytes": "{}" }}"#,
+                    hex::encode(include_str!("__TEST_DATA__/metadata.json"))
+                ));
+        });
+        server.mock(|when, then| {
+            when.method(POST)
+                .path("/chains/main/blocks/head/helpers/scripts/run_code")
+                .json_body_partial(
+                    r#"{
+                        "input": { "prim": "Pair", "args": [{ "int": "2" }, { "prim": "Pair", "args": [{ "int": "7" }, { "int": "42" }] }] },
+                        "storage": { "prim": "None" },
+                        "balance": "1000",
+                        "self": "KT1HNqxFJxnmUcX8wF915wxxaAAU4ixDwWQ7"
+                    }"#,
+                );
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(r#"{ "storage": { "prim": "Some", "args": [{ "int": "84" }] }, "operations": [] }"#);
+        });
+        let rpc = TezosRpc::new(server.base_url());
+        let contract = rpc
+            .contract_at("KT1HNqxFJxnmUcX8wF915wxxaAAU4ixDwWQ7".try_into()?, None)
+            .await?;
+
+        let metadata = contract.metadata(&HttpMetadataFetcher::new(), None).await?;
+        assert_eq!(Some("Counter".into()), metadata.name);
+        assert_eq!(vec!["TZIP-016".to_string()], metadata.interfaces);
+
+        let view = metadata.view("multiply").unwrap();
+        assert!(view.pure);
+        let expected: Micheline = micheline::int(84);
+        assert_eq!(expected, view.run(&contract, Some(2u8.into()), None).await?);
+        assert!(matches!(
+            view.run(&contract, None, None).await,
+            Err(Error::IncompatibleValue { .. })
+        ));
+
+        let mut script: serde_json::Value =
+            serde_json::from_str(include_str!("__TEST_DATA__/metadata_contract.json")).unwrap();
+        script["storage"]["args"][1] = serde_json::json!({ "int": "5" });
+        server.mock(|when, then| {
+            when.method(POST).path(
+                "/chains/main/blocks/10/context/contracts/KT1HNqxFJxnmUcX8wF915wxxaAAU4ixDwWQ7/script/normalized",
+            );
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(script.to_string());
+        });
+        server.mock(|when, then| {
+            when.method(GET).path(
+                "/chains/main/blocks/10/context/contracts/KT1HNqxFJxnmUcX8wF915wxxaAAU4ixDwWQ7/balance",
+            );
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(r#""20""#);
+        });
+        server.mock(|when, then| {
+            when.method(POST)
+                .path("/chains/main/blocks/10/helpers/scripts/run_code")
+                .json_body_partial(
+                    r#"{
+                        "input": { "prim": "Pair", "args": [{ "int": "2" }, { "prim": "Pair", "args": [{ "int": "7" }, { "int": "5" }] }] },
+                        "balance": "20"
+                    }"#,
+                );
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(r#"{ "storage": { "prim": "Some", "args": [{ "int": "10" }] }, "operations": [] }"#);
+        });
+        let expected: Micheline = micheline::int(10);
+        assert_eq!(
+            expected,
+            view.run(&contract, Some(2u8.into()), Some(&BlockId::Level(10)))
+                .await?
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_metadata_network() -> Result<()> {
+        for (network, is_valid) in [
+            ("mainnet", true),
+            ("NetXdQprcVkpaWU", true),
+            ("ghostnet", false),
+            ("NetXnHfVqm9iesp", false),
+        ] {
+            let server = metadata_server(&format!(
+                "tezos-storage://KT1HNqxFJxnmUcX8wF915wxxaAAU4ixDwWQ7.{}/here",
+                network
+            ));
+            server.mock(|when, then| {
+                when.method(POST).path(
+                    "/chains/main/blocks/head/context/big_maps/7/expruaHzyjwFcmFKHqR49qdxwJupAna6ygSKo2mFJQtqZQjid5t8GK/normalized",
+                );
+                then.status(200)
+                    .header("content-type", "application/json")
+                    .body(format!(
+                        r#"{{ "bytes": "{}" }}"#,
+                        hex::encode(include_str!("__TEST_DATA__/metadata.json"))
+                    ));
+            });
+            server.mock(|when, then| {
+                when.method(GET).path("/chains/main/chain_id");
+                then.status(200)
+                    .header("content-type", "application/json")
+                    .body(r#""NetXdQprcVkpaWU""#);
+            });
+            let rpc = TezosRpc::new(server.base_url());
+            let contract = rpc
+                .contract_at("KT1HNqxFJxnmUcX8wF915wxxaAAU4ixDwWQ7".try_into()?, None)
+                .await?;
+
+            let result = contract.metadata(&HttpMetadataFetcher::new(), None).await;
+            if is_valid {
+                assert_eq!(Some("Counter".into()), result?.name);
+            } else {
+                assert!(matches!(
+                    result,
+                    Err(Error::MetadataNetworkMismatch { chain_id, .. }) if chain_id == "NetXdQprcVkpaWU"
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_metadata_fetcher() -> Result<()> {
+        let hash = hex::encode(sha2::Sha256::digest(include_bytes!(
+            "__TEST_DATA__/metadata.json"
+        )));
+        let uri = format!(
+            "sha256://0x{}/ipfs%3A%2F%2FQmWDcp3BpBjvu8uJYxVqb7JLfr1pcyXsL97Cfkt3y1758o",
+            hash
+        );
+        let server = metadata_server(&uri);
+        let rpc = TezosRpc::new(server.base_url());
+        let contract = rpc
+            .contract_at("KT1HNqxFJxnmUcX8wF915wxxaAAU4ixDwWQ7".try_into()?, None)
+            .await?;
+        let metadata = contract
+            .metadata(
+                &StaticFetcher(include_bytes!("__TEST_DATA__/metadata.json")),
+                None,
+            )
+            .await?;
+        assert_eq!(Some("1.0.0".into()), metadata.version);
+
+        let uri = uri.replace(&hash, &"0".repeat(64));
+        let server = metadata_server(&uri);
+        let rpc = TezosRpc::new(server.base_url());
+        let contract = rpc
+            .contract_at("KT1HNqxFJxnmUcX8wF915wxxaAAU4ixDwWQ7".try_into()?, None)
+            .await?;
+        assert!(matches!(
+            contract
+                .metadata(
+                    &StaticFetcher(include_bytes!("__TEST_DATA__/metadata.json")),
+                    None
+                )
+                .await,
+            Err(Error::MetadataHashMismatch { .. })
+        ));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_token_metadata() -> Result<()> {
+        let server = MockServer::start();
+        let contract_address: ContractHash = "KT1J4CiyWPmtFPXAjpgBezM5hoVHXHNzWBHK".try_into()?;
+        server.mock(|when, then| {
+            when.method(POST).path(format!(
+                "/chains/main/blocks/head/context/contracts/{}/script/normalized",
+                contract_address.value(),
+            ));
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(include_str!("__TEST_DATA__/contract.json"));
+        });
+        let rpc = TezosRpc::new(server.base_url());
+        let fa2 = Fa2::new(rpc.contract_at(contract_address, None).await?)?;
+        let token_metadata = fa2
+            .contract()
+            .storage()
+            .big_maps()
+            .get_by_name("token_metadata")
+            .unwrap();
+        server.mock(|when, then| {
+            when.method(POST).path(format!(
+                "/chains/main/blocks/head/context/big_maps/{}/exprtZBwZUeYYYfUs9B9Rg2ywHezVHnCCnmF9WsDQVrs582dSK63dC/normalized",
+                token_metadata.id,
+            ));
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(r#"{
+                    "prim": "Pair",
+                    "args": [
+                        { "int": "0" },
+                        [
+                            { "prim": "Elt", "args": [{ "string": "" }, { "bytes": "697066733a2f2f516d57446370334270426a767538754a5978567162374a4c66723170637958734c393743666b743379313735386f" }] },
+                            { "prim": "Elt", "args": [{ "string": "decimals" }, { "bytes": "36" }] },
+                            { "prim": "Elt", "args": [{ "string": "symbol" }, { "bytes": "747a425443" }] }
+                        ]
+                    ]
+                }"#);
+        });
+
+        let fetcher = StaticFetcher(include_bytes!("__TEST_DATA__/token_metadata.json"));
+        let metadata = fa2.token_metadata(&0u8.into(), &fetcher, None).await?;
+        assert_eq!("0", metadata.token_id.to_string());
+        assert_eq!(Some("Wrapped Token".into()), metadata.name);
+        assert_eq!(Some("tzBTC".into()), metadata.symbol);
+        assert_eq!(Some(6), metadata.decimals);
+        assert_eq!(1, metadata.formats.len());
+        assert_eq!(Some(1024), metadata.formats[0].file_size);
+        assert_eq!(
+            Some("512x512".into()),
+            metadata.formats[0]
+                .dimensions
+                .as_ref()
+                .map(|dimensions| dimensions.value.clone())
+        );
+        assert_eq!("rarity", metadata.attributes[0].name);
+        assert_eq!(
+            Some(&serde_json::Value::String("No License".into())),
+            metadata.extra.get("rights")
+        );
+
+        let server = metadata_server("tezos-storage:here");
+        server.mock(|when, then| {
+            when.method(POST).path(
+                "/chains/main/blocks/head/context/big_maps/7/expruaHzyjwFcmFKHqR49qdxwJupAna6ygSKo2mFJQtqZQjid5t8GK/normalized",
+            );
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(format!(
+                    r#"{{ "bytes": "{}" }}"#,
+                    hex::encode(include_str!("__TEST_DATA__/metadata.json"))
+                ));
+        });
+        server.mock(|when, then| {
+            when.method(POST)
+                .path("/chains/main/blocks/head/helpers/scripts/run_code")
+                .json_body_partial(
+                    r#"{ "input": { "prim": "Pair", "args": [{ "int": "3" }, { "prim": "Pair", "args": [{ "int": "7" }, { "int": "42" }] }] } }"#,
+                );
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(r#"{
+                    "storage": {
+                        "prim": "Some",
+                        "args": [{ "prim": "Pair", "args": [{ "int": "3" }, [{ "prim": "Elt", "args": [{ "string": "name" }, { "bytes": "436f756e74657220746f6b656e" }] }]] }]
+                    },
+                    "operations": []
+                }"#);
+        });
+        let rpc = TezosRpc::new(server.base_url());
+        let contract = rpc
+            .contract_at("KT1HNqxFJxnmUcX8wF915wxxaAAU4ixDwWQ7".try_into()?, None)
+            .await?;
+        let metadata = contract
+            .token_metadata(&3u8.into(), &HttpMetadataFetcher::new(), None)
+            .await?;
+        assert_eq!("3", metadata.token_id.to_string());
+        assert_eq!(Some("Counter token".into()), metadata.name);
+        assert_eq!(None, metadata.decimals);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_call_view() -> Result<()> {
+        let server = metadata_server("tezos-storage:here");
+        server.mock(|when, then| {
+            when.method(POST)
+                .path("/chains/main/blocks/head/helpers/scripts/run_script_view")
+                .json_body_partial(
+                    r#"{
+                        "contract": "KT1HNqxFJxnmUcX8wF915wxxaAAU4ixDwWQ7",
+                        "view": "multiply",
+                        "input": { "int": "2" },
+                        "unlimited_gas": true,
+                        "source": "tz1YY1LvD6TFH4z74pvxPQXBjAKHE5tB5Q8f",
+                        "payer": "tz1YY1LvD6TFH4z74pvxPQXBjAKHE5tB5Q8f"
+                    }"#,
+                );
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(r#"{ "data": { "int": "84" } }"#);
+        });
+        let rpc = TezosRpc::new(server.base_url());
+        let contract = rpc
+            .contract_at("KT1HNqxFJxnmUcX8wF915wxxaAAU4ixDwWQ7".try_into()?, None)
+            .await?;
+
+        let nat: Type = types::nat();
+        assert_eq!(
+            vec![ContractView {
+                name: "multiply".into(),
+                input_type: nat.clone(),
+                output_type: nat,
+            }],
+            contract.views()
+        );
+
+        let result = contract
+            .call_view("multiply", 2u8.into())?
+            .source("tz1YY1LvD6TFH4z74pvxPQXBjAKHE5tB5Q8f".try_into()?)
+            .send()
+            .await?;
+        let expected: Micheline = micheline::int(84);
+        assert_eq!(expected, result);
+
+        assert!(matches!(
+            contract.call_view("divide", 2u8.into()),
+            Err(Error::ViewNotFound { name }) if name == "divide"
+        ));
+        assert!(matches!(
+            contract.call_view("multiply", try_string("2")?),
+            Err(Error::IncompatibleValue { .. })
+        ));
+
+        let mut script: serde_json::Value =
+            serde_json::from_str(include_str!("__TEST_DATA__/metadata_contract.json")).unwrap();
+        script["code"]
+            .as_array_mut()
+            .unwrap()
+            .push(serde_json::json!({
+                "prim": "view",
+                "args": [{ "string": "future" }, { "prim": "future_type" }, { "prim": "nat" }, []]
+            }));
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(POST).path(
+                "/chains/main/blocks/head/context/contracts/KT1HNqxFJxnmUcX8wF915wxxaAAU4ixDwWQ7/script/normalized",
+            );
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(script.to_string());
+        });
+        let rpc = TezosRpc::new(server.base_url());
+        let contract = rpc
+            .contract_at("KT1HNqxFJxnmUcX8wF915wxxaAAU4ixDwWQ7".try_into()?, None)
+            .await?;
+        assert_eq!(1, contract.views().len());
+        assert!(contract.call_view("multiply", 2u8.into()).is_ok());
+        assert!(matches!(
+            contract.call_view("future", 2u8.into()),
+            Err(Error::InvalidView { name, .. }) if name == "future"
+        ));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_originator() -> Result<()> {
+        let code = r#"[
+            { "prim": "parameter", "args": [{ "prim": "nat" }] },
+            { "prim": "storage", "args": [{ "prim": "pair", "args": [{ "prim": "big_map", "args": [{ "prim": "nat" }, { "prim": "nat" }] }, { "prim": "nat" }] }] },
+            { "prim": "code", "args": [[{ "prim": "CDR" }, { "prim": "NIL", "args": [{ "prim": "operation" }] }, { "prim": "PAIR" }]] }
+        ]"#;
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(GET).path("/chains/main/blocks/head/hash");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(r#""BLyKu3tnc9NCuiFfCqfeVGPCoZTyW63dYh2XAYxkM7fQYKCqsju""#);
+        });
+        server.mock(|when, then| {
+            when.method(GET).path(
+                "/chains/main/blocks/head/context/contracts/tz1YY1LvD6TFH4z74pvxPQXBjAKHE5tB5Q8f/counter",
+            );
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(r#""10""#);
+        });
+        server.mock(|when, then| {
+            when.method(POST)
+                .path("/chains/main/blocks/head/helpers/scripts/run_operation");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(format!(
+                    r#"{{
+                        "contents": [{{
+                            "kind": "origination",
+                            "source": "tz1YY1LvD6TFH4z74pvxPQXBjAKHE5tB5Q8f",
+                            "fee": "0",
+                            "counter": "11",
+                            "gas_limit": "1040000",
+                            "storage_limit": "60000",
+                            "balance": "0",
+                            "script": {{ "code": {}, "storage": {{ "prim": "Pair", "args": [[], {{ "int": "0" }}] }} }},
+                            "metadata": {{
+                                "balance_updates": [],
+                                "operation_result": {{
+                                    "status": "applied",
+                                    "originated_contracts": ["KT1RTw7Mufvtod3Jhh3kcD95rjJ2iqF7Nw1y"],
+                                    "consumed_milligas": "1420000",
+                                    "storage_size": "120",
+                                    "paid_storage_size_diff": "120"
+                                }}
+                            }}
+                        }}]
+                    }}"#,
+                    code
+                ));
+        });
+        let rpc = TezosRpc::new(server.base_url());
+
+        assert!(matches!(
+            Originator::from_json(&rpc, code, r#"{ "int": "0" }"#),
+            Err(Error::IncompatibleValue { .. })
+        ));
+
+        let originator = Originator::from_json(
+            &rpc,
+            code,
+            r#"{ "prim": "Pair", "args": [[], { "int": "0" }] }"#,
+        )?;
+        let source: ImplicitAddress = "tz1YY1LvD6TFH4z74pvxPQXBjAKHE5tB5Q8f".try_into()?;
+        let secret_key: SecretKey = "edskRv7VyXGVZb8EsrR7D9XKUbbAQNQGtALP6QeB16ZCD7SmmJpzyeneJVg3Mq56YLbxRA1kSdAXiswwPiaVfR3NHGMCXCziuZ".try_into()?;
+        let operation = originator.estimate(&source).await?;
+        match operation.contents.as_slice() {
+            [OperationContent::Origination(origination)] => {
+                assert_eq!(origination.counter, 11u8.into());
+                assert_eq!(origination.gas_limit, 1520u16.into());
+                assert!(origination.fee > 0u8.into());
+            }
+            _ => panic!("expected a single origination"),
+        }
+
+        let operation_hash = operation.into_signed_operation(&secret_key)?.hash()?;
+        let injection = server.mock(|when, then| {
+            when.method(POST).path("/injection/operation");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(format!(r#""{}""#, operation_hash.value()));
+        });
+        let originated = originator.send(&source, &secret_key).await?;
+        injection.assert();
+        assert_eq!(operation_hash, originated.operation_hash);
+        assert_eq!(contract_address(&operation_hash, 0)?, originated.address);
+
+        let operation_hash: OperationHash =
+            "oneQf8XP5wBE7mZPQ1koZj18NJABqtccdXQ19iCnfZYsGTnSCPM".try_into()?;
+        assert_eq!(
+            "KT1RTw7Mufvtod3Jhh3kcD95rjJ2iqF7Nw1y",
+            contract_address(&operation_hash, 0)?.value()
+        );
+        assert_eq!(
+            "KT1FjwxEhbDFjRSVNFYDtKNGJbqw5bd8gRSU",
+            contract_address(&operation_hash, 1)?.value()
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_big_map_mirror() -> Result<()> {
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(POST).path(
+                "/chains/main/blocks/head/context/contracts/KT1J4CiyWPmtFPXAjpgBezM5hoVHXHNzWBHK/script/normalized",
+            );
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(include_str!("__TEST_DATA__/ledger_contract.json"));
+        });
+        server.mock(|when, then| {
+            when.method(GET)
+                .path("/chains/main/blocks/head/context/big_maps/9");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(r#"[{ "int": "7" }]"#);
+        });
+        server.mock(|when, then| {
+            when.method(POST).path(
+                "/chains/main/blocks/99/context/big_maps/9/exprua97oRxvyAVsw5QEcWaELYNdeqsZUoFRMXTBuKUoG6MHtbV7C3/normalized",
+            );
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(r#"{ "int": "4" }"#);
+        });
+        let blocks: Vec<serde_json::Value> =
+            serde_json::from_str(include_str!("__TEST_DATA__/ledger_blocks.json"))?;
+        for (block_id, block) in ["100", "101", "head"].iter().zip(blocks.iter()) {
+            server.mock(|when, then| {
+                when.method(GET)
+                    .path(format!("/chains/main/blocks/{}", block_id));
+                then.status(200)
+                    .header("content-type", "application/json")
+                    .body(block.to_string());
+            });
+        }
+        let rpc = TezosRpc::new(server.base_url());
+        let contract = rpc
+            .contract_at("KT1J4CiyWPmtFPXAjpgBezM5hoVHXHNzWBHK".try_into()?, None)
+            .await?;
+        let big_map = contract.storage().big_maps().get_by_name("ledger").unwrap();
+
+        let values: Vec<Micheline> = vec![micheline::int(7)];
+        assert_eq!(values, big_map.values(None, None, None).await?);
+
+        let owner = "tz1YY1LvD6TFH4z74pvxPQXBjAKHE5tB5Q8f";
+        let other = "tz1agAtczEzZS8tV67KGF4urNqxfNCSPksiW";
+        let key = |owner: &str, token_id: u8| -> Result<Data> {
+            Ok(pair(vec![try_string(owner)?, token_id.into()]))
+        };
+        let optimized_key: Data = pair(vec![
+            bytes(hex::decode("00008d760390453ce759265dcf970705a114c2cae601").unwrap()),
+            0u8.into(),
+        ]);
+        let mut mirror = big_map.mirror(vec![optimized_key.into()], 99).await?;
+        assert_eq!(
+            Some(&BigMapEntry::new(key(owner, 0)?.into(), micheline::int(4))),
+            mirror.get(key(owner, 0)?.into())?
+        );
+
+        mirror.sync(&rpc, None).await?;
+        assert_eq!(102, mirror.level());
+        assert_eq!(2, mirror.len());
+        assert_eq!(
+            Some(&BigMapEntry::new(key(owner, 0)?.into(), micheline::int(5))),
+            mirror.get(key(owner, 0)?.into())?
+        );
+        assert_eq!(
+            Some(&BigMapEntry::new(key(owner, 1)?.into(), micheline::int(7))),
+            mirror.get(key(owner, 1)?.into())?
+        );
+        assert_eq!(None, mirror.get(key(other, 0)?.into())?);
+
+        let block = serde_json::from_str(include_str!("__TEST_DATA__/big_map_block.json"))?;
+        let mut copy = BigMapMirror::new(8, types::string(), types::bytes(), 99);
+        copy.apply_block(&block)?;
+        assert_eq!(2, copy.len());
+        assert_eq!(
+            Some(&BigMapEntry::new(
+                try_string("b")?,
+                micheline::bytes(vec![2u8])
+            )),
+            copy.get(try_string("b")?)?
+        );
+        assert!(copy.get(try_string("c")?)?.is_some());
+
+        let mut ledger = BigMapMirror::new(
+            9,
+            types::pair(vec![types::address(), types::nat()]),
+            types::nat(),
+            99,
+        );
+        ledger.apply_block(&block)?;
+        assert_eq!(2, ledger.len());
+        for (owner, balance) in [
+            ("tz1YY1LvD6TFH4z74pvxPQXBjAKHE5tB5Q8f", 5),
+            ("tz1agAtczEzZS8tV67KGF4urNqxfNCSPksiW", 6),
+        ] {
+            let key: Data = pair(vec![try_string(owner)?, 0u8.into()]);
+            assert_eq!(
+                Some(&BigMapEntry::new(
+                    key.clone().into(),
+                    micheline::int(balance)
+                )),
+                ledger.get(key.into())?
+            );
+        }
+        assert!(matches!(
+            copy.apply_block(&block),
+            Err(Error::UnexpectedBlockLevel {
+                expected: 101,
+                level: 100
+            })
+        ));
+
+        Ok(())
+    }
 }
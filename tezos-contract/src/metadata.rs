@@ -0,0 +1,196 @@
+mod fetcher;
+mod uri;
+mod view;
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tezos_michelson::michelson::{
+    convert::FromMichelson,
+    data::{self, Bytes, Data},
+    Michelson,
+};
+use tezos_rpc::{http::Http, models::block::BlockId};
+
+use crate::{Contract, ContractFetcher, Error, Result, Storage};
+
+#[cfg(feature = "http")]
+pub use self::fetcher::HttpMetadataFetcher;
+pub use self::{
+    fetcher::MetadataFetcher,
+    uri::MetadataUri,
+    view::{MichelsonStorageView, RestApiQuery, View, ViewAnnotation, ViewImplementation},
+};
+
+/// The name of the big map holding the metadata of a contract.
+const METADATA_BIG_MAP: &str = "metadata";
+
+/// The chain ids of the long-lived networks `tezos-storage` URIs may refer to by name.
+const NETWORK_CHAIN_IDS: [(&str, &str); 2] = [
+    ("mainnet", "NetXdQprcVkpaWU"),
+    ("ghostnet", "NetXnHfVqm9iesp"),
+];
+
+/// A [TZIP-16](https://gitlab.com/tezos/tzip/-/blob/master/proposals/tzip-16/tzip-16.md) contract metadata document.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct ContractMetadata {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub license: Option<License>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub authors: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub homepage: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<Source>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub interfaces: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub views: Vec<View>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct License {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub details: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Source {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tools: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+}
+
+impl ContractMetadata {
+    pub fn from_slice(value: &[u8]) -> Result<Self> {
+        Ok(serde_json::from_slice(value)?)
+    }
+
+    pub fn view(&self, name: &str) -> Option<&View> {
+        self.views.iter().find(|view| view.name == name)
+    }
+}
+
+impl<'a, HttpClient: Http + Sync> Contract<'a, HttpClient> {
+    /// Reads the URI of the contract's metadata, stored at the empty key of its `%metadata` big map.
+    pub async fn metadata_uri(&self, block_id: Option<&BlockId>) -> Result<MetadataUri> {
+        let uri = metadata_value(self.storage(), "", block_id).await?;
+        let uri = String::from_utf8(uri).map_err(|error| Error::InvalidMetadataUri {
+            uri: String::from_utf8_lossy(error.as_bytes()).into(),
+        })?;
+
+        uri.parse()
+    }
+
+    /// Reads the contract's [ContractMetadata], resolving its URI.
+    ///
+    /// `tezos-storage:` URIs are read from the `%metadata` big map of this contract, or of the contract they
+    /// designate, while `ipfs://` and `http(s)://` URIs are fetched with `fetcher`.
+    pub async fn metadata<Fetcher: MetadataFetcher + ?Sized>(
+        &self,
+        fetcher: &Fetcher,
+        block_id: Option<&BlockId>,
+    ) -> Result<ContractMetadata> {
+        let uri = self.metadata_uri(block_id).await?;
+        let content = self.resolve_metadata_uri(&uri, fetcher, block_id).await?;
+
+        ContractMetadata::from_slice(&content)
+    }
+
+    /// Reads the content `uri` points to, checking its hash for `sha256://` URIs.
+    pub(crate) async fn resolve_metadata_uri<Fetcher: MetadataFetcher + ?Sized>(
+        &self,
+        uri: &MetadataUri,
+        fetcher: &Fetcher,
+        block_id: Option<&BlockId>,
+    ) -> Result<Vec<u8>> {
+        match uri {
+            MetadataUri::Sha256 { hash, uri } => {
+                let content = self.metadata_content(uri, fetcher, block_id).await?;
+                if Sha256::digest(&content).as_slice() != hash.as_slice() {
+                    return Err(Error::MetadataHashMismatch {
+                        uri: uri.to_string(),
+                    });
+                }
+                Ok(content)
+            }
+            uri => self.metadata_content(uri, fetcher, block_id).await,
+        }
+    }
+
+    async fn metadata_content<Fetcher: MetadataFetcher + ?Sized>(
+        &self,
+        uri: &MetadataUri,
+        fetcher: &Fetcher,
+        block_id: Option<&BlockId>,
+    ) -> Result<Vec<u8>> {
+        if let MetadataUri::TezosStorage {
+            network: Some(network),
+            ..
+        } = uri
+        {
+            self.check_network(uri, network).await?;
+        }
+        match uri {
+            MetadataUri::TezosStorage {
+                address: Some(address),
+                key,
+                ..
+            } if address != self.address() => {
+                let contract = self.client().contract_at(address.clone(), block_id).await?;
+                metadata_value(contract.storage(), key, block_id).await
+            }
+            MetadataUri::TezosStorage { key, .. } => {
+                metadata_value(self.storage(), key, block_id).await
+            }
+            MetadataUri::Sha256 { .. } => Err(Error::InvalidMetadataUri {
+                uri: uri.to_string(),
+            }),
+            MetadataUri::Ipfs(_) | MetadataUri::Http(_) => fetcher.fetch(uri).await,
+        }
+    }
+
+    /// Checks that `network`, a chain id or the name of a known network, designates the chain of the client.
+    async fn check_network(&self, uri: &MetadataUri, network: &str) -> Result<()> {
+        let expected = NETWORK_CHAIN_IDS
+            .iter()
+            .find(|(name, _)| *name == network)
+            .map_or(network, |(_, chain_id)| chain_id);
+        let chain_id = self.client().get_chain_id().send().await?;
+        if chain_id != expected {
+            return Err(Error::MetadataNetworkMismatch {
+                uri: uri.to_string(),
+                chain_id,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+async fn metadata_value<HttpClient: Http + Sync>(
+    storage: &Storage<'_, HttpClient>,
+    key: &str,
+    block_id: Option<&BlockId>,
+) -> Result<Vec<u8>> {
+    let big_map = storage
+        .big_maps()
+        .get_by_name(METADATA_BIG_MAP)
+        .ok_or(Error::MetadataNotFound)?;
+    let key: Data = data::try_string(key)?;
+    let value: Data = big_map
+        .get_value(Michelson::from(key), block_id)
+        .await?
+        .try_into()?;
+
+    Ok((&Bytes::from_michelson(value)?).into())
+}
@@ -0,0 +1,83 @@
+use async_trait::async_trait;
+
+use crate::Result;
+
+use super::MetadataUri;
+
+/// Fetches the content of the off-chain [MetadataUri]s, i.e. [MetadataUri::Ipfs] and [MetadataUri::Http].
+///
+/// `tezos-storage:` URIs are read from the contract's storage and `sha256://` URIs are checked after fetching
+/// the URI they wrap, so implementations only need to support the off-chain schemes. An implementation serving
+/// fixed documents can be used to read metadata offline, e.g. in tests.
+#[async_trait]
+pub trait MetadataFetcher {
+    async fn fetch(&self, uri: &MetadataUri) -> Result<Vec<u8>>;
+}
+
+#[cfg(feature = "http")]
+mod http {
+    use async_trait::async_trait;
+
+    use crate::{Error, Result};
+
+    use super::{MetadataFetcher, MetadataUri};
+
+    /// A [MetadataFetcher] downloading the documents over HTTP, resolving `ipfs://` URIs through an IPFS gateway.
+    #[derive(Debug, Clone)]
+    pub struct HttpMetadataFetcher {
+        client: reqwest::Client,
+        ipfs_gateway: String,
+    }
+
+    impl HttpMetadataFetcher {
+        const DEFAULT_IPFS_GATEWAY: &'static str = "https://ipfs.io/ipfs/";
+
+        pub fn new() -> Self {
+            Self::with_ipfs_gateway(Self::DEFAULT_IPFS_GATEWAY.into())
+        }
+
+        /// Creates a fetcher resolving `ipfs://<path>` URIs to `<ipfs_gateway><path>`.
+        pub fn with_ipfs_gateway(ipfs_gateway: String) -> Self {
+            Self {
+                client: reqwest::Client::new(),
+                ipfs_gateway,
+            }
+        }
+
+        async fn get(&self, url: &str) -> reqwest::Result<Vec<u8>> {
+            let response = self.client.get(url).send().await?.error_for_status()?;
+
+            Ok(response.bytes().await?.to_vec())
+        }
+    }
+
+    impl Default for HttpMetadataFetcher {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    #[async_trait]
+    impl MetadataFetcher for HttpMetadataFetcher {
+        async fn fetch(&self, uri: &MetadataUri) -> Result<Vec<u8>> {
+            let url = match uri {
+                MetadataUri::Ipfs(path) => format!("{}{}", self.ipfs_gateway, path),
+                MetadataUri::Http(url) => url.clone(),
+                _ => {
+                    return Err(Error::MetadataFetch {
+                        uri: uri.to_string(),
+                        description: "unsupported scheme".into(),
+                    })
+                }
+            };
+
+            self.get(&url).await.map_err(|error| Error::MetadataFetch {
+                uri: uri.to_string(),
+                description: error.to_string(),
+            })
+        }
+    }
+}
+
+#[cfg(feature = "http")]
+pub use self::http::HttpMetadataFetcher;
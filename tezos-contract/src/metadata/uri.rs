@@ -0,0 +1,169 @@
+use std::str::FromStr;
+
+use percent_encoding::{percent_decode_str, utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+use tezos_core::types::encoded::{ContractHash, Encoded};
+
+use crate::{Error, Result};
+
+/// The characters escaped in the components of a URI.
+const COMPONENT: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'_')
+    .remove(b'.')
+    .remove(b'~');
+
+/// A [TZIP-16](https://gitlab.com/tezos/tzip/-/blob/master/proposals/tzip-16/tzip-16.md) metadata URI.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MetadataUri {
+    /// `tezos-storage:<key>` or `tezos-storage://<address>[.<network>]/<key>`, pointing to a value of the
+    /// `%metadata` big map of the current contract or of `address`.
+    TezosStorage {
+        address: Option<ContractHash>,
+        network: Option<String>,
+        key: String,
+    },
+    /// `sha256://0x<hash>/<uri>`, whose content must match `hash`.
+    Sha256 {
+        hash: Vec<u8>,
+        uri: Box<MetadataUri>,
+    },
+    /// `ipfs://<cid>[/<path>]`, holding the part following the scheme.
+    Ipfs(String),
+    /// `http://` or `https://`, holding the whole URL.
+    Http(String),
+}
+
+impl MetadataUri {
+    const TEZOS_STORAGE_SCHEME: &'static str = "tezos-storage:";
+    const SHA256_SCHEME: &'static str = "sha256://";
+    const IPFS_SCHEME: &'static str = "ipfs://";
+
+    fn invalid(value: &str) -> Error {
+        Error::InvalidMetadataUri {
+            uri: value.to_string(),
+        }
+    }
+
+    fn decode(value: &str) -> Result<String> {
+        percent_decode_str(value)
+            .decode_utf8()
+            .map(Into::into)
+            .map_err(|_| Self::invalid(value))
+    }
+
+    fn parse_tezos_storage(value: &str) -> Result<Self> {
+        let path = &value[Self::TEZOS_STORAGE_SCHEME.len()..];
+        let (authority, key) = match path.strip_prefix("//") {
+            Some(path) => {
+                let (authority, key) = path.split_once('/').ok_or_else(|| Self::invalid(value))?;
+                (Some(authority), key)
+            }
+            None => (None, path),
+        };
+        if key.is_empty() || key.contains('/') {
+            return Err(Self::invalid(value));
+        }
+        let (address, network) = match authority {
+            Some(authority) => {
+                let (address, network) = match authority.split_once('.') {
+                    Some((address, network)) => (address, Some(network.to_string())),
+                    None => (authority, None),
+                };
+                let address: ContractHash = address.try_into().map_err(|_| Self::invalid(value))?;
+                (Some(address), network)
+            }
+            None => (None, None),
+        };
+
+        Ok(Self::TezosStorage {
+            address,
+            network,
+            key: Self::decode(key)?,
+        })
+    }
+
+    fn parse_sha256(value: &str) -> Result<Self> {
+        let path = &value[Self::SHA256_SCHEME.len()..];
+        let (hash, uri) = path.split_once('/').ok_or_else(|| Self::invalid(value))?;
+        let hash = hash
+            .strip_prefix("0x")
+            .and_then(|hash| hex::decode(hash).ok())
+            .filter(|hash| hash.len() == 32)
+            .ok_or_else(|| Self::invalid(value))?;
+        let uri: Self = Self::decode(uri)?.parse()?;
+        if let Self::Sha256 { .. } = uri {
+            return Err(Self::invalid(value));
+        }
+
+        Ok(Self::Sha256 {
+            hash,
+            uri: Box::new(uri),
+        })
+    }
+}
+
+impl FromStr for MetadataUri {
+    type Err = Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        if value.starts_with(Self::TEZOS_STORAGE_SCHEME) {
+            return Self::parse_tezos_storage(value);
+        }
+        if value.starts_with(Self::SHA256_SCHEME) {
+            return Self::parse_sha256(value);
+        }
+        if let Some(path) = value.strip_prefix(Self::IPFS_SCHEME) {
+            if path.is_empty() {
+                return Err(Self::invalid(value));
+            }
+            return Ok(Self::Ipfs(path.into()));
+        }
+        if value.starts_with("https://") || value.starts_with("http://") {
+            return Ok(Self::Http(value.into()));
+        }
+
+        Err(Self::invalid(value))
+    }
+}
+
+impl TryFrom<&str> for MetadataUri {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self> {
+        value.parse()
+    }
+}
+
+impl std::fmt::Display for MetadataUri {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::TezosStorage {
+                address,
+                network,
+                key,
+            } => {
+                write!(f, "{}", Self::TEZOS_STORAGE_SCHEME)?;
+                if let Some(address) = address {
+                    write!(f, "//{}", address.value())?;
+                    if let Some(network) = network {
+                        write!(f, ".{}", network)?;
+                    }
+                    write!(f, "/")?;
+                }
+                write!(f, "{}", utf8_percent_encode(key, COMPONENT))
+            }
+            Self::Sha256 { hash, uri } => {
+                let uri = uri.to_string();
+                write!(
+                    f,
+                    "{}0x{}/{}",
+                    Self::SHA256_SCHEME,
+                    hex::encode(hash),
+                    utf8_percent_encode(&uri, COMPONENT)
+                )
+            }
+            Self::Ipfs(path) => write!(f, "{}{}", Self::IPFS_SCHEME, path),
+            Self::Http(url) => write!(f, "{}", url),
+        }
+    }
+}
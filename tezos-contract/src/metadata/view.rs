@@ -0,0 +1,190 @@
+use serde::{Deserialize, Serialize};
+use tezos_core::types::{encoded::Address, mutez::Mutez};
+use tezos_michelson::{
+    micheline::{self, sequence::Sequence, Micheline},
+    michelson::{
+        data::Data,
+        types::{self, Type},
+        DataPrimitive, InstructionPrimitive, TypePrimitive,
+    },
+    MichelinePacker,
+};
+use tezos_rpc::{
+    http::Http,
+    models::{block::BlockId, contract::UnparsingMode},
+};
+
+use crate::{Contract, Error, Result};
+
+/// An off-chain view declared in the contract's metadata.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct View {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub pure: bool,
+    pub implementations: Vec<ViewImplementation>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum ViewImplementation {
+    MichelsonStorageView(MichelsonStorageView),
+    RestApiQuery(RestApiQuery),
+}
+
+/// A view implemented by Michelson code running against the contract's storage.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct MichelsonStorageView {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parameter: Option<Micheline>,
+    pub return_type: Micheline,
+    pub code: Micheline,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub annotations: Vec<ViewAnnotation>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ViewAnnotation {
+    pub name: String,
+    pub description: String,
+}
+
+/// A view implemented by a REST API described by an OpenAPI specification.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct RestApiQuery {
+    pub specification_uri: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base_uri: Option<String>,
+    pub path: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub method: Option<String>,
+}
+
+impl View {
+    /// Returns the view's first Michelson storage implementation.
+    pub fn michelson_storage_view(&self) -> Option<&MichelsonStorageView> {
+        self.implementations
+            .iter()
+            .find_map(|implementation| match implementation {
+                ViewImplementation::MichelsonStorageView(view) => Some(view),
+                _ => None,
+            })
+    }
+
+    /// Runs the view against the storage of `contract` at `block_id`, see [MichelsonStorageView::run].
+    pub async fn run<HttpClient: Http + Sync>(
+        &self,
+        contract: &Contract<'_, HttpClient>,
+        parameter: Option<Data>,
+        block_id: Option<&BlockId>,
+    ) -> Result<Micheline> {
+        self.michelson_storage_view()
+            .ok_or_else(|| Error::ViewNotFound {
+                name: self.name.clone(),
+            })?
+            .run(contract, parameter, block_id)
+            .await
+    }
+}
+
+impl MichelsonStorageView {
+    /// Runs the view against the storage of `contract` with the `run_code` RPC, returning its result.
+    ///
+    /// The view's code is wrapped in a script taking the pair of `parameter` (`Unit` if the view does not expect
+    /// one) and the contract's storage as its parameter, and storing the view's result. The storage and the balance
+    /// of the contract are fetched at `block_id` (the head by default) and the script is run at the same block.
+    pub async fn run<HttpClient: Http + Sync>(
+        &self,
+        contract: &Contract<'_, HttpClient>,
+        parameter: Option<Data>,
+        block_id: Option<&BlockId>,
+    ) -> Result<Micheline> {
+        let rpc = contract.client();
+        let address: Address = contract.address().into();
+        let mut script_request = rpc
+            .get_contract_script(&address)
+            .unparsing_mode(UnparsingMode::Optimized_legacy);
+        let mut balance_request = rpc.get_contract_balance(&address);
+        if let Some(block_id) = block_id {
+            script_request = script_request.block_id(block_id);
+            balance_request = balance_request.block_id(block_id);
+        }
+        let storage = script_request.send().await?.storage;
+        let balance: Mutez = balance_request.send().await?.to_string().parse()?;
+
+        let (parameter_type, parameter): (Type, Micheline) = match (&self.parameter, parameter) {
+            (Some(parameter_type), Some(parameter)) => {
+                (parameter_type.clone().try_into()?, parameter.into())
+            }
+            (None, None) => (
+                types::unit(),
+                micheline::primitive_application(DataPrimitive::Unit).into(),
+            ),
+            _ => {
+                return Err(Error::IncompatibleValue {
+                    description: "the parameter does not match the view's declaration".into(),
+                })
+            }
+        };
+        let view_type: Type =
+            types::pair(vec![parameter_type, contract.storage().r#type().clone()]);
+        let result_type: Type = types::option(self.return_type.clone().try_into()?);
+        let script: Sequence = vec![
+            micheline::primitive_application(TypePrimitive::Parameter)
+                .with_args(vec![view_type.into()])
+                .into(),
+            micheline::primitive_application(TypePrimitive::Storage)
+                .with_args(vec![result_type.into()])
+                .into(),
+            micheline::primitive_application(TypePrimitive::Code)
+                .with_args(vec![micheline::sequence(vec![
+                    micheline::primitive_application(InstructionPrimitive::Car).into(),
+                    self.code.clone(),
+                    micheline::primitive_application(InstructionPrimitive::Some).into(),
+                    micheline::primitive_application(InstructionPrimitive::Nil)
+                        .with_args(vec![micheline::primitive_application(
+                            TypePrimitive::Operation,
+                        )
+                        .into()])
+                        .into(),
+                    micheline::primitive_application(InstructionPrimitive::Pair).into(),
+                ])])
+                .into(),
+        ]
+        .into();
+        let input: Micheline = micheline::primitive_application(DataPrimitive::Pair)
+            .with_args(vec![parameter, storage])
+            .into();
+        let initial_storage: Micheline =
+            micheline::primitive_application(DataPrimitive::None).into();
+
+        let mut request = rpc
+            .run_code(&script, &initial_storage, &input)
+            .balance(&balance)
+            .self_address(contract.address())
+            .unparsing_mode(UnparsingMode::Optimized_legacy);
+        if let Some(block_id) = block_id {
+            request = request.block_id(block_id);
+        }
+        let result = request.send().await?;
+        let value = match result.storage {
+            Micheline::PrimitiveApplication(primitive_application)
+                if primitive_application.prim() == DataPrimitive::Some.to_str() =>
+            {
+                primitive_application
+                    .into_args()
+                    .and_then(|args| args.into_iter().next())
+                    .ok_or(Error::InvalidViewResult)?
+            }
+            _ => return Err(Error::InvalidViewResult),
+        };
+
+        Ok(MichelinePacker::post_unpack(value, &self.return_type)?)
+    }
+}
@@ -0,0 +1,82 @@
+mod fa12;
+mod fa2;
+mod ledger;
+mod metadata;
+
+use tezos_michelson::{
+    micheline::{self, primitive_application::PrimitiveApplication, Micheline},
+    michelson::{data::Data, types::Type},
+};
+use tezos_operation::operations::{Entrypoint, Parameters};
+use tezos_rpc::http::Http;
+
+use crate::{Contract, Error, MappedEntrypoints, PartialTransaction, Result};
+
+pub use self::{
+    fa12::{Fa12, Fa12Response},
+    fa2::{BalanceRequest, Fa2, Fa2Transfer, Fa2TransferDestination, OperatorUpdate},
+    ledger::{Ledger, LedgerLayout},
+    metadata::{TokenAttribute, TokenFormat, TokenFormatUnitValue, TokenMetadata},
+};
+
+/// Checks that `entrypoint` exists and that its type matches `expected`, ignoring annotations.
+fn check_entrypoint(
+    entrypoints: &MappedEntrypoints,
+    entrypoint: &str,
+    expected: Type,
+) -> Result<()> {
+    let actual = entrypoints
+        .get(&Entrypoint::from_str(entrypoint))
+        .ok_or(Error::EntrypointNotFound)?;
+    if !same_type(actual, &expected) {
+        return Err(Error::IncompatibleEntrypoint {
+            name: entrypoint.into(),
+        });
+    }
+
+    Ok(())
+}
+
+/// Creates a call to an entrypoint whose type has already been checked.
+fn call<HttpClient: Http>(
+    contract: &Contract<'_, HttpClient>,
+    entrypoint: &str,
+    value: Data,
+) -> PartialTransaction {
+    let value: Micheline = value.into();
+
+    PartialTransaction::new(
+        0u8.into(),
+        contract.address().into(),
+        Some(Parameters::new(entrypoint.into(), value.normalized())),
+    )
+}
+
+/// Compares two types, ignoring their annotations and comb representation.
+pub(crate) fn same_type(lhs: &Type, rhs: &Type) -> bool {
+    let lhs: Micheline = lhs.clone().normalized().into();
+    let rhs: Micheline = rhs.clone().normalized().into();
+
+    without_annotations(lhs) == without_annotations(rhs)
+}
+
+fn without_annotations(value: Micheline) -> Micheline {
+    match value {
+        Micheline::PrimitiveApplication(primitive_application) => {
+            let prim = primitive_application.prim().to_string();
+            let args = primitive_application
+                .into_args()
+                .filter(|args| !args.is_empty())
+                .map(|args| args.into_iter().map(without_annotations).collect());
+            PrimitiveApplication::new(prim, args, None).into()
+        }
+        Micheline::Sequence(sequence) => micheline::sequence(
+            sequence
+                .into_values()
+                .into_iter()
+                .map(without_annotations)
+                .collect::<Vec<_>>(),
+        ),
+        literal => literal,
+    }
+}
@@ -0,0 +1,195 @@
+use tezos_core::types::{encoded::Address, number::Nat};
+use tezos_michelson::michelson::{
+    convert::IntoMichelson,
+    data::{self, Data},
+    types::{self, Type},
+};
+use tezos_rpc::{http::Http, models::block::BlockId};
+
+use crate::{Contract, ContractView, PartialTransaction, Result};
+
+use super::{call, check_entrypoint, same_type, Ledger};
+
+/// The names under which FA1.2 contracts commonly expose their getters as on-chain views.
+const ALLOWANCE_VIEWS: [&str; 2] = ["getAllowance", "get_allowance"];
+const BALANCE_VIEWS: [&str; 2] = ["getBalance", "get_balance"];
+
+/// The result of an FA1.2 getter: the value returned by the contract's on-chain view or, if the contract does not
+/// expose one, a call sending the value to a callback contract.
+#[derive(Debug, Clone)]
+pub enum Fa12Response {
+    Value(Nat),
+    Callback(PartialTransaction),
+}
+
+/// A client for [FA1.2](https://gitlab.com/tezos/tzip/-/blob/master/proposals/tzip-7/tzip-7.md) token contracts.
+///
+/// The contract's entrypoints are checked against the standard when the client is created.
+#[derive(Debug, Clone)]
+pub struct Fa12<'a, HttpClient: Http> {
+    contract: Contract<'a, HttpClient>,
+}
+
+impl<'a, HttpClient: Http> Fa12<'a, HttpClient> {
+    pub fn new(contract: Contract<'a, HttpClient>) -> Result<Self> {
+        let address: Type = types::address();
+        let nat: Type = types::nat();
+        let callback: Type = types::contract(nat.clone());
+        let entrypoints = contract.entrypoints();
+        check_entrypoint(
+            entrypoints,
+            "transfer",
+            types::pair(vec![address.clone(), address.clone(), nat.clone()]),
+        )?;
+        check_entrypoint(
+            entrypoints,
+            "approve",
+            types::pair(vec![address.clone(), nat.clone()]),
+        )?;
+        check_entrypoint(
+            entrypoints,
+            "getAllowance",
+            types::pair(vec![
+                types::pair(vec![address.clone(), address.clone()]),
+                callback.clone(),
+            ]),
+        )?;
+        check_entrypoint(
+            entrypoints,
+            "getBalance",
+            types::pair(vec![address, callback.clone()]),
+        )?;
+        check_entrypoint(
+            entrypoints,
+            "getTotalSupply",
+            types::pair(vec![types::unit(), callback]),
+        )?;
+
+        Ok(Self { contract })
+    }
+
+    pub fn contract(&self) -> &Contract<'a, HttpClient> {
+        &self.contract
+    }
+
+    /// Creates a call transferring `value` tokens from `from` to `to`.
+    pub fn transfer(&self, from: Address, to: Address, value: Nat) -> Result<PartialTransaction> {
+        self.call(
+            "transfer",
+            data::pair(vec![
+                from.into_michelson()?,
+                to.into_michelson()?,
+                value.into_michelson()?,
+            ]),
+        )
+    }
+
+    /// Creates a call allowing `spender` to transfer up to `value` tokens.
+    pub fn approve(&self, spender: Address, value: Nat) -> Result<PartialTransaction> {
+        self.call(
+            "approve",
+            data::pair(vec![spender.into_michelson()?, value.into_michelson()?]),
+        )
+    }
+
+    /// Reads the allowance of `spender` over `owner`'s tokens with the contract's on-chain view if it has one,
+    /// otherwise creates a call sending it to `callback`.
+    pub async fn get_allowance(
+        &self,
+        owner: Address,
+        spender: Address,
+        callback: Address,
+        block_id: Option<&BlockId>,
+    ) -> Result<Fa12Response>
+    where
+        HttpClient: Sync,
+    {
+        let address: Type = types::address();
+        let input = data::pair(vec![owner.into_michelson()?, spender.into_michelson()?]);
+        if let Some(view) = self.view(
+            &ALLOWANCE_VIEWS,
+            types::pair(vec![address.clone(), address]),
+        ) {
+            return self.run_view(view, input, block_id).await;
+        }
+
+        Ok(Fa12Response::Callback(self.call(
+            "getAllowance",
+            data::pair(vec![input, callback.into_michelson()?]),
+        )?))
+    }
+
+    /// Reads the balance of `owner` with the contract's on-chain view if it has one, otherwise creates a call
+    /// sending it to `callback`.
+    pub async fn get_balance(
+        &self,
+        owner: Address,
+        callback: Address,
+        block_id: Option<&BlockId>,
+    ) -> Result<Fa12Response>
+    where
+        HttpClient: Sync,
+    {
+        let input = owner.into_michelson()?;
+        if let Some(view) = self.view(&BALANCE_VIEWS, types::address()) {
+            return self.run_view(view, input, block_id).await;
+        }
+
+        Ok(Fa12Response::Callback(self.call(
+            "getBalance",
+            data::pair(vec![input, callback.into_michelson()?]),
+        )?))
+    }
+
+    /// Creates a call sending the total supply to `callback`.
+    pub fn get_total_supply(&self, callback: Address) -> Result<PartialTransaction> {
+        self.call(
+            "getTotalSupply",
+            data::pair(vec![data::unit(), callback.into_michelson()?]),
+        )
+    }
+
+    /// Finds the contract's ledger big map.
+    pub fn ledger(&self) -> Result<Ledger<'_, 'a, HttpClient>> {
+        Ledger::find(self.contract.storage())
+    }
+
+    /// Reads the balance of `owner` from the contract's ledger.
+    pub async fn balance(&self, owner: &Address, block_id: Option<&BlockId>) -> Result<Nat>
+    where
+        HttpClient: Sync,
+    {
+        self.ledger()?.balance(owner, &0u8.into(), block_id).await
+    }
+
+    /// Finds a view named after one of `names`, taking `input_type` and returning a `nat`.
+    fn view(&self, names: &[&str], input_type: Type) -> Option<&ContractView> {
+        let nat: Type = types::nat();
+        self.contract.views().iter().find(|view| {
+            names.contains(&view.name.as_str())
+                && same_type(&view.input_type, &input_type)
+                && same_type(&view.output_type, &nat)
+        })
+    }
+
+    async fn run_view(
+        &self,
+        view: &ContractView,
+        input: Data,
+        block_id: Option<&BlockId>,
+    ) -> Result<Fa12Response>
+    where
+        HttpClient: Sync,
+    {
+        let mut builder = self.contract.call_view(&view.name, input)?;
+        if let Some(block_id) = block_id {
+            builder = builder.block_id(block_id);
+        }
+
+        Ok(Fa12Response::Value(builder.send().await?.try_into()?))
+    }
+
+    fn call(&self, entrypoint: &str, value: Data) -> Result<PartialTransaction> {
+        Ok(call(&self.contract, entrypoint, value))
+    }
+}
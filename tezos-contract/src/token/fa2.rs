@@ -0,0 +1,222 @@
+use tezos_core::types::{encoded::Address, number::Nat};
+use tezos_michelson::michelson::{
+    convert::IntoMichelson,
+    data::{self, Data},
+    types::{self, Type},
+};
+use tezos_rpc::{http::Http, models::block::BlockId};
+
+use crate::{Contract, MetadataFetcher, PartialTransaction, Result};
+
+use super::{call, check_entrypoint, Ledger, TokenMetadata};
+
+/// A batch of transfers from a single owner.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Fa2Transfer {
+    pub from: Address,
+    pub txs: Vec<Fa2TransferDestination>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Fa2TransferDestination {
+    pub to: Address,
+    pub token_id: Nat,
+    pub amount: Nat,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum OperatorUpdate {
+    Add {
+        owner: Address,
+        operator: Address,
+        token_id: Nat,
+    },
+    Remove {
+        owner: Address,
+        operator: Address,
+        token_id: Nat,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BalanceRequest {
+    pub owner: Address,
+    pub token_id: Nat,
+}
+
+impl TryFrom<Fa2Transfer> for Data {
+    type Error = tezos_michelson::Error;
+
+    fn try_from(value: Fa2Transfer) -> tezos_michelson::Result<Self> {
+        Ok(data::pair(vec![
+            value.from.into_michelson()?,
+            data::sequence(
+                value
+                    .txs
+                    .into_iter()
+                    .map(TryInto::try_into)
+                    .collect::<tezos_michelson::Result<_>>()?,
+            ),
+        ]))
+    }
+}
+
+impl TryFrom<Fa2TransferDestination> for Data {
+    type Error = tezos_michelson::Error;
+
+    fn try_from(value: Fa2TransferDestination) -> tezos_michelson::Result<Self> {
+        Ok(data::pair(vec![
+            value.to.into_michelson()?,
+            data::pair(vec![
+                value.token_id.into_michelson()?,
+                value.amount.into_michelson()?,
+            ]),
+        ]))
+    }
+}
+
+impl TryFrom<OperatorUpdate> for Data {
+    type Error = tezos_michelson::Error;
+
+    fn try_from(value: OperatorUpdate) -> tezos_michelson::Result<Self> {
+        let operator = |owner: Address, operator: Address, token_id: Nat| {
+            Ok::<_, tezos_michelson::Error>(data::pair(vec![
+                owner.into_michelson()?,
+                data::pair(vec![operator.into_michelson()?, token_id.into_michelson()?]),
+            ]))
+        };
+        Ok(match value {
+            OperatorUpdate::Add {
+                owner,
+                operator: operator_address,
+                token_id,
+            } => data::left(operator(owner, operator_address, token_id)?),
+            OperatorUpdate::Remove {
+                owner,
+                operator: operator_address,
+                token_id,
+            } => data::right(operator(owner, operator_address, token_id)?),
+        })
+    }
+}
+
+impl TryFrom<BalanceRequest> for Data {
+    type Error = tezos_michelson::Error;
+
+    fn try_from(value: BalanceRequest) -> tezos_michelson::Result<Self> {
+        Ok(data::pair(vec![
+            value.owner.into_michelson()?,
+            value.token_id.into_michelson()?,
+        ]))
+    }
+}
+
+/// A client for [FA2](https://gitlab.com/tezos/tzip/-/blob/master/proposals/tzip-12/tzip-12.md) token contracts.
+///
+/// The contract's entrypoints are checked against the standard when the client is created.
+#[derive(Debug, Clone)]
+pub struct Fa2<'a, HttpClient: Http> {
+    contract: Contract<'a, HttpClient>,
+}
+
+impl<'a, HttpClient: Http> Fa2<'a, HttpClient> {
+    pub fn new(contract: Contract<'a, HttpClient>) -> Result<Self> {
+        let address: Type = types::address();
+        let nat: Type = types::nat();
+        let request: Type = types::pair(vec![address.clone(), nat.clone()]);
+        let operator: Type = types::pair(vec![address.clone(), address.clone(), nat.clone()]);
+        let entrypoints = contract.entrypoints();
+        check_entrypoint(
+            entrypoints,
+            "transfer",
+            types::list(types::pair(vec![
+                address.clone(),
+                types::list(types::pair(vec![address, nat.clone(), nat.clone()])),
+            ])),
+        )?;
+        check_entrypoint(
+            entrypoints,
+            "update_operators",
+            types::list(types::or(operator.clone(), operator)),
+        )?;
+        check_entrypoint(
+            entrypoints,
+            "balance_of",
+            types::pair(vec![
+                types::list(request.clone()),
+                types::contract(types::list(types::pair(vec![request, nat]))),
+            ]),
+        )?;
+
+        Ok(Self { contract })
+    }
+
+    pub fn contract(&self) -> &Contract<'a, HttpClient> {
+        &self.contract
+    }
+
+    /// Creates a call executing a batch of transfers.
+    pub fn transfer(&self, transfers: Vec<Fa2Transfer>) -> Result<PartialTransaction> {
+        Ok(call(&self.contract, "transfer", sequence(transfers)?))
+    }
+
+    /// Creates a call adding or removing operators.
+    pub fn update_operators(&self, updates: Vec<OperatorUpdate>) -> Result<PartialTransaction> {
+        Ok(call(&self.contract, "update_operators", sequence(updates)?))
+    }
+
+    /// Creates a call sending the balances matching `requests` to `callback`.
+    pub fn balance_of(
+        &self,
+        requests: Vec<BalanceRequest>,
+        callback: Address,
+    ) -> Result<PartialTransaction> {
+        Ok(call(
+            &self.contract,
+            "balance_of",
+            data::pair(vec![sequence(requests)?, callback.into_michelson()?]),
+        ))
+    }
+
+    /// Resolves the metadata of `token_id`, see [Contract::token_metadata].
+    pub async fn token_metadata<Fetcher: MetadataFetcher + ?Sized>(
+        &self,
+        token_id: &Nat,
+        fetcher: &Fetcher,
+        block_id: Option<&BlockId>,
+    ) -> Result<TokenMetadata>
+    where
+        HttpClient: Sync,
+    {
+        self.contract
+            .token_metadata(token_id, fetcher, block_id)
+            .await
+    }
+
+    /// Finds the contract's ledger big map.
+    pub fn ledger(&self) -> Result<Ledger<'_, 'a, HttpClient>> {
+        Ledger::find(self.contract.storage())
+    }
+
+    /// Reads the balance of `owner` for `token_id` from the contract's ledger.
+    pub async fn balance(
+        &self,
+        owner: &Address,
+        token_id: &Nat,
+        block_id: Option<&BlockId>,
+    ) -> Result<Nat>
+    where
+        HttpClient: Sync,
+    {
+        self.ledger()?.balance(owner, token_id, block_id).await
+    }
+}
+
+fn sequence<T: TryInto<Data, Error = tezos_michelson::Error>>(values: Vec<T>) -> Result<Data> {
+    Ok(data::sequence(
+        values
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<tezos_michelson::Result<_>>()?,
+    ))
+}
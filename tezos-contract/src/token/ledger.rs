@@ -0,0 +1,194 @@
+use tezos_core::types::{encoded::Address, number::Nat};
+use tezos_michelson::michelson::{
+    convert::{FromMichelson, IntoMichelson},
+    data::{self, Data},
+    types::{self, Type},
+    Michelson,
+};
+use tezos_rpc::{http::Http, models::block::BlockId};
+
+use crate::{AnnotatedValue, BigMap, Error, Result, Storage};
+
+use super::same_type;
+
+/// The names commonly given to the ledger big map of token contracts.
+const LEDGER_NAMES: [&str; 3] = ["ledger", "balances", "tokens"];
+
+/// The common layouts of the ledger big map of token contracts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LedgerLayout {
+    /// `big_map address nat`, used by FA1.2 and single asset FA2 contracts.
+    Balance,
+    /// `big_map address (pair (nat %balance) (map %approvals address nat))`, in any order, used by the FA1.2
+    /// reference implementation.
+    BalanceWithApprovals,
+    /// `big_map (pair address nat) nat`, keyed by owner and token id, used by multi asset FA2 contracts.
+    MultiAsset,
+    /// `big_map (pair nat address) nat`, keyed by token id and owner.
+    MultiAssetByToken,
+    /// `big_map nat address`, mapping each token id to its owner, used by NFT contracts.
+    Nft,
+}
+
+impl LedgerLayout {
+    /// Detects the layout of a ledger big map from its key and value types.
+    pub fn detect(key_type: &Type, value_type: &Type) -> Option<Self> {
+        let address: Type = types::address();
+        let nat: Type = types::nat();
+        if same_type(key_type, &address) {
+            if same_type(value_type, &nat) {
+                return Some(Self::Balance);
+            }
+            if let Type::Pair(pair) = value_type {
+                let has_balance = pair.types.iter().any(|r#type| {
+                    same_type(r#type, &nat)
+                        && r#type
+                            .metadata()
+                            .field_name()
+                            .as_ref()
+                            .map_or(false, |name| name.value_without_prefix() == "balance")
+                });
+                if has_balance {
+                    return Some(Self::BalanceWithApprovals);
+                }
+            }
+        } else if same_type(key_type, &types::pair(vec![address.clone(), nat.clone()])) {
+            if same_type(value_type, &nat) {
+                return Some(Self::MultiAsset);
+            }
+        } else if same_type(key_type, &types::pair(vec![nat.clone(), address.clone()])) {
+            if same_type(value_type, &nat) {
+                return Some(Self::MultiAssetByToken);
+            }
+        } else if same_type(key_type, &nat) && same_type(value_type, &address) {
+            return Some(Self::Nft);
+        }
+
+        None
+    }
+}
+
+/// A token ledger, reading balances from a big map whose [LedgerLayout] is known.
+#[derive(Debug, Clone)]
+pub struct Ledger<'b, 'a, HttpClient: Http> {
+    big_map: &'b BigMap<'a, HttpClient>,
+    layout: LedgerLayout,
+}
+
+impl<'b, 'a, HttpClient: Http> Ledger<'b, 'a, HttpClient> {
+    /// Creates a ledger out of `big_map`, detecting its layout.
+    pub fn new(big_map: &'b BigMap<'a, HttpClient>) -> Result<Self> {
+        let layout = LedgerLayout::detect(&big_map.key_type, &big_map.value_type)
+            .ok_or(Error::LedgerNotFound)?;
+
+        Ok(Self::with_layout(big_map, layout))
+    }
+
+    pub fn with_layout(big_map: &'b BigMap<'a, HttpClient>, layout: LedgerLayout) -> Self {
+        Self { big_map, layout }
+    }
+
+    /// Finds the ledger among the big maps of `storage`, looking for the names commonly used by token contracts.
+    pub fn find(storage: &'b Storage<'a, HttpClient>) -> Result<Self> {
+        LEDGER_NAMES
+            .iter()
+            .find_map(|name| storage.big_maps().get_by_name(name))
+            .ok_or(Error::LedgerNotFound)
+            .and_then(Self::new)
+    }
+
+    pub fn big_map(&self) -> &BigMap<'a, HttpClient> {
+        self.big_map
+    }
+
+    pub fn layout(&self) -> LedgerLayout {
+        self.layout
+    }
+
+    /// Reads the balance of `owner` for `token_id`, which is ignored by single asset layouts.
+    ///
+    /// Owners without an entry in the ledger, and tokens without an owner, have a balance of 0.
+    pub async fn balance(
+        &self,
+        owner: &Address,
+        token_id: &Nat,
+        block_id: Option<&BlockId>,
+    ) -> Result<Nat>
+    where
+        HttpClient: Sync,
+    {
+        let owner = owner.clone().into_michelson()?;
+        let token_id = token_id.clone().into_michelson()?;
+        let key: Data = match self.layout {
+            LedgerLayout::Balance | LedgerLayout::BalanceWithApprovals => owner,
+            LedgerLayout::MultiAsset => data::pair(vec![owner, token_id]),
+            LedgerLayout::MultiAssetByToken => data::pair(vec![token_id, owner]),
+            LedgerLayout::Nft => {
+                let token_owner = match self.optional_value(token_id, block_id).await? {
+                    Some(token_owner) => Some(Address::from_michelson(token_owner)?),
+                    None => None,
+                };
+                let owner = Address::from_michelson(owner)?;
+                return Ok(if token_owner == Some(owner) { 1u8 } else { 0u8 }.into());
+            }
+        };
+        let value = match self.optional_value(key, block_id).await? {
+            Some(value) => value,
+            None => return Ok(0u8.into()),
+        };
+        if self.layout == LedgerLayout::BalanceWithApprovals {
+            let value = AnnotatedValue::from_micheline(&value.into(), &self.big_map.value_type)?;
+            return match value.get("balance") {
+                Some(AnnotatedValue::Nat(balance)) => Ok(balance.clone()),
+                _ => Err(Error::IncompatibleValue {
+                    description: "expected a `balance` in the ledger value".into(),
+                }),
+            };
+        }
+
+        Ok(Nat::from_michelson(value)?)
+    }
+
+    /// Reads the owner of `token_id`, only supported by the [LedgerLayout::Nft] layout.
+    pub async fn owner(&self, token_id: &Nat, block_id: Option<&BlockId>) -> Result<Address>
+    where
+        HttpClient: Sync,
+    {
+        if self.layout != LedgerLayout::Nft {
+            return Err(Error::IncompatibleValue {
+                description: format!("{:?} ledgers do not map tokens to owners", self.layout),
+            });
+        }
+        let value = self
+            .value(token_id.clone().into_michelson()?, block_id)
+            .await?;
+
+        Ok(Address::from_michelson(value)?)
+    }
+
+    async fn value(&self, key: Data, block_id: Option<&BlockId>) -> Result<Data>
+    where
+        HttpClient: Sync,
+    {
+        let value = self
+            .big_map
+            .get_value(Michelson::from(key), block_id)
+            .await?;
+
+        Ok(value.try_into()?)
+    }
+
+    /// Reads the value at `key`, or `None` if the node does not find it.
+    async fn optional_value(&self, key: Data, block_id: Option<&BlockId>) -> Result<Option<Data>>
+    where
+        HttpClient: Sync,
+    {
+        match self.value(key, block_id).await {
+            Ok(value) => Ok(Some(value)),
+            Err(Error::Rpc {
+                source: tezos_rpc::Error::NotFound { .. },
+            }) => Ok(None),
+            Err(error) => Err(error),
+        }
+    }
+}
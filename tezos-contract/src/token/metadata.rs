@@ -0,0 +1,241 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::{Map, Value};
+use tezos_core::types::number::Nat;
+use tezos_michelson::{
+    micheline::Micheline,
+    michelson::{
+        convert::{internal::pair_values, FromMichelson, IntoMichelson},
+        data::{Bytes, Data},
+        Michelson,
+    },
+};
+use tezos_rpc::{http::Http, models::block::BlockId};
+
+use crate::{Contract, Error, MetadataFetcher, Result};
+
+/// The name of the big map holding the metadata of the tokens.
+const TOKEN_METADATA_BIG_MAP: &str = "token_metadata";
+/// The name of the off-chain view returning the metadata of a token.
+const TOKEN_METADATA_VIEW: &str = "token_metadata";
+/// The `token_info` fields whose bytes hold a JSON value rather than a plain string.
+const JSON_FIELDS: [&str; 8] = [
+    "decimals",
+    "isBooleanAmount",
+    "isTransferable",
+    "shouldPreferSymbol",
+    "creators",
+    "tags",
+    "formats",
+    "attributes",
+];
+
+/// The metadata of a token, as defined by [TZIP-12](https://gitlab.com/tezos/tzip/-/blob/master/proposals/tzip-12/tzip-12.md)
+/// and [TZIP-21](https://gitlab.com/tezos/tzip/-/blob/master/proposals/tzip-21/tzip-21.md).
+///
+/// The fields not covered by the standards are kept in `extra`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenMetadata {
+    #[serde(rename = "token_id")]
+    pub token_id: Nat,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<String>,
+    #[serde(
+        default,
+        deserialize_with = "number_or_string",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub decimals: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub artifact_uri: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_uri: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub thumbnail_uri: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_boolean_amount: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub should_prefer_symbol: Option<bool>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub creators: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub formats: Vec<TokenFormat>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attributes: Vec<TokenAttribute>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// A file associated with a token, e.g. its artifact or thumbnail.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenFormat {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub uri: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hash: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mime_type: Option<String>,
+    #[serde(
+        default,
+        deserialize_with = "number_or_string",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub file_size: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duration: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dimensions: Option<TokenFormatUnitValue>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data_rate: Option<TokenFormatUnitValue>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TokenFormatUnitValue {
+    pub value: String,
+    pub unit: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TokenAttribute {
+    pub name: String,
+    pub value: Value,
+    #[serde(default, rename = "type", skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+}
+
+impl TokenMetadata {
+    /// Creates the metadata of `token_id` out of its `token_info` map, whose values are the UTF-8 encoded fields,
+    /// and of the JSON document its `""` key points to, if any.
+    ///
+    /// The fields of `token_info` take precedence over the ones of the document.
+    pub fn new(
+        token_id: Nat,
+        token_info: BTreeMap<String, Vec<u8>>,
+        document: Option<Map<String, Value>>,
+    ) -> Result<Self> {
+        let mut fields = document.unwrap_or_default();
+        for (key, value) in token_info {
+            if key.is_empty() {
+                continue;
+            }
+            let value = String::from_utf8(value).map_err(|_| Error::IncompatibleValue {
+                description: format!("the `{}` token info is not valid UTF-8", key),
+            })?;
+            let value = if JSON_FIELDS.contains(&key.as_str()) {
+                serde_json::from_str(&value).unwrap_or(Value::String(value))
+            } else {
+                Value::String(value)
+            };
+            fields.insert(key, value);
+        }
+        fields.insert("token_id".into(), Value::String(token_id.to_string()));
+
+        Ok(serde_json::from_value(Value::Object(fields))?)
+    }
+}
+
+impl<'a, HttpClient: Http + Sync> Contract<'a, HttpClient> {
+    /// Resolves the metadata of `token_id` from the `%token_metadata` big map or, if the contract does not have one,
+    /// from the `token_metadata` off-chain view of its [ContractMetadata](crate::ContractMetadata).
+    ///
+    /// The off-chain metadata and the JSON document referenced by the token's `""` key are fetched with `fetcher`.
+    pub async fn token_metadata<Fetcher: MetadataFetcher + ?Sized>(
+        &self,
+        token_id: &Nat,
+        fetcher: &Fetcher,
+        block_id: Option<&BlockId>,
+    ) -> Result<TokenMetadata> {
+        let token_id_value = token_id.clone().into_michelson()?;
+        let value = match self
+            .storage()
+            .big_maps()
+            .get_by_name(TOKEN_METADATA_BIG_MAP)
+        {
+            Some(big_map) => {
+                big_map
+                    .get_value(Michelson::from(token_id_value), block_id)
+                    .await?
+            }
+            None => {
+                let metadata = self.metadata(fetcher, block_id).await?;
+                let view =
+                    metadata
+                        .view(TOKEN_METADATA_VIEW)
+                        .ok_or_else(|| Error::ViewNotFound {
+                            name: TOKEN_METADATA_VIEW.into(),
+                        })?;
+                view.run(self, Some(token_id_value), block_id).await?
+            }
+        };
+        let (token_id, token_info) = token_info(value)?;
+        let document = match token_info.get("") {
+            Some(uri) => Some(self.token_metadata_document(uri, fetcher, block_id).await?),
+            None => None,
+        };
+
+        TokenMetadata::new(token_id, token_info, document)
+    }
+
+    async fn token_metadata_document<Fetcher: MetadataFetcher + ?Sized>(
+        &self,
+        uri: &[u8],
+        fetcher: &Fetcher,
+        block_id: Option<&BlockId>,
+    ) -> Result<Map<String, Value>> {
+        let uri = String::from_utf8(uri.to_vec()).map_err(|_| Error::InvalidMetadataUri {
+            uri: String::from_utf8_lossy(uri).into(),
+        })?;
+        let content = self
+            .resolve_metadata_uri(&uri.parse()?, fetcher, block_id)
+            .await?;
+        match serde_json::from_slice(&content)? {
+            Value::Object(document) => Ok(document),
+            _ => Err(Error::IncompatibleValue {
+                description: format!("the token metadata at `{}` is not a JSON object", uri),
+            }),
+        }
+    }
+}
+
+/// Splits a `pair (nat %token_id) (map %token_info string bytes)` value.
+fn token_info(value: Micheline) -> Result<(Nat, BTreeMap<String, Vec<u8>>)> {
+    let data: Data = value.try_into()?;
+    let mut values = pair_values(data, 2)?.into_iter();
+    let token_id = Nat::from_michelson(values.next().ok_or(Error::InvalidViewResult)?)?;
+    let token_info =
+        BTreeMap::<String, Bytes>::from_michelson(values.next().ok_or(Error::InvalidViewResult)?)?
+            .into_iter()
+            .map(|(key, value)| (key, (&value).into()))
+            .collect();
+
+    Ok((token_id, token_info))
+}
+
+fn number_or_string<'de, D, T>(deserializer: D) -> std::result::Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: std::str::FromStr + TryFrom<u64>,
+{
+    let invalid = || serde::de::Error::custom("expected an unsigned integer");
+    match Option::<Value>::deserialize(deserializer)? {
+        None | Some(Value::Null) => Ok(None),
+        Some(Value::Number(number)) => number
+            .as_u64()
+            .and_then(|number| T::try_from(number).ok())
+            .map(Some)
+            .ok_or_else(invalid),
+        Some(Value::String(value)) => value.parse().map(Some).map_err(|_| invalid()),
+        Some(_) => Err(invalid()),
+    }
+}
@@ -18,6 +18,7 @@ pub enum Error {
     InvalidIntegerString,
     InvalidUnsignedIntegerString,
     InvalidTezString,
+    InvalidTimestampString,
     BigIntParse {
         source: num_bigint::ParseBigIntError,
     },
@@ -0,0 +1,162 @@
+//! Hash functions deriving the Tezos identifiers (script expressions, operations, blocks, addresses, etc.).
+//!
+//! The functions take the already encoded bytes, e.g. the packed Micheline value or the forged operation,
+//! as the encoding is provided by the `tezos-michelson` and `tezos-operation` crates.
+
+use crate::{
+    internal::crypto::Crypto,
+    types::encoded::{
+        BlockHash, ContractHash, Ed25519PublicKeyHash, Encoded, ImplicitAddress, OperationHash,
+        P256PublicKeyHash, PublicKey, ScriptExprHash, Secp256K1PublicKeyHash,
+    },
+    Result,
+};
+
+const HASH_SIZE: usize = 32;
+const ADDRESS_HASH_SIZE: usize = 20;
+
+/// Computes the [ScriptExprHash] of a packed value (`0x05` prefix included), e.g. to look up a big map key.
+pub fn script_expr_hash(packed: &[u8]) -> Result<ScriptExprHash> {
+    ScriptExprHash::from_bytes(&blake2b(packed, HASH_SIZE)?)
+}
+
+/// Computes the [OperationHash] of a forged operation, signature included.
+pub fn operation_hash(bytes: &[u8]) -> Result<OperationHash> {
+    OperationHash::from_bytes(&blake2b(bytes, HASH_SIZE)?)
+}
+
+/// Computes the [BlockHash] of a forged block header, signature included.
+pub fn block_hash(header: &[u8]) -> Result<BlockHash> {
+    BlockHash::from_bytes(&blake2b(header, HASH_SIZE)?)
+}
+
+/// Derives the implicit address (**tz1**, **tz2** or **tz3**) of a public key.
+pub fn public_key_hash(public_key: &PublicKey) -> Result<ImplicitAddress> {
+    Ok(match public_key {
+        PublicKey::Ed25519(key) => {
+            Ed25519PublicKeyHash::from_bytes(&blake2b(&key.to_bytes()?, ADDRESS_HASH_SIZE)?)?.into()
+        }
+        PublicKey::Secp256K1(key) => {
+            Secp256K1PublicKeyHash::from_bytes(&blake2b(&key.to_bytes()?, ADDRESS_HASH_SIZE)?)?
+                .into()
+        }
+        PublicKey::P256(key) => {
+            P256PublicKeyHash::from_bytes(&blake2b(&key.to_bytes()?, ADDRESS_HASH_SIZE)?)?.into()
+        }
+    })
+}
+
+/// Derives the address of a contract originated by the operation `operation_hash`, where `index` is the number
+/// of contracts originated before it by the same operation, internal originations included.
+pub fn contract_address(operation_hash: &OperationHash, index: u32) -> Result<ContractHash> {
+    let nonce = [operation_hash.to_bytes()?, index.to_be_bytes().to_vec()].concat();
+
+    ContractHash::from_bytes(&blake2b(&nonce, ADDRESS_HASH_SIZE)?)
+}
+
+/// Computes the hash under which a global constant is registered, given the binary Micheline expression
+/// (without the `0x05` pack prefix).
+pub fn global_constant_hash(expr: &[u8]) -> Result<ScriptExprHash> {
+    ScriptExprHash::from_bytes(&blake2b(expr, HASH_SIZE)?)
+}
+
+fn blake2b(message: &[u8], size: usize) -> Result<Vec<u8>> {
+    Crypto::new(None, None, None).blake2b(message, size)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::types::hex_string::HexString;
+
+    #[test]
+    fn test_script_expr_hash() -> Result<()> {
+        let hash = script_expr_hash(&[0x05, 0x00, 0x00])?;
+        assert_eq!(
+            hash.value(),
+            "exprtZBwZUeYYYfUs9B9Rg2ywHezVHnCCnmF9WsDQVrs582dSK63dC"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_global_constant_hash() -> Result<()> {
+        // `999`, the constant registered in the protocol documentation
+        let hash = global_constant_hash(&[0x00, 0xa7, 0x0f])?;
+        assert_eq!(
+            hash.value(),
+            "expruQN5r2umbZVHy6WynYM8f71F8zS4AERz9bugF8UkPBEqrHLuU8"
+        );
+        assert_ne!(hash, script_expr_hash(&[0x05, 0x00, 0xa7, 0x0f])?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_public_key_hash() -> Result<()> {
+        let public_key: PublicKey =
+            "edpkttZKC51wemRqL2QxwpMnEKxWnbd35pq47Y6xsCHp5M1f7LN8NP".try_into()?;
+        assert_eq!(
+            public_key_hash(&public_key)?.value(),
+            "tz1RFxSHjxxyaaXyAvJG3uRQeJUCCm4jk2LX"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_operation_hash() -> Result<()> {
+        // The first endorsement of the mainnet block 2504461
+        let bytes = HexString::new(
+            concat!(
+                "fb296d42dd061b4bd445559a24ca8adb42b1266c0ba64fcef5a1367eb536479e1500000026370c00",
+                "000000411299d46c8f67c0e4a38f5e9b804de730921757d04aa63bc00c72c5b0d44fc89c24b35e5d",
+                "dc237d1b8acf17ad1789c9ad1aed54497f69310436a8237093911f719ab2ce96ebb572713d72e84c",
+                "03a88b557ecffd1f201c387d9b4b8d35a38006",
+            )
+            .into(),
+        )?
+        .to_bytes();
+        assert_eq!(
+            operation_hash(&bytes)?.value(),
+            "ooohBfUnCfHUQrza1aF5mCsY21rYFjzEEiNnQrK8ymzWsU1E1qD"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_block_hash() -> Result<()> {
+        // The header of the mainnet block 2504461
+        let bytes = HexString::new(
+            concat!(
+                "0026370d0d9444f40d9c1824d30918fa20dcbc28261a8973d9a544f33f5cc56a13830189af000000",
+                "0062c28ab804d7bfc960df8ce93f0b9356181c5651bf90ebcae5b2a2776b66aeeda0000f42290000",
+                "00210000000102000000040026370d0000000000000004ffffffff000000040000000083e9c04c6f",
+                "3c610e6b5c65b33805c24a6c1902b58bec02a4cc482ffca828b77ac4451b4e45ee2eae5d15652c88",
+                "26ee011f22915ae1387dd605766c5ad1b5cf8c00000000cb9f439e02840100000214a92861740b16",
+                "b11c8bb6791abdae138bf18f18a85915896a7d5da0b59fc0c3a16494f7832b05164f63f1d5797d31",
+                "03256e5c2e1f0ddf3fca57e71ab8c4a56a",
+            )
+            .into(),
+        )?
+        .to_bytes();
+        assert_eq!(
+            block_hash(&bytes)?.value(),
+            "BKvBmAJVpJ8drHMTzDZmFKEYc45xzeyHo1MUNtcomm6FMZkpXDW"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_contract_address() -> Result<()> {
+        let operation_hash: OperationHash =
+            "oneQf8XP5wBE7mZPQ1koZj18NJABqtccdXQ19iCnfZYsGTnSCPM".try_into()?;
+        assert_eq!(
+            contract_address(&operation_hash, 0)?.value(),
+            "KT1RTw7Mufvtod3Jhh3kcD95rjJ2iqF7Nw1y"
+        );
+        assert_eq!(
+            contract_address(&operation_hash, 1)?.value(),
+            "KT1FjwxEhbDFjRSVNFYDtKNGJbqw5bd8gRSU"
+        );
+        Ok(())
+    }
+}
@@ -125,6 +125,7 @@
 
 pub mod crypto;
 mod error;
+pub mod hash;
 pub mod internal;
 pub mod types;
 
@@ -4,3 +4,4 @@ pub mod encoded;
 pub mod hex_string;
 pub mod mutez;
 pub mod number;
+pub mod timestamp;
@@ -1,3 +1,5 @@
+use std::cmp::Ordering;
+
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -96,6 +98,20 @@ impl TryFrom<&Address> for Vec<u8> {
     }
 }
 
+/// Addresses are ordered as in Michelson: implicit accounts before originated contracts,
+/// then by their binary representation.
+impl Ord for Address {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.to_bytes().ok().cmp(&other.to_bytes().ok())
+    }
+}
+
+impl PartialOrd for Address {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 impl From<ImplicitAddress> for Address {
     fn from(value: ImplicitAddress) -> Self {
         Self::Implicit(value)
@@ -243,6 +259,20 @@ impl TryFrom<&ImplicitAddress> for Vec<u8> {
     }
 }
 
+/// Implicit addresses are ordered as in Michelson: **tz1** before **tz2** before **tz3**,
+/// then by their public key hash.
+impl Ord for ImplicitAddress {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.to_bytes().ok().cmp(&other.to_bytes().ok())
+    }
+}
+
+impl PartialOrd for ImplicitAddress {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 impl TryFrom<Address> for ImplicitAddress {
     type Error = Error;
 
@@ -416,6 +446,29 @@ impl From<ContractAddress> for ContractHash {
 mod test {
     use super::*;
 
+    #[test]
+    fn test_cmp() -> Result<()> {
+        let mut addresses: Vec<Address> = vec![
+            "KT1BEqzn5Wx8uJrZNvuS9DVHmLvG9td3fDLi".try_into()?,
+            "tz3Nk25g51knuzFZZz2DeA5PveaQYmCtV68B".try_into()?,
+            "tz1YY1LvD6TFH4z74pvxPQXBjAKHE5tB5Q8f".try_into()?,
+            "tz2MVED1t9Jery77Bwm1m5YhUx8Wp5KWWRQe".try_into()?,
+            "tz1Mj7RzPmMAqDUNFBn5t5VbXmWW4cSUAdtT".try_into()?,
+        ];
+        addresses.sort();
+        assert_eq!(
+            addresses.iter().map(Encoded::value).collect::<Vec<_>>(),
+            vec![
+                "tz1Mj7RzPmMAqDUNFBn5t5VbXmWW4cSUAdtT",
+                "tz1YY1LvD6TFH4z74pvxPQXBjAKHE5tB5Q8f",
+                "tz2MVED1t9Jery77Bwm1m5YhUx8Wp5KWWRQe",
+                "tz3Nk25g51knuzFZZz2DeA5PveaQYmCtV68B",
+                "KT1BEqzn5Wx8uJrZNvuS9DVHmLvG9td3fDLi",
+            ]
+        );
+        Ok(())
+    }
+
     #[test]
     fn test_tz1_address() -> Result<()> {
         let address: Address = "tz1Mj7RzPmMAqDUNFBn5t5VbXmWW4cSUAdtT".try_into()?;
@@ -1,12 +1,16 @@
 //! Tezos Mutez type.
 
-use std::{fmt::Debug, str::FromStr};
+use std::{
+    fmt::{Debug, Display},
+    iter::Sum,
+    str::FromStr,
+};
 
 use derive_more;
 use derive_more::{
-    Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Display, Div,
-    DivAssign, Mul, MulAssign, Not, Octal, Product, Rem, RemAssign, Shl, ShlAssign, Shr, ShrAssign,
-    Sub, SubAssign, Sum,
+    Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div, DivAssign,
+    Mul, MulAssign, Not, Octal, Product, Rem, RemAssign, Shl, ShlAssign, Shr, ShrAssign, Sub,
+    SubAssign, Sum,
 };
 use lazy_static::lazy_static;
 use num_bigint::BigUint;
@@ -23,8 +27,11 @@ use super::number::Nat;
 
 lazy_static! {
     static ref REGEX: Regex = Regex::new(r"^[0-9]+$").unwrap();
+    static ref TEZ_REGEX: Regex = Regex::new(r"^([0-9]+)(?:\.([0-9]{1,6}))?$").unwrap();
 }
 
+const MUTEZ_PER_TEZ: i64 = 1_000_000;
+
 /// Tezos Mutez type. It can be encoded into and initialized from bytes and many other number
 /// representations.
 ///
@@ -34,9 +41,17 @@ lazy_static! {
 /// use tezos_core::types::mutez::Mutez;
 /// let amount_1: Mutez = "24".try_into().expect("valid number string can be converted to Mutez");
 /// let amount_2: Mutez = 42u8.into();
+/// let amount_3 = Mutez::from_tez_str("1.25").expect("valid tez string can be converted to Mutez");
+///
+/// assert_eq!(amount_3.checked_sub(amount_1), Some(1249976u32.into()));
+/// assert_eq!(format!("{}", amount_3), "1250000");
+/// assert_eq!(format!("{:#}", amount_3), "1.25");
 /// ```
 ///
 /// Internally the number is represented with an [i64], but negative values are invalid.
+///
+/// The derived arithmetic operators panic on overflow in debug builds, use the `checked_*` and `saturating_*`
+/// methods when the operands are not known to stay within the valid range.
 #[derive(
     Add,
     AddAssign,
@@ -46,7 +61,6 @@ lazy_static! {
     Eq,
     Clone,
     Copy,
-    Display,
     BitAnd,
     BitAndAssign,
     BitOr,
@@ -84,10 +98,86 @@ lazy_static! {
 pub struct Mutez(#[cfg_attr(feature = "serde", serde(serialize_with = "i64_to_string"))] i64);
 
 impl Mutez {
+    /// The largest valid amount.
+    pub const MAX: Mutez = Mutez(i64::MAX);
+
     pub fn is_valid(value: &str) -> bool {
         REGEX.is_match(value)
     }
 
+    /// Creates the [Mutez] value from an amount of tez with at most 6 decimal places, e.g. `"1.25"`.
+    pub fn from_tez_str(value: &str) -> Result<Self> {
+        let captures = TEZ_REGEX.captures(value).ok_or(Error::InvalidTezString)?;
+        let tez = captures[1]
+            .parse::<i64>()
+            .map_err(|_| Error::InvalidTezString)?;
+        let decimals = captures.get(2).map_or("", |decimals| decimals.as_str());
+        let fraction = format!("{:0<6}", decimals)
+            .parse::<i64>()
+            .map_err(|_| Error::InvalidTezString)?;
+
+        tez.checked_mul(MUTEZ_PER_TEZ)
+            .and_then(|mutez| mutez.checked_add(fraction))
+            .map(Self)
+            .ok_or(Error::InvalidTezString)
+    }
+
+    /// Formats the value as an amount of tez, without trailing zeros in the decimal places, e.g. `"1.25"`.
+    pub fn to_tez_string(&self) -> String {
+        let tez = self.0 / MUTEZ_PER_TEZ;
+        let fraction = self.0 % MUTEZ_PER_TEZ;
+        if fraction == 0 {
+            return tez.to_string();
+        }
+        let decimals = format!("{:06}", fraction);
+
+        format!("{}.{}", tez, decimals.trim_end_matches('0'))
+    }
+
+    /// Adds two amounts, returning `None` if the result is out of range.
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        self.0.checked_add(rhs.0).map(Self)
+    }
+
+    /// Subtracts `rhs` from the amount, returning `None` if the result is negative.
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.0
+            .checked_sub(rhs.0)
+            .filter(|value| *value >= 0)
+            .map(Self)
+    }
+
+    /// Multiplies the amount by `rhs`, returning `None` if the result is out of range.
+    pub fn checked_mul(self, rhs: u64) -> Option<Self> {
+        i64::try_from(rhs)
+            .ok()
+            .and_then(|rhs| self.0.checked_mul(rhs))
+            .map(Self)
+    }
+
+    /// Divides the amount by `rhs`, rounding down, returning `None` if `rhs` is zero.
+    pub fn checked_div(self, rhs: u64) -> Option<Self> {
+        self.value()
+            .checked_div(rhs)
+            .and_then(|value| i64::try_from(value).ok())
+            .map(Self)
+    }
+
+    /// Adds two amounts, saturating at [Mutez::MAX].
+    pub fn saturating_add(self, rhs: Self) -> Self {
+        Self(self.0.saturating_add(rhs.0))
+    }
+
+    /// Subtracts `rhs` from the amount, saturating at zero.
+    pub fn saturating_sub(self, rhs: Self) -> Self {
+        self.checked_sub(rhs).unwrap_or_default()
+    }
+
+    /// Multiplies the amount by `rhs`, saturating at [Mutez::MAX].
+    pub fn saturating_mul(self, rhs: u64) -> Self {
+        self.checked_mul(rhs).unwrap_or(Self::MAX)
+    }
+
     pub(super) fn value(&self) -> u64 {
         self.0.to_u64().unwrap()
     }
@@ -113,6 +203,23 @@ where
     s.serialize_str(&value.to_string())
 }
 
+/// Formats the amount in mutez, or in tez with the alternate flag (`{:#}`).
+impl Display for Mutez {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if f.alternate() {
+            f.write_str(&self.to_tez_string())
+        } else {
+            Display::fmt(&self.0, f)
+        }
+    }
+}
+
+impl<'a> Sum<&'a Mutez> for Mutez {
+    fn sum<I: Iterator<Item = &'a Mutez>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
 impl FromStr for Mutez {
     type Err = Error;
 
@@ -348,6 +455,59 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_checked_arithmetic() -> Result<()> {
+        let v1: Mutez = 1u8.into();
+        let v2: Mutez = 2u8.into();
+
+        assert_eq!(v1.checked_add(v2), Some(3u8.into()));
+        assert_eq!(Mutez::MAX.checked_add(v1), None);
+        assert_eq!(v2.checked_sub(v1), Some(1u8.into()));
+        assert_eq!(v1.checked_sub(v2), None);
+        assert_eq!(v2.checked_mul(3), Some(6u8.into()));
+        assert_eq!(v2.checked_mul(u64::MAX), None);
+        assert_eq!(Mutez::from(7u8).checked_div(2), Some(3u8.into()));
+        assert_eq!(v1.checked_div(0), None);
+        assert_eq!(Mutez::MAX.saturating_add(v1), Mutez::MAX);
+        assert_eq!(v1.saturating_sub(v2), Mutez::default());
+        assert_eq!(Mutez::MAX.saturating_mul(2), Mutez::MAX);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_sum() -> Result<()> {
+        let values: Vec<Mutez> = vec![1u8.into(), 2u8.into(), 3u8.into()];
+
+        assert_eq!(values.iter().sum::<Mutez>(), 6u8.into());
+        assert_eq!(values.into_iter().sum::<Mutez>(), 6u8.into());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_tez_string() -> Result<()> {
+        let values: Vec<(&str, Mutez, &str)> = vec![
+            ("0", 0u8.into(), "0"),
+            ("1.25", 1250000u32.into(), "1.25"),
+            ("1.250000", 1250000u32.into(), "1.25"),
+            ("0.000001", 1u8.into(), "0.000001"),
+            ("42", 42000000u32.into(), "42"),
+            ("9223372036854.775807", Mutez::MAX, "9223372036854.775807"),
+        ];
+        for (tez, mutez, formatted) in values {
+            assert_eq!(Mutez::from_tez_str(tez)?, mutez);
+            assert_eq!(mutez.to_tez_string(), formatted);
+            assert_eq!(format!("{:#}", mutez), formatted);
+        }
+        for invalid in ["", "1.", ".5", "-1", "1.0000001", "9223372036854.775808"] {
+            assert!(Mutez::from_tez_str(invalid).is_err());
+        }
+        assert_eq!(Mutez::from(1250000u32).to_string(), "1250000");
+
+        Ok(())
+    }
+
     #[test]
     fn test_cmp() -> Result<()> {
         let v1: Mutez = 1u8.into();
@@ -5,3 +5,6 @@ mod nat;
 
 pub use int::Int;
 pub use nat::Nat;
+
+/// The largest shift accepted by the `LSL` and `LSR` instructions.
+const MAX_SHIFT: usize = 256;
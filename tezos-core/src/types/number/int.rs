@@ -1,12 +1,14 @@
+use derive_more::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 use lazy_static::lazy_static;
-use num_bigint::{BigInt, ToBigInt};
+use num_bigint::{BigInt, BigUint, ToBigInt};
 use num_integer::Integer;
-use num_traits::{Num, ToPrimitive};
+use num_traits::{Num, Signed, ToPrimitive, Zero};
 use regex::Regex;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use std::{
     fmt::{Debug, Display},
+    ops::BitAnd,
     str::FromStr,
 };
 
@@ -22,19 +24,51 @@ lazy_static! {
 }
 
 /// An integer that can be encoded to a Zarith number
-#[derive(Debug, PartialEq, Eq, Clone)]
+///
+/// Arithmetic follows the semantics of the Michelson instructions on `int` values, e.g. the remainder of
+/// a division is never negative:
+///
+/// ```
+/// use tezos_core::types::number::{Int, Nat};
+///
+/// let a: Int = (-7i8).into();
+/// let b: Int = 2i8.into();
+///
+/// assert_eq!(a.clone() * b.clone(), (-14i8).into());
+/// assert_eq!(a.ediv(&b), Some(((-4i8).into(), 1u8.into())));
+/// assert_eq!(a.abs(), 7u8.into());
+/// assert_eq!(a.is_nat(), None);
+/// assert!(a < b);
+/// ```
+#[derive(
+    Debug,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Clone,
+    Add,
+    AddAssign,
+    Sub,
+    SubAssign,
+    Mul,
+    MulAssign,
+    Neg,
+)]
+#[mul(forward)]
+#[mul_assign(forward)]
 #[cfg_attr(
     feature = "serde",
     derive(Serialize, Deserialize),
-    serde(try_from = "String")
+    serde(try_from = "String", into = "String")
 )]
-pub struct Int(String);
+pub struct Int(BigInt);
 
 impl Int {
     pub fn from<S: Into<String>>(value: S) -> Result<Self> {
         let value: String = value.into();
         if Self::is_valid(&value) {
-            return Ok(Self(value));
+            return Ok(Self(BigInt::from_str_radix(&value, 10)?));
         }
         Err(Error::InvalidIntegerString)
     }
@@ -53,6 +87,7 @@ impl Int {
     {
         Ok(self
             .0
+            .to_string()
             .parse::<I>()
             .map_err(|_error| Error::InvalidIntegerConversion)?)
     }
@@ -65,94 +100,134 @@ impl Int {
         IntegerBytesCoder::encode(self)
     }
 
-    pub fn to_str(&self) -> &str {
-        &self.0
+    #[deprecated(note = "use `to_string` instead")]
+    pub fn to_str(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// Divides by `divisor`, returning the quotient and the remainder, or `None` if `divisor` is zero (`EDIV`).
+    ///
+    /// The remainder is never negative, the quotient is rounded accordingly.
+    pub fn ediv(&self, divisor: &Int) -> Option<(Int, Nat)> {
+        if divisor.0.is_zero() {
+            return None;
+        }
+        let remainder = self.0.mod_floor(&divisor.0.abs());
+        let quotient = (&self.0 - &remainder) / &divisor.0;
+
+        Some((Self(quotient), remainder.to_biguint()?.into()))
+    }
+
+    /// The absolute value (`ABS`).
+    pub fn abs(&self) -> Nat {
+        self.0.magnitude().clone().into()
+    }
+
+    /// The value as a [Nat] if it is positive, `None` otherwise (`ISNAT`).
+    pub fn is_nat(&self) -> Option<Nat> {
+        self.0.to_biguint().map(Into::into)
     }
 }
 
 impl Display for Int {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.0)
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl Default for Int {
+    fn default() -> Self {
+        Self(BigInt::zero())
+    }
+}
+
+/// The bitwise and of an integer, in two's complement, and a natural number (`AND`).
+impl BitAnd<Nat> for Int {
+    type Output = Nat;
+
+    fn bitand(self, rhs: Nat) -> Self::Output {
+        (self.0 & BigInt::from(BigUint::from(rhs)))
+            .to_biguint()
+            .expect("the bitwise and with a natural number is positive")
+            .into()
     }
 }
 
 impl ToPrimitive for Int {
     fn to_i64(&self) -> Option<i64> {
-        self.to_integer().ok()
+        self.0.to_i64()
     }
 
     fn to_u64(&self) -> Option<u64> {
-        self.to_integer().ok()
+        self.0.to_u64()
     }
 
     fn to_i128(&self) -> Option<i128> {
-        self.to_integer().ok()
+        self.0.to_i128()
     }
 
     fn to_u128(&self) -> Option<u128> {
-        self.to_integer().ok()
+        self.0.to_u128()
     }
 }
 
 impl ToBigInt for Int {
     fn to_bigint(&self) -> Option<BigInt> {
-        BigInt::from_str_radix(&self.0, 10)
-            .map(Some)
-            .unwrap_or(None)
+        Some(self.0.clone())
     }
 }
 
 impl From<i8> for Int {
     fn from(value: i8) -> Self {
-        Self::from_intenger(value)
+        Self(value.into())
     }
 }
 
 impl From<i16> for Int {
     fn from(value: i16) -> Self {
-        Self::from_intenger(value)
+        Self(value.into())
     }
 }
 
 impl From<i32> for Int {
     fn from(value: i32) -> Self {
-        Self::from_intenger(value)
+        Self(value.into())
     }
 }
 
 impl From<i64> for Int {
     fn from(value: i64) -> Self {
-        Self::from_intenger(value)
+        Self(value.into())
     }
 }
 
 impl From<i128> for Int {
     fn from(value: i128) -> Self {
-        Self::from_intenger(value)
+        Self(value.into())
     }
 }
 
 impl From<BigInt> for Int {
     fn from(value: BigInt) -> Self {
-        Self::from_intenger(value)
+        Self(value)
     }
 }
 
 impl From<Nat> for Int {
     fn from(value: Nat) -> Self {
-        Int(value.to_string())
+        Int(BigUint::from(value).into())
     }
 }
 
 impl From<&Nat> for Int {
     fn from(value: &Nat) -> Self {
-        Int(value.to_string())
+        value.clone().into()
     }
 }
 
 impl From<Int> for String {
     fn from(value: Int) -> Self {
-        value.0
+        value.0.to_string()
     }
 }
 
@@ -216,6 +291,79 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_arithmetic() -> Result<()> {
+        let a: Int = "-170141183460469231731687303715884105729".try_into()?;
+        let b: Int = 3i8.into();
+
+        assert_eq!(
+            a.clone() + b.clone(),
+            "-170141183460469231731687303715884105726".try_into()?
+        );
+        assert_eq!(
+            a.clone() - b.clone(),
+            "-170141183460469231731687303715884105732".try_into()?
+        );
+        assert_eq!(
+            a.clone() * b.clone(),
+            "-510423550381407695195061911147652317187".try_into()?
+        );
+        assert_eq!(-b.clone(), (-3i8).into());
+        assert_eq!(
+            a.abs(),
+            "170141183460469231731687303715884105729".try_into()?
+        );
+        assert_eq!(a.is_nat(), None);
+        assert_eq!(b.is_nat(), Some(3u8.into()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_ediv() -> Result<()> {
+        let values: Vec<(i8, i8, i8, u8)> = vec![
+            (7, 2, 3, 1),
+            (-7, 2, -4, 1),
+            (7, -2, -3, 1),
+            (-7, -2, 4, 1),
+            (6, -3, -2, 0),
+        ];
+        for (a, b, quotient, remainder) in values {
+            assert_eq!(
+                Int::from_intenger(a).ediv(&b.into()),
+                Some((quotient.into(), remainder.into()))
+            );
+        }
+        assert_eq!(Int::from_intenger(7i8).ediv(&Int::default()), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_and() -> Result<()> {
+        assert_eq!(
+            Int::from_intenger(-1i8) & Nat::from_integer(12u8),
+            12u8.into()
+        );
+        assert_eq!(
+            Int::from_intenger(-8i8) & Nat::from_integer(13u8),
+            8u8.into()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_cmp() -> Result<()> {
+        let a: Int = "-10".try_into()?;
+        let b: Int = "-9".try_into()?;
+
+        assert!(a < b);
+        assert_eq!(Int::from("-0")?, Int::default());
+
+        Ok(())
+    }
+
     #[test]
     fn test_invalid_integers() -> Result<()> {
         let integer_strings = vec!["", "abc", "1.", "1.0", " 10", " -10", "- 10", "10%"];
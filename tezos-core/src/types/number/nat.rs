@@ -1,11 +1,14 @@
+use derive_more::{Add, AddAssign, BitAnd, BitOr, BitXor, Mul, MulAssign};
 use lazy_static::lazy_static;
-use num_bigint::{BigUint, ToBigUint};
-use num_traits::{Num, Unsigned};
+use num_bigint::{BigInt, BigUint, ToBigUint};
+use num_integer::Integer;
+use num_traits::{Num, ToPrimitive, Unsigned, Zero};
 use regex::Regex;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use std::{
     fmt::{Debug, Display},
+    ops::Sub,
     str::FromStr,
 };
 
@@ -18,24 +21,58 @@ use crate::{
     Error, Result,
 };
 
+use super::{Int, MAX_SHIFT};
+
 lazy_static! {
     static ref REGEX: Regex = Regex::new(r"^[0-9]+$").unwrap();
 }
 
 /// An unsigned integer that can be encoded to a Zarith number
-#[derive(Debug, PartialEq, Eq, Clone)]
+///
+/// Arithmetic follows the semantics of the Michelson instructions on `nat` values, e.g. subtracting
+/// two [Nat]s results in an [Int]:
+///
+/// ```
+/// use tezos_core::types::number::{Int, Nat};
+///
+/// let a: Nat = 7u8.into();
+/// let b: Nat = 2u8.into();
+///
+/// assert_eq!(a.clone() + b.clone(), 9u8.into());
+/// assert_eq!(b.clone() - a.clone(), Int::from_intenger(-5i8));
+/// assert_eq!(a.ediv(&b), Some((3u8.into(), 1u8.into())));
+/// assert_eq!(b.lsl(&a), Some(256u16.into()));
+/// assert!(a > b);
+/// ```
+#[derive(
+    Debug,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Clone,
+    Add,
+    AddAssign,
+    Mul,
+    MulAssign,
+    BitAnd,
+    BitOr,
+    BitXor,
+)]
+#[mul(forward)]
+#[mul_assign(forward)]
 #[cfg_attr(
     feature = "serde",
     derive(Serialize, Deserialize),
-    serde(try_from = "String")
+    serde(try_from = "String", into = "String")
 )]
-pub struct Nat(String);
+pub struct Nat(BigUint);
 
 impl Nat {
     pub fn from<S: Into<String>>(value: S) -> Result<Self> {
         let value: String = value.into();
         if Self::is_valid(&value) {
-            return Ok(Self(value));
+            return Ok(Self(BigUint::from_str_radix(&value, 10)?));
         }
         Err(Error::InvalidIntegerString)
     }
@@ -54,6 +91,7 @@ impl Nat {
     {
         Ok(self
             .0
+            .to_string()
             .parse::<I>()
             .map_err(|_error| Error::InvalidNaturalConversion)?)
     }
@@ -74,70 +112,108 @@ impl Nat {
         NaturalBytesCoder::decode_consuming(bytes)
     }
 
-    pub fn to_str(&self) -> &str {
-        &self.0
+    #[deprecated(note = "use `to_string` instead")]
+    pub fn to_str(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// Divides by `divisor`, returning the quotient and the remainder, or `None` if `divisor` is zero (`EDIV`).
+    pub fn ediv(&self, divisor: &Nat) -> Option<(Nat, Nat)> {
+        if divisor.0.is_zero() {
+            return None;
+        }
+        let (quotient, remainder) = self.0.div_rem(&divisor.0);
+
+        Some((Self(quotient), Self(remainder)))
+    }
+
+    /// Shifts the bits to the left by `shift`, returning `None` if `shift` is greater than 256 (`LSL`).
+    pub fn lsl(&self, shift: &Nat) -> Option<Nat> {
+        Self::shift(shift).map(|shift| Self(&self.0 << shift))
+    }
+
+    /// Shifts the bits to the right by `shift`, returning `None` if `shift` is greater than 256 (`LSR`).
+    pub fn lsr(&self, shift: &Nat) -> Option<Nat> {
+        Self::shift(shift).map(|shift| Self(&self.0 >> shift))
+    }
+
+    fn shift(shift: &Nat) -> Option<usize> {
+        shift.0.to_usize().filter(|shift| *shift <= MAX_SHIFT)
     }
 }
 
 impl Display for Nat {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.0)
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl Default for Nat {
+    fn default() -> Self {
+        Self(BigUint::zero())
+    }
+}
+
+/// Subtracting two natural numbers results in an integer (`SUB`).
+impl Sub for Nat {
+    type Output = Int;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        (BigInt::from(self.0) - BigInt::from(rhs.0)).into()
     }
 }
 
 impl ToBigUint for Nat {
     fn to_biguint(&self) -> Option<BigUint> {
-        BigUint::from_str_radix(&self.0, 10)
-            .map(Some)
-            .unwrap_or(None)
+        Some(self.0.clone())
     }
 }
 
 impl From<u8> for Nat {
     fn from(value: u8) -> Self {
-        Self::from_integer(value)
+        Self(value.into())
     }
 }
 
 impl From<u16> for Nat {
     fn from(value: u16) -> Self {
-        Self::from_integer(value)
+        Self(value.into())
     }
 }
 
 impl From<u32> for Nat {
     fn from(value: u32) -> Self {
-        Self::from_integer(value)
+        Self(value.into())
     }
 }
 
 impl From<u64> for Nat {
     fn from(value: u64) -> Self {
-        Self::from_integer(value)
+        Self(value.into())
     }
 }
 
 impl From<u128> for Nat {
     fn from(value: u128) -> Self {
-        Self::from_integer(value)
+        Self(value.into())
     }
 }
 
 impl From<BigUint> for Nat {
     fn from(value: BigUint) -> Self {
-        Self::from_integer(value)
+        Self(value)
     }
 }
 
 impl From<&Mutez> for Nat {
     fn from(mutez: &Mutez) -> Self {
-        Self::from_integer(mutez.value())
+        Self(mutez.value().into())
     }
 }
 
 impl From<Nat> for String {
     fn from(value: Nat) -> Self {
-        value.0
+        value.0.to_string()
     }
 }
 
@@ -175,7 +251,7 @@ impl TryFrom<&Nat> for Vec<u8> {
 
 impl From<Nat> for BigUint {
     fn from(value: Nat) -> Self {
-        value.to_biguint().unwrap()
+        value.0
     }
 }
 
@@ -201,6 +277,69 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_arithmetic() -> Result<()> {
+        let a: Nat = "340282366920938463463374607431768211457".try_into()?;
+        let b: Nat = 2u8.into();
+
+        assert_eq!(
+            a.clone() + b.clone(),
+            "340282366920938463463374607431768211459".try_into()?
+        );
+        assert_eq!(
+            a.clone() * b.clone(),
+            "680564733841876926926749214863536422914".try_into()?
+        );
+        assert_eq!(
+            b.clone() - a.clone(),
+            "-340282366920938463463374607431768211455".try_into()?
+        );
+        assert_eq!(
+            a.ediv(&b),
+            Some((
+                "170141183460469231731687303715884105728".try_into()?,
+                1u8.into()
+            ))
+        );
+        assert_eq!(a.ediv(&Nat::default()), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_bitwise() -> Result<()> {
+        let a: Nat = 12u8.into();
+        let b: Nat = 10u8.into();
+
+        assert_eq!(a.clone() & b.clone(), 8u8.into());
+        assert_eq!(a.clone() | b.clone(), 14u8.into());
+        assert_eq!(a.clone() ^ b.clone(), 6u8.into());
+        assert_eq!(a.lsl(&2u8.into()), Some(48u8.into()));
+        assert_eq!(a.lsr(&2u8.into()), Some(3u8.into()));
+        assert_eq!(
+            Nat::from_integer(1u8).lsl(&256u16.into()),
+            Some(
+                "115792089237316195423570985008687907853269984665640564039457584007913129639936"
+                    .try_into()?
+            )
+        );
+        assert_eq!(a.lsl(&257u16.into()), None);
+        assert_eq!(a.lsr(&257u16.into()), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_cmp() -> Result<()> {
+        let a: Nat = "9".try_into()?;
+        let b: Nat = "10".try_into()?;
+
+        assert!(a < b);
+        assert_eq!(Nat::from("007")?, 7u8.into());
+
+        Ok(())
+    }
+
     #[test]
     fn test_invalid_naturals() -> Result<()> {
         let values = vec![
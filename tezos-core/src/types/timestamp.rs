@@ -0,0 +1,217 @@
+//! Tezos Timestamp type.
+
+use std::{fmt::Display, str::FromStr};
+
+use chrono::{DateTime, Datelike, SecondsFormat, TimeZone, Utc};
+use num_traits::ToPrimitive;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{types::number::Int, Error, Result};
+
+/// A Michelson timestamp: a number of seconds since epoch (`1970-01-01T00:00:00Z`).
+///
+/// Timestamps are represented either as RFC3339 dates or as numbers of seconds, which are not bounded.
+/// Values which cannot be represented as an RFC3339 date (before year 0 or after year 9999) are formatted
+/// as numbers of seconds.
+///
+/// # Example
+///
+/// ```
+/// use tezos_core::types::timestamp::Timestamp;
+///
+/// let timestamp: Timestamp = "2022-01-20T10:43:57Z".parse().expect("valid RFC3339 date");
+/// assert_eq!(timestamp, Timestamp::from(1642675437i64));
+/// assert_eq!(timestamp.to_string(), "2022-01-20T10:43:57Z");
+///
+/// let far_future: Timestamp = "9223372036854775808".parse().expect("valid number of seconds");
+/// assert_eq!(far_future.to_string(), "9223372036854775808");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(try_from = "String", into = "String")
+)]
+pub struct Timestamp(Int);
+
+impl Timestamp {
+    pub fn new(seconds: Int) -> Self {
+        Self(seconds)
+    }
+
+    /// The number of seconds since epoch.
+    pub fn seconds(&self) -> &Int {
+        &self.0
+    }
+
+    /// Parses an RFC3339 date, dropping the sub-second precision.
+    pub fn from_rfc3339(value: &str) -> Result<Self> {
+        let date_time =
+            DateTime::parse_from_rfc3339(value).map_err(|_| Error::InvalidTimestampString)?;
+
+        Ok(Self(date_time.timestamp().into()))
+    }
+
+    /// Formats the timestamp as an RFC3339 date (e.g. `2022-01-20T10:43:57Z`), if it can be represented as one.
+    pub fn to_rfc3339(&self) -> Option<String> {
+        self.to_date_time()
+            .filter(|date_time| (0..=9999).contains(&date_time.year()))
+            .map(|date_time| date_time.to_rfc3339_opts(SecondsFormat::Secs, true))
+    }
+
+    /// Converts the timestamp to a [DateTime], if it is in the range supported by `chrono`.
+    pub fn to_date_time(&self) -> Option<DateTime<Utc>> {
+        self.0
+            .to_i64()
+            .and_then(|seconds| Utc.timestamp_opt(seconds, 0).single())
+    }
+}
+
+impl Display for Timestamp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.to_rfc3339() {
+            Some(date) => f.write_str(&date),
+            None => Display::fmt(&self.0, f),
+        }
+    }
+}
+
+/// Parses either a number of seconds or an RFC3339 date.
+impl FromStr for Timestamp {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        if Int::is_valid(s) {
+            return Ok(Self(Int::from(s)?));
+        }
+        Self::from_rfc3339(s)
+    }
+}
+
+impl From<Int> for Timestamp {
+    fn from(value: Int) -> Self {
+        Self(value)
+    }
+}
+
+impl From<i64> for Timestamp {
+    fn from(value: i64) -> Self {
+        Self(value.into())
+    }
+}
+
+impl<Tz: TimeZone> From<DateTime<Tz>> for Timestamp {
+    fn from(value: DateTime<Tz>) -> Self {
+        Self(value.timestamp().into())
+    }
+}
+
+impl From<Timestamp> for Int {
+    fn from(value: Timestamp) -> Self {
+        value.0
+    }
+}
+
+impl From<Timestamp> for String {
+    fn from(value: Timestamp) -> Self {
+        value.to_string()
+    }
+}
+
+impl TryFrom<String> for Timestamp {
+    type Error = Error;
+
+    fn try_from(value: String) -> Result<Self> {
+        Self::from_str(&value)
+    }
+}
+
+impl TryFrom<&str> for Timestamp {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self> {
+        Self::from_str(value)
+    }
+}
+
+impl TryFrom<&Timestamp> for DateTime<Utc> {
+    type Error = Error;
+
+    fn try_from(value: &Timestamp) -> Result<Self> {
+        value.to_date_time().ok_or(Error::InvalidConversion)
+    }
+}
+
+impl TryFrom<Timestamp> for DateTime<Utc> {
+    type Error = Error;
+
+    fn try_from(value: Timestamp) -> Result<Self> {
+        (&value).try_into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse() -> Result<()> {
+        let values: Vec<(&str, Timestamp)> = vec![
+            ("1970-01-01T00:00:00Z", 0i64.into()),
+            ("2022-01-20T10:43:57Z", 1642675437i64.into()),
+            ("2022-01-20T10:43:57.103Z", 1642675437i64.into()),
+            ("2022-01-20T12:43:57+02:00", 1642675437i64.into()),
+            ("1969-12-31T23:59:59Z", (-1i64).into()),
+            ("1642675437", 1642675437i64.into()),
+            ("-62135596800", (-62135596800i64).into()),
+            (
+                "-170141183460469231731687303715884105728",
+                Int::from("-170141183460469231731687303715884105728")?.into(),
+            ),
+        ];
+        for (value, expected) in values {
+            assert_eq!(value.parse::<Timestamp>()?, expected);
+        }
+        for invalid in ["", "2022-01-20", "2022-01-20T10:43:57", "1.5", "now"] {
+            assert!(invalid.parse::<Timestamp>().is_err());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_format() -> Result<()> {
+        let values: Vec<(Timestamp, &str)> = vec![
+            (0i64.into(), "1970-01-01T00:00:00Z"),
+            ((-1i64).into(), "1969-12-31T23:59:59Z"),
+            ((-62167219200i64).into(), "0000-01-01T00:00:00Z"),
+            ((-62167219201i64).into(), "-62167219201"),
+            (253402300799i64.into(), "9999-12-31T23:59:59Z"),
+            (253402300800i64.into(), "253402300800"),
+            (
+                Int::from("9223372036854775808")?.into(),
+                "9223372036854775808",
+            ),
+        ];
+        for (timestamp, expected) in values {
+            assert_eq!(timestamp.to_string(), expected);
+            assert_eq!(expected.parse::<Timestamp>()?, timestamp);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_date_time() -> Result<()> {
+        let date_time = Utc.timestamp_opt(1642675437, 0).unwrap();
+        let timestamp: Timestamp = date_time.into();
+
+        assert_eq!(DateTime::<Utc>::try_from(&timestamp)?, date_time);
+        assert!(
+            DateTime::<Utc>::try_from(Timestamp::from(Int::from("9223372036854775808")?)).is_err()
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,330 @@
+//! The `tezos-michelson-derive` crate provides the `IntoMichelson` and `FromMichelson` derive macros,
+//! re-exported by `tezos-michelson` under the `derive` feature.
+//!
+//! * structs are mapped to right combed pairs, each component being annotated with the field name,
+//! * enums are mapped to right combed `or`s, each branch being annotated with the snake cased variant name,
+//! * tuple structs and tuple variants are mapped to unannotated pairs.
+//!
+//! Annotations can be overridden with the `#[michelson(rename = "...")]` attribute.
+//!
+//! `IntoMichelson` also implements `MichelsonType`, so that the Michelson type of a value can be used as
+//! a packing schema or compared against a contract parameter or storage type.
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use syn::{
+    parse_macro_input, parse_quote, Attribute, Data, DataEnum, DeriveInput, Error, Fields,
+    Generics, Ident, LitStr, Result,
+};
+
+#[proc_macro_derive(IntoMichelson, attributes(michelson))]
+pub fn derive_into_michelson(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    into_michelson(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+#[proc_macro_derive(FromMichelson, attributes(michelson))]
+pub fn derive_from_michelson(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    from_michelson(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn convert_path() -> TokenStream2 {
+    quote!(::tezos_michelson::michelson::convert)
+}
+
+fn into_michelson(input: DeriveInput) -> Result<TokenStream2> {
+    let convert = convert_path();
+    let name = &input.ident;
+    let (michelson_type, into_michelson) = match &input.data {
+        Data::Struct(data) => {
+            let fields = fields(&data.fields)?;
+            let michelson_type = fields_type(&fields);
+            let bindings = fields.iter().map(|field| &field.binding);
+            let pattern = fields_pattern(&data.fields, &fields);
+            (
+                quote!(Ok(#michelson_type)),
+                quote! {
+                    let Self #pattern = self;
+                    Ok(#convert::internal::pair(vec![#(#convert::IntoMichelson::into_michelson(#bindings)?),*]))
+                },
+            )
+        }
+        Data::Enum(data) => {
+            let variants = variants(data)?;
+            let count = variants.len();
+            let types = variants.iter().map(|variant| {
+                let annotation = &variant.annotation;
+                let michelson_type = fields_type(&variant.fields);
+                quote!(#michelson_type.with_field_annotation(#annotation.into()))
+            });
+            let arms = variants.iter().enumerate().map(|(index, variant)| {
+                let ident = &variant.ident;
+                let pattern = &variant.pattern;
+                let bindings = variant.fields.iter().map(|field| &field.binding);
+                quote! {
+                    Self::#ident #pattern => #convert::internal::or(
+                        #index,
+                        #count,
+                        #convert::internal::pair(vec![#(#convert::IntoMichelson::into_michelson(#bindings)?),*]),
+                    )
+                }
+            });
+            (
+                quote!(Ok(#convert::internal::or_type(vec![#(#types),*]))),
+                quote! {
+                    Ok(match self {
+                        #(#arms,)*
+                    })
+                },
+            )
+        }
+        Data::Union(_) => {
+            return Err(Error::new(
+                Span::call_site(),
+                "IntoMichelson cannot be derived for unions",
+            ))
+        }
+    };
+
+    let generics = with_bound(&input.generics, quote!(#convert::IntoMichelson));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #convert::MichelsonType for #name #ty_generics #where_clause {
+            fn michelson_type() -> ::tezos_michelson::Result<::tezos_michelson::michelson::types::Type> {
+                #michelson_type
+            }
+        }
+
+        impl #impl_generics #convert::IntoMichelson for #name #ty_generics #where_clause {
+            fn into_michelson(self) -> ::tezos_michelson::Result<::tezos_michelson::michelson::data::Data> {
+                #into_michelson
+            }
+        }
+    })
+}
+
+fn from_michelson(input: DeriveInput) -> Result<TokenStream2> {
+    let convert = convert_path();
+    let name = &input.ident;
+    let from_michelson = match &input.data {
+        Data::Struct(data) => {
+            let fields = fields(&data.fields)?;
+            let constructor = fields_constructor(&quote!(Self), &data.fields, &fields);
+            quote! {
+                let value = data;
+                #constructor
+            }
+        }
+        Data::Enum(data) => {
+            let variants = variants(data)?;
+            let (last, others) = variants.split_last().ok_or_else(|| {
+                Error::new(
+                    Span::call_site(),
+                    "FromMichelson cannot be derived for enums without variants",
+                )
+            })?;
+            let others = others.iter().map(|variant| {
+                let ident = &variant.ident;
+                let constructor = fields_constructor(
+                    &quote!(Self::#ident),
+                    &variant.variant_fields,
+                    &variant.fields,
+                );
+                quote! {
+                    match #convert::internal::branch(data)? {
+                        #convert::internal::Branch::Left(value) => return { #constructor },
+                        #convert::internal::Branch::Right(value) => data = value,
+                    }
+                }
+            });
+            let ident = &last.ident;
+            let last =
+                fields_constructor(&quote!(Self::#ident), &last.variant_fields, &last.fields);
+            quote! {
+                let mut data = data;
+                #(#others)*
+                let value = data;
+                #last
+            }
+        }
+        Data::Union(_) => {
+            return Err(Error::new(
+                Span::call_site(),
+                "FromMichelson cannot be derived for unions",
+            ))
+        }
+    };
+
+    let generics = with_bound(&input.generics, quote!(#convert::FromMichelson));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #convert::FromMichelson for #name #ty_generics #where_clause {
+            #[allow(unused_mut, unused_assignments)]
+            fn from_michelson(data: ::tezos_michelson::michelson::data::Data) -> ::tezos_michelson::Result<Self> {
+                #from_michelson
+            }
+        }
+    })
+}
+
+struct Field {
+    ident: Option<Ident>,
+    ty: syn::Type,
+    binding: Ident,
+    annotation: Option<String>,
+}
+
+struct Variant {
+    ident: Ident,
+    annotation: String,
+    variant_fields: Fields,
+    fields: Vec<Field>,
+    pattern: TokenStream2,
+}
+
+fn fields(fields: &Fields) -> Result<Vec<Field>> {
+    fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| {
+            let annotation = match &field.ident {
+                Some(ident) => Some(rename(&field.attrs)?.unwrap_or_else(|| unraw(ident))),
+                None => rename(&field.attrs)?,
+            };
+            Ok(Field {
+                ident: field.ident.clone(),
+                ty: field.ty.clone(),
+                binding: format_ident!("field_{}", index),
+                annotation,
+            })
+        })
+        .collect()
+}
+
+fn variants(data: &DataEnum) -> Result<Vec<Variant>> {
+    data.variants
+        .iter()
+        .map(|variant| {
+            let fields = fields(&variant.fields)?;
+            let pattern = fields_pattern(&variant.fields, &fields);
+            Ok(Variant {
+                ident: variant.ident.clone(),
+                annotation: rename(&variant.attrs)?
+                    .unwrap_or_else(|| to_snake_case(&unraw(&variant.ident))),
+                variant_fields: variant.fields.clone(),
+                fields,
+                pattern,
+            })
+        })
+        .collect()
+}
+
+/// The Michelson type of a group of fields: a right combed pair of their (annotated) types.
+fn fields_type(fields: &[Field]) -> TokenStream2 {
+    let convert = convert_path();
+    let types = fields.iter().map(|field| {
+        let ty = &field.ty;
+        let michelson_type = quote!(<#ty as #convert::MichelsonType>::michelson_type()?);
+        match &field.annotation {
+            Some(annotation) => quote!(#michelson_type.with_field_annotation(#annotation.into())),
+            None => michelson_type,
+        }
+    });
+
+    quote!(#convert::internal::pair_type(vec![#(#types),*]))
+}
+
+/// A pattern binding the fields to `field_<index>` variables.
+fn fields_pattern(kind: &Fields, fields: &[Field]) -> TokenStream2 {
+    let bindings = fields.iter().map(|field| &field.binding);
+    match kind {
+        Fields::Named(_) => {
+            let idents = fields.iter().map(|field| &field.ident);
+            quote!({ #(#idents: #bindings),* })
+        }
+        Fields::Unnamed(_) => quote!((#(#bindings),*)),
+        Fields::Unit => quote!(),
+    }
+}
+
+/// An expression constructing `path` with fields decoded from the `value` variable.
+fn fields_constructor(path: &TokenStream2, kind: &Fields, fields: &[Field]) -> TokenStream2 {
+    let convert = convert_path();
+    let count = fields.len();
+    let values = fields.iter().map(|_| {
+        quote! {
+            #convert::FromMichelson::from_michelson(
+                values.next().ok_or(::tezos_michelson::Error::InvalidMichelsonData)?
+            )?
+        }
+    });
+    let constructor = match kind {
+        Fields::Named(_) => {
+            let idents = fields.iter().map(|field| &field.ident);
+            quote!(#path { #(#idents: #values),* })
+        }
+        Fields::Unnamed(_) => quote!(#path(#(#values),*)),
+        Fields::Unit => quote!(#path),
+    };
+
+    quote! {
+        let mut values = #convert::internal::pair_values(value, #count)?.into_iter();
+        Ok(#constructor)
+    }
+}
+
+fn with_bound(generics: &Generics, bound: TokenStream2) -> Generics {
+    let mut generics = generics.clone();
+    for param in generics.type_params_mut() {
+        param.bounds.push(parse_quote!(#bound));
+    }
+
+    generics
+}
+
+fn rename(attrs: &[Attribute]) -> Result<Option<String>> {
+    let mut rename = None;
+    for attr in attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("michelson"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("rename") {
+                let value: LitStr = meta.value()?.parse()?;
+                rename = Some(value.value());
+                return Ok(());
+            }
+            Err(meta.error("unsupported michelson attribute"))
+        })?;
+    }
+
+    Ok(rename)
+}
+
+fn unraw(ident: &Ident) -> String {
+    ident.to_string().trim_start_matches("r#").into()
+}
+
+fn to_snake_case(value: &str) -> String {
+    let mut snake_case = String::new();
+    for (index, character) in value.char_indices() {
+        if character.is_uppercase() {
+            if index > 0 {
+                snake_case.push('_');
+            }
+            snake_case.extend(character.to_lowercase());
+        } else {
+            snake_case.push(character);
+        }
+    }
+
+    snake_case
+}
@@ -0,0 +1,278 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use chrono::{DateTime, TimeZone, Utc};
+use tezos_core::types::{encoded::Address, mutez::Mutez, number::Nat};
+use tezos_michelson::{
+    micheline::Micheline,
+    michelson::{
+        convert::{FromMichelson, IntoMichelson, MichelsonType},
+        data::{self, Data},
+        types::{self, Type},
+    },
+    Result,
+};
+
+#[derive(Debug, Clone, PartialEq, IntoMichelson, FromMichelson)]
+struct Transfer {
+    from_: Address,
+    txs: Vec<Destination>,
+}
+
+#[derive(Debug, Clone, PartialEq, IntoMichelson, FromMichelson)]
+struct Destination {
+    to_: Address,
+    token_id: Nat,
+    amount: Nat,
+}
+
+#[derive(Debug, Clone, PartialEq, IntoMichelson, FromMichelson)]
+enum Action {
+    Pause,
+    #[michelson(rename = "set_admin")]
+    SetAdministrator(Address),
+    Fund {
+        amount: Mutez,
+        until: Option<DateTime<Utc>>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, IntoMichelson, FromMichelson)]
+struct Storage {
+    admins: BTreeSet<String>,
+    balances: BTreeMap<String, u64>,
+    action: Action,
+    tuple: Tuple,
+}
+
+#[derive(Debug, Clone, PartialEq, IntoMichelson, FromMichelson)]
+struct Tuple(i32, bool);
+
+fn address(value: &str) -> Address {
+    value.try_into().unwrap()
+}
+
+fn from_json<T: TryFrom<Micheline, Error = tezos_michelson::Error>>(value: &str) -> T {
+    let micheline: Micheline = serde_json::from_str(value).unwrap();
+    micheline.try_into().unwrap()
+}
+
+#[test]
+fn test_struct_type() {
+    let expected: Type = from_json(
+        r#"{
+            "prim": "pair",
+            "args": [
+                { "prim": "address", "annots": ["%from_"] },
+                {
+                    "prim": "list",
+                    "args": [{
+                        "prim": "pair",
+                        "args": [
+                            { "prim": "address", "annots": ["%to_"] },
+                            {
+                                "prim": "pair",
+                                "args": [
+                                    { "prim": "nat", "annots": ["%token_id"] },
+                                    { "prim": "nat", "annots": ["%amount"] }
+                                ]
+                            }
+                        ]
+                    }],
+                    "annots": ["%txs"]
+                }
+            ]
+        }"#,
+    );
+    assert_eq!(expected, Transfer::michelson_type().unwrap());
+}
+
+#[test]
+fn test_enum_type() {
+    let expected: Type = from_json(
+        r#"{
+            "prim": "or",
+            "args": [
+                { "prim": "unit", "annots": ["%pause"] },
+                {
+                    "prim": "or",
+                    "args": [
+                        { "prim": "address", "annots": ["%set_admin"] },
+                        {
+                            "prim": "pair",
+                            "args": [
+                                { "prim": "mutez", "annots": ["%amount"] },
+                                { "prim": "option", "args": [{ "prim": "timestamp" }], "annots": ["%until"] }
+                            ],
+                            "annots": ["%fund"]
+                        }
+                    ]
+                }
+            ]
+        }"#,
+    );
+    assert_eq!(expected, Action::michelson_type().unwrap());
+}
+
+#[test]
+fn test_into_michelson() {
+    let transfer = Transfer {
+        from_: address("tz1YY1LvD6TFH4z74pvxPQXBjAKHE5tB5Q8f"),
+        txs: vec![Destination {
+            to_: address("tz1agAtczEzZS8tV67KGF4urNqxfNCSPksiW"),
+            token_id: 0u8.into(),
+            amount: 100u8.into(),
+        }],
+    };
+    let expected: Data = from_json(
+        r#"{
+            "prim": "Pair",
+            "args": [
+                { "string": "tz1YY1LvD6TFH4z74pvxPQXBjAKHE5tB5Q8f" },
+                [{
+                    "prim": "Pair",
+                    "args": [
+                        { "string": "tz1agAtczEzZS8tV67KGF4urNqxfNCSPksiW" },
+                        { "prim": "Pair", "args": [{ "int": "0" }, { "int": "100" }] }
+                    ]
+                }]
+            ]
+        }"#,
+    );
+    let micheline: Micheline = transfer.into_michelson().unwrap().into();
+    assert_eq!(Micheline::from(expected), micheline);
+
+    let action = Action::Fund {
+        amount: 10u8.into(),
+        until: Some(Utc.timestamp_opt(1654090529, 0).unwrap()),
+    };
+    let expected: Data = from_json(
+        r#"{
+            "prim": "Right",
+            "args": [{
+                "prim": "Right",
+                "args": [{
+                    "prim": "Pair",
+                    "args": [{ "int": "10" }, { "prim": "Some", "args": [{ "int": "1654090529" }] }]
+                }]
+            }]
+        }"#,
+    );
+    let micheline: Micheline = action.into_michelson().unwrap().into();
+    assert_eq!(Micheline::from(expected), micheline);
+}
+
+#[test]
+fn test_from_michelson() -> Result<()> {
+    // Pairs are accepted in their flattened form, and addresses in their optimized form.
+    let data: Data = from_json(
+        r#"{
+            "prim": "Pair",
+            "args": [
+                [{ "string": "alice" }, { "string": "bob" }],
+                [{ "prim": "Elt", "args": [{ "string": "alice" }, { "int": "10" }] }],
+                { "prim": "Right", "args": [{ "prim": "Left", "args": [{ "bytes": "00008d760390453ce759265dcf970705a114c2cae601" }] }] },
+                { "int": "-1" },
+                { "prim": "True" }
+            ]
+        }"#,
+    );
+    let storage = Storage::from_michelson(data)?;
+    assert_eq!(
+        Storage {
+            admins: BTreeSet::from(["alice".into(), "bob".into()]),
+            balances: BTreeMap::from([("alice".into(), 10)]),
+            action: Action::SetAdministrator(address("tz1YY1LvD6TFH4z74pvxPQXBjAKHE5tB5Q8f")),
+            tuple: Tuple(-1, true),
+        },
+        storage
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_round_trip() -> Result<()> {
+    let values = vec![
+        Action::Pause,
+        Action::SetAdministrator(address("KT1J4CiyWPmtFPXAjpgBezM5hoVHXHNzWBHK")),
+        Action::Fund {
+            amount: 1u8.into(),
+            until: None,
+        },
+    ];
+    for value in values {
+        let data = value.clone().into_michelson()?;
+        // Values must be packable with their own type.
+        let micheline: Micheline = data.clone().into();
+        micheline.pack(Some(&Action::michelson_type()?.into()))?;
+        assert_eq!(value, Action::from_michelson(data)?);
+    }
+    assert!(Action::from_michelson(data::unit()).is_err());
+    assert_eq!(types::unit::<Type>(), <()>::michelson_type()?);
+
+    Ok(())
+}
+
+#[test]
+fn test_michelson_order() -> Result<()> {
+    let originated = address("KT1J4CiyWPmtFPXAjpgBezM5hoVHXHNzWBHK");
+    let implicit = address("tz1YY1LvD6TFH4z74pvxPQXBjAKHE5tB5Q8f");
+
+    // Implicit accounts come first in Michelson, unlike in the base58 order.
+    let balances = BTreeMap::from([(originated.clone(), 1u8), (implicit.clone(), 2u8)]);
+    let data = balances.clone().into_michelson()?;
+    assert_eq!(
+        data,
+        data::map(vec![
+            data::Elt::new(implicit.clone().into_michelson()?, data::nat(2u8)),
+            data::Elt::new(originated.clone().into_michelson()?, data::nat(1u8)),
+        ])
+    );
+    assert_eq!(balances, BTreeMap::from_michelson(data)?);
+
+    let admins = BTreeSet::from([originated.clone(), implicit.clone()]);
+    assert_eq!(
+        admins.into_michelson()?,
+        data::sequence(vec![
+            implicit.into_michelson()?,
+            originated.into_michelson()?
+        ])
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_invalid_values() {
+    let action = Action::Fund {
+        amount: 1u8.into(),
+        until: None,
+    };
+    let storage = Storage {
+        admins: BTreeSet::from(["alice\u{7}".into()]),
+        balances: BTreeMap::new(),
+        action,
+        tuple: Tuple(0, false),
+    };
+    assert!(matches!(
+        storage.into_michelson(),
+        Err(tezos_michelson::Error::InvalidStringValue)
+    ));
+    assert!(matches!(
+        String::from("tezos ꜩ").into_michelson(),
+        Err(tezos_michelson::Error::InvalidStringValue)
+    ));
+    assert!(matches!(
+        BTreeSet::<Vec<u8>>::michelson_type(),
+        Err(tezos_michelson::Error::InvalidMichelsonComparableType)
+    ));
+    assert!(matches!(
+        Option::<BTreeSet<BTreeSet<u8>>>::michelson_type(),
+        Err(tezos_michelson::Error::InvalidMichelsonComparableType)
+    ));
+    assert!(matches!(
+        BTreeMap::<Vec<u8>, bool>::michelson_type(),
+        Err(tezos_michelson::Error::InvalidMichelsonComparableType)
+    ));
+    assert!(String::from("line\nfeed").into_michelson().is_ok());
+}
@@ -33,6 +33,7 @@ pub enum Error {
     InvalidPrimitiveApplication,
     InvalidMichelineLiteral,
     MichelineValueSchemaMismatch,
+    DuplicateComparableValue,
 }
 
 pub type Result<T> = result::Result<T, Error>;
@@ -1,3 +1,4 @@
 pub mod coder;
+pub mod comparator;
 pub mod normalizer;
 pub mod packer;
@@ -0,0 +1,267 @@
+use std::cmp::Ordering;
+
+use crate::{
+    micheline::{literals::Literal, primitive_application::PrimitiveApplication, Micheline},
+    michelson::{ComparableTypePrimitive, DataPrimitive, Primitive, TypePrimitive},
+    Error, Result,
+};
+
+use super::packer::MichelinePacker;
+
+pub struct MichelineComparator;
+
+impl MichelineComparator {
+    /// Compares two values of the comparable type `schema` as the Michelson `COMPARE` instruction does.
+    ///
+    /// Addresses, keys, key hashes, signatures and chain ids are compared by their binary representation
+    /// (e.g. implicit accounts before originated contracts) and timestamps by their number of seconds,
+    /// whatever their representation in `lhs` and `rhs`.
+    pub fn compare(lhs: &Micheline, rhs: &Micheline, schema: &Micheline) -> Result<Ordering> {
+        Ok(Self::key(lhs.clone(), schema)?.cmp(&Self::key(rhs.clone(), schema)?))
+    }
+
+    /// Sorts the values of the comparable type `schema` in the Michelson order, as required for
+    /// the elements of a `set` and the keys of a `map`, where `value` returns the value to compare.
+    ///
+    /// Fails with [Error::DuplicateComparableValue] if two values are equal.
+    pub fn sort<T, F>(values: Vec<T>, schema: &Micheline, value: F) -> Result<Vec<T>>
+    where
+        F: Fn(&T) -> &Micheline,
+    {
+        let mut values = values
+            .into_iter()
+            .map(|item| Ok((Self::key(value(&item).clone(), schema)?, item)))
+            .collect::<Result<Vec<_>>>()?;
+        values.sort_by(|(lhs, _), (rhs, _)| lhs.cmp(rhs));
+        if values.windows(2).any(|pair| pair[0].0 == pair[1].0) {
+            return Err(Error::DuplicateComparableValue);
+        }
+
+        Ok(values.into_iter().map(|(_, item)| item).collect())
+    }
+
+    fn key(value: Micheline, schema: &Micheline) -> Result<Key> {
+        let value = MichelinePacker::pre_pack(value, schema)?;
+        let schema = match schema {
+            Micheline::PrimitiveApplication(schema) => schema.clone().normalized(),
+            _ => return Err(Error::InvalidMichelsonComparableType),
+        };
+        Self::packed_key(value, &schema)
+    }
+
+    fn packed_key(value: Micheline, schema: &PrimitiveApplication) -> Result<Key> {
+        let prim: Primitive = schema.prim().try_into()?;
+        match prim {
+            Primitive::ComparableType(ComparableTypePrimitive::Unit) => {
+                Self::data_primitive(&value).and_then(|primitive| match primitive {
+                    DataPrimitive::Unit => Ok(Key::Unit),
+                    _ => Err(Error::MichelineValueSchemaMismatch),
+                })
+            }
+            Primitive::ComparableType(ComparableTypePrimitive::Bool) => {
+                Self::data_primitive(&value).and_then(|primitive| match primitive {
+                    DataPrimitive::False => Ok(Key::Bool(false)),
+                    DataPrimitive::True => Ok(Key::Bool(true)),
+                    _ => Err(Error::MichelineValueSchemaMismatch),
+                })
+            }
+            Primitive::ComparableType(
+                ComparableTypePrimitive::Int
+                | ComparableTypePrimitive::Nat
+                | ComparableTypePrimitive::Mutez
+                | ComparableTypePrimitive::Timestamp,
+            ) => match value {
+                Micheline::Literal(Literal::Int(value)) => Ok(Key::Int(value)),
+                _ => Err(Error::MichelineValueSchemaMismatch),
+            },
+            Primitive::ComparableType(ComparableTypePrimitive::String) => match value {
+                Micheline::Literal(Literal::String(value)) => Ok(Key::String(value.into())),
+                _ => Err(Error::MichelineValueSchemaMismatch),
+            },
+            Primitive::ComparableType(
+                ComparableTypePrimitive::Bytes
+                | ComparableTypePrimitive::Address
+                | ComparableTypePrimitive::KeyHash
+                | ComparableTypePrimitive::Key
+                | ComparableTypePrimitive::Signature
+                | ComparableTypePrimitive::ChainId,
+            ) => match value {
+                Micheline::Literal(Literal::Bytes(value)) => Ok(Key::Bytes((&value).into())),
+                _ => Err(Error::MichelineValueSchemaMismatch),
+            },
+            Primitive::Type(TypePrimitive::Pair) => {
+                let mut values = Self::args(value, DataPrimitive::Pair, 2)?;
+                let schemas = Self::schema_args(schema, 2)?;
+                let rhs = Self::packed_key(values.remove(1), &schemas[1])?;
+                let lhs = Self::packed_key(values.remove(0), &schemas[0])?;
+                Ok(Key::Pair(Box::new(lhs), Box::new(rhs)))
+            }
+            Primitive::Type(TypePrimitive::Or) => {
+                let schemas = Self::schema_args(schema, 2)?;
+                match Self::data_primitive(&value)? {
+                    DataPrimitive::Left => Ok(Key::Left(Box::new(Self::packed_key(
+                        Self::args(value, DataPrimitive::Left, 1)?.remove(0),
+                        &schemas[0],
+                    )?))),
+                    DataPrimitive::Right => Ok(Key::Right(Box::new(Self::packed_key(
+                        Self::args(value, DataPrimitive::Right, 1)?.remove(0),
+                        &schemas[1],
+                    )?))),
+                    _ => Err(Error::MichelineValueSchemaMismatch),
+                }
+            }
+            Primitive::Type(TypePrimitive::Option) => {
+                let schemas = Self::schema_args(schema, 1)?;
+                match Self::data_primitive(&value)? {
+                    DataPrimitive::None => Ok(Key::None),
+                    DataPrimitive::Some => Ok(Key::Some(Box::new(Self::packed_key(
+                        Self::args(value, DataPrimitive::Some, 1)?.remove(0),
+                        &schemas[0],
+                    )?))),
+                    _ => Err(Error::MichelineValueSchemaMismatch),
+                }
+            }
+            _ => Err(Error::InvalidMichelsonComparableType),
+        }
+    }
+
+    fn data_primitive(value: &Micheline) -> Result<DataPrimitive> {
+        match value {
+            Micheline::PrimitiveApplication(value) => Ok(value.prim().parse::<DataPrimitive>()?),
+            _ => Err(Error::MichelineValueSchemaMismatch),
+        }
+    }
+
+    fn args(value: Micheline, primitive: DataPrimitive, count: usize) -> Result<Vec<Micheline>> {
+        let value = value
+            .into_primitive_application()
+            .ok_or(Error::MichelineValueSchemaMismatch)?;
+        if value.prim() != primitive.to_str() || value.args_count() != count {
+            return Err(Error::MichelineValueSchemaMismatch);
+        }
+        Ok(value.into_args().unwrap_or_default())
+    }
+
+    fn schema_args(
+        schema: &PrimitiveApplication,
+        count: usize,
+    ) -> Result<Vec<PrimitiveApplication>> {
+        let args = schema
+            .args()
+            .as_ref()
+            .filter(|args| args.len() == count)
+            .ok_or(Error::InvalidMichelsonComparableType)?;
+        args.iter()
+            .map(|arg| match arg {
+                Micheline::PrimitiveApplication(arg) => Ok(arg.clone().normalized()),
+                _ => Err(Error::InvalidMichelsonComparableType),
+            })
+            .collect()
+    }
+}
+
+/// A comparable value reduced to what its ordering depends on, the derived [Ord] implementing the Michelson order
+/// between values of the same type.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum Key {
+    Unit,
+    Bool(bool),
+    Int(crate::micheline::literals::Int),
+    String(String),
+    Bytes(Vec<u8>),
+    Pair(Box<Key>, Box<Key>),
+    Left(Box<Key>),
+    Right(Box<Key>),
+    None,
+    Some(Box<Key>),
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        micheline::{int, primitive_application, try_string},
+        michelson::types::{self, Type},
+    };
+
+    #[test]
+    fn test_compare() -> Result<()> {
+        let address: Micheline = types::address::<Type>().into();
+        let values = vec![
+            try_string("KT1BEqzn5Wx8uJrZNvuS9DVHmLvG9td3fDLi")?,
+            try_string("tz3Nk25g51knuzFZZz2DeA5PveaQYmCtV68B")?,
+            try_string("tz1YY1LvD6TFH4z74pvxPQXBjAKHE5tB5Q8f")?,
+            try_string("tz2MVED1t9Jery77Bwm1m5YhUx8Wp5KWWRQe")?,
+        ];
+        let sorted = MichelineComparator::sort(values.clone(), &address, |value| value)?;
+        assert_eq!(
+            sorted,
+            vec![
+                values[2].clone(),
+                values[3].clone(),
+                values[1].clone(),
+                values[0].clone()
+            ]
+        );
+
+        let timestamp: Micheline = types::timestamp::<Type>().into();
+        assert_eq!(
+            MichelineComparator::compare(
+                &try_string("1970-01-01T00:01:40Z")?,
+                &int(99),
+                &timestamp
+            )?,
+            Ordering::Greater
+        );
+
+        let pair: Micheline = types::pair::<Type>(vec![
+            types::option(types::nat()),
+            types::or(types::string(), types::bool()),
+            types::int(),
+        ])
+        .into();
+        let value = |option: Micheline, or: Micheline, int: i32| -> Micheline {
+            primitive_application(DataPrimitive::Pair)
+                .with_args(vec![option, or, crate::micheline::int(int)])
+                .into()
+        };
+        let none: Micheline = primitive_application(DataPrimitive::None).into();
+        let some: Micheline = primitive_application(DataPrimitive::Some)
+            .with_args(vec![int(0)])
+            .into();
+        let left: Micheline = primitive_application(DataPrimitive::Left)
+            .with_args(vec![try_string("b")?])
+            .into();
+        let right: Micheline = primitive_application(DataPrimitive::Right)
+            .with_args(vec![primitive_application(DataPrimitive::False).into()])
+            .into();
+        let values = vec![
+            value(some.clone(), left.clone(), -1),
+            value(none.clone(), right.clone(), 10),
+            value(none.clone(), left.clone(), 10),
+            value(none, left, 9),
+            value(some, right, -2),
+        ];
+        let sorted = MichelineComparator::sort(values.clone(), &pair, |value| value)?;
+        assert_eq!(
+            sorted,
+            vec![
+                values[3].clone(),
+                values[2].clone(),
+                values[1].clone(),
+                values[0].clone(),
+                values[4].clone()
+            ]
+        );
+
+        assert!(matches!(
+            MichelineComparator::sort(
+                vec![int(1), int(0), int(1)],
+                &types::nat::<Type>().into(),
+                |value| value
+            ),
+            Err(Error::DuplicateComparableValue)
+        ));
+        Ok(())
+    }
+}
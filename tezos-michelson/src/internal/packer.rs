@@ -1,8 +1,9 @@
-use chrono::{DateTime, NaiveDateTime, SecondsFormat, Utc};
-use num_traits::ToPrimitive;
 use tezos_core::{
     internal::types::BytesTag,
-    types::encoded::{Address, ChainId, Encoded, ImplicitAddress, Key, Signature},
+    types::{
+        encoded::{Address, ChainId, Encoded, ImplicitAddress, Key, Signature},
+        timestamp::Timestamp,
+    },
 };
 
 use crate::{
@@ -71,6 +72,9 @@ impl MichelinePacker {
                 Self::pre_pack_address(value.try_into()?)
             }
             Primitive::Type(TypePrimitive::Pair) => Self::pre_pack_pair(value, schema),
+            Primitive::Type(TypePrimitive::Ticket) => {
+                Self::pre_pack_pair(value, &Self::ticket_schema(schema)?)
+            }
             Primitive::Type(TypePrimitive::Lambda) => {
                 Self::pre_pack_lambda(value.try_into()?, schema)
             }
@@ -113,6 +117,9 @@ impl MichelinePacker {
                 Self::post_unpack_address(value.try_into()?)
             }
             Primitive::Type(TypePrimitive::Pair) => Self::post_unpack_pair(value, schema),
+            Primitive::Type(TypePrimitive::Ticket) => {
+                Self::post_unpack_pair(value.normalized(), &Self::ticket_schema(schema)?)
+            }
             Primitive::Type(TypePrimitive::Lambda) => {
                 Self::post_unpack_lambda(value.try_into()?, schema)
             }
@@ -348,6 +355,24 @@ impl MichelinePacker {
         Err(Error::MichelineValueSchemaMismatch)
     }
 
+    /// Returns the type of the values of `ticket cty`: `pair address (pair cty nat)`.
+    fn ticket_schema(schema: &PrimitiveApplication) -> Result<PrimitiveApplication> {
+        let content_type = schema
+            .first_arg()
+            .ok_or(Error::InvalidPrimitiveApplication)?
+            .clone();
+
+        Ok(primitive_application(TypePrimitive::Pair).with_args(vec![
+            primitive_application(ComparableTypePrimitive::Address).into(),
+            primitive_application(TypePrimitive::Pair)
+                .with_args(vec![
+                    content_type,
+                    primitive_application(ComparableTypePrimitive::Nat).into(),
+                ])
+                .into(),
+        ]))
+    }
+
     fn pre_pack_lambda(value: Sequence, schema: &PrimitiveApplication) -> Result<Micheline> {
         let values = value.into_values();
         Ok(values
@@ -738,9 +763,9 @@ impl MichelinePacker {
         match value {
             Literal::Int(_) => Ok(value.into()),
             Literal::String(value) => {
-                let date_time = DateTime::parse_from_rfc3339(value.to_str())
+                let timestamp = Timestamp::from_rfc3339(value.to_str())
                     .map_err(|_error| Error::MichelineValueSchemaMismatch)?;
-                Ok(Literal::Int(date_time.timestamp_millis().into()).into())
+                Ok(Literal::Int(timestamp.into()).into())
             }
             _ => Err(Error::MichelineValueSchemaMismatch),
         }
@@ -748,20 +773,10 @@ impl MichelinePacker {
 
     fn post_unpack_timestamp(value: Literal) -> Result<Micheline> {
         match value {
-            Literal::Int(value) => {
-                let value = value.to_i64().ok_or(Error::MichelineValueSchemaMismatch)?;
-                let ts_secs = value / 1000;
-                let ts_ns = (value % 1000) * 1_000_000;
-                let dt = DateTime::<Utc>::from_utc(
-                    NaiveDateTime::from_timestamp_opt(ts_secs, ts_ns as u32)
-                        .expect("out-of-range number of seconds and/or invalid nanosecond"),
-                    Utc,
-                );
-                Ok(
-                    Literal::String(dt.to_rfc3339_opts(SecondsFormat::Millis, true).try_into()?)
-                        .into(),
-                )
-            }
+            Literal::Int(value) => match Timestamp::new(value.clone()).to_rfc3339() {
+                Some(date) => Ok(Literal::String(date.try_into()?).into()),
+                None => Ok(Literal::Int(value).into()),
+            },
             Literal::String(_) => Ok(value.into()),
             _ => Err(Error::MichelineValueSchemaMismatch),
         }
@@ -981,8 +996,8 @@ mod test {
                 Some(types::key()),
             ),
             (
-                &hex!("0500aff8aff1ce5f"),
-                data::try_string("2022-01-20T10:43:57.103Z").unwrap(),
+                &hex!("0500adf3c99e0c"),
+                data::try_string("2022-01-20T10:43:57Z").unwrap(),
                 Some(types::timestamp()),
             ),
             (
@@ -512,7 +512,7 @@ pub mod micheline;
 pub mod michelson;
 
 pub use error::{Error, Result};
-pub use internal::packer::MichelinePacker;
+pub use internal::{comparator::MichelineComparator, packer::MichelinePacker};
 
 #[cfg(test)]
 mod test {
@@ -1,6 +1,8 @@
 pub mod annotations;
+pub mod convert;
 pub mod data;
 pub mod metadata;
+pub mod ticket;
 pub mod types;
 
 use annotations::Annotation;
@@ -0,0 +1,589 @@
+//! Conversions between Rust values and Michelson [Data], along with their Michelson [Type].
+//!
+//! The traits can be implemented by hand or derived with the `IntoMichelson` and `FromMichelson`
+//! macros (requires the `derive` feature):
+//!
+//! * structs are mapped to right combed pairs, annotated with the field names,
+//! * enums are mapped to right combed `or`s, annotated with the snake cased variant names,
+//! * [Option], [Vec], [BTreeSet] and [BTreeMap] are mapped to `option`, `list`, `set` and `map`.
+//!
+//! ```rust
+//! # #[cfg(feature = "derive")]
+//! # {
+//! use tezos_michelson::michelson::convert::{FromMichelson, IntoMichelson, MichelsonType};
+//! use tezos_core::types::{encoded::Address, number::Nat};
+//!
+//! #[derive(Debug, PartialEq, IntoMichelson, FromMichelson)]
+//! struct Transfer {
+//!     to: Address,
+//!     token_id: Nat,
+//!     amount: Nat,
+//! }
+//!
+//! let transfer = Transfer {
+//!     to: "tz1YY1LvD6TFH4z74pvxPQXBjAKHE5tB5Q8f".try_into().unwrap(),
+//!     token_id: 0u8.into(),
+//!     amount: 100u8.into(),
+//! };
+//! let r#type = Transfer::michelson_type().unwrap(); // pair (address %to) (pair (nat %token_id) (nat %amount))
+//! let data = transfer.into_michelson().unwrap();
+//! # }
+//! ```
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use chrono::{DateTime, Utc};
+use tezos_core::types::{
+    encoded::{Address, ChainId, Encoded, ImplicitAddress, PublicKey, Signature},
+    mutez::Mutez,
+    number::{Int, Nat},
+    timestamp::Timestamp,
+};
+
+#[cfg(feature = "derive")]
+pub use tezos_michelson_derive::{FromMichelson, IntoMichelson};
+
+use super::{
+    data::{self, Bytes, Data},
+    types::{self, ComparableType, Type},
+};
+use crate::{micheline::Micheline, Error, MichelineComparator, Result};
+
+/// A Rust type with a Michelson [Type] counterpart.
+///
+/// Fails if the Rust type cannot be represented in Michelson, e.g. a [BTreeSet] of values
+/// whose Michelson type is not comparable.
+pub trait MichelsonType {
+    fn michelson_type() -> Result<Type>;
+}
+
+/// Converts a value into Michelson [Data] of type [MichelsonType::michelson_type].
+///
+/// Fails if the value cannot be represented in Michelson, e.g. a [String] with non printable ASCII characters.
+pub trait IntoMichelson: MichelsonType {
+    fn into_michelson(self) -> Result<Data>;
+}
+
+/// Creates a value out of Michelson [Data].
+pub trait FromMichelson: Sized {
+    fn from_michelson(data: Data) -> Result<Self>;
+}
+
+impl MichelsonType for () {
+    fn michelson_type() -> Result<Type> {
+        Ok(types::unit())
+    }
+}
+
+impl IntoMichelson for () {
+    fn into_michelson(self) -> Result<Data> {
+        Ok(data::unit())
+    }
+}
+
+impl FromMichelson for () {
+    fn from_michelson(data: Data) -> Result<Self> {
+        match data {
+            Data::Unit(_) => Ok(()),
+            _ => Err(Error::InvalidMichelsonData),
+        }
+    }
+}
+
+impl MichelsonType for bool {
+    fn michelson_type() -> Result<Type> {
+        Ok(types::bool())
+    }
+}
+
+impl IntoMichelson for bool {
+    fn into_michelson(self) -> Result<Data> {
+        Ok(self.into())
+    }
+}
+
+impl FromMichelson for bool {
+    fn from_michelson(data: Data) -> Result<Self> {
+        match data {
+            Data::True(_) => Ok(true),
+            Data::False(_) => Ok(false),
+            _ => Err(Error::InvalidMichelsonData),
+        }
+    }
+}
+
+impl MichelsonType for String {
+    fn michelson_type() -> Result<Type> {
+        Ok(types::string())
+    }
+}
+
+impl IntoMichelson for String {
+    /// Fails with [Error::InvalidStringValue] if the string contains characters other than printable ASCII
+    /// and line feeds, which are the only characters allowed in Michelson strings.
+    fn into_michelson(self) -> Result<Data> {
+        if !self
+            .chars()
+            .all(|character| character == '\n' || (' '..='~').contains(&character))
+        {
+            return Err(Error::InvalidStringValue);
+        }
+        Ok(data::String::from_string(self)?.into())
+    }
+}
+
+impl FromMichelson for String {
+    fn from_michelson(data: Data) -> Result<Self> {
+        match data {
+            Data::String(value) => Ok(value.into_string()),
+            _ => Err(Error::InvalidMichelsonData),
+        }
+    }
+}
+
+impl MichelsonType for Bytes {
+    fn michelson_type() -> Result<Type> {
+        Ok(types::bytes())
+    }
+}
+
+impl IntoMichelson for Bytes {
+    fn into_michelson(self) -> Result<Data> {
+        Ok(self.into())
+    }
+}
+
+impl FromMichelson for Bytes {
+    fn from_michelson(data: Data) -> Result<Self> {
+        data.try_into()
+    }
+}
+
+impl MichelsonType for Int {
+    fn michelson_type() -> Result<Type> {
+        Ok(types::int())
+    }
+}
+
+impl IntoMichelson for Int {
+    fn into_michelson(self) -> Result<Data> {
+        Ok(self.into())
+    }
+}
+
+impl FromMichelson for Int {
+    fn from_michelson(data: Data) -> Result<Self> {
+        match data {
+            Data::Int(value) => Ok(value),
+            Data::Nat(value) => Ok(value.into()),
+            _ => Err(Error::InvalidMichelsonData),
+        }
+    }
+}
+
+impl MichelsonType for Nat {
+    fn michelson_type() -> Result<Type> {
+        Ok(types::nat())
+    }
+}
+
+impl IntoMichelson for Nat {
+    fn into_michelson(self) -> Result<Data> {
+        Ok(self.into())
+    }
+}
+
+impl FromMichelson for Nat {
+    fn from_michelson(data: Data) -> Result<Self> {
+        match data {
+            Data::Nat(value) => Ok(value),
+            Data::Int(value) => Ok(value.to_string().try_into()?),
+            _ => Err(Error::InvalidMichelsonData),
+        }
+    }
+}
+
+impl MichelsonType for Mutez {
+    fn michelson_type() -> Result<Type> {
+        Ok(types::mutez())
+    }
+}
+
+impl IntoMichelson for Mutez {
+    fn into_michelson(self) -> Result<Data> {
+        Ok(<Nat as From<&Mutez>>::from(&self).into())
+    }
+}
+
+impl FromMichelson for Mutez {
+    fn from_michelson(data: Data) -> Result<Self> {
+        Ok((&Nat::from_michelson(data)?).try_into()?)
+    }
+}
+
+macro_rules! impl_integers {
+    ($michelson_type:ident, $($integer:ty),+) => {
+        $(
+            impl MichelsonType for $integer {
+                fn michelson_type() -> Result<Type> {
+                    Ok(types::$michelson_type())
+                }
+            }
+
+            impl IntoMichelson for $integer {
+                fn into_michelson(self) -> Result<Data> {
+                    Ok(data::$michelson_type(self))
+                }
+            }
+
+            impl FromMichelson for $integer {
+                fn from_michelson(data: Data) -> Result<Self> {
+                    Ok(Int::from_michelson(data)?.to_integer()?)
+                }
+            }
+        )+
+    };
+}
+
+impl_integers!(nat, u8, u16, u32, u64, u128);
+impl_integers!(int, i8, i16, i32, i64, i128);
+
+macro_rules! impl_encoded {
+    ($(($encoded:ty, $michelson_type:ident)),+) => {
+        $(
+            impl MichelsonType for $encoded {
+                fn michelson_type() -> Result<Type> {
+                    Ok(types::$michelson_type())
+                }
+            }
+
+            impl IntoMichelson for $encoded {
+                fn into_michelson(self) -> Result<Data> {
+                    self.into_string().into_michelson()
+                }
+            }
+
+            impl FromMichelson for $encoded {
+                fn from_michelson(data: Data) -> Result<Self> {
+                    let micheline: Micheline = match data {
+                        Data::String(_) => data.into(),
+                        Data::Bytes(_) => crate::MichelinePacker::post_unpack(
+                            data.into(),
+                            &Self::michelson_type()?.into(),
+                        )?,
+                        _ => return Err(Error::InvalidMichelsonData),
+                    };
+                    let data: Data = micheline.try_into()?;
+                    Ok(String::from_michelson(data)?.try_into()?)
+                }
+            }
+        )+
+    };
+}
+
+impl_encoded!(
+    (Address, address),
+    (ImplicitAddress, key_hash),
+    (PublicKey, key),
+    (Signature, signature),
+    (ChainId, chain_id)
+);
+
+/// Timestamps are encoded as the number of seconds since epoch.
+impl MichelsonType for Timestamp {
+    fn michelson_type() -> Result<Type> {
+        Ok(types::timestamp())
+    }
+}
+
+impl IntoMichelson for Timestamp {
+    fn into_michelson(self) -> Result<Data> {
+        Ok(Data::Int(self.into()))
+    }
+}
+
+impl FromMichelson for Timestamp {
+    fn from_michelson(data: Data) -> Result<Self> {
+        match data {
+            Data::Int(value) => Ok(value.into()),
+            Data::String(value) => {
+                Timestamp::from_rfc3339(value.to_str()).map_err(|_| Error::InvalidMichelsonData)
+            }
+            _ => Err(Error::InvalidMichelsonData),
+        }
+    }
+}
+
+impl MichelsonType for DateTime<Utc> {
+    fn michelson_type() -> Result<Type> {
+        Timestamp::michelson_type()
+    }
+}
+
+impl IntoMichelson for DateTime<Utc> {
+    fn into_michelson(self) -> Result<Data> {
+        Timestamp::from(self).into_michelson()
+    }
+}
+
+impl FromMichelson for DateTime<Utc> {
+    fn from_michelson(data: Data) -> Result<Self> {
+        Timestamp::from_michelson(data)?
+            .to_date_time()
+            .ok_or(Error::InvalidMichelsonData)
+    }
+}
+
+impl<T: MichelsonType> MichelsonType for Option<T> {
+    fn michelson_type() -> Result<Type> {
+        Ok(types::option(T::michelson_type()?))
+    }
+}
+
+impl<T: IntoMichelson> IntoMichelson for Option<T> {
+    fn into_michelson(self) -> Result<Data> {
+        Ok(match self {
+            Some(value) => data::some(value.into_michelson()?),
+            None => data::none(),
+        })
+    }
+}
+
+impl<T: FromMichelson> FromMichelson for Option<T> {
+    fn from_michelson(data: Data) -> Result<Self> {
+        internal::option_value(data)?
+            .map(T::from_michelson)
+            .transpose()
+    }
+}
+
+impl<T: MichelsonType> MichelsonType for Vec<T> {
+    fn michelson_type() -> Result<Type> {
+        Ok(types::list(T::michelson_type()?))
+    }
+}
+
+impl<T: IntoMichelson> IntoMichelson for Vec<T> {
+    fn into_michelson(self) -> Result<Data> {
+        Ok(data::sequence(
+            self.into_iter()
+                .map(IntoMichelson::into_michelson)
+                .collect::<Result<_>>()?,
+        ))
+    }
+}
+
+impl<T: FromMichelson> FromMichelson for Vec<T> {
+    fn from_michelson(data: Data) -> Result<Self> {
+        internal::sequence_values(data)?
+            .into_iter()
+            .map(T::from_michelson)
+            .collect()
+    }
+}
+
+impl<T: MichelsonType> MichelsonType for BTreeSet<T> {
+    fn michelson_type() -> Result<Type> {
+        Ok(types::set(comparable_type::<T>()?))
+    }
+}
+
+/// The elements are sorted in the Michelson order, which may differ from their [Ord] order.
+impl<T: IntoMichelson> IntoMichelson for BTreeSet<T> {
+    fn into_michelson(self) -> Result<Data> {
+        let elements = self
+            .into_iter()
+            .map(|element| {
+                let element = element.into_michelson()?;
+                Ok((element.clone().into(), element))
+            })
+            .collect::<Result<Vec<(Micheline, Data)>>>()?;
+        let schema: Micheline = T::michelson_type()?.into();
+
+        Ok(data::sequence(
+            MichelineComparator::sort(elements, &schema, |(element, _)| element)?
+                .into_iter()
+                .map(|(_, element)| element)
+                .collect(),
+        ))
+    }
+}
+
+impl<T: FromMichelson + Ord> FromMichelson for BTreeSet<T> {
+    fn from_michelson(data: Data) -> Result<Self> {
+        Ok(Vec::<T>::from_michelson(data)?.into_iter().collect())
+    }
+}
+
+impl<K: MichelsonType, V: MichelsonType> MichelsonType for BTreeMap<K, V> {
+    fn michelson_type() -> Result<Type> {
+        let key_type: Micheline = comparable_type::<K>()?.into();
+
+        Ok(types::map(key_type.try_into()?, V::michelson_type()?))
+    }
+}
+
+/// The entries are sorted by key in the Michelson order, which may differ from their [Ord] order.
+impl<K: IntoMichelson, V: IntoMichelson> IntoMichelson for BTreeMap<K, V> {
+    fn into_michelson(self) -> Result<Data> {
+        let entries = self
+            .into_iter()
+            .map(|(key, value)| {
+                let key = key.into_michelson()?;
+                Ok((key.clone().into(), key, value.into_michelson()?))
+            })
+            .collect::<Result<Vec<(Micheline, Data, Data)>>>()?;
+        let schema: Micheline = K::michelson_type()?.into();
+
+        Ok(data::map(
+            MichelineComparator::sort(entries, &schema, |(key, _, _)| key)?
+                .into_iter()
+                .map(|(_, key, value)| data::Elt::new(key, value))
+                .collect(),
+        ))
+    }
+}
+
+impl<K: FromMichelson + Ord, V: FromMichelson> FromMichelson for BTreeMap<K, V> {
+    fn from_michelson(data: Data) -> Result<Self> {
+        internal::map_entries(data)?
+            .into_iter()
+            .map(|(key, value)| Ok((K::from_michelson(key)?, V::from_michelson(value)?)))
+            .collect()
+    }
+}
+
+/// Fails with [Error::InvalidMichelsonComparableType] if the Michelson type of `T` is not comparable.
+fn comparable_type<T: MichelsonType>() -> Result<ComparableType> {
+    let micheline: Micheline = T::michelson_type()?.into();
+    micheline
+        .try_into()
+        .map_err(|_| Error::InvalidMichelsonComparableType)
+}
+
+/// Helpers used by the code generated with the `IntoMichelson` and `FromMichelson` macros.
+#[doc(hidden)]
+pub mod internal {
+    use super::super::data::{self, Data};
+    use super::super::types::{self, Type};
+    use crate::{Error, Result};
+
+    pub enum Branch {
+        Left(Data),
+        Right(Data),
+    }
+
+    /// Creates a right combed pair out of the values.
+    pub fn pair(mut values: Vec<Data>) -> Data {
+        match values.len() {
+            0 => data::unit(),
+            1 => values.remove(0),
+            _ => {
+                let first = values.remove(0);
+                data::pair(vec![first, pair(values)])
+            }
+        }
+    }
+
+    /// Creates a right combed pair type out of the types.
+    pub fn pair_type(mut types: Vec<Type>) -> Type {
+        match types.len() {
+            0 => types::unit(),
+            1 => types.remove(0),
+            _ => {
+                let first = types.remove(0);
+                types::pair(vec![first, pair_type(types)])
+            }
+        }
+    }
+
+    /// Returns the values of a (possibly combed) pair, as `count` components.
+    pub fn pair_values(value: Data, count: usize) -> Result<Vec<Data>> {
+        match count {
+            0 => super::FromMichelson::from_michelson(value).map(|()| vec![]),
+            1 => Ok(vec![value]),
+            _ => {
+                let mut values = match value {
+                    Data::Pair(pair) => pair.values,
+                    Data::Sequence(sequence) => sequence.into_values(),
+                    _ => return Err(Error::InvalidMichelsonData),
+                };
+                if values.len() < 2 {
+                    return Err(Error::InvalidMichelsonData);
+                }
+                if values.len() > count {
+                    let rest = values.split_off(count - 1);
+                    values.push(data::pair(rest));
+                } else if values.len() < count {
+                    let last = values.pop().unwrap();
+                    let missing = count - values.len();
+                    values.extend(pair_values(last, missing)?);
+                }
+                Ok(values)
+            }
+        }
+    }
+
+    /// Wraps the value of the `index`th branch out of `count` in right combed `Left`/`Right`s.
+    pub fn or(index: usize, count: usize, value: Data) -> Data {
+        let value = if index + 1 < count {
+            data::left(value)
+        } else {
+            value
+        };
+        (0..index).fold(value, |value, _| data::right(value))
+    }
+
+    /// Creates a right combed or type out of the types.
+    pub fn or_type(mut types: Vec<Type>) -> Type {
+        match types.len() {
+            0 => types::never(),
+            1 => types.remove(0),
+            _ => {
+                let first = types.remove(0);
+                types::or(first, or_type(types))
+            }
+        }
+    }
+
+    pub fn option_value(value: Data) -> Result<Option<Data>> {
+        match value {
+            Data::Some(value) => Ok(Some(*value.value)),
+            Data::None(_) => Ok(None),
+            _ => Err(Error::InvalidMichelsonData),
+        }
+    }
+
+    pub fn sequence_values(value: Data) -> Result<Vec<Data>> {
+        match value {
+            Data::Sequence(sequence) => Ok(sequence.into_values()),
+            _ => Err(Error::InvalidMichelsonData),
+        }
+    }
+
+    /// Returns the entries of a map, which may also be represented as a sequence of `Elt`.
+    pub fn map_entries(value: Data) -> Result<Vec<(Data, Data)>> {
+        let elements = match value {
+            Data::Map(map) => map.into_values(),
+            Data::Sequence(sequence) => sequence
+                .into_values()
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<Vec<data::Elt>>>()?,
+            _ => return Err(Error::InvalidMichelsonData),
+        };
+
+        Ok(elements
+            .into_iter()
+            .map(|element| (*element.key, *element.value))
+            .collect())
+    }
+
+    pub fn branch(value: Data) -> Result<Branch> {
+        match value {
+            Data::Left(left) => Ok(Branch::Left(*left.value)),
+            Data::Right(right) => Ok(Branch::Right(*right.value)),
+            _ => Err(Error::InvalidMichelsonData),
+        }
+    }
+}
@@ -0,0 +1,230 @@
+use tezos_core::{
+    hash,
+    types::{
+        encoded::{Address, Encoded, ScriptExprHash},
+        number::Nat,
+    },
+};
+
+use crate::{
+    micheline::{
+        literals::{Bytes, Literal},
+        primitive_application,
+        primitive_application::PrimitiveApplication,
+        try_string, Micheline,
+    },
+    michelson::{types::Type, DataPrimitive, TypePrimitive},
+    Error, MichelinePacker, Result,
+};
+
+/// A ticket, as held in the storage of a contract or passed as a parameter.
+///
+/// A ticket value is represented in Micheline as `Pair ticketer (Pair content amount)`, where `ticketer` is the
+/// address of the contract which created the ticket and `content` is a value of the ticket's content type.
+///
+/// ```rust
+/// use tezos_michelson::{micheline::{int, Micheline}, michelson::{ticket::Ticket, types::{self, Type}}};
+///
+/// let ticket = Ticket::new(
+///     "KT1HNqxFJxnmUcX8wF915wxxaAAU4ixDwWQ7".try_into().unwrap(),
+///     types::nat::<Type>().into(),
+///     int(1),
+///     10u8.into(),
+/// );
+/// let value: Micheline = ticket.clone().into();
+/// assert_eq!(Ticket::from_micheline(value, types::nat::<Type>().into()).unwrap(), ticket);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ticket {
+    pub ticketer: Address,
+    pub content_type: Micheline,
+    pub content: Micheline,
+    pub amount: Nat,
+}
+
+impl Ticket {
+    pub fn new(
+        ticketer: Address,
+        content_type: Micheline,
+        content: Micheline,
+        amount: Nat,
+    ) -> Self {
+        Self {
+            ticketer,
+            content_type,
+            content,
+            amount,
+        }
+    }
+
+    /// Creates a [Ticket] out of its Micheline `value`, in either the readable or the optimized representation,
+    /// where `content_type` is the type of the ticket's content.
+    pub fn from_micheline(value: Micheline, content_type: Micheline) -> Result<Self> {
+        let schema: Micheline = primitive_application(TypePrimitive::Ticket)
+            .with_args(vec![content_type.clone()])
+            .into();
+        let value = MichelinePacker::post_unpack(value, &schema)?;
+        let mut args = value
+            .into_primitive_application()
+            .and_then(|pair| pair.into_args())
+            .ok_or(Error::InvalidMichelsonData)?;
+        let mut content_args = args
+            .pop()
+            .and_then(|pair| pair.into_primitive_application())
+            .and_then(|pair| pair.into_args())
+            .ok_or(Error::InvalidMichelsonData)?;
+        let ticketer = match args.pop() {
+            Some(Micheline::Literal(Literal::String(ticketer))) => {
+                Address::new(ticketer.into_string())?
+            }
+            _ => return Err(Error::InvalidMichelsonData),
+        };
+        let amount: Nat = content_args
+            .pop()
+            .ok_or(Error::InvalidMichelsonData)?
+            .try_into()?;
+        let content = content_args.pop().ok_or(Error::InvalidMichelsonData)?;
+
+        Ok(Self::new(ticketer, content_type, content, amount))
+    }
+
+    /// Creates a [Ticket] out of its Micheline `value`, where `r#type` is the `ticket` type of the value.
+    pub fn from_typed_micheline(value: Micheline, r#type: &Type) -> Result<Self> {
+        match r#type {
+            Type::Ticket(ticket) => Self::from_micheline(value, (*ticket.r#type).clone().into()),
+            _ => Err(Error::InvalidMichelsonType),
+        }
+    }
+
+    /// Computes the hash identifying the balance of tickets of this ticketer and content owned by `owner`, as used
+    /// by the ticket balance table of the protocol.
+    pub fn hash(&self, owner: &Address) -> Result<ScriptExprHash> {
+        let content =
+            MichelinePacker::pre_pack(self.content.clone().normalized(), &self.content_type)?;
+        let key: Micheline = primitive_application(DataPrimitive::Pair)
+            .with_args(vec![
+                Bytes::from(self.ticketer.to_bytes()?).into(),
+                strip_annotations(self.content_type.clone()),
+                content,
+                Bytes::from(owner.to_bytes()?).into(),
+            ])
+            .into();
+        Ok(hash::script_expr_hash(&key.to_bytes()?)?)
+    }
+}
+
+fn strip_annotations(value: Micheline) -> Micheline {
+    match value {
+        Micheline::PrimitiveApplication(value) => {
+            let prim = value.prim().to_string();
+            let args = value
+                .into_args()
+                .map(|args| args.into_iter().map(strip_annotations).collect());
+            PrimitiveApplication::new(prim, args, None).into()
+        }
+        Micheline::Sequence(value) => value
+            .into_values()
+            .into_iter()
+            .map(strip_annotations)
+            .collect::<Vec<_>>()
+            .into(),
+        value => value,
+    }
+}
+
+impl From<Ticket> for Micheline {
+    fn from(value: Ticket) -> Self {
+        let ticketer: Micheline = try_string(value.ticketer.into_string())
+            .expect("an address is a valid Micheline string");
+        primitive_application(DataPrimitive::Pair)
+            .with_args(vec![
+                ticketer,
+                primitive_application(DataPrimitive::Pair)
+                    .with_args(vec![value.content, value.amount.into()])
+                    .into(),
+            ])
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use hex_literal::hex;
+
+    use super::*;
+    use crate::{
+        micheline::{bytes, int},
+        michelson::types,
+    };
+
+    fn ticket() -> Ticket {
+        Ticket::new(
+            "KT1HNqxFJxnmUcX8wF915wxxaAAU4ixDwWQ7".try_into().unwrap(),
+            types::nat::<Type>().into(),
+            int(1),
+            10u8.into(),
+        )
+    }
+
+    #[test]
+    fn test_pack_ticket() -> Result<()> {
+        let schema: Micheline = types::ticket::<Type>(types::nat::<Type>()).into();
+        let value: Micheline = ticket().into();
+        let packed = value.clone().pack(Some(&schema))?;
+        assert_eq!(
+            packed,
+            hex!("0507070a00000016016077cd98fd8aca94851b83a4c44203b705d2004b0007070001000a")
+        );
+        assert_eq!(Micheline::unpack(&packed, Some(&schema))?, value);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_ticket_from_micheline() -> Result<()> {
+        let optimized: Micheline = primitive_application(DataPrimitive::Pair)
+            .with_args(vec![
+                bytes(hex!("016077cd98fd8aca94851b83a4c44203b705d2004b00").to_vec()),
+                int(1),
+                int(10),
+            ])
+            .into();
+        assert_eq!(
+            Ticket::from_micheline(optimized, types::nat::<Type>().into())?,
+            ticket()
+        );
+        assert_eq!(
+            Ticket::from_typed_micheline(
+                ticket().into(),
+                &types::ticket::<Type>(types::nat::<Type>())
+            )?,
+            ticket()
+        );
+        assert!(Ticket::from_typed_micheline(ticket().into(), &types::nat::<Type>()).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_ticket_hash() -> Result<()> {
+        let owner: Address = "tz1YY1LvD6TFH4z74pvxPQXBjAKHE5tB5Q8f".try_into()?;
+        // The protocol hashes the binary encoding, without the packing prefix, of
+        // `Pair <ticketer bytes> <content type> <content> <owner bytes>`.
+        let key = hex!(
+            "09070000003a"
+            "0a00000016016077cd98fd8aca94851b83a4c44203b705d2004b00"
+            "0362"
+            "0001"
+            "0a0000001600008d760390453ce759265dcf970705a114c2cae601"
+            "00000000"
+        );
+        let expected = hash::script_expr_hash(&key)?;
+        assert_eq!(
+            expected.value(),
+            "expruv83irQ9TgjtRMQvNBrt59kobLaR7SDZu4JDPbzEk74w6YGtZJ"
+        );
+        assert_eq!(ticket().hash(&owner)?, expected);
+
+        Ok(())
+    }
+}
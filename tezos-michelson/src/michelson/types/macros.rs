@@ -42,6 +42,17 @@ macro_rules! make_types {
                     )*
                 }
             }
+
+            pub fn with_field_annotation(self, annotation: std::string::String) -> Self {
+                match self {
+                    $(
+                        Type::$enum_case_name(value) => Type::$enum_case_name(value.with_field_annotation(annotation)),
+                    )?
+                    $(
+                        Type::$name(value) => value.with_field_annotation(annotation),
+                    )*
+                }
+            }
         }
 
         $(
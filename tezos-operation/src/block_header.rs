@@ -1,12 +1,25 @@
 use chrono::NaiveDateTime;
 use num_derive::{FromPrimitive, ToPrimitive};
-use tezos_core::types::{
-    encoded::{
-        BlockHash, BlockPayloadHash, ContextHash, NonceHash, OperationListListHash, Signature,
+use num_traits::{FromPrimitive, ToPrimitive};
+use tezos_core::{
+    hash,
+    internal::{
+        coder::{Decoder, Encoder},
+        crypto::Crypto,
     },
-    hex_string::HexString,
+    types::{
+        encoded::{
+            BlockHash, BlockPayloadHash, ContextHash, NonceHash, OperationListListHash, Signature,
+        },
+        hex_string::HexString,
+    },
+};
+
+use crate::{
+    internal::coder::operation_content_bytes_coder::OperationContentBytesCoder, Error, Result,
 };
 
+/// A Tenderbake block header, made of the shell header fields followed by the protocol data.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct BlockHeader {
     pub level: i32,
@@ -22,9 +35,100 @@ pub struct BlockHeader {
     pub proof_of_work_nonce: HexString,
     pub seed_nonce_hash: Option<NonceHash>,
     pub liquidity_baking_toggle_vote: LiquidityBakingToggleVote,
+    /// The adaptive issuance vote, forged together with the liquidity baking vote in the
+    /// per-block votes byte. Protocols predating the vote leave its bits unset, which reads as [AdaptiveIssuanceVote::On].
+    pub adaptive_issuance_vote: AdaptiveIssuanceVote,
     pub signature: Signature,
 }
 
+impl BlockHeader {
+    pub const PROOF_OF_WORK_NONCE_SIZE: usize = 8;
+
+    /// Returns the block header forged bytes, including the signature.
+    pub fn to_forged_bytes(&self) -> Result<Vec<u8>> {
+        OperationContentBytesCoder::encode(self)
+    }
+
+    /// Returns the block header forged bytes without the signature.
+    ///
+    /// These are the bytes the proof of work is computed on and, prefixed with the
+    /// watermark and chain id, the bytes a baker signs.
+    pub fn to_unsigned_forged_bytes(&self) -> Result<Vec<u8>> {
+        OperationContentBytesCoder::encode_unsigned_block_header(self)
+    }
+
+    /// Creates a [BlockHeader] from its forged bytes (including the signature).
+    pub fn from_forged_bytes<T: AsRef<[u8]>>(bytes: T) -> Result<Self> {
+        OperationContentBytesCoder::decode(bytes.as_ref())
+    }
+
+    /// Calculates the block hash.
+    pub fn hash(&self) -> Result<BlockHash> {
+        Ok(hash::block_hash(&self.to_forged_bytes()?)?)
+    }
+
+    /// Decodes the block header `fitness`.
+    pub fn decoded_fitness(&self) -> Result<Fitness> {
+        Fitness::from_raw(&self.fitness)
+    }
+
+    /// Checks whether the header's proof of work nonce satisfies the given `proof_of_work_threshold`
+    /// (as found in the protocol constants).
+    ///
+    /// The first 8 bytes of the hash of the unsigned header, read as a big-endian unsigned integer,
+    /// must not exceed the threshold. A negative threshold disables the check.
+    pub fn check_proof_of_work(&self, proof_of_work_threshold: i64) -> Result<bool> {
+        let crypto = Crypto::new(None, None, None);
+        Self::check_proof_of_work_with(
+            &crypto,
+            &self.to_unsigned_forged_bytes()?,
+            proof_of_work_threshold,
+        )
+    }
+
+    /// Searches for a proof of work nonce satisfying `proof_of_work_threshold`, starting from the current
+    /// `proof_of_work_nonce` and incrementing it, and stores it in the header.
+    ///
+    /// The signature is left untouched and has to be computed afterwards.
+    pub fn stamp_proof_of_work(&mut self, proof_of_work_threshold: i64) -> Result<()> {
+        let crypto = Crypto::new(None, None, None);
+        let mut bytes = self.to_unsigned_forged_bytes()?;
+        let nonce_offset = Self::proof_of_work_nonce_offset(&bytes, self.seed_nonce_hash.is_some());
+        let nonce_range = nonce_offset..(nonce_offset + Self::PROOF_OF_WORK_NONCE_SIZE);
+
+        let mut nonce = u64::from_be_bytes(
+            bytes[nonce_range.clone()]
+                .try_into()
+                .map_err(|_| Error::InvalidBytes)?,
+        );
+        loop {
+            bytes[nonce_range.clone()].copy_from_slice(&nonce.to_be_bytes());
+            if Self::check_proof_of_work_with(&crypto, &bytes, proof_of_work_threshold)? {
+                self.proof_of_work_nonce = HexString::from_bytes(nonce.to_be_bytes());
+                return Ok(());
+            }
+            nonce = nonce.wrapping_add(1);
+        }
+    }
+
+    fn check_proof_of_work_with(
+        crypto: &Crypto,
+        unsigned_bytes: &[u8],
+        proof_of_work_threshold: i64,
+    ) -> Result<bool> {
+        let hash = crypto.blake2b(unsigned_bytes, 32)?;
+        let stamp = u64::from_be_bytes(hash[..8].try_into().map_err(|_| Error::InvalidBytes)?);
+        Ok(stamp <= proof_of_work_threshold as u64)
+    }
+
+    fn proof_of_work_nonce_offset(unsigned_bytes: &[u8], has_seed_nonce_hash: bool) -> usize {
+        // The protocol data ends with: nonce (8), seed nonce hash presence (1),
+        // optional seed nonce hash (32) and per-block votes (1).
+        let seed_nonce_hash_size = if has_seed_nonce_hash { 32 } else { 0 };
+        unsigned_bytes.len() - 1 - seed_nonce_hash_size - 1 - Self::PROOF_OF_WORK_NONCE_SIZE
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, FromPrimitive, ToPrimitive)]
 #[repr(u8)]
 pub enum LiquidityBakingToggleVote {
@@ -32,3 +136,271 @@ pub enum LiquidityBakingToggleVote {
     Off = 1,
     Pass = 2,
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum AdaptiveIssuanceVote {
+    On = 0,
+    Off = 1,
+    Pass = 2,
+}
+
+impl FromPrimitive for AdaptiveIssuanceVote {
+    fn from_i64(value: i64) -> Option<Self> {
+        match value {
+            0 => Some(Self::On),
+            1 => Some(Self::Off),
+            2 => Some(Self::Pass),
+            _ => None,
+        }
+    }
+
+    fn from_u64(value: u64) -> Option<Self> {
+        i64::try_from(value).ok().and_then(Self::from_i64)
+    }
+}
+
+impl ToPrimitive for AdaptiveIssuanceVote {
+    fn to_i64(&self) -> Option<i64> {
+        Some(*self as i64)
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        Some(*self as u64)
+    }
+}
+
+/// The decoded Tenderbake block fitness.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Fitness {
+    pub level: i32,
+    pub locked_round: Option<i32>,
+    pub predecessor_round: i32,
+    pub round: i32,
+}
+
+impl Fitness {
+    const VERSION: u8 = 2;
+
+    /// Decodes the fitness from its raw representation as found in a block header.
+    pub fn from_raw(raw: &[HexString]) -> Result<Self> {
+        let bytes = raw.iter().map(|item| item.to_bytes()).collect::<Vec<_>>();
+        match bytes.as_slice() {
+            [version, level, locked_round, predecessor_round, round]
+                if version.as_slice() == [Self::VERSION] =>
+            {
+                let locked_round = if locked_round.is_empty() {
+                    None
+                } else {
+                    Some(Self::decode_i32(locked_round)?)
+                };
+                Ok(Self {
+                    level: Self::decode_i32(level)?,
+                    locked_round,
+                    predecessor_round: -Self::decode_i32(predecessor_round)? - 1,
+                    round: Self::decode_i32(round)?,
+                })
+            }
+            _ => Err(Error::InvalidBytes),
+        }
+    }
+
+    /// Encodes the fitness into its raw representation as found in a block header.
+    pub fn to_raw(&self) -> Vec<HexString> {
+        vec![
+            HexString::from_bytes([Self::VERSION]),
+            HexString::from_bytes(self.level.to_be_bytes()),
+            HexString::from_bytes(
+                self.locked_round
+                    .map(|round| round.to_be_bytes().to_vec())
+                    .unwrap_or_default(),
+            ),
+            HexString::from_bytes((-self.predecessor_round - 1).to_be_bytes()),
+            HexString::from_bytes(self.round.to_be_bytes()),
+        ]
+    }
+
+    fn decode_i32(bytes: &[u8]) -> Result<i32> {
+        Ok(i32::from_be_bytes(
+            bytes.try_into().map_err(|_| Error::InvalidBytes)?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use chrono::DateTime;
+    use tezos_core::types::encoded::Encoded;
+
+    fn block_header() -> BlockHeader {
+        BlockHeader {
+            level: 2,
+            proto: 2,
+            predecessor: "BMaBxGyVhtTiMKd7KA8HXJnbTK4e1TzffNc94G18op55HGQYVRk".try_into().unwrap(),
+            timestamp: DateTime::parse_from_rfc3339("2022-08-04T14:20:32Z").unwrap().naive_utc(),
+            validation_pass: 4,
+            operations_hash: "LLoaNF9sd5z2SZtSmUopYNX6qs77QAUJqrnd5ei378H4bcJhQcPt5".try_into().unwrap(),
+            fitness: vec![
+                "02".try_into().unwrap(),
+                "00000002".try_into().unwrap(),
+                "".try_into().unwrap(),
+                "ffffffff".try_into().unwrap(),
+                "00000001".try_into().unwrap(),
+            ],
+            context: "CoVj5HxwnPHpC1SgCC6pgqVPgw2vqFEqaC2bF5STqcbyX6giVrGn".try_into().unwrap(),
+            payload_hash: "vh2MHqgJtw8v7CDrZKYWtLmqGJtjzkRvs9yUeHNQqdgDJyCYm21q".try_into().unwrap(),
+            payload_round: 1,
+            proof_of_work_nonce: "0000000000000000".try_into().unwrap(),
+            seed_nonce_hash: Some("nceUeUCJRZ4M7FCSBsAUZU6dmxePdH7irje9Gfj9zWwCdfWd5B4Ee".try_into().unwrap()),
+            liquidity_baking_toggle_vote: LiquidityBakingToggleVote::Pass,
+            adaptive_issuance_vote: AdaptiveIssuanceVote::Off,
+            signature: "sigRsUhHqaFVBeV4qzyCZ6Y9TvoKajyNwyPQQCW3SbgPYY99MrpTqR2FopjzZEHMWoJG7LaTaHu7bnieKQRKqCRLA7hB7Ekp".try_into().unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_forge_and_unforge() -> Result<()> {
+        let header = block_header();
+        let forged = header.to_forged_bytes()?;
+        let unsigned = header.to_unsigned_forged_bytes()?;
+
+        assert_eq!(forged.len(), unsigned.len() + 64);
+        assert_eq!(&forged[..unsigned.len()], unsigned.as_slice());
+        // per-block votes: liquidity baking `pass` (2) | adaptive issuance `off` (1 << 2)
+        assert_eq!(unsigned.last(), Some(&0x06));
+        assert_eq!(BlockHeader::from_forged_bytes(&forged)?, header);
+        Ok(())
+    }
+
+    /// Mainnet block headers, with their forged bytes and the hash returned by the node.
+    fn mainnet_block_headers() -> Vec<(BlockHeader, &'static str, &'static str)> {
+        vec![
+            (
+                BlockHeader {
+                    level: 2504461,
+                    proto: 13,
+                    predecessor: "BLqagE7bXgwbLCeL6ooaj57HASQm52yUxxSGLUJrePeKSso3PWq".try_into().unwrap(),
+                    timestamp: DateTime::parse_from_rfc3339("2022-07-04T06:37:44Z").unwrap().naive_utc(),
+                    validation_pass: 4,
+                    operations_hash: "LLoaxj33NkFZLAc9uvXcwNRZKdk6z83Ke5PEKrs32cUCPpkb9cJ8S".try_into().unwrap(),
+                    fitness: vec![
+                        "02".try_into().unwrap(),
+                        "0026370d".try_into().unwrap(),
+                        "".try_into().unwrap(),
+                        "ffffffff".try_into().unwrap(),
+                        "00000000".try_into().unwrap(),
+                    ],
+                    context: "CoVeQQ6XqvBF4vp8uCSatdWuZsyDx5x3SQwopjwcoWES8RFcjbgZ".try_into().unwrap(),
+                    payload_hash: "vh3AZZo8CDvasrq8NCwQvHSWvuSu63ahP3Q8DBkyMksccsKzb9fP".try_into().unwrap(),
+                    payload_round: 0,
+                    proof_of_work_nonce: "cb9f439e02840100".try_into().unwrap(),
+                    seed_nonce_hash: None,
+                    liquidity_baking_toggle_vote: LiquidityBakingToggleVote::Pass,
+                    adaptive_issuance_vote: AdaptiveIssuanceVote::On,
+                    signature: "sigQh51NhuohSTe11Pp8hXSE9VMn1TbbqZrGKAbLEUus852PPSmXs5Hjf9v3cbwtRewvcYK8F6PLUg85ZBYrUGX5GqgkRFC7".try_into().unwrap(),
+                },
+                concat!(
+                    "0026370d0d9444f40d9c1824d30918fa20dcbc28261a8973d9a544f33f5cc56a13830189af000000",
+                    "0062c28ab804d7bfc960df8ce93f0b9356181c5651bf90ebcae5b2a2776b66aeeda0000f42290000",
+                    "00210000000102000000040026370d0000000000000004ffffffff000000040000000083e9c04c6f",
+                    "3c610e6b5c65b33805c24a6c1902b58bec02a4cc482ffca828b77ac4451b4e45ee2eae5d15652c88",
+                    "26ee011f22915ae1387dd605766c5ad1b5cf8c00000000cb9f439e02840100000214a92861740b16",
+                    "b11c8bb6791abdae138bf18f18a85915896a7d5da0b59fc0c3a16494f7832b05164f63f1d5797d31",
+                    "03256e5c2e1f0ddf3fca57e71ab8c4a56a",
+                ),
+                "BKvBmAJVpJ8drHMTzDZmFKEYc45xzeyHo1MUNtcomm6FMZkpXDW",
+            ),
+            (
+                BlockHeader {
+                    level: 2490368,
+                    proto: 13,
+                    predecessor: "BLDfwRq7NMcXi4tmbANH2k1bPTEsvpLhXZqqDrzL5mSnc5BUBt1".try_into().unwrap(),
+                    timestamp: DateTime::parse_from_rfc3339("2022-06-28T21:11:59Z").unwrap().naive_utc(),
+                    validation_pass: 4,
+                    operations_hash: "LLob2cL6GNwNsExmdtJpguCX1xgJUAo1hXVZm71EQNwE51wmepYz5".try_into().unwrap(),
+                    fitness: vec![
+                        "02".try_into().unwrap(),
+                        "00260000".try_into().unwrap(),
+                        "".try_into().unwrap(),
+                        "ffffffff".try_into().unwrap(),
+                        "00000001".try_into().unwrap(),
+                    ],
+                    context: "CoWDZMju612DygL6Pnx8jYmZKrWu51YfNwA7ukUKSuTyzJpSdHML".try_into().unwrap(),
+                    payload_hash: "vh2yscRP6t4VwZGm6Jed5vC5Z1pzY6tspKXTkPhCKKiB1WaA1k2q".try_into().unwrap(),
+                    payload_round: 1,
+                    proof_of_work_nonce: "cb9f439e20220200".try_into().unwrap(),
+                    seed_nonce_hash: Some("nceVRm6CY4R6Nmm3uwm1T3rHrcf7qAwWUYuaLSuoL6WBEdE1a8KFZ".try_into().unwrap()),
+                    liquidity_baking_toggle_vote: LiquidityBakingToggleVote::On,
+                    adaptive_issuance_vote: AdaptiveIssuanceVote::On,
+                    signature: "sigQgCVcYak23SSyqkvdTkvC6S87DQfSLr3QEudZo9Sa9ogn8SjQwr5mjPmhqGXHoV46L5QuTpNV7uWCcLaVfM9TXn472bmh".try_into().unwrap(),
+                },
+                concat!(
+                    "002600000d42bba2902c416eca8489b898b5f93fe67fd18318cde1cdd496ed95adefa0ca7c000000",
+                    "0062bb6e9f04e091b213b4682407b6436e335b80190ce96bd1d5e2e5e7fa5caa57c367522f7d0000",
+                    "0021000000010200000004002600000000000000000004ffffffff0000000400000001cf31c29544",
+                    "b85bd6deb95efae945dae761c3bd3a68b9d33f1e417a7e3b0f98c8abffe282ca37a1aa464250d053",
+                    "856eee819872de3a73de151db54149d4ff210600000001cb9f439e20220200ff9e83f4946cf0bf03",
+                    "922de73ea6c0e610045428af8ef18ae394ca9dd03d61002500148bc6823e5838e49c0243273af62a",
+                    "7fa432b0eadf681202a209c25d51db778d48cc4e099fe8a2c3883ab6cdbc4517095555b61d2d2aea",
+                    "88a036b8bdddd5f709",
+                ),
+                "BM9hYQqFDriuJtt96gmrzJGfQjoRYZ7kz1xjMUXYvqbCnniVizQ",
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_hash() -> Result<()> {
+        for (header, forged, hash) in mainnet_block_headers() {
+            let forged = HexString::new(forged.into())?.to_bytes();
+            assert_eq!(header.to_forged_bytes()?, forged);
+            assert_eq!(BlockHeader::from_forged_bytes(&forged)?, header);
+            assert_eq!(header.hash()?.value(), hash);
+        }
+
+        let header = block_header();
+        let mut other = header.clone();
+        other.adaptive_issuance_vote = AdaptiveIssuanceVote::Pass;
+        assert_ne!(other.hash()?, header.hash()?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_fitness() -> Result<()> {
+        let header = block_header();
+        let fitness = header.decoded_fitness()?;
+        assert_eq!(
+            fitness,
+            Fitness {
+                level: 2,
+                locked_round: None,
+                predecessor_round: 0,
+                round: 1
+            }
+        );
+        assert_eq!(fitness.to_raw(), header.fitness);
+
+        let locked = Fitness {
+            level: 10,
+            locked_round: Some(3),
+            predecessor_round: 2,
+            round: 4,
+        };
+        assert_eq!(Fitness::from_raw(&locked.to_raw())?, locked);
+
+        assert!(Fitness::from_raw(&[]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_proof_of_work() -> Result<()> {
+        let mut header = block_header();
+        assert!(header.check_proof_of_work(-1)?);
+
+        let threshold = 0x00ffffffffffffff;
+        header.stamp_proof_of_work(threshold)?;
+        assert!(header.check_proof_of_work(threshold)?);
+        Ok(())
+    }
+}
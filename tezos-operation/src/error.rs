@@ -8,6 +8,7 @@ pub enum Error {
     Michelson { source: tezos_michelson::Error },
     InvalidOperationContentTag,
     InvalidBytes,
+    InvalidMessage,
     InvalidStringConversion { source: FromUtf8Error },
 }
 
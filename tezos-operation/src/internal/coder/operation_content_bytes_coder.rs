@@ -1,4 +1,4 @@
-use chrono::NaiveDateTime;
+use chrono::DateTime;
 use num_traits::{FromPrimitive, ToPrimitive};
 use tezos_core::{
     internal::{
@@ -20,7 +20,7 @@ use tezos_core::{
 use tezos_michelson::micheline::Micheline;
 
 use crate::{
-    block_header::{BlockHeader, LiquidityBakingToggleVote},
+    block_header::{AdaptiveIssuanceVote, BlockHeader, LiquidityBakingToggleVote},
     operations::{
         ActivateAccount, Ballot, BallotType, Delegation, DoubleBakingEvidence,
         DoubleEndorsementEvidence, DoublePreendorsementEvidence, Endorsement, Entrypoint,
@@ -28,7 +28,7 @@ use crate::{
         OperationContentTag, Origination, Parameters, Preendorsement, Proposals,
         RegisterGlobalConstant, Reveal, Script, SeedNonceRevelation, SetDepositsLimit,
         TraitOperationConsensusContent, TraitOperationContent, TraitOperationManagerContent,
-        Transaction,
+        Transaction, TransferTicket,
     },
     Error, Result,
 };
@@ -117,6 +117,7 @@ impl Encoder<OperationContent, Vec<u8>, Error> for OperationContentBytesCoder {
             OperationContent::Delegation(value) => Self::encode(value),
             OperationContent::RegisterGlobalConstant(value) => Self::encode(value),
             OperationContent::SetDepositsLimit(value) => Self::encode(value),
+            OperationContent::TransferTicket(value) => Self::encode(value),
         }
     }
 }
@@ -164,12 +165,14 @@ impl Encoder<DoubleBakingEvidence, Vec<u8>, Error> for OperationContentBytesCode
     }
 }
 
-impl Encoder<BlockHeader, Vec<u8>, Error> for OperationContentBytesCoder {
-    fn encode(value: &BlockHeader) -> Result<Vec<u8>> {
+impl OperationContentBytesCoder {
+    /// Encodes the block header without its signature, i.e. the shell header followed by
+    /// the protocol data contents.
+    pub(crate) fn encode_unsigned_block_header(value: &BlockHeader) -> Result<Vec<u8>> {
         let level_bytes = utils::encode_i32(value.level);
         let proto_bytes = [value.proto];
         let predecessor_bytes = value.predecessor.to_bytes()?;
-        let timestamp_bytes = utils::encode_i64(value.timestamp.timestamp_millis());
+        let timestamp_bytes = utils::encode_i64(value.timestamp.and_utc().timestamp());
         let validation_pass_bytes = [value.validation_pass];
         let operation_hash_bytes = value.operations_hash.to_bytes()?;
         let fitness_bytes = utils::encode_bytes(&value.fitness.iter().fold(
@@ -183,15 +186,17 @@ impl Encoder<BlockHeader, Vec<u8>, Error> for OperationContentBytesCoder {
         let payload_hash_bytes = value.payload_hash.to_bytes()?;
         let payload_round_bytes = utils::encode_i32(value.payload_round);
         let proof_of_work_nonce_bytes = value.proof_of_work_nonce.to_bytes();
+        if proof_of_work_nonce_bytes.len() != BlockHeader::PROOF_OF_WORK_NONCE_SIZE {
+            return Err(Error::InvalidBytes);
+        }
         let seed_nonce_hash_bytes = if let Some(value) = &value.seed_nonce_hash {
             value.to_bytes()?
         } else {
             vec![]
         };
         let seed_nonce_hash_presence = utils::encode_bool(!seed_nonce_hash_bytes.is_empty());
-        let liquidity_baking_escape_vote_bytes =
-            [value.liquidity_baking_toggle_vote.to_u8().unwrap()];
-        let signature_bytes = value.signature.to_bytes()?;
+        let per_block_votes_bytes = [value.liquidity_baking_toggle_vote.to_u8().unwrap()
+            | (value.adaptive_issuance_vote.to_u8().unwrap() << 2)];
 
         Ok([
             level_bytes.as_slice(),
@@ -207,13 +212,27 @@ impl Encoder<BlockHeader, Vec<u8>, Error> for OperationContentBytesCoder {
             proof_of_work_nonce_bytes.as_slice(),
             seed_nonce_hash_presence.as_slice(),
             seed_nonce_hash_bytes.as_slice(),
-            liquidity_baking_escape_vote_bytes.as_slice(),
-            signature_bytes.as_slice(),
+            per_block_votes_bytes.as_slice(),
         ]
         .concat())
     }
 }
 
+impl Encoder<BlockHeader, Vec<u8>, Error> for OperationContentBytesCoder {
+    fn encode(value: &BlockHeader) -> Result<Vec<u8>> {
+        let unsigned_bytes = Self::encode_unsigned_block_header(value)?;
+        let signature_bytes = value.signature.to_bytes()?;
+
+        Ok([unsigned_bytes, signature_bytes].concat())
+    }
+}
+
+impl Decoder<BlockHeader, [u8], Error> for OperationContentBytesCoder {
+    fn decode(value: &[u8]) -> Result<BlockHeader> {
+        Self::decode_consuming(&mut ConsumableBytes::new(value))
+    }
+}
+
 impl Encoder<ActivateAccount, Vec<u8>, Error> for OperationContentBytesCoder {
     fn encode(value: &ActivateAccount) -> Result<Vec<u8>> {
         let pkh_bytes = value.pkh.to_bytes()?;
@@ -467,6 +486,32 @@ impl Encoder<SetDepositsLimit, Vec<u8>, Error> for OperationContentBytesCoder {
     }
 }
 
+impl Encoder<TransferTicket, Vec<u8>, Error> for OperationContentBytesCoder {
+    fn encode(value: &TransferTicket) -> Result<Vec<u8>> {
+        let content_bytes = Self::encode_manager_operation_content(value)?;
+        let ticket_contents_bytes = utils::encode_bytes(&value.ticket_contents.to_bytes()?);
+        let ticket_ty_bytes = utils::encode_bytes(&value.ticket_ty.to_bytes()?);
+        let ticket_ticketer_bytes = value.ticket_ticketer.to_bytes()?;
+        let ticket_amount_bytes = value.ticket_amount.to_bytes()?;
+        let destination_bytes = value.destination.to_bytes()?;
+        let entrypoint_bytes = utils::encode_string(&value.entrypoint);
+
+        let tag = TransferTicket::tag().to_bytes();
+
+        Ok([
+            tag.as_slice(),
+            &content_bytes,
+            &ticket_contents_bytes,
+            &ticket_ty_bytes,
+            &ticket_ticketer_bytes,
+            &ticket_amount_bytes,
+            &destination_bytes,
+            &entrypoint_bytes,
+        ]
+        .concat())
+    }
+}
+
 impl Decoder<OperationContent, [u8], Error> for OperationContentBytesCoder {
     fn decode(value: &[u8]) -> Result<OperationContent> {
         Self::decode_consuming(&mut ConsumableBytes::new(value))
@@ -527,6 +572,9 @@ impl ConsumingDecoder<OperationContent, u8, Error> for OperationContentBytesCode
             OperationContentTag::SetDepositsLimit => Ok(OperationContent::SetDepositsLimit(
                 Self::decode_consuming(value)?,
             )),
+            OperationContentTag::TransferTicket => Ok(OperationContent::TransferTicket(
+                Self::decode_consuming(value)?,
+            )),
         }
     }
 }
@@ -599,12 +647,9 @@ impl ConsumingDecoder<BlockHeader, u8, Error> for OperationContentBytesCoder {
         let level = utils::decode_consuming_i32(value)?;
         let proto = value.consume_first()?;
         let predecessor = BlockHash::from_consumable_bytes(value)?;
-        let timestamp_millis = utils::decode_consuming_i64(value)?;
-
-        let ts_secs = timestamp_millis / 1000;
-        let ts_ns = (timestamp_millis % 1000) * 1_000_000;
-        let timestamp = NaiveDateTime::from_timestamp_opt(ts_secs, ts_ns as u32)
-            .expect("out-of-range number of seconds and/or invalid nanosecond");
+        let timestamp = DateTime::from_timestamp(utils::decode_consuming_i64(value)?, 0)
+            .ok_or(Error::InvalidBytes)?
+            .naive_utc();
 
         let validation_pass = value.consume_first()?;
         let operations_hash = OperationListListHash::from_consumable_bytes(value)?;
@@ -620,7 +665,8 @@ impl ConsumingDecoder<BlockHeader, u8, Error> for OperationContentBytesCoder {
         let context = ContextHash::from_consumable_bytes(value)?;
         let payload_hash = BlockPayloadHash::from_consumable_bytes(value)?;
         let payload_round = utils::decode_consuming_i32(value)?;
-        let proof_of_work_nonce = HexString::from_bytes(value.consume_until(8)?);
+        let proof_of_work_nonce =
+            HexString::from_bytes(value.consume_until(BlockHeader::PROOF_OF_WORK_NONCE_SIZE)?);
 
         let seed_nonce_hash_presence = utils::decode_consuming_bool(value)?;
         let seed_nonce_hash = if seed_nonce_hash_presence {
@@ -629,9 +675,11 @@ impl ConsumingDecoder<BlockHeader, u8, Error> for OperationContentBytesCoder {
             None
         };
 
+        let per_block_votes = value.consume_first()?;
         let liquidity_baking_toggle_vote =
-            LiquidityBakingToggleVote::from_u8(value.consume_first()?)
-                .ok_or(Error::InvalidBytes)?;
+            LiquidityBakingToggleVote::from_u8(per_block_votes & 3).ok_or(Error::InvalidBytes)?;
+        let adaptive_issuance_vote =
+            AdaptiveIssuanceVote::from_u8((per_block_votes >> 2) & 3).ok_or(Error::InvalidBytes)?;
         let signature = Signature::from_consumable_bytes(value)?;
 
         Ok(BlockHeader {
@@ -648,6 +696,7 @@ impl ConsumingDecoder<BlockHeader, u8, Error> for OperationContentBytesCoder {
             proof_of_work_nonce,
             seed_nonce_hash,
             liquidity_baking_toggle_vote,
+            adaptive_issuance_vote,
             signature,
         })
     }
@@ -937,3 +986,34 @@ impl ConsumingDecoder<SetDepositsLimit, u8, Error> for OperationContentBytesCode
         )
     }
 }
+
+impl ConsumingDecoder<TransferTicket, u8, Error> for OperationContentBytesCoder {
+    fn decode_consuming<CL: ConsumableList<u8>>(value: &mut CL) -> Result<TransferTicket> {
+        Self::require_consume_operation_content_tag(OperationContentTag::TransferTicket, value)?;
+        Self::decode_manager_operation(
+            value,
+            |source, fee, counter, gas_limit, storage_limit, value| {
+                let ticket_contents = Micheline::from_bytes(&utils::decode_bytes(value)?)?;
+                let ticket_ty = Micheline::from_bytes(&utils::decode_bytes(value)?)?;
+                let ticket_ticketer = Address::from_consumable_bytes(value)?;
+                let ticket_amount = Nat::from_consumable_bytes(value)?;
+                let destination = Address::from_consumable_bytes(value)?;
+                let entrypoint = utils::decode_string(value)?;
+
+                Ok(TransferTicket::new(
+                    source,
+                    fee,
+                    counter,
+                    gas_limit,
+                    storage_limit,
+                    ticket_contents,
+                    ticket_ty,
+                    ticket_ticketer,
+                    ticket_amount,
+                    destination,
+                    entrypoint,
+                ))
+            },
+        )
+    }
+}
@@ -48,27 +48,70 @@ impl OperationSigner {
         Self { crypto }
     }
 
-    fn sign_raw<F>(&self, operation: &UnsignedOperation, key: &[u8], signer: F) -> Result<Vec<u8>>
+    /// The watermarked forged bytes of `operation`, as signed by [OperationSigner::sign_bytes].
+    fn payload<O: Operation>(operation: &O) -> Result<Vec<u8>> {
+        Ok([&[Self::WATERMARK], operation.to_forged_bytes()?.as_slice()].concat())
+    }
+
+    fn sign_operation<Output>(
+        &self,
+        operation: &UnsignedOperation,
+        key: SecretKey,
+    ) -> Result<Output>
     where
-        F: FnOnce(&[u8], &[u8]) -> Result<Vec<u8>>,
+        Output: for<'a> TryFrom<&'a Vec<u8>, Error = tezos_core::Error>,
     {
-        signer(&self.hash(operation)?, key)
+        let signature = self.sign_bytes(&Self::payload(operation)?, &key)?;
+
+        Ok((&signature.to_bytes()?).try_into()?)
     }
+}
 
-    fn hash<O: Operation>(&self, operation: &O) -> Result<Vec<u8>> {
-        Ok(self.crypto.blake2b(
-            &[&[Self::WATERMARK], operation.to_forged_bytes()?.as_slice()].concat(),
-            Self::MESSAGE_HASH_SIZE,
-        )?)
+impl OperationSigner {
+    /// Signs the hash of an arbitrary payload, which is expected to already start with its watermark.
+    pub fn sign_bytes(&self, payload: &[u8], key: &SecretKey) -> Result<Signature> {
+        let message = self.crypto.blake2b(payload, Self::MESSAGE_HASH_SIZE)?;
+        let signature: Signature = match key {
+            SecretKey::Ed25519(key) => {
+                let signature = self.crypto.sign_ed25519(&message, &key.to_bytes()?)?;
+                Ed25519Signature::try_from(&signature)?.into()
+            }
+            SecretKey::Secp256K1(key) => {
+                let signature = self.crypto.sign_secp256_k1(&message, &key.to_bytes()?)?;
+                Secp256K1Signature::try_from(&signature)?.into()
+            }
+            SecretKey::P256(key) => {
+                let signature = self.crypto.sign_p256(&message, &key.to_bytes()?)?;
+                P256Signature::try_from(&signature)?.into()
+            }
+        };
+
+        Ok(signature)
     }
 
-    fn verify_raw<F>(&self, operation: &SignedOperation, key: &[u8], verifier: F) -> Result<bool>
-    where
-        F: FnOnce(&[u8], &[u8], &[u8]) -> Result<bool>,
-    {
-        let signature = operation.signature.to_bytes()?;
-        self.hash(operation)
-            .map_or(Ok(false), |message| verifier(&message, &signature, key))
+    /// Verifies the signature of an arbitrary payload, which is expected to already start with its watermark.
+    pub fn verify_bytes(
+        &self,
+        payload: &[u8],
+        signature: &Signature,
+        key: &PublicKey,
+    ) -> Result<bool> {
+        let message = self.crypto.blake2b(payload, Self::MESSAGE_HASH_SIZE)?;
+        let signature = signature.to_bytes()?;
+        Ok(match key {
+            PublicKey::Ed25519(key) => {
+                self.crypto
+                    .verify_ed25519(&message, &signature, &key.to_bytes()?)?
+            }
+            PublicKey::Secp256K1(key) => {
+                self.crypto
+                    .verify_secp256_k1(&message, &signature, &key.to_bytes()?)?
+            }
+            PublicKey::P256(key) => {
+                self.crypto
+                    .verify_p256(&message, &signature, &key.to_bytes()?)?
+            }
+        })
     }
 }
 
@@ -78,11 +121,7 @@ impl Signer<SecretKey> for OperationSigner {
     type Error = Error;
 
     fn sign(&self, message: &Self::Message, secret: &SecretKey) -> Result<Self::Output> {
-        match secret {
-            SecretKey::Ed25519(key) => self.sign(message, key).map(|signature| signature.into()),
-            SecretKey::Secp256K1(key) => self.sign(message, key).map(|signature| signature.into()),
-            SecretKey::P256(key) => self.sign(message, key).map(|signature| signature.into()),
-        }
+        self.sign_bytes(&Self::payload(message)?, secret)
     }
 }
 
@@ -90,11 +129,9 @@ impl Verifier<PublicKey> for OperationSigner {
     type Message = SignedOperation;
 
     fn verify(&self, message: &Self::Message, key: &PublicKey) -> Result<bool> {
-        match key {
-            PublicKey::Ed25519(key) => self.verify(message, key),
-            PublicKey::Secp256K1(key) => self.verify(message, key),
-            PublicKey::P256(key) => self.verify(message, key),
-        }
+        Self::payload(message).map_or(Ok(false), |payload| {
+            self.verify_bytes(&payload, &message.signature, key)
+        })
     }
 }
 
@@ -104,12 +141,7 @@ impl Signer<Ed25519SecretKey> for OperationSigner {
     type Error = Error;
 
     fn sign(&self, message: &Self::Message, secret: &Ed25519SecretKey) -> Result<Self::Output> {
-        let key = secret.to_bytes()?;
-        let signature = self.sign_raw(message, &key, |message, secret| {
-            Ok(self.crypto.sign_ed25519(message, secret)?)
-        })?;
-
-        Ok((&signature).try_into()?)
+        self.sign_operation(message, secret.clone().into())
     }
 }
 
@@ -117,10 +149,7 @@ impl Verifier<Ed25519PublicKey> for OperationSigner {
     type Message = SignedOperation;
 
     fn verify(&self, message: &Self::Message, key: &Ed25519PublicKey) -> Result<bool> {
-        let key = key.to_bytes()?;
-        self.verify_raw(message, &key, |message, signature, key| {
-            Ok(self.crypto.verify_ed25519(message, signature, key)?)
-        })
+        self.verify(message, &PublicKey::from(key.clone()))
     }
 }
 
@@ -130,12 +159,7 @@ impl Signer<Secp256K1SecretKey> for OperationSigner {
     type Error = Error;
 
     fn sign(&self, message: &Self::Message, secret: &Secp256K1SecretKey) -> Result<Self::Output> {
-        let key = secret.to_bytes()?;
-        let signature = self.sign_raw(message, &key, |message, secret| {
-            Ok(self.crypto.sign_secp256_k1(message, secret)?)
-        })?;
-
-        Ok((&signature).try_into()?)
+        self.sign_operation(message, secret.clone().into())
     }
 }
 
@@ -143,10 +167,7 @@ impl Verifier<Secp256K1PublicKey> for OperationSigner {
     type Message = SignedOperation;
 
     fn verify(&self, message: &Self::Message, key: &Secp256K1PublicKey) -> Result<bool> {
-        let key = key.to_bytes()?;
-        self.verify_raw(message, &key, |message, signature, key| {
-            Ok(self.crypto.verify_secp256_k1(message, signature, key)?)
-        })
+        self.verify(message, &PublicKey::from(key.clone()))
     }
 }
 
@@ -156,12 +177,7 @@ impl Signer<P256SecretKey> for OperationSigner {
     type Error = Error;
 
     fn sign(&self, message: &Self::Message, secret: &P256SecretKey) -> Result<Self::Output> {
-        let key = secret.to_bytes()?;
-        let signature = self.sign_raw(message, &key, |message, secret| {
-            Ok(self.crypto.sign_p256(message, secret)?)
-        })?;
-
-        Ok((&signature).try_into()?)
+        self.sign_operation(message, secret.clone().into())
     }
 }
 
@@ -169,10 +185,7 @@ impl Verifier<P256PublicKey> for OperationSigner {
     type Message = SignedOperation;
 
     fn verify(&self, message: &Self::Message, key: &P256PublicKey) -> Result<bool> {
-        let key = key.to_bytes()?;
-        self.verify_raw(message, &key, |message, signature, key| {
-            Ok(self.crypto.verify_p256(message, signature, key)?)
-        })
+        self.verify(message, &PublicKey::from(key.clone()))
     }
 }
 
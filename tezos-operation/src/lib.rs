@@ -143,6 +143,7 @@
 pub mod block_header;
 mod error;
 mod internal;
+pub mod message;
 pub mod operations;
 
 pub use error::{Error, Result};
@@ -0,0 +1,354 @@
+use std::{fmt::Display, str::FromStr};
+
+use chrono::{DateTime, FixedOffset, SecondsFormat};
+use tezos_core::{
+    hash::public_key_hash,
+    types::{
+        encoded::{BlockHash, ImplicitAddress, PublicKey, SecretKey, Signature},
+        hex_string::HexString,
+    },
+    Tezos,
+};
+use tezos_michelson::micheline::{literals::Literal, try_string, Micheline};
+
+use crate::{
+    internal::signer::OperationSigner,
+    operations::{FailingNoop, Operation, OperationContent, UnsignedOperation},
+    Error, Result,
+};
+
+/// An off-chain message to be signed by an implicit account, e.g. to prove the ownership of an address
+/// in a "sign in with Tezos" flow.
+///
+/// The message is never meant to be included in a block: both envelopes produce payloads
+/// that cannot be mistaken for a valid operation.
+///
+/// # Example
+///
+/// ```rust
+/// use tezos_core::types::encoded::{PublicKey, SecretKey};
+/// use tezos_operation::message::Message;
+///
+/// let secret_key: SecretKey = "edskRv7VyXGVZb8EsrR7D9XKUbbAQNQGtALP6QeB16ZCD7SmmJpzyeneJVg3Mq56YLbxRA1kSdAXiswwPiaVfR3NHGMCXCziuZ".try_into().unwrap();
+/// let public_key: PublicKey = "edpkttZKC51wemRqL2QxwpMnEKxWnbd35pq47Y6xsCHp5M1f7LN8NP".try_into().unwrap();
+///
+/// let message = Message::micheline("Tezos Signed Message: example.com 2022-08-04T14:20:32.000Z Sign in");
+/// # #[cfg(feature = "ed25519")]
+/// # {
+/// let signature = message.sign(&secret_key).unwrap();
+/// assert!(message.verify(&signature, &public_key).unwrap());
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Message {
+    pub text: String,
+    pub envelope: Envelope,
+}
+
+/// The way a [Message] is turned into a signable payload.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Envelope {
+    /// The text is packed as a Micheline string, the `05` prefix of packed data acting as the watermark.
+    /// The text must be a valid Michelson string.
+    Micheline,
+    /// The UTF-8 text is the `arbitrary` field of a `failing_noop` operation on the given branch,
+    /// signed with the generic operation watermark.
+    FailingNoop { branch: BlockHash },
+}
+
+impl Message {
+    const MICHELINE_WATERMARK: u8 = 5;
+    const OPERATION_WATERMARK: u8 = 3;
+
+    /// Creates a new [Message].
+    pub fn new<S: Into<String>>(text: S, envelope: Envelope) -> Self {
+        Self {
+            text: text.into(),
+            envelope,
+        }
+    }
+
+    /// Creates a [Message] signed as a packed Micheline string.
+    pub fn micheline<S: Into<String>>(text: S) -> Self {
+        Self::new(text, Envelope::Micheline)
+    }
+
+    /// Creates a [Message] signed as a `failing_noop` operation on the given branch.
+    pub fn failing_noop<S: Into<String>>(text: S, branch: BlockHash) -> Self {
+        Self::new(text, Envelope::FailingNoop { branch })
+    }
+
+    /// Creates a [Message] from a payload produced by [Message::to_payload], e.g. to display
+    /// a signing request to the user.
+    pub fn from_payload<T: AsRef<[u8]>>(payload: T) -> Result<Self> {
+        match payload.as_ref() {
+            [Self::MICHELINE_WATERMARK, ..] => {
+                match Micheline::unpack(payload.as_ref(), None)?.into_literal() {
+                    Some(Literal::String(text)) => Ok(Self::micheline(text.into_string())),
+                    _ => Err(Error::InvalidMessage),
+                }
+            }
+            [Self::OPERATION_WATERMARK, bytes @ ..] => {
+                let operation = UnsignedOperation::from_forged_bytes(bytes)?;
+                match operation.contents.as_slice() {
+                    [OperationContent::FailingNoop(content)] => Ok(Self::failing_noop(
+                        String::from_utf8(content.arbitrary.to_bytes())?,
+                        operation.branch,
+                    )),
+                    _ => Err(Error::InvalidMessage),
+                }
+            }
+            _ => Err(Error::InvalidMessage),
+        }
+    }
+
+    /// Returns the bytes to be signed, watermark included.
+    pub fn to_payload(&self) -> Result<Vec<u8>> {
+        match &self.envelope {
+            Envelope::Micheline => {
+                let text: Micheline = try_string(self.text.as_str())?;
+                Ok(text.pack(None)?)
+            }
+            Envelope::FailingNoop { branch } => {
+                let operation = UnsignedOperation::new(
+                    branch.clone(),
+                    vec![FailingNoop::new(HexString::from_bytes(self.text.as_bytes())).into()],
+                );
+                Ok([
+                    [Self::OPERATION_WATERMARK].as_slice(),
+                    operation.to_forged_bytes()?.as_slice(),
+                ]
+                .concat())
+            }
+        }
+    }
+
+    /// Parses the message text as a [SignInMessage].
+    pub fn template(&self) -> Result<SignInMessage> {
+        self.text.parse()
+    }
+
+    /// Signs the message with the given secret key and using
+    /// the crypto providers configured in the given [Tezos] instance.
+    pub fn sign_with(&self, key: &SecretKey, tezos: &Tezos) -> Result<Signature> {
+        let signer = OperationSigner::new(tezos.get_crypto());
+        signer.sign_bytes(&self.to_payload()?, key)
+    }
+
+    /// Signs the message with the given secret key.
+    pub fn sign(&self, key: &SecretKey) -> Result<Signature> {
+        let tezos: Tezos = Default::default();
+        self.sign_with(key, &tezos)
+    }
+
+    /// Verifies the message signature with the given public key and using
+    /// the crypto providers configured in the given [Tezos] instance.
+    pub fn verify_with(
+        &self,
+        signature: &Signature,
+        key: &PublicKey,
+        tezos: &Tezos,
+    ) -> Result<bool> {
+        let signer = OperationSigner::new(tezos.get_crypto());
+        signer.verify_bytes(&self.to_payload()?, signature, key)
+    }
+
+    /// Verifies the message signature with the given public key.
+    pub fn verify(&self, signature: &Signature, key: &PublicKey) -> Result<bool> {
+        let tezos: Tezos = Default::default();
+        self.verify_with(signature, key, &tezos)
+    }
+
+    /// Verifies that the message has been signed by `address`, given the public key it has revealed.
+    ///
+    /// Returns `false` if the public key does not belong to the address.
+    pub fn verify_address(
+        &self,
+        signature: &Signature,
+        address: &ImplicitAddress,
+        key: &PublicKey,
+    ) -> Result<bool> {
+        if &public_key_hash(key)? != address {
+            return Ok(false);
+        }
+        self.verify(signature, key)
+    }
+}
+
+/// Signs arbitrary bytes with the given secret key. The bytes are hashed as they are,
+/// so they are expected to already start with a watermark (e.g. `03` for operations, `05` for packed data).
+pub fn sign_bytes(bytes: &[u8], key: &SecretKey) -> Result<Signature> {
+    let tezos: Tezos = Default::default();
+    OperationSigner::new(tezos.get_crypto()).sign_bytes(bytes, key)
+}
+
+/// Verifies the signature of arbitrary bytes, as produced by [sign_bytes], with the given public key.
+pub fn verify_bytes(bytes: &[u8], signature: &Signature, key: &PublicKey) -> Result<bool> {
+    let tezos: Tezos = Default::default();
+    OperationSigner::new(tezos.get_crypto()).verify_bytes(bytes, signature, key)
+}
+
+/// The message template commonly used by wallets and dApps for off-chain signing:
+///
+/// `Tezos Signed Message: <dapp url> <ISO 8601 timestamp> <statement>`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignInMessage {
+    pub dapp_url: String,
+    pub timestamp: DateTime<FixedOffset>,
+    pub statement: String,
+}
+
+impl SignInMessage {
+    pub const PREFIX: &'static str = "Tezos Signed Message:";
+
+    /// Creates a new [SignInMessage].
+    pub fn new<S: Into<String>>(
+        dapp_url: S,
+        timestamp: DateTime<FixedOffset>,
+        statement: S,
+    ) -> Self {
+        Self {
+            dapp_url: dapp_url.into(),
+            timestamp,
+            statement: statement.into(),
+        }
+    }
+
+    /// Creates a [Message] with the template text.
+    pub fn to_message(&self, envelope: Envelope) -> Message {
+        Message::new(self.to_string(), envelope)
+    }
+}
+
+impl Display for SignInMessage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {} {} {}",
+            Self::PREFIX,
+            self.dapp_url,
+            self.timestamp.to_rfc3339_opts(SecondsFormat::Millis, true),
+            self.statement
+        )
+    }
+}
+
+impl FromStr for SignInMessage {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut parts = s
+            .strip_prefix(Self::PREFIX)
+            .ok_or(Error::InvalidMessage)?
+            .trim_start()
+            .splitn(3, ' ');
+        let dapp_url = parts
+            .next()
+            .filter(|value| !value.is_empty())
+            .ok_or(Error::InvalidMessage)?;
+        let timestamp = parts
+            .next()
+            .and_then(|value| DateTime::parse_from_rfc3339(value).ok())
+            .ok_or(Error::InvalidMessage)?;
+        let statement = parts.next().unwrap_or_default();
+
+        Ok(Self::new(dapp_url, timestamp, statement))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn branch() -> BlockHash {
+        "BLjg4HU2BwnCgJfRutxJX5rHACzLDxRJes1MXqbXXdxvHWdK3Te"
+            .try_into()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_micheline_payload() -> Result<()> {
+        let message = Message::micheline("Hello");
+        let payload = message.to_payload()?;
+
+        assert_eq!(payload, hex::decode("05010000000548656c6c6f").unwrap());
+        assert_eq!(Message::from_payload(&payload)?, message);
+        Ok(())
+    }
+
+    #[test]
+    fn test_failing_noop_payload() -> Result<()> {
+        let message = Message::failing_noop("Tezos ✓", branch());
+        let payload = message.to_payload()?;
+
+        assert_eq!(payload[0], 3);
+        assert_eq!(payload[33], 17);
+        assert_eq!(Message::from_payload(&payload)?, message);
+        assert!(Message::from_payload([1, 2, 3]).is_err());
+        Ok(())
+    }
+
+    #[cfg(feature = "ed25519")]
+    #[test]
+    fn test_sign_and_verify() -> Result<()> {
+        let secret_key: SecretKey = "edskRv7VyXGVZb8EsrR7D9XKUbbAQNQGtALP6QeB16ZCD7SmmJpzyeneJVg3Mq56YLbxRA1kSdAXiswwPiaVfR3NHGMCXCziuZ".try_into()?;
+        let public_key: PublicKey =
+            "edpkttZKC51wemRqL2QxwpMnEKxWnbd35pq47Y6xsCHp5M1f7LN8NP".try_into()?;
+        let address: ImplicitAddress = "tz1RFxSHjxxyaaXyAvJG3uRQeJUCCm4jk2LX".try_into()?;
+        let other: ImplicitAddress = "tz2AjVPbMHdDF1XwHVhUrTg6ZvqY83AYhJEy".try_into()?;
+
+        for message in [
+            Message::micheline("Hello"),
+            Message::failing_noop("Hello", branch()),
+        ] {
+            let signature = message.sign(&secret_key)?;
+
+            assert!(message.verify(&signature, &public_key)?);
+            assert!(message.verify_address(&signature, &address, &public_key)?);
+            assert!(!message.verify_address(&signature, &other, &public_key)?);
+            assert!(!Message::micheline("Bye").verify(&signature, &public_key)?);
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "ed25519")]
+    #[test]
+    fn test_sign_bytes() -> Result<()> {
+        let secret_key: SecretKey = "edskRv7VyXGVZb8EsrR7D9XKUbbAQNQGtALP6QeB16ZCD7SmmJpzyeneJVg3Mq56YLbxRA1kSdAXiswwPiaVfR3NHGMCXCziuZ".try_into()?;
+        let public_key: PublicKey =
+            "edpkttZKC51wemRqL2QxwpMnEKxWnbd35pq47Y6xsCHp5M1f7LN8NP".try_into()?;
+        let operation = UnsignedOperation::new(branch(), vec![]);
+        let bytes = [[3u8].as_slice(), operation.to_forged_bytes()?.as_slice()].concat();
+
+        let signature = sign_bytes(&bytes, &secret_key)?;
+        assert_eq!(signature, operation.sign(&secret_key)?);
+        assert!(verify_bytes(&bytes, &signature, &public_key)?);
+        assert!(!verify_bytes(&bytes[1..], &signature, &public_key)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_sign_in_message() -> Result<()> {
+        let text =
+            "Tezos Signed Message: example.com 2022-08-04T14:20:32.000Z I accept the terms of use";
+        let template: SignInMessage = text.parse()?;
+
+        assert_eq!(template.dapp_url, "example.com");
+        assert_eq!(
+            template.timestamp,
+            DateTime::parse_from_rfc3339("2022-08-04T14:20:32Z").unwrap()
+        );
+        assert_eq!(template.statement, "I accept the terms of use");
+        assert_eq!(template.to_string(), text);
+        assert_eq!(
+            template.to_message(Envelope::Micheline).template()?,
+            template
+        );
+
+        assert!("Hello".parse::<SignInMessage>().is_err());
+        assert!("Tezos Signed Message: example.com yesterday"
+            .parse::<SignInMessage>()
+            .is_err());
+        Ok(())
+    }
+}
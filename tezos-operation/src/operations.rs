@@ -14,13 +14,12 @@ mod reveal;
 mod seed_nonce_revelation;
 mod set_deposit_limit;
 mod transaction;
+mod transfer_ticket;
 
 use num_derive::FromPrimitive;
 use tezos_core::{
-    internal::{
-        coder::{Decoder, Encoder},
-        crypto::Crypto,
-    },
+    hash,
+    internal::coder::{Decoder, Encoder},
     types::{
         encoded::{BlockHash, Encoded, OperationHash, PublicKey, SecretKey, Signature},
         mutez::Mutez,
@@ -61,6 +60,7 @@ pub use self::{
         OperationManagerContent as TraitOperationManagerContent,
     },
     transaction::{Entrypoint, Parameters, Transaction},
+    transfer_ticket::TransferTicket,
 };
 
 /// The [Operation] trait defines common methods for [UnsignedOperation] and [SignedOperation].
@@ -213,9 +213,7 @@ impl SignedOperation {
 
     /// Static method that calculates and encodes operation hash given its forged bytes (including signature)
     pub fn operation_hash(payload: &[u8]) -> Result<OperationHash> {
-        let crypto = Crypto::new(None, None, None);
-        let hash = crypto.blake2b(payload, 32)?;
-        OperationHash::from_bytes(&hash).map_err(|e| e.into())
+        Ok(hash::operation_hash(payload)?)
     }
 
     /// Calculates operation hash
@@ -253,6 +251,7 @@ pub enum OperationContent {
     Delegation(Delegation),
     RegisterGlobalConstant(RegisterGlobalConstant),
     SetDepositsLimit(SetDepositsLimit),
+    TransferTicket(TransferTicket),
 }
 
 impl OperationContent {
@@ -272,6 +271,7 @@ impl OperationContent {
             Self::Delegation(value) => value.fee,
             Self::RegisterGlobalConstant(value) => value.fee,
             Self::SetDepositsLimit(value) => value.fee,
+            Self::TransferTicket(value) => value.fee,
             _ => 0u8.into(),
         }
     }
@@ -377,6 +377,12 @@ impl From<SetDepositsLimit> for OperationContent {
     }
 }
 
+impl From<TransferTicket> for OperationContent {
+    fn from(value: TransferTicket) -> Self {
+        Self::TransferTicket(value)
+    }
+}
+
 #[derive(Debug, Clone, Copy, FromPrimitive)]
 #[repr(u8)]
 pub enum OperationContentTag {
@@ -396,6 +402,7 @@ pub enum OperationContentTag {
     Delegation = 110,
     RegisterGlobalConstant = 111,
     SetDepositsLimit = 112,
+    TransferTicket = 158,
 }
 
 impl OperationContentTag {
@@ -469,11 +476,12 @@ mod traits {
 
 #[cfg(test)]
 mod test {
-    use crate::block_header::{BlockHeader, LiquidityBakingToggleVote};
+    use crate::block_header::{AdaptiveIssuanceVote, BlockHeader, LiquidityBakingToggleVote};
 
     use super::*;
     use chrono::DateTime;
     use hex_literal::hex;
+    use tezos_michelson::micheline::{int, primitive_application};
 
     #[test]
     fn test_forge_operation() -> Result<()> {
@@ -581,7 +589,7 @@ mod test {
                         level: 1,
                         proto: 1,
                         predecessor: "BKsP8FYgikDmqbUiVxfgXVjWuay5LQZY6LP4EvcsFK8uuqj4wQD".try_into().unwrap(),
-                        timestamp: DateTime::parse_from_rfc3339("1970-01-01T00:00:00.001Z").unwrap().naive_utc(),
+                        timestamp: DateTime::parse_from_rfc3339("1970-01-01T00:00:01Z").unwrap().naive_utc(),
                         validation_pass: 1,
                         operations_hash: "LLoaLP6mc6nVzG2Rp3fSrHFvvGpUvkbHCjLASVduN7GzQAKnPctrR".try_into().unwrap(),
                         fitness: vec![],
@@ -591,13 +599,14 @@ mod test {
                         proof_of_work_nonce: "d4d34b5686c98ae1".try_into().unwrap(),
                         seed_nonce_hash: None,
                         liquidity_baking_toggle_vote: LiquidityBakingToggleVote::On,
+                        adaptive_issuance_vote: AdaptiveIssuanceVote::On,
                         signature: "sigiaEd9dHEGKgccx3JBBDw4eb6WVxGH3MvyziYbQqWQRMmyecdo5VuSkYWkgZvcQXshB4vV2qkTb6AxbKruaNPfnMg4u2EA".try_into().unwrap()
                     },
                     BlockHeader {
                         level: 2,
                         proto: 2,
                         predecessor: "BMaBxGyVhtTiMKd7KA8HXJnbTK4e1TzffNc94G18op55HGQYVRk".try_into().unwrap(),
-                        timestamp: DateTime::parse_from_rfc3339("1970-01-01T00:00:00.002Z").unwrap().naive_utc(),
+                        timestamp: DateTime::parse_from_rfc3339("1970-01-01T00:00:02Z").unwrap().naive_utc(),
                         validation_pass: 2,
                         operations_hash: "LLoaNF9sd5z2SZtSmUopYNX6qs77QAUJqrnd5ei378H4bcJhQcPt5".try_into().unwrap(),
                         fitness: vec![],
@@ -607,6 +616,7 @@ mod test {
                         proof_of_work_nonce: "336ebf95efce0475".try_into().unwrap(),
                         seed_nonce_hash: Some("nceUeUCJRZ4M7FCSBsAUZU6dmxePdH7irje9Gfj9zWwCdfWd5B4Ee".try_into().unwrap()),
                         liquidity_baking_toggle_vote: LiquidityBakingToggleVote::Off,
+                        adaptive_issuance_vote: AdaptiveIssuanceVote::On,
                         signature: "sigRsUhHqaFVBeV4qzyCZ6Y9TvoKajyNwyPQQCW3SbgPYY99MrpTqR2FopjzZEHMWoJG7LaTaHu7bnieKQRKqCRLA7hB7Ekp".try_into().unwrap()
                     },
                 )
@@ -619,7 +629,7 @@ mod test {
                         level: 1,
                         proto: 1,
                         predecessor: "BKsP8FYgikDmqbUiVxfgXVjWuay5LQZY6LP4EvcsFK8uuqj4wQD".try_into().unwrap(),
-                        timestamp: DateTime::parse_from_rfc3339("1970-01-01T00:00:00.001Z").unwrap().naive_utc(),
+                        timestamp: DateTime::parse_from_rfc3339("1970-01-01T00:00:01Z").unwrap().naive_utc(),
                         validation_pass: 1,
                         operations_hash: "LLoaLP6mc6nVzG2Rp3fSrHFvvGpUvkbHCjLASVduN7GzQAKnPctrR".try_into().unwrap(),
                         fitness: vec!["00000001000000000100000001".try_into().unwrap()],
@@ -629,13 +639,14 @@ mod test {
                         proof_of_work_nonce: "d4d34b5686c98ae1".try_into().unwrap(),
                         seed_nonce_hash: None,
                         liquidity_baking_toggle_vote: LiquidityBakingToggleVote::Off,
+                        adaptive_issuance_vote: AdaptiveIssuanceVote::On,
                         signature: "sigiaEd9dHEGKgccx3JBBDw4eb6WVxGH3MvyziYbQqWQRMmyecdo5VuSkYWkgZvcQXshB4vV2qkTb6AxbKruaNPfnMg4u2EA".try_into().unwrap()
                     },
                     BlockHeader {
                         level: 2,
                         proto: 2,
                         predecessor: "BMaBxGyVhtTiMKd7KA8HXJnbTK4e1TzffNc94G18op55HGQYVRk".try_into().unwrap(),
-                        timestamp: DateTime::parse_from_rfc3339("1970-01-01T00:00:00.002Z").unwrap().naive_utc(),
+                        timestamp: DateTime::parse_from_rfc3339("1970-01-01T00:00:02Z").unwrap().naive_utc(),
                         validation_pass: 2,
                         operations_hash: "LLoaNF9sd5z2SZtSmUopYNX6qs77QAUJqrnd5ei378H4bcJhQcPt5".try_into().unwrap(),
                         fitness: vec!["00000002ff000000020000000200000002".try_into().unwrap(), "00000002000000000200000002".try_into().unwrap()],
@@ -645,6 +656,7 @@ mod test {
                         proof_of_work_nonce: "336ebf95efce0475".try_into().unwrap(),
                         seed_nonce_hash: Some("nceUeUCJRZ4M7FCSBsAUZU6dmxePdH7irje9Gfj9zWwCdfWd5B4Ee".try_into().unwrap()),
                         liquidity_baking_toggle_vote: LiquidityBakingToggleVote::Pass,
+                        adaptive_issuance_vote: AdaptiveIssuanceVote::On,
                         signature: "sigRsUhHqaFVBeV4qzyCZ6Y9TvoKajyNwyPQQCW3SbgPYY99MrpTqR2FopjzZEHMWoJG7LaTaHu7bnieKQRKqCRLA7hB7Ekp".try_into().unwrap()
                     },
                 )
@@ -762,6 +774,10 @@ mod test {
                 SetDepositsLimit::new("tz1gxabEuUaCKk15qUKnhASJJoXhm9A7DVLM".try_into().unwrap(), 135675u32.into(), 154u32.into(), 23675u32.into(), 34152u32.into(), Some(634u32.into())).into(),
                 &hex!("7000e9dcc1a4a82c49aeec327b15e9ed457dc22a1ebcfba3089a01fbb801e88a02fffa04"),
             ),
+            (
+                TransferTicket::new("tz1gxabEuUaCKk15qUKnhASJJoXhm9A7DVLM".try_into().unwrap(), 135675u32.into(), 154u32.into(), 23675u32.into(), 34152u32.into(), int(1), primitive_application("nat").into(), "KT1HNqxFJxnmUcX8wF915wxxaAAU4ixDwWQ7".try_into().unwrap(), 10u8.into(), "tz1YY1LvD6TFH4z74pvxPQXBjAKHE5tB5Q8f".try_into().unwrap(), "default".into()).into(),
+                &hex!("9e00e9dcc1a4a82c49aeec327b15e9ed457dc22a1ebcfba3089a01fbb801e88a02000000020001000000020362016077cd98fd8aca94851b83a4c44203b705d2004b000a00008d760390453ce759265dcf970705a114c2cae6010000000764656661756c74"),
+            ),
         ]
     }
 
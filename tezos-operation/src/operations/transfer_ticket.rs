@@ -0,0 +1,82 @@
+use tezos_core::types::{
+    encoded::{Address, ImplicitAddress},
+    mutez::Mutez,
+    number::Nat,
+};
+use tezos_michelson::micheline::Micheline;
+
+use super::{OperationContentTag, TraitOperationContent, TraitOperationManagerContent};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransferTicket {
+    pub source: ImplicitAddress,
+    pub fee: Mutez,
+    pub counter: Nat,
+    pub gas_limit: Nat,
+    pub storage_limit: Nat,
+    pub ticket_contents: Micheline,
+    pub ticket_ty: Micheline,
+    pub ticket_ticketer: Address,
+    pub ticket_amount: Nat,
+    pub destination: Address,
+    pub entrypoint: String,
+}
+
+impl TransferTicket {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        source: ImplicitAddress,
+        fee: Mutez,
+        counter: Nat,
+        gas_limit: Nat,
+        storage_limit: Nat,
+        ticket_contents: Micheline,
+        ticket_ty: Micheline,
+        ticket_ticketer: Address,
+        ticket_amount: Nat,
+        destination: Address,
+        entrypoint: String,
+    ) -> Self {
+        Self {
+            source,
+            fee,
+            counter,
+            gas_limit,
+            storage_limit,
+            ticket_contents,
+            ticket_ty,
+            ticket_ticketer,
+            ticket_amount,
+            destination,
+            entrypoint,
+        }
+    }
+}
+
+impl TraitOperationContent for TransferTicket {
+    fn tag() -> OperationContentTag {
+        OperationContentTag::TransferTicket
+    }
+}
+
+impl TraitOperationManagerContent for TransferTicket {
+    fn source(&self) -> &ImplicitAddress {
+        &self.source
+    }
+
+    fn fee(&self) -> Mutez {
+        self.fee
+    }
+
+    fn counter(&self) -> &Nat {
+        &self.counter
+    }
+
+    fn gas_limit(&self) -> &Nat {
+        &self.gas_limit
+    }
+
+    fn storage_limit(&self) -> &Nat {
+        &self.storage_limit
+    }
+}
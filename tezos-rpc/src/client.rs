@@ -1,12 +1,16 @@
-use tezos_core::types::encoded::{Address, BlockHash, ChainId, Encoded, ScriptExprHash};
-use tezos_operation::operations::UnsignedOperation;
+use tezos_core::types::encoded::{
+    Address, BlockHash, ChainId, ContractHash, Encoded, ImplicitAddress, PublicKey, ScriptExprHash,
+    Signature,
+};
+use tezos_michelson::micheline::{sequence::Sequence, Micheline};
+use tezos_operation::{message::Message, operations::UnsignedOperation};
 
 #[cfg(feature = "http")]
 use crate::http::default::HttpClient;
 use crate::{
     http::Http,
     internal::estimator::{FeeEstimator, OperationFeeEstimator},
-    models::limits::Limits,
+    models::{limits::Limits, ticket::TicketToken},
     Result,
 };
 
@@ -112,7 +116,9 @@ impl TezosRpc<HttpClient> {
     pub fn new_with_chain_id(rpc_endpoint: String, chain_id: TezosRpcChainId) -> Self {
         Self::new_rpc_with_chain_id(rpc_endpoint, chain_id)
     }
+}
 
+impl<HttpClient: Http + Send + Sync> TezosRpc<HttpClient> {
     /// Estimates the minimum fee for the given `operation`. The returned operation has the fee, gas_limit and storage_limit values set accordingly.
     pub async fn min_fee(
         &self,
@@ -124,6 +130,26 @@ impl TezosRpc<HttpClient> {
             .min_fee(operation, limits.unwrap_or(&Default::default()))
             .await
     }
+
+    /// Verifies that `message` has been signed by `address`, using the public key revealed by the account.
+    ///
+    /// Returns `false` if the account has not revealed its public key yet.
+    pub async fn verify_message(
+        &self,
+        message: &Message,
+        signature: &Signature,
+        address: &ImplicitAddress,
+    ) -> Result<bool> {
+        let contract: Address = address.clone().into();
+        let public_key = self.get_contract_manager_key(&contract).send().await?;
+        match public_key {
+            Some(public_key) => {
+                let public_key: PublicKey = public_key.try_into()?;
+                Ok(message.verify_address(signature, address, &public_key)?)
+            }
+            None => Ok(false),
+        }
+    }
 }
 
 impl<HttpClient: Http> TezosRpc<HttpClient> {
@@ -157,9 +183,17 @@ impl<HttpClient: Http> TezosRpc<HttpClient> {
     /// let client = TezosRpc::<HttpClient>::new_rpc_with_chain_id("https://tezos-node.prod.gke.papers.tech".into(), Default::default());
     /// ```
     pub fn new_rpc_with_chain_id(rpc_endpoint: String, chain_id: TezosRpcChainId) -> Self {
-        Self {
-            context: TezosRpcContext::new(chain_id, HttpClient::new(rpc_endpoint)),
-        }
+        Self::from_context(TezosRpcContext::new(
+            chain_id,
+            HttpClient::new(rpc_endpoint),
+        ))
+    }
+
+    /// Creates a Tezos RPC client from an already configured context.
+    ///
+    /// This is useful when the `HttpClient` requires more configuration than the RPC endpoint.
+    pub fn from_context(context: TezosRpcContext<HttpClient>) -> Self {
+        Self { context }
     }
 }
 
@@ -422,6 +456,28 @@ impl<HttpClient: Http> TezosRpc<HttpClient> {
         protocol_rpc::block::context::contract::script::get_or_post(&self.context, address)
     }
 
+    /// Access the balance of a ticket owned by a contract.
+    ///
+    /// [`POST /chains/<chain_id>/blocks/<block>/context/contracts/<contract_id>/ticket_balance`](https://tezos.gitlab.io/active/rpc.html#post-block-id-context-contracts-contract-id-ticket-balance)
+    pub fn get_contract_ticket_balance<'a>(
+        &'a self,
+        address: &'a Address,
+        ticket: &'a TicketToken,
+    ) -> protocol_rpc::block::context::contract::ticket_balance::RpcRequestBuilder<HttpClient> {
+        protocol_rpc::block::context::contract::ticket_balance::post(&self.context, address, ticket)
+    }
+
+    /// Access the balances of all the tickets owned by an originated contract.
+    ///
+    /// [`GET /chains/<chain_id>/blocks/<block>/context/contracts/<contract_id>/all_ticket_balances`](https://tezos.gitlab.io/active/rpc.html#get-block-id-context-contracts-contract-id-all-ticket-balances)
+    pub fn get_contract_all_ticket_balances<'a>(
+        &'a self,
+        address: &'a Address,
+    ) -> protocol_rpc::block::context::contract::all_ticket_balances::RpcRequestBuilder<HttpClient>
+    {
+        protocol_rpc::block::context::contract::all_ticket_balances::get(&self.context, address)
+    }
+
     /// Get the (optionally paginated) list of values in a big map. Order of values is unspecified, but is guaranteed to be consistent.
     ///
     /// Optional query arguments:
@@ -483,6 +539,36 @@ impl<HttpClient: Http> TezosRpc<HttpClient> {
         protocol_rpc::block::helpers::scripts::run_operation::post(&self.context, operation)
     }
 
+    /// Run a Michelson script in the current context, returning the resulting storage and operations.
+    ///
+    /// [`POST /chains/<chain_id>/blocks/<block_id>/helpers/scripts/run_code`](https://tezos.gitlab.io/active/rpc.html#post-block-id-helpers-scripts-run-code)
+    pub fn run_code<'a>(
+        &'a self,
+        script: &'a Sequence,
+        storage: &'a Micheline,
+        input: &'a Micheline,
+    ) -> protocol_rpc::block::helpers::scripts::run_code::RpcRequestBuilder<'a, HttpClient> {
+        protocol_rpc::block::helpers::scripts::run_code::post(&self.context, script, storage, input)
+    }
+
+    /// Simulate a call to a Michelson view of a contract.
+    ///
+    /// [`POST /chains/<chain_id>/blocks/<block_id>/helpers/scripts/run_script_view`](https://tezos.gitlab.io/active/rpc.html#post-block-id-helpers-scripts-run-script-view)
+    pub fn run_script_view<'a>(
+        &'a self,
+        contract: &'a ContractHash,
+        view: &'a str,
+        input: &'a Micheline,
+    ) -> protocol_rpc::block::helpers::scripts::run_script_view::RpcRequestBuilder<'a, HttpClient>
+    {
+        protocol_rpc::block::helpers::scripts::run_script_view::post(
+            &self.context,
+            contract,
+            view,
+            input,
+        )
+    }
+
     /// Returns the delegate info (e.g. voting power) found in the listings of the current voting period.
     ///
     /// [`GET /chains/<chain_id>/blocks/<block>/context/delegates/<pkh>/voting_info`](https://tezos.gitlab.io/active/rpc.html#get-block-id-context-delegates-pkh-voting-info)
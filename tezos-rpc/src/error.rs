@@ -24,10 +24,39 @@ pub enum Error {
     ParseBigIntError {
         source: num_bigint::ParseBigIntError,
     },
+    IoError {
+        source: std::io::Error,
+    },
     RpcErrorPlain {
         description: String,
     },
     RpcErrors(#[error(not(source))] RpcErrors),
+    /// The node responded with `404 Not Found`, e.g. when a big map has no value at the requested key.
+    #[from(ignore)]
+    NotFound {
+        description: String,
+    },
+    #[from(ignore)]
+    MissingFixture {
+        description: String,
+    },
+    #[from(ignore)]
+    Indexer {
+        description: String,
+    },
+    #[display(
+        fmt = "the chain has been reorganized deeper than the indexed history at level {}",
+        level
+    )]
+    #[from(ignore)]
+    ReorgTooDeep {
+        level: i32,
+    },
+    #[display(fmt = "the protocol constant `{}` is missing", name)]
+    #[from(ignore)]
+    MissingConstant {
+        name: String,
+    },
     InvalidConversion,
     OperationNotSupported,
 }
@@ -4,6 +4,9 @@ use {
     serde::{de::DeserializeOwned, Serialize},
 };
 
+pub mod mock;
+pub mod replay;
+
 #[async_trait]
 pub trait Http {
     fn new(rpc_endpoint: String) -> Self;
@@ -60,6 +63,11 @@ pub mod default {
             &self,
             response: Response,
         ) -> Result<T, Error> {
+            if response.status() == 404 {
+                return Err(Error::NotFound {
+                    description: response.text().await?,
+                });
+            }
             if response.status() != 200 {
                 // Do not parse JSON when the content type is `plain/text`
                 if response.headers()["content-type"] == "application/json" {
@@ -0,0 +1,958 @@
+//! An in-memory implementation of the [Http] trait simulating a Tezos node.
+//!
+//! [MockNode] keeps a ledger of implicit accounts (balance, counter, revealed key and delegate) and a chain
+//! of blocks produced on demand with [MockNode::bake]. It answers the RPCs needed to simulate and inject
+//! reveals, transactions between implicit accounts and delegations, and to read the resulting state:
+//!
+//! - `GET /chains/<chain_id>/chain_id`
+//! - `GET /chains/<chain_id>/blocks/<block_id>`, `.../hash` and `.../header`
+//! - `GET .../context/contracts/<contract_id>`, `.../balance`, `.../counter`, `.../manager_key` and `.../delegate`
+//! - `POST .../helpers/scripts/run_operation` and `.../helpers/preapply/operations`
+//! - `POST /injection/operation`
+//!
+//! Injected operations are validated against the head state and the operations already waiting in the mempool,
+//! then included in the next baked block. Unlike a real node, an operation that would fail is rejected as a whole
+//! with the corresponding node error (e.g. `counter_in_the_past` or `balance_too_low`) instead of being included
+//! with a failed status. Signatures are checked on preapply and injection, which requires one of the crypto
+//! features of `tezos-operation`.
+//!
+//! ```rust
+//! use tezos_rpc::{
+//!     client::{TezosRpc, TezosRpcContext},
+//!     http::mock::MockNode,
+//! };
+//!
+//! let node = MockNode::default().with_account(
+//!     "tz1RFxSHjxxyaaXyAvJG3uRQeJUCCm4jk2LX".try_into().unwrap(),
+//!     10_000_000u32.into(),
+//! );
+//! let rpc = TezosRpc::from_context(TezosRpcContext::new(Default::default(), node.clone()));
+//! // build, sign and inject operations with `rpc`, then include them in a new block
+//! node.bake().unwrap();
+//! ```
+
+use {
+    super::{replay::Method, Http},
+    crate::{
+        constants::{BLOCK_GENESIS_ALIAS, BLOCK_HEAD_ALIAS},
+        error::{Error, Result},
+        models::{
+            block::Block,
+            error::RpcError,
+            limits::ALLOCATION_FEE,
+            operation::{Operation, OperationContent as RpcOperationContent},
+        },
+    },
+    async_trait::async_trait,
+    chrono::DateTime,
+    serde::{de::DeserializeOwned, Deserialize, Serialize},
+    serde_json::{json, Map, Value},
+    std::{
+        collections::BTreeMap,
+        sync::{Arc, Mutex, MutexGuard, PoisonError},
+    },
+    tezos_core::{
+        hash::public_key_hash,
+        types::{
+            encoded::{
+                Address, BlockHash, BlockPayloadHash, ContextHash, Encoded, ImplicitAddress,
+                OperationHash, OperationListListHash, PublicKey, Signature,
+            },
+            hex_string::HexString,
+            mutez::Mutez,
+            number::Nat,
+        },
+    },
+    tezos_operation::{
+        block_header::{AdaptiveIssuanceVote, BlockHeader, Fitness, LiquidityBakingToggleVote},
+        operations::{OperationContent, SignedOperation, UnsignedOperation},
+    },
+};
+
+const CHAIN_ID: &str = "NetXdQprcVkpaWU";
+const PROTOCOL: &str = "PtJakart2xVj7pYXJBXrqHgd82rdkLey5ZeeGwDgPp9rhQUbSqY";
+const ERROR_ID_PREFIX: &str = "proto.013-PtJakart.";
+/// 2022-01-01T00:00:00Z
+const GENESIS_TIMESTAMP: i64 = 1_640_995_200;
+const BLOCK_TIME: i64 = 15;
+const MANAGER_OPERATIONS_PASS: usize = 3;
+const SIGNATURE_SIZE: usize = 64;
+
+const REVEAL_GAS: u32 = 1_000;
+const TRANSACTION_GAS: u32 = 2_101;
+const DELEGATION_GAS: u32 = 1_000;
+const ALLOCATION_STORAGE: u32 = 257;
+
+/// The state of an implicit account in the [MockNode] ledger.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MockAccount {
+    pub balance: Mutez,
+    pub counter: Nat,
+    pub manager_key: Option<PublicKey>,
+    pub delegate: Option<ImplicitAddress>,
+}
+
+type Ledger = BTreeMap<String, MockAccount>;
+
+#[derive(Debug, Clone)]
+struct MockBlock {
+    block: Block,
+    ledger: Ledger,
+}
+
+#[derive(Debug)]
+struct State {
+    blocks: Vec<MockBlock>,
+    mempool: Vec<Operation>,
+    mempool_ledger: Ledger,
+}
+
+/// An [Http] implementation simulating a Tezos node in memory, see the [module](self) documentation.
+///
+/// Clones share the same chain, so a clone can be kept to bake blocks and inspect the ledger
+/// while another one is used by a [TezosRpc](crate::client::TezosRpc).
+#[derive(Debug, Clone)]
+pub struct MockNode {
+    state: Arc<Mutex<State>>,
+}
+
+impl Default for MockNode {
+    fn default() -> Self {
+        let genesis = MockBlock {
+            block: new_block(None, vec![]).expect("the genesis block is valid"),
+            ledger: Ledger::new(),
+        };
+
+        Self {
+            state: Arc::new(Mutex::new(State {
+                blocks: vec![genesis],
+                mempool: vec![],
+                mempool_ledger: Ledger::new(),
+            })),
+        }
+    }
+}
+
+impl MockNode {
+    /// Funds the implicit account `address` with `balance` in the head state.
+    pub fn with_account(self, address: ImplicitAddress, balance: Mutez) -> Self {
+        self.insert_account(
+            &address,
+            MockAccount {
+                balance,
+                ..Default::default()
+            },
+        );
+
+        self
+    }
+
+    /// Funds the implicit account of `public_key` with `balance` in the head state and marks the key as revealed.
+    pub fn with_revealed_account(self, public_key: PublicKey, balance: Mutez) -> Result<Self> {
+        self.insert_account(
+            &public_key_hash(&public_key)?,
+            MockAccount {
+                balance,
+                manager_key: Some(public_key),
+                ..Default::default()
+            },
+        );
+
+        Ok(self)
+    }
+
+    /// Produces a new block including the operations injected since the previous one and returns its hash.
+    pub fn bake(&self) -> Result<BlockHash> {
+        let mut state = self.state();
+        let operations = std::mem::take(&mut state.mempool);
+        let block = new_block(Some(&state.head().block), operations)?;
+        let hash = block.hash.clone();
+        let ledger = state.mempool_ledger.clone();
+        state.blocks.push(MockBlock { block, ledger });
+
+        Ok(hash)
+    }
+
+    /// Returns the head block.
+    pub fn head(&self) -> Block {
+        self.state().head().block.clone()
+    }
+
+    /// Returns the state of the implicit account `address` in the head block, if it has ever been funded.
+    pub fn account(&self, address: &ImplicitAddress) -> Option<MockAccount> {
+        self.state().head().ledger.get(address.value()).cloned()
+    }
+
+    /// Returns the hashes of the injected operations waiting to be included in a block.
+    pub fn pending_operations(&self) -> Vec<OperationHash> {
+        self.state()
+            .mempool
+            .iter()
+            .filter_map(|operation| operation.hash.clone())
+            .collect()
+    }
+
+    fn insert_account(&self, address: &ImplicitAddress, account: MockAccount) {
+        let mut state = self.state();
+        if let Some(head) = state.blocks.last_mut() {
+            head.ledger.insert(address.value().into(), account.clone());
+        }
+        state.mempool_ledger.insert(address.value().into(), account);
+    }
+
+    fn state(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn respond<T: DeserializeOwned>(
+        &self,
+        method: Method,
+        url: &str,
+        body: Option<Value>,
+    ) -> Result<T> {
+        let response = self.handle(method, url, body)?;
+
+        Ok(serde_json::from_value(response)?)
+    }
+
+    fn handle(&self, method: Method, url: &str, body: Option<Value>) -> Result<Value> {
+        let mut state = self.state();
+        let segments = url.trim_start_matches('/').split('/').collect::<Vec<_>>();
+        match (method, segments.as_slice(), body) {
+            (Method::Get, ["chains", _, "chain_id"], _) => Ok(json!(CHAIN_ID)),
+            (Method::Get, ["chains", _, "blocks", block_id, path @ ..], _) => {
+                let block = state.block(block_id)?;
+                match path {
+                    [] => Ok(serde_json::to_value(&block.block)?),
+                    ["hash"] => Ok(serde_json::to_value(&block.block.hash)?),
+                    ["header"] => Ok(serde_json::to_value(&block.block.header)?),
+                    ["context", "contracts", contract, path @ ..] => {
+                        contract_value(&block.ledger, contract, path)
+                    }
+                    _ => Err(not_found(method, url)),
+                }
+            }
+            (
+                Method::Post,
+                ["chains", _, "blocks", block_id, "helpers", "scripts", "run_operation"],
+                Some(body),
+            ) => {
+                let param: RunOperationParam = serde_json::from_value(body)?;
+                let mut ledger = state.block(block_id)?.ledger.clone();
+                let contents = apply(&mut ledger, param.operation.try_into()?, None)?;
+
+                Ok(json!({ "contents": contents }))
+            }
+            (
+                Method::Post,
+                ["chains", _, "blocks", block_id, "helpers", "preapply", "operations"],
+                Some(body),
+            ) => {
+                let operations: Vec<Operation> = serde_json::from_value(body)?;
+                let mut ledger = state.block(block_id)?.ledger.clone();
+                let results = operations
+                    .into_iter()
+                    .map(|operation| {
+                        let operation: SignedOperation = operation.try_into()?;
+                        let signature = operation.signature.clone();
+                        let contents = apply(
+                            &mut ledger,
+                            UnsignedOperation::new(operation.branch, operation.contents),
+                            Some(&signature),
+                        )?;
+                        Ok(json!({ "contents": contents, "signature": signature }))
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+
+                Ok(Value::Array(results))
+            }
+            (Method::Post, ["injection", "operation"], Some(body)) => {
+                let payload: String = serde_json::from_value(body)?;
+                Ok(serde_json::to_value(state.inject(&payload)?)?)
+            }
+            _ => Err(not_found(method, url)),
+        }
+    }
+}
+
+impl State {
+    fn head(&self) -> &MockBlock {
+        self.blocks.last().expect("the chain has a genesis block")
+    }
+
+    fn block(&self, block_id: &str) -> Result<&MockBlock> {
+        let head_level = self.blocks.len() - 1;
+        let level = match block_id {
+            BLOCK_HEAD_ALIAS => Some(head_level),
+            BLOCK_GENESIS_ALIAS => Some(0),
+            _ => match block_id.strip_prefix("head~") {
+                Some(offset) => offset
+                    .parse::<usize>()
+                    .ok()
+                    .and_then(|offset| head_level.checked_sub(offset)),
+                None => block_id.parse::<usize>().ok().or_else(|| {
+                    self.blocks
+                        .iter()
+                        .position(|block| block.block.hash.value() == block_id)
+                }),
+            },
+        };
+
+        level
+            .and_then(|level| self.blocks.get(level))
+            .ok_or_else(|| Error::RpcErrorPlain {
+                description: format!("Block `{}` not found", block_id),
+            })
+    }
+
+    fn inject(&mut self, payload: &str) -> Result<OperationHash> {
+        let bytes = HexString::new(payload.into())?.to_bytes();
+        if bytes.len() <= SIGNATURE_SIZE {
+            return Err(Error::InvalidConversion);
+        }
+        let (forged, signature) = bytes.split_at(bytes.len() - SIGNATURE_SIZE);
+        let operation = UnsignedOperation::from_forged_bytes(forged)?;
+        let signature = Signature::from_bytes(signature)?;
+        let hash = SignedOperation::from(operation.clone(), signature.clone()).hash()?;
+
+        let mut ledger = self.mempool_ledger.clone();
+        let contents = apply(&mut ledger, operation.clone(), Some(&signature))?;
+        self.mempool_ledger = ledger;
+        self.mempool.push(Operation {
+            protocol: Some(PROTOCOL.try_into()?),
+            chain_id: Some(CHAIN_ID.try_into()?),
+            hash: Some(hash.clone()),
+            branch: operation.branch,
+            signature: Some(signature),
+            contents,
+        });
+
+        Ok(hash)
+    }
+}
+
+#[async_trait]
+impl Http for MockNode {
+    /// Creates an empty mock node. The `rpc_endpoint` is ignored.
+    fn new(_rpc_endpoint: String) -> Self {
+        Self::default()
+    }
+
+    fn change_rpc_endpoint(&mut self, _rpc_endpoint: String) {}
+
+    async fn get<T: DeserializeOwned>(&self, url: &str) -> Result<T> {
+        self.respond(Method::Get, url, None)
+    }
+
+    async fn get_with_query<T: DeserializeOwned, Q: Serialize + ?Sized + Sync>(
+        &self,
+        url: &str,
+        _query: &Q,
+    ) -> Result<T> {
+        self.respond(Method::Get, url, None)
+    }
+
+    async fn post<B: Serialize + Sync, T: DeserializeOwned, Q: Serialize + Sync>(
+        &self,
+        url: &str,
+        body: &B,
+        _query: Option<&Q>,
+    ) -> Result<T> {
+        self.respond(Method::Post, url, Some(serde_json::to_value(body)?))
+    }
+
+    async fn patch<B: Serialize + Sync, T: DeserializeOwned>(
+        &self,
+        url: &str,
+        _body: Option<&B>,
+    ) -> Result<T> {
+        Err(not_found(Method::Patch, url))
+    }
+
+    async fn delete<B: Serialize + Sync, T: DeserializeOwned>(
+        &self,
+        url: &str,
+        _body: Option<&B>,
+    ) -> Result<T> {
+        Err(not_found(Method::Delete, url))
+    }
+}
+
+#[derive(Deserialize)]
+struct RunOperationParam {
+    operation: Operation,
+}
+
+fn not_found(method: Method, url: &str) -> Error {
+    Error::RpcErrorPlain {
+        description: format!("No service found at {} {}", method.as_str(), url),
+    }
+}
+
+fn new_block(predecessor: Option<&Block>, operations: Vec<Operation>) -> Result<Block> {
+    let level = predecessor.map_or(0, |block| block.header.level + 1);
+    let header = BlockHeader {
+        level,
+        proto: 1,
+        predecessor: match predecessor {
+            Some(block) => block.hash.clone(),
+            None => BlockHash::from_bytes(&[0; 32])?,
+        },
+        timestamp: DateTime::from_timestamp(GENESIS_TIMESTAMP + BLOCK_TIME * i64::from(level), 0)
+            .ok_or(Error::InvalidConversion)?
+            .naive_utc(),
+        validation_pass: 4,
+        operations_hash: OperationListListHash::from_bytes(&[0; 32])?,
+        fitness: Fitness {
+            level,
+            locked_round: None,
+            predecessor_round: 0,
+            round: 0,
+        }
+        .to_raw(),
+        context: ContextHash::from_bytes(&[0; 32])?,
+        payload_hash: BlockPayloadHash::from_bytes(&[0; 32])?,
+        payload_round: 0,
+        proof_of_work_nonce: HexString::from_bytes([0; BlockHeader::PROOF_OF_WORK_NONCE_SIZE]),
+        seed_nonce_hash: None,
+        liquidity_baking_toggle_vote: LiquidityBakingToggleVote::Pass,
+        adaptive_issuance_vote: AdaptiveIssuanceVote::Pass,
+        signature: Signature::from_bytes(&[0; SIGNATURE_SIZE])?,
+    };
+    let mut passes = vec![vec![]; MANAGER_OPERATIONS_PASS + 1];
+    passes[MANAGER_OPERATIONS_PASS] = operations;
+
+    Ok(Block {
+        protocol: PROTOCOL.try_into()?,
+        chain_id: CHAIN_ID.try_into()?,
+        hash: header.hash()?,
+        header: header.into(),
+        operations: passes,
+        metadata: None,
+    })
+}
+
+fn contract_value(ledger: &Ledger, contract: &str, path: &[&str]) -> Result<Value> {
+    let address: ImplicitAddress = contract.try_into().map_err(|_| Error::RpcErrorPlain {
+        description: format!("Contract `{}` not found", contract),
+    })?;
+    let account = ledger.get(address.value()).cloned().unwrap_or_default();
+    match path {
+        [] => {
+            let mut info = Map::new();
+            info.insert("balance".into(), serde_json::to_value(account.balance)?);
+            info.insert("counter".into(), json!(account.counter.to_string()));
+            if let Some(delegate) = account.delegate {
+                info.insert("delegate".into(), serde_json::to_value(delegate)?);
+            }
+            Ok(Value::Object(info))
+        }
+        ["balance"] => Ok(serde_json::to_value(account.balance)?),
+        ["counter"] => Ok(json!(account.counter.to_string())),
+        ["manager_key"] => Ok(serde_json::to_value(account.manager_key)?),
+        ["delegate"] => match account.delegate {
+            Some(delegate) => Ok(serde_json::to_value(delegate)?),
+            None => Err(Error::RpcErrorPlain {
+                description: format!("Contract `{}` has no delegate", contract),
+            }),
+        },
+        _ => Err(Error::RpcErrorPlain {
+            description: format!("No service found for contract `{}`", contract),
+        }),
+    }
+}
+
+/// Applies the manager operation contents on `ledger`, checking the `signature` if any,
+/// and returns the contents with their metadata.
+fn apply(
+    ledger: &mut Ledger,
+    operation: UnsignedOperation,
+    signature: Option<&Signature>,
+) -> Result<Vec<RpcOperationContent>> {
+    if let Some(signature) = signature {
+        verify_signature(ledger, &operation, signature)?;
+    }
+
+    operation
+        .contents
+        .into_iter()
+        .map(|content| {
+            let metadata = apply_content(ledger, &content)?;
+            let mut value = serde_json::to_value(RpcOperationContent::from(content))?;
+            value["metadata"] = metadata;
+
+            Ok(serde_json::from_value(value)?)
+        })
+        .collect()
+}
+
+fn verify_signature(
+    ledger: &Ledger,
+    operation: &UnsignedOperation,
+    signature: &Signature,
+) -> Result<()> {
+    let source = match operation.contents.first() {
+        Some(content) => manager_fields(content)?.source,
+        None => return Err(Error::InvalidConversion),
+    };
+    let revealed_key = operation.contents.iter().find_map(|content| match content {
+        OperationContent::Reveal(reveal) => Some(reveal.public_key.clone()),
+        _ => None,
+    });
+    let public_key = ledger
+        .get(source.value())
+        .and_then(|account| account.manager_key.clone())
+        .or(revealed_key)
+        .ok_or_else(|| {
+            rejected(RpcError {
+                contract: Some(source.value().into()),
+                ..node_error("contract.unrevealed_key")
+            })
+        })?;
+    let operation = SignedOperation::from(operation.clone(), signature.clone());
+    if !operation.verify(&public_key)? {
+        return Err(rejected(node_error("operation.invalid_signature")));
+    }
+
+    Ok(())
+}
+
+struct ManagerFields<'a> {
+    source: &'a ImplicitAddress,
+    fee: Mutez,
+    counter: &'a Nat,
+    gas_limit: &'a Nat,
+    storage_limit: &'a Nat,
+}
+
+fn manager_fields(content: &OperationContent) -> Result<ManagerFields<'_>> {
+    match content {
+        OperationContent::Reveal(value) => Ok(ManagerFields {
+            source: &value.source,
+            fee: value.fee,
+            counter: &value.counter,
+            gas_limit: &value.gas_limit,
+            storage_limit: &value.storage_limit,
+        }),
+        OperationContent::Transaction(value) => Ok(ManagerFields {
+            source: &value.source,
+            fee: value.fee,
+            counter: &value.counter,
+            gas_limit: &value.gas_limit,
+            storage_limit: &value.storage_limit,
+        }),
+        OperationContent::Delegation(value) => Ok(ManagerFields {
+            source: &value.source,
+            fee: value.fee,
+            counter: &value.counter,
+            gas_limit: &value.gas_limit,
+            storage_limit: &value.storage_limit,
+        }),
+        _ => Err(Error::OperationNotSupported),
+    }
+}
+
+/// Applies a single manager operation content and returns its metadata.
+fn apply_content(ledger: &mut Ledger, content: &OperationContent) -> Result<Value> {
+    let fields = manager_fields(content)?;
+    let source = fields.source;
+    let mut account = ledger.get(source.value()).cloned().ok_or_else(|| {
+        rejected(RpcError {
+            implicit: Some(source.value().into()),
+            ..node_error("implicit.empty_implicit_contract")
+        })
+    })?;
+
+    let one: Nat = 1u8.into();
+    let expected_counter = account.counter.clone() + one;
+    if fields.counter != &expected_counter {
+        let id = if fields.counter < &expected_counter {
+            "contract.counter_in_the_past"
+        } else {
+            "contract.counter_in_the_future"
+        };
+        return Err(rejected(RpcError {
+            contract: Some(source.value().into()),
+            expected: Some(expected_counter.to_string()),
+            found: Some(fields.counter.to_string()),
+            ..node_error(id)
+        }));
+    }
+    account.counter = expected_counter;
+    if account.manager_key.is_none() && !matches!(content, OperationContent::Reveal(_)) {
+        return Err(rejected(RpcError {
+            contract: Some(source.value().into()),
+            ..node_error("contract.unrevealed_key")
+        }));
+    }
+    account.balance = debit(source, account.balance, fields.fee)?;
+    let fee_updates = json!([
+        balance_update(source, format!("-{}", fields.fee)),
+        { "kind": "accumulator", "category": "block fees", "change": fields.fee.to_string(), "origin": "block" },
+    ]);
+
+    let (consumed_gas, operation_result) = match content {
+        OperationContent::Reveal(reveal) => {
+            if account.manager_key.is_some() {
+                return Err(rejected(RpcError {
+                    contract: Some(source.value().into()),
+                    ..node_error("contract.previously_revealed_key")
+                }));
+            }
+            if &public_key_hash(&reveal.public_key)? != source {
+                return Err(rejected(RpcError {
+                    contract: Some(source.value().into()),
+                    ..node_error("contract.manager.inconsistent_public_key")
+                }));
+            }
+            account.manager_key = Some(reveal.public_key.clone());
+            ledger.insert(source.value().into(), account);
+
+            (REVEAL_GAS, json!({ "status": "applied" }))
+        }
+        OperationContent::Delegation(delegation) => {
+            account.delegate = delegation.delegate.clone();
+            ledger.insert(source.value().into(), account);
+
+            (DELEGATION_GAS, json!({ "status": "applied" }))
+        }
+        OperationContent::Transaction(transaction) => {
+            let destination = match &transaction.destination {
+                Address::Implicit(address) => address,
+                Address::Originated(_) => return Err(Error::OperationNotSupported),
+            };
+            account.balance = debit(source, account.balance, transaction.amount)?;
+            let allocated = destination != source && !ledger.contains_key(destination.value());
+            let mut balance_updates = vec![];
+            if transaction.amount > Mutez::from(0u8) {
+                balance_updates.push(balance_update(source, format!("-{}", transaction.amount)));
+                balance_updates.push(balance_update(destination, transaction.amount.to_string()));
+            }
+            if allocated {
+                if fields.storage_limit < &ALLOCATION_STORAGE.into() {
+                    return Err(rejected(node_error("storage_exhausted.operation")));
+                }
+                let allocation_fee: Mutez = ALLOCATION_FEE.try_into()?;
+                account.balance = debit(source, account.balance, allocation_fee)?;
+                balance_updates.push(balance_update(source, format!("-{}", allocation_fee)));
+                balance_updates.push(json!({
+                    "kind": "burned",
+                    "category": "storage fees",
+                    "change": allocation_fee.to_string(),
+                    "origin": "block",
+                }));
+            }
+            ledger.insert(source.value().into(), account);
+            let destination_account = ledger.entry(destination.value().into()).or_default();
+            destination_account.balance = destination_account
+                .balance
+                .checked_add(transaction.amount)
+                .ok_or(Error::InvalidConversion)?;
+
+            let mut result = json!({
+                "status": "applied",
+                "balance_updates": balance_updates,
+            });
+            if allocated {
+                result["allocated_destination_contract"] = json!(true);
+            }
+            (TRANSACTION_GAS, result)
+        }
+        _ => return Err(Error::OperationNotSupported),
+    };
+
+    if fields.gas_limit < &consumed_gas.into() {
+        return Err(rejected(node_error("gas_exhausted.operation")));
+    }
+    let mut operation_result = operation_result;
+    operation_result["consumed_gas"] = json!(consumed_gas.to_string());
+    operation_result["consumed_milligas"] = json!((u64::from(consumed_gas) * 1000).to_string());
+
+    Ok(json!({
+        "balance_updates": fee_updates,
+        "operation_result": operation_result,
+    }))
+}
+
+fn debit(source: &ImplicitAddress, balance: Mutez, amount: Mutez) -> Result<Mutez> {
+    balance.checked_sub(amount).ok_or_else(|| {
+        rejected(RpcError {
+            contract: Some(source.value().into()),
+            balance: Some(balance.to_string()),
+            amount: Some(amount.to_string()),
+            ..node_error("contract.balance_too_low")
+        })
+    })
+}
+
+fn balance_update(contract: &ImplicitAddress, change: String) -> Value {
+    json!({
+        "kind": "contract",
+        "contract": contract.value(),
+        "change": change,
+        "origin": "block",
+    })
+}
+
+fn node_error(id: &str) -> RpcError {
+    RpcError {
+        kind: "temporary".into(),
+        id: format!("{}{}", ERROR_ID_PREFIX, id),
+        message: None,
+        msg: None,
+        amount: None,
+        balance: None,
+        contract: None,
+        expected: None,
+        found: None,
+        implicit: None,
+        location: None,
+        with: None,
+    }
+}
+
+fn rejected(error: RpcError) -> Error {
+    Error::RpcErrors(vec![error].into())
+}
+
+#[cfg(test)]
+mod test {
+    use {
+        super::*,
+        crate::{
+            client::{TezosRpc, TezosRpcContext},
+            models::{block::BlockId, error::NodeError},
+        },
+        num_bigint::BigInt,
+        tezos_core::types::encoded::SecretKey,
+        tezos_operation::operations::{Delegation, Reveal, Transaction},
+    };
+
+    const SECRET_KEY: &str = "edskRv7VyXGVZb8EsrR7D9XKUbbAQNQGtALP6QeB16ZCD7SmmJpzyeneJVg3Mq56YLbxRA1kSdAXiswwPiaVfR3NHGMCXCziuZ";
+    const PUBLIC_KEY: &str = "edpkttZKC51wemRqL2QxwpMnEKxWnbd35pq47Y6xsCHp5M1f7LN8NP";
+    const SOURCE: &str = "tz1RFxSHjxxyaaXyAvJG3uRQeJUCCm4jk2LX";
+    const DESTINATION: &str = "tz1PwXjsrgYBi9wpe3tFhazJpt7JMTVzBp5c";
+
+    fn node() -> Result<(MockNode, TezosRpc<MockNode>)> {
+        let node = MockNode::default().with_account(SOURCE.try_into()?, 10_000_000u32.into());
+        let rpc = TezosRpc::from_context(TezosRpcContext::new(Default::default(), node.clone()));
+
+        Ok((node, rpc))
+    }
+
+    fn reveal(counter: u8) -> Result<OperationContent> {
+        Ok(Reveal::new(
+            SOURCE.try_into()?,
+            0u8.into(),
+            counter.into(),
+            10_000u16.into(),
+            300u16.into(),
+            PUBLIC_KEY.try_into()?,
+        )
+        .into())
+    }
+
+    fn transaction(counter: u8, amount: Mutez) -> Result<OperationContent> {
+        Ok(Transaction::new(
+            SOURCE.try_into()?,
+            0u8.into(),
+            counter.into(),
+            10_000u16.into(),
+            300u16.into(),
+            amount,
+            Address::try_from(DESTINATION)?,
+            None,
+        )
+        .into())
+    }
+
+    async fn prepare(
+        rpc: &TezosRpc<MockNode>,
+        contents: Vec<OperationContent>,
+    ) -> Result<SignedOperation> {
+        let branch = rpc.get_block_hash().send().await?;
+        let operation = rpc
+            .min_fee(UnsignedOperation::new(branch, contents), None)
+            .await?;
+
+        Ok(operation.into_signed_operation(&SecretKey::try_from(SECRET_KEY)?)?)
+    }
+
+    fn node_errors(result: Result<impl std::fmt::Debug>) -> Vec<NodeError> {
+        match result {
+            Err(Error::RpcErrors(errors)) => errors.node_errors(),
+            result => panic!("expected node errors, got {:?}", result),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_transfer() -> Result<()> {
+        let (node, rpc) = node()?;
+        let source: Address = SOURCE.try_into()?;
+        let destination: Address = DESTINATION.try_into()?;
+
+        let operation =
+            prepare(&rpc, vec![reveal(1)?, transaction(2, 1_000_000u32.into())?]).await?;
+        let fees = operation
+            .contents
+            .iter()
+            .map(|content| content.fee())
+            .sum::<Mutez>();
+        assert!(fees > 0u8.into());
+
+        let preapplied = rpc
+            .preapply_operations(&vec![&operation.clone().into()])
+            .send()
+            .await?;
+        assert_eq!(preapplied[0].contents.len(), 2);
+
+        let hash = rpc
+            .inject_operation(&operation.to_injectable_string()?)
+            .send()
+            .await?;
+        assert_eq!(hash, operation.hash()?);
+        assert_eq!(node.pending_operations(), vec![hash.clone()]);
+        assert_eq!(
+            rpc.get_contract_balance(&source).send().await?,
+            BigInt::from(10_000_000)
+        );
+
+        let block_hash = node.bake()?;
+        assert!(node.pending_operations().is_empty());
+
+        let block = rpc.get_block().send().await?;
+        assert_eq!(block.hash, block_hash);
+        assert_eq!(block.header.level, 1);
+        assert_eq!(block.operations[3][0].hash, Some(hash));
+        assert_eq!(rpc.get_block_hash().send().await?, block_hash);
+
+        let allocation_fee: Mutez = ALLOCATION_FEE.try_into()?;
+        let expected_balance =
+            Mutez::from(10_000_000u32) - Mutez::from(1_000_000u32) - fees - allocation_fee;
+        assert_eq!(
+            rpc.get_contract_balance(&source).send().await?,
+            BigInt::from(i64::try_from(expected_balance)?)
+        );
+        assert_eq!(
+            rpc.get_contract_balance(&destination).send().await?,
+            BigInt::from(1_000_000)
+        );
+        assert_eq!(rpc.get_contract_counter(&source).send().await?, 2u8.into());
+        assert_eq!(
+            rpc.get_contract_manager_key(&source).send().await?,
+            Some(PUBLIC_KEY.into())
+        );
+
+        let genesis_balance = rpc
+            .get_contract_balance(&source)
+            .block_id(&BlockId::Genesis)
+            .send()
+            .await?;
+        assert_eq!(genesis_balance, BigInt::from(10_000_000));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_delegation() -> Result<()> {
+        let (node, rpc) = node()?;
+        let source: Address = SOURCE.try_into()?;
+
+        let delegation = Delegation::new(
+            SOURCE.try_into()?,
+            0u8.into(),
+            2u8.into(),
+            0u8.into(),
+            0u8.into(),
+            Some(SOURCE.try_into()?),
+        );
+        let operation = prepare(&rpc, vec![reveal(1)?, delegation.into()]).await?;
+        rpc.inject_operation(&operation.to_injectable_string()?)
+            .send()
+            .await?;
+        assert_eq!(rpc.get_contract_delegate(&source).send().await?, None);
+
+        node.bake()?;
+        assert_eq!(
+            rpc.get_contract_delegate(&source).send().await?,
+            Some(SOURCE.into())
+        );
+        assert_eq!(
+            rpc.get_contract(&source).send().await?.delegate,
+            Some(SOURCE.try_into()?)
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_rejected_operations() -> Result<()> {
+        let (node, rpc) = node()?;
+        let branch = rpc.get_block_hash().send().await?;
+        let run = |contents: Vec<OperationContent>| -> Operation {
+            UnsignedOperation::new(branch.clone(), contents).into()
+        };
+
+        assert_eq!(
+            node_errors(
+                rpc.run_operation(&run(vec![transaction(1, 1u8.into())?]))
+                    .send()
+                    .await
+            ),
+            vec![NodeError::UnrevealedKey {
+                contract: SOURCE.try_into()?
+            }]
+        );
+        assert_eq!(
+            node_errors(rpc.run_operation(&run(vec![reveal(2)?])).send().await),
+            vec![NodeError::CounterInTheFuture {
+                contract: SOURCE.try_into()?,
+                expected: 1u8.into(),
+                found: 2u8.into(),
+            }]
+        );
+        assert!(matches!(
+            node_errors(
+                rpc.run_operation(&run(vec![
+                    reveal(1)?,
+                    transaction(2, 20_000_000u32.into())?
+                ]))
+                .send()
+                .await
+            )
+            .as_slice(),
+            [NodeError::BalanceTooLow { .. }]
+        ));
+
+        let operation = prepare(&rpc, vec![reveal(1)?]).await?;
+        rpc.inject_operation(&operation.to_injectable_string()?)
+            .send()
+            .await?;
+        assert_eq!(
+            node_errors(
+                rpc.inject_operation(&operation.to_injectable_string()?)
+                    .send()
+                    .await
+            ),
+            vec![NodeError::CounterInThePast {
+                contract: SOURCE.try_into()?,
+                expected: 2u8.into(),
+                found: 1u8.into(),
+            }]
+        );
+
+        node.bake()?;
+        let mut forged = prepare(&rpc, vec![transaction(2, 1u8.into())?]).await?;
+        forged.signature = Signature::from_bytes(&[1; SIGNATURE_SIZE])?;
+        assert_eq!(
+            node_errors(
+                rpc.inject_operation(&forged.to_injectable_string()?)
+                    .send()
+                    .await
+            ),
+            vec![NodeError::InvalidSignature]
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,585 @@
+//! Record/replay implementations of the [Http] trait.
+//!
+//! [ReplayClient] answers requests from previously captured fixtures instead of contacting a node,
+//! which makes code built on top of [TezosRpc](crate::client::TezosRpc) deterministic in tests.
+//! Fixtures are matched by HTTP method, URL, query and body.
+//!
+//! [RecordingClient] wraps any other [Http] implementation, forwards every request to it
+//! and stores the exchanged data as fixtures that can later be replayed by [ReplayClient].
+//!
+//! ```rust
+//! use tezos_rpc::{
+//!     client::{TezosRpc, TezosRpcContext},
+//!     http::replay::{Fixture, Method, ReplayClient},
+//! };
+//!
+//! let client = ReplayClient::new_with_fixtures_dir("__TEST_DATA__/fixtures").with_fixture(
+//!     Fixture::new(Method::Get, "/chains/main/chain_id", "NetXdQprcVkpaWU".into()),
+//! );
+//! let rpc = TezosRpc::from_context(TezosRpcContext::new(Default::default(), client));
+//! ```
+
+use {
+    super::Http,
+    crate::{
+        error::{Error, Result},
+        models::error::RpcError,
+    },
+    async_trait::async_trait,
+    serde::{de::DeserializeOwned, Deserialize, Serialize},
+    serde_json::Value,
+    std::{
+        collections::HashMap,
+        fs,
+        path::{Path, PathBuf},
+    },
+    tezos_core::{types::hex_string::HexString, Tezos},
+};
+
+/// Environment variable read by [RecordingClient::new](Http::new) to locate the fixtures directory.
+pub const FIXTURES_DIR_ENV: &str = "TEZOS_RPC_FIXTURES_DIR";
+/// The fixtures directory used by [RecordingClient::new](Http::new) when [FIXTURES_DIR_ENV] is not set.
+pub const DEFAULT_FIXTURES_DIR: &str = "fixtures";
+
+const MAX_FILE_NAME_PREFIX_LENGTH: usize = 80;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum Method {
+    Get,
+    Post,
+    Patch,
+    Delete,
+}
+
+impl Method {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Get => "GET",
+            Self::Post => "POST",
+            Self::Patch => "PATCH",
+            Self::Delete => "DELETE",
+        }
+    }
+}
+
+/// A captured request together with the response returned by the node.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Fixture {
+    pub method: Method,
+    pub url: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub query: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<Value>,
+    #[serde(default = "Fixture::default_status")]
+    pub status: u16,
+    pub response: Value,
+}
+
+impl Fixture {
+    const OK_STATUS: u16 = 200;
+    const NOT_FOUND_STATUS: u16 = 404;
+    const ERROR_STATUS: u16 = 500;
+
+    pub fn new<S: Into<String>>(method: Method, url: S, response: Value) -> Self {
+        Self {
+            method,
+            url: url.into(),
+            query: None,
+            body: None,
+            status: Self::OK_STATUS,
+            response,
+        }
+    }
+
+    /// Sets the query parameters the request must have been sent with.
+    pub fn with_query(mut self, query: Value) -> Self {
+        self.query = Some(query);
+
+        self
+    }
+
+    /// Sets the body the request must have been sent with.
+    pub fn with_body(mut self, body: Value) -> Self {
+        self.body = Some(body);
+
+        self
+    }
+
+    /// Sets the HTTP status of the response. Any status other than `200` is replayed as an error,
+    /// `404` as [Error::NotFound].
+    pub fn with_status(mut self, status: u16) -> Self {
+        self.status = status;
+
+        self
+    }
+
+    /// The key used to match requests against this fixture.
+    pub fn key(&self) -> String {
+        request_key(
+            self.method,
+            &self.url,
+            self.query.as_ref(),
+            self.body.as_ref(),
+        )
+    }
+
+    /// The name of the file this fixture is stored in, relative to the fixtures directory.
+    pub fn file_name(&self) -> Result<String> {
+        file_name(
+            self.method,
+            &self.url,
+            self.query.as_ref(),
+            self.body.as_ref(),
+        )
+    }
+
+    fn default_status() -> u16 {
+        Self::OK_STATUS
+    }
+
+    fn into_response<T: DeserializeOwned>(self) -> Result<T> {
+        if self.status == Self::OK_STATUS {
+            return Ok(serde_json::from_value(self.response)?);
+        }
+        if self.status == Self::NOT_FOUND_STATUS {
+            let description = match self.response {
+                Value::String(description) => description,
+                value => value.to_string(),
+            };
+            return Err(Error::NotFound { description });
+        }
+        match self.response {
+            Value::Array(_) => {
+                let errors: Vec<RpcError> = serde_json::from_value(self.response)?;
+                Err(Error::RpcErrors(errors.into()))
+            }
+            Value::String(description) => Err(Error::RpcErrorPlain { description }),
+            value => Err(Error::RpcErrorPlain {
+                description: value.to_string(),
+            }),
+        }
+    }
+}
+
+fn to_json<S: Serialize + ?Sized>(value: Option<&S>) -> Result<Option<Value>> {
+    Ok(value
+        .map(serde_json::to_value)
+        .transpose()?
+        .filter(|value| !value.is_null()))
+}
+
+fn request_key(method: Method, url: &str, query: Option<&Value>, body: Option<&Value>) -> String {
+    let to_string = |value: Option<&Value>| value.map(Value::to_string).unwrap_or_default();
+    format!(
+        "{} {}\n{}\n{}",
+        method.as_str(),
+        url,
+        to_string(query),
+        to_string(body)
+    )
+}
+
+fn file_name(
+    method: Method,
+    url: &str,
+    query: Option<&Value>,
+    body: Option<&Value>,
+) -> Result<String> {
+    let key = request_key(method, url, query, body);
+    let hash = Tezos::default().get_crypto().blake2b(key.as_bytes(), 8)?;
+    let prefix: String = format!("{}{}", method.as_str(), url)
+        .to_lowercase()
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .take(MAX_FILE_NAME_PREFIX_LENGTH)
+        .collect();
+
+    Ok(format!(
+        "{}-{}.json",
+        prefix,
+        HexString::from_bytes(hash).into_string(false)
+    ))
+}
+
+/// An [Http] implementation that answers requests with previously recorded [Fixture]s.
+///
+/// Fixtures registered with [ReplayClient::with_fixture] take precedence over the ones
+/// stored in the fixtures directory. A request without a matching fixture fails with [Error::MissingFixture].
+#[derive(Debug, Clone)]
+pub struct ReplayClient {
+    fixtures_dir: PathBuf,
+    fixtures: HashMap<String, Fixture>,
+}
+
+impl ReplayClient {
+    /// Creates a client replaying the fixtures stored in `fixtures_dir`.
+    pub fn new_with_fixtures_dir<P: AsRef<Path>>(fixtures_dir: P) -> Self {
+        Self {
+            fixtures_dir: fixtures_dir.as_ref().to_path_buf(),
+            fixtures: HashMap::new(),
+        }
+    }
+
+    /// Registers an in-memory fixture.
+    pub fn with_fixture(mut self, fixture: Fixture) -> Self {
+        self.fixtures.insert(fixture.key(), fixture);
+
+        self
+    }
+
+    pub fn fixtures_dir(&self) -> &Path {
+        &self.fixtures_dir
+    }
+
+    fn replay<T: DeserializeOwned>(
+        &self,
+        method: Method,
+        url: &str,
+        query: Option<Value>,
+        body: Option<Value>,
+    ) -> Result<T> {
+        let key = request_key(method, url, query.as_ref(), body.as_ref());
+        if let Some(fixture) = self.fixtures.get(&key) {
+            return fixture.clone().into_response();
+        }
+
+        let path = self
+            .fixtures_dir
+            .join(file_name(method, url, query.as_ref(), body.as_ref())?);
+        if !path.exists() {
+            return Err(Error::MissingFixture {
+                description: format!("no fixture found for `{}` at {}", key, path.display()),
+            });
+        }
+        let fixture: Fixture = serde_json::from_str(&fs::read_to_string(path)?)?;
+
+        fixture.into_response()
+    }
+}
+
+#[async_trait]
+impl Http for ReplayClient {
+    /// Creates a client replaying fixtures. The `rpc_endpoint` is interpreted as the fixtures directory.
+    fn new(rpc_endpoint: String) -> Self {
+        Self::new_with_fixtures_dir(rpc_endpoint)
+    }
+
+    fn change_rpc_endpoint(&mut self, rpc_endpoint: String) {
+        self.fixtures_dir = rpc_endpoint.into();
+    }
+
+    async fn get<T: DeserializeOwned>(&self, url: &str) -> Result<T> {
+        self.replay(Method::Get, url, None, None)
+    }
+
+    async fn get_with_query<T: DeserializeOwned, Q: Serialize + ?Sized + Sync>(
+        &self,
+        url: &str,
+        query: &Q,
+    ) -> Result<T> {
+        self.replay(Method::Get, url, to_json(Some(query))?, None)
+    }
+
+    async fn post<B: Serialize + Sync, T: DeserializeOwned, Q: Serialize + Sync>(
+        &self,
+        url: &str,
+        body: &B,
+        query: Option<&Q>,
+    ) -> Result<T> {
+        self.replay(Method::Post, url, to_json(query)?, to_json(Some(body))?)
+    }
+
+    async fn patch<B: Serialize + Sync, T: DeserializeOwned>(
+        &self,
+        url: &str,
+        body: Option<&B>,
+    ) -> Result<T> {
+        self.replay(Method::Patch, url, None, to_json(body)?)
+    }
+
+    async fn delete<B: Serialize + Sync, T: DeserializeOwned>(
+        &self,
+        url: &str,
+        body: Option<&B>,
+    ) -> Result<T> {
+        self.replay(Method::Delete, url, None, to_json(body)?)
+    }
+}
+
+/// An [Http] implementation that forwards requests to another client and stores
+/// every exchange as a [Fixture] in the fixtures directory.
+#[derive(Debug)]
+pub struct RecordingClient<HttpClient: Http> {
+    inner: HttpClient,
+    fixtures_dir: PathBuf,
+}
+
+impl<HttpClient: Http> RecordingClient<HttpClient> {
+    /// Creates a client recording the exchanges made through `inner` into `fixtures_dir`.
+    pub fn new_with_fixtures_dir<P: AsRef<Path>>(inner: HttpClient, fixtures_dir: P) -> Self {
+        Self {
+            inner,
+            fixtures_dir: fixtures_dir.as_ref().to_path_buf(),
+        }
+    }
+
+    pub fn fixtures_dir(&self) -> &Path {
+        &self.fixtures_dir
+    }
+
+    pub fn inner(&self) -> &HttpClient {
+        &self.inner
+    }
+
+    fn record<T: DeserializeOwned>(
+        &self,
+        method: Method,
+        url: &str,
+        query: Option<Value>,
+        body: Option<Value>,
+        result: Result<Value>,
+    ) -> Result<T> {
+        let (status, response) = match result {
+            Ok(value) => (Fixture::OK_STATUS, value),
+            Err(Error::RpcErrors(errors)) => {
+                (Fixture::ERROR_STATUS, serde_json::to_value(&errors)?)
+            }
+            Err(Error::RpcErrorPlain { description }) => {
+                (Fixture::ERROR_STATUS, Value::String(description))
+            }
+            Err(Error::NotFound { description }) => {
+                (Fixture::NOT_FOUND_STATUS, Value::String(description))
+            }
+            Err(error) => return Err(error),
+        };
+        let fixture = Fixture {
+            method,
+            url: url.into(),
+            query,
+            body,
+            status,
+            response,
+        };
+
+        fs::create_dir_all(&self.fixtures_dir)?;
+        fs::write(
+            self.fixtures_dir.join(fixture.file_name()?),
+            serde_json::to_string_pretty(&fixture)?,
+        )?;
+
+        fixture.into_response()
+    }
+}
+
+#[async_trait]
+impl<HttpClient: Http + Sync + Send> Http for RecordingClient<HttpClient> {
+    /// Creates a recording client wrapping a new `HttpClient` connected to `rpc_endpoint`.
+    ///
+    /// The fixtures are stored in the directory set in the [FIXTURES_DIR_ENV] environment variable,
+    /// or in [DEFAULT_FIXTURES_DIR] if it is not set.
+    fn new(rpc_endpoint: String) -> Self {
+        let fixtures_dir =
+            std::env::var(FIXTURES_DIR_ENV).unwrap_or_else(|_| DEFAULT_FIXTURES_DIR.into());
+        Self::new_with_fixtures_dir(HttpClient::new(rpc_endpoint), fixtures_dir)
+    }
+
+    fn change_rpc_endpoint(&mut self, rpc_endpoint: String) {
+        self.inner.change_rpc_endpoint(rpc_endpoint);
+    }
+
+    async fn get<T: DeserializeOwned>(&self, url: &str) -> Result<T> {
+        let result = self.inner.get(url).await;
+        self.record(Method::Get, url, None, None, result)
+    }
+
+    async fn get_with_query<T: DeserializeOwned, Q: Serialize + ?Sized + Sync>(
+        &self,
+        url: &str,
+        query: &Q,
+    ) -> Result<T> {
+        let result = self.inner.get_with_query(url, query).await;
+        self.record(Method::Get, url, to_json(Some(query))?, None, result)
+    }
+
+    async fn post<B: Serialize + Sync, T: DeserializeOwned, Q: Serialize + Sync>(
+        &self,
+        url: &str,
+        body: &B,
+        query: Option<&Q>,
+    ) -> Result<T> {
+        let result = self.inner.post(url, body, query).await;
+        self.record(
+            Method::Post,
+            url,
+            to_json(query)?,
+            to_json(Some(body))?,
+            result,
+        )
+    }
+
+    async fn patch<B: Serialize + Sync, T: DeserializeOwned>(
+        &self,
+        url: &str,
+        body: Option<&B>,
+    ) -> Result<T> {
+        let result = self.inner.patch(url, body).await;
+        self.record(Method::Patch, url, None, to_json(body)?, result)
+    }
+
+    async fn delete<B: Serialize + Sync, T: DeserializeOwned>(
+        &self,
+        url: &str,
+        body: Option<&B>,
+    ) -> Result<T> {
+        let result = self.inner.delete(url, body).await;
+        self.record(Method::Delete, url, None, to_json(body)?, result)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use {
+        super::*,
+        crate::client::{TezosRpc, TezosRpcContext},
+        serde_json::json,
+    };
+
+    fn rpc(client: ReplayClient) -> TezosRpc<ReplayClient> {
+        TezosRpc::from_context(TezosRpcContext::new(Default::default(), client))
+    }
+
+    #[tokio::test]
+    async fn test_replay_in_memory_fixture() -> Result<()> {
+        let client = ReplayClient::new_with_fixtures_dir("").with_fixture(Fixture::new(
+            Method::Get,
+            "/chains/main/chain_id",
+            json!("NetXdQprcVkpaWU"),
+        ));
+        let chain_id = rpc(client).get_chain_id().send().await?;
+        assert_eq!(chain_id, "NetXdQprcVkpaWU");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_replay_matches_body() -> Result<()> {
+        let client = ReplayClient::new_with_fixtures_dir("").with_fixture(
+            Fixture::new(
+                Method::Post,
+                "/injection/operation",
+                json!("ooG169iWhv7vQccPGcB2EWeAjFWvxcrmQVCi4eWCviUTHeQuH24"),
+            )
+            .with_body(json!("aabb")),
+        );
+
+        let hash: String = client
+            .post::<_, _, ()>("/injection/operation", &"aabb", None)
+            .await?;
+        assert_eq!(hash, "ooG169iWhv7vQccPGcB2EWeAjFWvxcrmQVCi4eWCviUTHeQuH24");
+
+        let result: Result<String> = client
+            .post::<_, _, ()>("/injection/operation", &"ccdd", None)
+            .await;
+        assert!(matches!(result, Err(Error::MissingFixture { .. })));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_replay_errors() -> Result<()> {
+        let client = ReplayClient::new_with_fixtures_dir("")
+            .with_fixture(
+                Fixture::new(
+                    Method::Get,
+                    "/errors",
+                    json!([{ "kind": "temporary", "id": "failure" }]),
+                )
+                .with_status(500),
+            )
+            .with_fixture(
+                Fixture::new(Method::Get, "/plain", json!("Internal error")).with_status(500),
+            )
+            .with_fixture(
+                Fixture::new(Method::Get, "/missing", json!("Not found")).with_status(404),
+            );
+
+        let result: Result<Value> = client.get("/errors").await;
+        assert!(matches!(result, Err(Error::RpcErrors(_))));
+
+        let result: Result<Value> = client.get("/plain").await;
+        assert!(
+            matches!(result, Err(Error::RpcErrorPlain { description }) if description == "Internal error")
+        );
+
+        let result: Result<Value> = client.get("/missing").await;
+        assert!(
+            matches!(result, Err(Error::NotFound { description }) if description == "Not found")
+        );
+
+        Ok(())
+    }
+
+    #[cfg(feature = "http")]
+    #[tokio::test]
+    async fn test_record_and_replay() -> Result<()> {
+        use {crate::http::default::HttpClient, httpmock::prelude::*};
+
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(GET).path("/chains/main/chain_id");
+            then.status(200)
+                .header("content-type", "application/json")
+                .json_body(json!("NetXdQprcVkpaWU"));
+        });
+        server.mock(|when, then| {
+            when.method(POST)
+                .path("/injection/operation")
+                .json_body(json!("aabb"));
+            then.status(200)
+                .header("content-type", "application/json")
+                .json_body(json!("ooG169iWhv7vQccPGcB2EWeAjFWvxcrmQVCi4eWCviUTHeQuH24"));
+        });
+        server.mock(|when, then| {
+            when.method(GET).path("/missing");
+            then.status(404).body("Not found");
+        });
+
+        let fixtures_dir = std::env::temp_dir().join(format!(
+            "tezos-rpc-fixtures-{}-{}",
+            std::process::id(),
+            line!()
+        ));
+        let recording = TezosRpc::from_context(TezosRpcContext::new(
+            Default::default(),
+            RecordingClient::new_with_fixtures_dir(
+                HttpClient::new(server.base_url()),
+                &fixtures_dir,
+            ),
+        ));
+        let chain_id = recording.get_chain_id().send().await?;
+        let hash = recording.inject_operation("aabb").send().await?;
+        let result: Result<Value> = RecordingClient::new_with_fixtures_dir(
+            HttpClient::new(server.base_url()),
+            &fixtures_dir,
+        )
+        .get("/missing")
+        .await;
+        assert!(matches!(result, Err(Error::NotFound { .. })));
+
+        let replay = rpc(ReplayClient::new_with_fixtures_dir(&fixtures_dir));
+        assert_eq!(replay.get_chain_id().send().await?, chain_id);
+        assert_eq!(replay.inject_operation("aabb").send().await?, hash);
+        let result: Result<Value> = ReplayClient::new_with_fixtures_dir(&fixtures_dir)
+            .get("/missing")
+            .await;
+        assert!(
+            matches!(result, Err(Error::NotFound { description }) if description == "Not found")
+        );
+
+        fs::remove_dir_all(fixtures_dir)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,644 @@
+mod checkpoint;
+mod handler;
+
+pub use self::{
+    checkpoint::{Checkpoint, CheckpointStore, MemoryCheckpointStore},
+    handler::{BlockHandler, OperationContext},
+};
+
+use std::{collections::VecDeque, future::Future};
+
+use futures_util::stream::{FuturesOrdered, StreamExt};
+
+use crate::{
+    client::TezosRpc,
+    http::Http,
+    models::block::{Block, BlockId},
+    Error, Result,
+};
+
+/// The default number of blocks fetched concurrently.
+const DEFAULT_CONCURRENCY: usize = 4;
+/// The default number of processed blocks remembered to find a common ancestor after a reorganization.
+const DEFAULT_MAX_REORG_DEPTH: usize = 20;
+
+/// Processes the blocks of a chain in order and passes them to a list of [BlockHandler]s.
+///
+/// Blocks are fetched concurrently with [TezosRpc::get_block] but delivered to the handlers in order. After each
+/// block, a [Checkpoint] is saved with the [CheckpointStore], from which indexing resumes.
+///
+/// When a block doesn't follow the last processed one, the chain has been reorganized: the indexer looks for the
+/// last processed block which is still part of the chain, notifies the handlers with [BlockHandler::on_rollback]
+/// and resumes from there.
+///
+/// ```rust
+/// use std::future::Future;
+/// use tezos_rpc::{
+///     client::TezosRpc,
+///     indexer::{BlockHandler, Indexer, MemoryCheckpointStore, OperationContext},
+///     models::operation::operation_contents_and_result::transaction::Transaction,
+///     Result,
+/// };
+///
+/// struct Transfers;
+///
+/// #[async_trait::async_trait]
+/// impl BlockHandler for Transfers {
+///     async fn on_transaction(&mut self, context: OperationContext<'_>, transaction: &Transaction) -> Result<()> {
+///         println!("{}: {:?}", context.block.header.level, transaction.amount);
+///         Ok(())
+///     }
+/// }
+///
+/// async fn index<Fut: Future<Output = bool>>(wait_for_next_block: impl FnMut() -> Fut) -> Result<()> {
+///     let rpc = TezosRpc::new("https://testnet-tezos.giganode.io".into());
+///     let mut indexer = Indexer::new(&rpc, MemoryCheckpointStore::default()).handler(Transfers);
+///     indexer.index_range(100, 200).await?;
+///     indexer.follow(wait_for_next_block).await
+/// }
+/// ```
+pub struct Indexer<'a, HttpClient: Http, Store: CheckpointStore> {
+    rpc: &'a TezosRpc<HttpClient>,
+    store: Store,
+    handlers: Vec<Box<dyn BlockHandler + 'a>>,
+    concurrency: usize,
+    max_reorg_depth: usize,
+    history: VecDeque<Checkpoint>,
+    loaded: bool,
+}
+
+impl<'a, HttpClient: Http, Store: CheckpointStore> Indexer<'a, HttpClient, Store> {
+    pub fn new(rpc: &'a TezosRpc<HttpClient>, store: Store) -> Self {
+        Self {
+            rpc,
+            store,
+            handlers: vec![],
+            concurrency: DEFAULT_CONCURRENCY,
+            max_reorg_depth: DEFAULT_MAX_REORG_DEPTH,
+            history: VecDeque::new(),
+            loaded: false,
+        }
+    }
+
+    /// Adds a handler, called after the previously added ones.
+    pub fn handler<Handler: BlockHandler + 'a>(mut self, handler: Handler) -> Self {
+        self.handlers.push(Box::new(handler));
+
+        self
+    }
+
+    /// Sets the maximum number of blocks fetched at the same time.
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+
+        self
+    }
+
+    /// Sets the maximum number of blocks which can be rolled back after a reorganization.
+    pub fn max_reorg_depth(mut self, max_reorg_depth: usize) -> Self {
+        self.max_reorg_depth = max_reorg_depth.max(1);
+
+        self
+    }
+
+    pub fn store(&self) -> &Store {
+        &self.store
+    }
+
+    /// The last processed block, once the indexer has started.
+    pub fn checkpoint(&self) -> Option<&Checkpoint> {
+        self.history.back()
+    }
+
+    /// Processes the blocks from level `from` to level `to`, both included.
+    ///
+    /// Levels up to the checkpoint are skipped, so that an interrupted range can be resumed.
+    pub async fn index_range(&mut self, from: i32, to: i32) -> Result<()> {
+        self.load().await?;
+        let rpc = self.rpc;
+        let mut next = match self.checkpoint() {
+            Some(checkpoint) => from.max(checkpoint.level + 1),
+            None => from,
+        };
+        let mut pending = FuturesOrdered::new();
+        loop {
+            while pending.len() < self.concurrency && next <= to {
+                pending.push_back(fetch_block(rpc, next));
+                next += 1;
+            }
+            let block = match pending.next().await {
+                Some(block) => block?,
+                None => return Ok(()),
+            };
+            if let Some(ancestor) = self.process(&block).await? {
+                pending = FuturesOrdered::new();
+                next = ancestor.level + 1;
+            }
+        }
+    }
+
+    /// Processes the blocks following the checkpoint up to the current head. Without a checkpoint, only the head
+    /// is processed.
+    ///
+    /// If the head is not above the checkpoint, it must be one of the processed blocks: otherwise the chain has been
+    /// reorganized to a shorter branch and the indexer rolls back to the common ancestor first.
+    ///
+    /// Returns the level of the head.
+    pub async fn index_to_head(&mut self) -> Result<i32> {
+        self.load().await?;
+        let head = self.rpc.get_block().send().await?;
+        let level = head.header.level;
+        match self.checkpoint().map(|checkpoint| checkpoint.level) {
+            Some(last) if last < level => self.index_range(last + 1, level).await?,
+            Some(_) => {
+                let known = self
+                    .history
+                    .iter()
+                    .any(|checkpoint| checkpoint.level == level && checkpoint.hash == head.hash);
+                if !known {
+                    let ancestor = self.rollback(level).await?;
+                    self.index_range(ancestor.level + 1, level).await?;
+                }
+            }
+            None => {
+                self.process(&head).await?;
+            }
+        }
+
+        Ok(level)
+    }
+
+    /// Follows the head of the chain, calling [Indexer::index_to_head] and then awaiting `wait` until it resolves
+    /// to `false` or an error occurs.
+    pub async fn follow<F, Fut>(&mut self, mut wait: F) -> Result<()>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = bool>,
+    {
+        loop {
+            self.index_to_head().await?;
+            if !wait().await {
+                return Ok(());
+            }
+        }
+    }
+
+    async fn load(&mut self) -> Result<()> {
+        if !self.loaded {
+            if let Some(checkpoint) = self.store.load().await? {
+                self.history.push_back(checkpoint);
+            }
+            self.loaded = true;
+        }
+
+        Ok(())
+    }
+
+    /// Passes `block` to the handlers if it follows the checkpoint. Otherwise, rolls back to the common ancestor
+    /// and returns it.
+    async fn process(&mut self, block: &Block) -> Result<Option<Checkpoint>> {
+        if let Some(checkpoint) = self.checkpoint() {
+            if block.header.level <= checkpoint.level {
+                return Ok(None);
+            }
+            if block.header.level == checkpoint.level + 1
+                && block.header.predecessor != checkpoint.hash
+            {
+                return self.rollback(block.header.level).await.map(Some);
+            }
+        }
+        for handler in self.handlers.iter_mut() {
+            handler::dispatch(handler.as_mut(), block).await?;
+        }
+        self.save(block.into()).await?;
+
+        Ok(None)
+    }
+
+    /// Rolls back to the last processed block below `level` which is still part of the chain. The history is left
+    /// untouched if there is none.
+    async fn rollback(&mut self, level: i32) -> Result<Checkpoint> {
+        let mut position = None;
+        for (index, checkpoint) in self.history.iter().enumerate().rev() {
+            if checkpoint.level >= level {
+                continue;
+            }
+            let hash = self
+                .rpc
+                .get_block_hash()
+                .block_id(&BlockId::Level(checkpoint.level))
+                .send()
+                .await?;
+            if hash == checkpoint.hash {
+                position = Some(index);
+                break;
+            }
+        }
+        let position = position.ok_or(Error::ReorgTooDeep { level })?;
+        self.history.truncate(position + 1);
+        let ancestor = self.history[position].clone();
+        for handler in self.handlers.iter_mut() {
+            handler.on_rollback(&ancestor).await?;
+        }
+        self.store.save(&ancestor).await?;
+
+        Ok(ancestor)
+    }
+
+    async fn save(&mut self, checkpoint: Checkpoint) -> Result<()> {
+        self.store.save(&checkpoint).await?;
+        self.history.push_back(checkpoint);
+        if self.history.len() > self.max_reorg_depth {
+            self.history.pop_front();
+        }
+
+        Ok(())
+    }
+}
+
+async fn fetch_block<HttpClient: Http>(rpc: &TezosRpc<HttpClient>, level: i32) -> Result<Block> {
+    rpc.get_block()
+        .block_id(&BlockId::Level(level))
+        .send()
+        .await
+}
+
+#[cfg(all(test, feature = "http"))]
+mod tests {
+    use {
+        super::*,
+        crate::{
+            client::TezosRpcChainId,
+            models::operation::{
+                operation_contents_and_result::transaction::Transaction,
+                operation_result::operations::{
+                    delegation::InternalDelegationOperationResult,
+                    origination::InternalOriginationOperationResult,
+                    transaction::InternalTransactionOperationResult,
+                },
+            },
+        },
+        httpmock::prelude::*,
+        serde_json::{json, Value},
+        std::sync::{Arc, Mutex},
+        tezos_core::types::encoded::{Address, BlockHash, Encoded},
+    };
+
+    const CONTRACT: &str = "KT1HNqxFJxnmUcX8wF915wxxaAAU4ixDwWQ7";
+
+    struct Recorder {
+        destinations: Vec<Address>,
+        events: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl Recorder {
+        fn record(&self, event: String) {
+            self.events.lock().unwrap().push(event);
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl BlockHandler for Recorder {
+        fn destinations(&self) -> Option<&[Address]> {
+            Some(&self.destinations)
+        }
+
+        async fn on_block(&mut self, block: &Block) -> Result<()> {
+            self.record(format!("block {}", block.header.level));
+            Ok(())
+        }
+
+        async fn on_transaction(
+            &mut self,
+            context: OperationContext<'_>,
+            transaction: &Transaction,
+        ) -> Result<()> {
+            self.record(format!(
+                "transaction {} {}",
+                context.block.header.level,
+                transaction.destination.value()
+            ));
+            Ok(())
+        }
+
+        async fn on_internal_transaction(
+            &mut self,
+            context: OperationContext<'_>,
+            transaction: &InternalTransactionOperationResult,
+        ) -> Result<()> {
+            self.record(format!(
+                "internal transaction {} {}",
+                context.block.header.level,
+                transaction.destination.value()
+            ));
+            Ok(())
+        }
+
+        async fn on_internal_origination(
+            &mut self,
+            context: OperationContext<'_>,
+            _origination: &InternalOriginationOperationResult,
+        ) -> Result<()> {
+            self.record(format!(
+                "internal origination {}",
+                context.block.header.level
+            ));
+            Ok(())
+        }
+
+        async fn on_internal_delegation(
+            &mut self,
+            context: OperationContext<'_>,
+            _delegation: &InternalDelegationOperationResult,
+        ) -> Result<()> {
+            self.record(format!(
+                "internal delegation {}",
+                context.block.header.level
+            ));
+            Ok(())
+        }
+
+        async fn on_rollback(&mut self, checkpoint: &Checkpoint) -> Result<()> {
+            self.record(format!("rollback {}", checkpoint.level));
+            Ok(())
+        }
+    }
+
+    fn block(level: i32, hash: &str, predecessor: &str, destinations: &[&str]) -> String {
+        let mut block: Value = serde_json::from_str(include_str!(
+            "protocol_rpc/block/__TEST_DATA__/block_genesis.json"
+        ))
+        .unwrap();
+        block["hash"] = json!(hash);
+        block["header"]["level"] = json!(level);
+        block["header"]["predecessor"] = json!(predecessor);
+        let contents = destinations
+            .iter()
+            .map(|destination| {
+                json!({
+                    "kind": "transaction",
+                    "source": "tz1YY1LvD6TFH4z74pvxPQXBjAKHE5tB5Q8f",
+                    "fee": "1000",
+                    "counter": "11",
+                    "gas_limit": "10000",
+                    "storage_limit": "100",
+                    "amount": "10",
+                    "destination": destination,
+                    "metadata": {
+                        "balance_updates": [],
+                        "operation_result": { "status": "applied" }
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+        block["operations"] =
+            json!([[], [], [], [{ "branch": predecessor, "contents": contents }]]);
+
+        block.to_string()
+    }
+
+    fn mock_block(server: &MockServer, level: i32, body: String) -> httpmock::Mock<'_> {
+        server.mock(|when, then| {
+            when.method(GET).path(format!(
+                "/chains/{}/blocks/{}",
+                TezosRpcChainId::Main.value(),
+                level
+            ));
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(body);
+        })
+    }
+
+    fn mock_head(server: &MockServer, body: String) -> httpmock::Mock<'_> {
+        server.mock(|when, then| {
+            when.method(GET).path(format!(
+                "/chains/{}/blocks/head",
+                TezosRpcChainId::Main.value()
+            ));
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(body);
+        })
+    }
+
+    fn mock_hash(server: &MockServer, level: i32, hash: &str) {
+        server.mock(|when, then| {
+            when.method(GET).path(format!(
+                "/chains/{}/blocks/{}/hash",
+                TezosRpcChainId::Main.value(),
+                level
+            ));
+            then.status(200)
+                .header("content-type", "application/json")
+                .json_body(json!(hash));
+        });
+    }
+
+    #[tokio::test]
+    async fn test_index_range_with_reorg() -> Result<()> {
+        let server = MockServer::start();
+        let rpc = TezosRpc::new(server.base_url());
+
+        let h0 = "BLockGenesisGenesisGenesisGenesisGenesisf79b5d1CoW2";
+        let h1 = "BL5cbRjCJc67gFTTwq38hJ5TvXNfoR2h3LxoBjpuAKbcyYRrm3B";
+        let h2 = "BKxC1UxT3AsXSTKdFrbrRyeqhYfHunBDDhbXypAe7u5PgcrK9gJ";
+        let h3 = "BLNyzBgdqn1t8mjCFnMBXKM6j2e6gcHAJ7muPboHdDWyBtobCpo";
+        let h2r = "BLR9nvDjPdnFRWty33rwHD7NRPDQXaoTkpYXbUpCtZ1NZLUidQa";
+        let h3r = "BMSmSazNVo2naiAYuWNh1UpgXNQiQK9CU1EDUxuwBmKC1pzehS9";
+        let h4r = "BLh3HAaEEGLc7HU4ZfYEpCPRSTZ8cSMwznWgP6JLLHgCtdVShgh";
+        let other = "tz1PwXjsrgYBi9wpe3tFhazJpt7JMTVzBp5c";
+
+        mock_block(&server, 1, block(1, h1, h0, &[]));
+        let mut block_2 = mock_block(&server, 2, block(2, h2, h1, &[CONTRACT, other]));
+        let mut block_3 = mock_block(&server, 3, block(3, h3, h2, &[]));
+
+        let events = Arc::new(Mutex::new(vec![]));
+        let recorder = Recorder {
+            destinations: vec![CONTRACT.try_into()?],
+            events: events.clone(),
+        };
+        let mut indexer = Indexer::new(&rpc, MemoryCheckpointStore::default())
+            .handler(recorder)
+            .concurrency(2);
+        indexer.index_range(1, 3).await?;
+        assert_eq!(
+            events.lock().unwrap().drain(..).collect::<Vec<_>>(),
+            vec![
+                "block 1".to_string(),
+                "block 2".into(),
+                format!("transaction 2 {}", CONTRACT),
+                "block 3".into(),
+            ]
+        );
+
+        block_2.delete();
+        block_3.delete();
+        mock_block(&server, 2, block(2, h2r, h1, &[]));
+        mock_block(&server, 3, block(3, h3r, h2r, &[CONTRACT]));
+        mock_block(&server, 4, block(4, h4r, h3r, &[]));
+        mock_hash(&server, 1, h1);
+        mock_hash(&server, 2, h2r);
+        mock_hash(&server, 3, h3r);
+
+        indexer.index_range(1, 4).await?;
+        assert_eq!(
+            events.lock().unwrap().drain(..).collect::<Vec<_>>(),
+            vec![
+                "rollback 1".to_string(),
+                "block 2".into(),
+                "block 3".into(),
+                format!("transaction 3 {}", CONTRACT),
+                "block 4".into(),
+            ]
+        );
+        let hash: BlockHash = h4r.try_into()?;
+        assert_eq!(
+            indexer.checkpoint(),
+            Some(&Checkpoint::new(4, hash.clone()))
+        );
+        assert_eq!(
+            indexer.store().checkpoint(),
+            Some(&Checkpoint::new(4, hash))
+        );
+
+        let mut resumed = Indexer::new(&rpc, indexer.store().clone());
+        mock_block(&server, 5, block(5, h2, h3, &[]));
+        mock_hash(&server, 4, h1);
+        assert!(matches!(
+            resumed.index_range(5, 5).await,
+            Err(Error::ReorgTooDeep { level: 5 })
+        ));
+        assert_eq!(
+            resumed.checkpoint(),
+            Some(&Checkpoint::new(4, h4r.try_into()?))
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_index_to_head_with_reorg() -> Result<()> {
+        let server = MockServer::start();
+        let rpc = TezosRpc::new(server.base_url());
+
+        let h0 = "BLockGenesisGenesisGenesisGenesisGenesisf79b5d1CoW2";
+        let h1 = "BL5cbRjCJc67gFTTwq38hJ5TvXNfoR2h3LxoBjpuAKbcyYRrm3B";
+        let h2 = "BKxC1UxT3AsXSTKdFrbrRyeqhYfHunBDDhbXypAe7u5PgcrK9gJ";
+        let h3 = "BLNyzBgdqn1t8mjCFnMBXKM6j2e6gcHAJ7muPboHdDWyBtobCpo";
+        let h2r = "BLR9nvDjPdnFRWty33rwHD7NRPDQXaoTkpYXbUpCtZ1NZLUidQa";
+
+        mock_block(&server, 1, block(1, h1, h0, &[]));
+        let mut block_2 = mock_block(&server, 2, block(2, h2, h1, &[]));
+        let mut head = mock_head(&server, block(3, h3, h2, &[]));
+        mock_block(&server, 3, block(3, h3, h2, &[]));
+
+        let events = Arc::new(Mutex::new(vec![]));
+        let recorder = Recorder {
+            destinations: vec![CONTRACT.try_into()?],
+            events: events.clone(),
+        };
+        let mut indexer = Indexer::new(&rpc, MemoryCheckpointStore::default()).handler(recorder);
+        indexer.index_range(1, 2).await?;
+        assert_eq!(indexer.index_to_head().await?, 3);
+        assert_eq!(
+            events.lock().unwrap().drain(..).collect::<Vec<_>>(),
+            vec!["block 1", "block 2", "block 3"]
+        );
+
+        head.delete();
+        block_2.delete();
+        mock_head(&server, block(2, h2r, h1, &[]));
+        mock_block(&server, 2, block(2, h2r, h1, &[]));
+        mock_hash(&server, 1, h1);
+
+        assert_eq!(indexer.index_to_head().await?, 2);
+        assert_eq!(
+            events.lock().unwrap().drain(..).collect::<Vec<_>>(),
+            vec!["rollback 1", "block 2"]
+        );
+        assert_eq!(
+            indexer.checkpoint(),
+            Some(&Checkpoint::new(2, h2r.try_into()?))
+        );
+
+        assert_eq!(indexer.index_to_head().await?, 2);
+        assert!(events.lock().unwrap().is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_internal_operation_results() -> Result<()> {
+        let h0 = "BLockGenesisGenesisGenesisGenesisGenesisf79b5d1CoW2";
+        let h1 = "BL5cbRjCJc67gFTTwq38hJ5TvXNfoR2h3LxoBjpuAKbcyYRrm3B";
+        let source = "tz1YY1LvD6TFH4z74pvxPQXBjAKHE5tB5Q8f";
+        let internal_results = json!([
+            {
+                "kind": "transaction",
+                "source": CONTRACT,
+                "nonce": 0,
+                "amount": "1",
+                "destination": CONTRACT,
+                "result": { "status": "applied" }
+            },
+            {
+                "kind": "origination",
+                "source": CONTRACT,
+                "nonce": 1,
+                "balance": "0",
+                "result": { "status": "applied" }
+            },
+            {
+                "kind": "delegation",
+                "source": CONTRACT,
+                "nonce": 2,
+                "result": { "status": "backtracked" }
+            }
+        ]);
+
+        let mut block: Value = serde_json::from_str(&block(1, h1, h0, &[CONTRACT])).unwrap();
+        let contents = &mut block["operations"][3][0]["contents"];
+        contents[0]["metadata"]["internal_operation_results"] = internal_results.clone();
+        contents.as_array_mut().unwrap().push(json!({
+            "kind": "transfer_ticket",
+            "source": source,
+            "fee": "1000",
+            "counter": "12",
+            "gas_limit": "10000",
+            "storage_limit": "100",
+            "ticket_contents": { "string": "ticket" },
+            "ticket_ty": { "prim": "string" },
+            "ticket_ticketer": CONTRACT,
+            "ticket_amount": "1",
+            "destination": CONTRACT,
+            "entrypoint": "default",
+            "metadata": {
+                "balance_updates": [],
+                "operation_result": { "status": "applied" },
+                "internal_operation_results": internal_results
+            }
+        }));
+        let block: Block = serde_json::from_value(block)?;
+
+        let events = Arc::new(Mutex::new(vec![]));
+        let mut recorder = Recorder {
+            destinations: vec![CONTRACT.try_into()?],
+            events: events.clone(),
+        };
+        handler::dispatch(&mut recorder, &block).await?;
+        assert_eq!(
+            events.lock().unwrap().drain(..).collect::<Vec<_>>(),
+            vec![
+                "block 1".to_string(),
+                format!("transaction 1 {}", CONTRACT),
+                format!("internal transaction 1 {}", CONTRACT),
+                "internal origination 1".into(),
+                format!("internal transaction 1 {}", CONTRACT),
+                "internal origination 1".into(),
+            ]
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,62 @@
+use async_trait::async_trait;
+use tezos_core::types::encoded::BlockHash;
+
+use crate::{models::block::Block, Result};
+
+/// The last block processed by an [Indexer](super::Indexer).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Checkpoint {
+    pub level: i32,
+    pub hash: BlockHash,
+}
+
+impl Checkpoint {
+    pub fn new(level: i32, hash: BlockHash) -> Self {
+        Self { level, hash }
+    }
+}
+
+impl From<&Block> for Checkpoint {
+    fn from(block: &Block) -> Self {
+        Self::new(block.header.level, block.hash.clone())
+    }
+}
+
+/// A storage for the [Checkpoint] of an [Indexer](super::Indexer), so that indexing can be resumed after a restart.
+#[async_trait]
+pub trait CheckpointStore {
+    /// Loads the last saved checkpoint, if any.
+    async fn load(&self) -> Result<Option<Checkpoint>>;
+
+    /// Saves `checkpoint`, replacing the previous one.
+    async fn save(&mut self, checkpoint: &Checkpoint) -> Result<()>;
+}
+
+/// A [CheckpointStore] keeping the checkpoint in memory.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryCheckpointStore {
+    checkpoint: Option<Checkpoint>,
+}
+
+impl MemoryCheckpointStore {
+    pub fn new(checkpoint: Option<Checkpoint>) -> Self {
+        Self { checkpoint }
+    }
+
+    pub fn checkpoint(&self) -> Option<&Checkpoint> {
+        self.checkpoint.as_ref()
+    }
+}
+
+#[async_trait]
+impl CheckpointStore for MemoryCheckpointStore {
+    async fn load(&self) -> Result<Option<Checkpoint>> {
+        Ok(self.checkpoint.clone())
+    }
+
+    async fn save(&mut self, checkpoint: &Checkpoint) -> Result<()> {
+        self.checkpoint = Some(checkpoint.clone());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,203 @@
+use async_trait::async_trait;
+use tezos_core::types::encoded::Address;
+
+use crate::{
+    models::{
+        block::Block,
+        operation::{
+            operation_contents_and_result::{
+                delegation::Delegation, origination::Origination, transaction::Transaction,
+            },
+            operation_result::{
+                operations::{
+                    delegation::InternalDelegationOperationResult,
+                    origination::InternalOriginationOperationResult,
+                    transaction::InternalTransactionOperationResult, InternalOperationResult,
+                },
+                OperationResultStatus,
+            },
+            Operation, OperationContent,
+        },
+    },
+    Result,
+};
+
+use super::Checkpoint;
+
+/// The block and the operation an operation content belongs to.
+#[derive(Debug, Clone, Copy)]
+pub struct OperationContext<'a> {
+    pub block: &'a Block,
+    pub operation: &'a Operation,
+}
+
+/// Receives the blocks processed by an [Indexer](super::Indexer).
+///
+/// Only the operation contents and internal operation results which have been applied are passed to the per-kind
+/// methods, which do nothing by default.
+#[async_trait]
+pub trait BlockHandler: Send {
+    /// The contracts whose incoming transactions should be passed to [BlockHandler::on_transaction] and
+    /// [BlockHandler::on_internal_transaction]. All transactions are passed if `None`.
+    fn destinations(&self) -> Option<&[Address]> {
+        None
+    }
+
+    /// Called for every block, before its operations are dispatched.
+    async fn on_block(&mut self, _block: &Block) -> Result<()> {
+        Ok(())
+    }
+
+    async fn on_transaction(
+        &mut self,
+        _context: OperationContext<'_>,
+        _transaction: &Transaction,
+    ) -> Result<()> {
+        Ok(())
+    }
+
+    async fn on_internal_transaction(
+        &mut self,
+        _context: OperationContext<'_>,
+        _transaction: &InternalTransactionOperationResult,
+    ) -> Result<()> {
+        Ok(())
+    }
+
+    async fn on_origination(
+        &mut self,
+        _context: OperationContext<'_>,
+        _origination: &Origination,
+    ) -> Result<()> {
+        Ok(())
+    }
+
+    async fn on_internal_origination(
+        &mut self,
+        _context: OperationContext<'_>,
+        _origination: &InternalOriginationOperationResult,
+    ) -> Result<()> {
+        Ok(())
+    }
+
+    async fn on_delegation(
+        &mut self,
+        _context: OperationContext<'_>,
+        _delegation: &Delegation,
+    ) -> Result<()> {
+        Ok(())
+    }
+
+    async fn on_internal_delegation(
+        &mut self,
+        _context: OperationContext<'_>,
+        _delegation: &InternalDelegationOperationResult,
+    ) -> Result<()> {
+        Ok(())
+    }
+
+    /// Called when the chain has been reorganized, the blocks following `checkpoint` have been discarded and
+    /// will be replaced by the ones of the new branch.
+    async fn on_rollback(&mut self, _checkpoint: &Checkpoint) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Passes `block` and its applied operation contents to `handler`.
+pub(super) async fn dispatch<Handler: BlockHandler + ?Sized>(
+    handler: &mut Handler,
+    block: &Block,
+) -> Result<()> {
+    handler.on_block(block).await?;
+    for operation in block.operations.iter().flatten() {
+        let context = OperationContext { block, operation };
+        for content in &operation.contents {
+            match content {
+                OperationContent::Transaction(transaction) => {
+                    let metadata = match &transaction.metadata {
+                        Some(metadata) => metadata,
+                        None => continue,
+                    };
+                    if is_applied(metadata.operation_result.status)
+                        && is_destination(handler, &transaction.destination)
+                    {
+                        handler.on_transaction(context, transaction).await?;
+                    }
+                    dispatch_internal(handler, context, &metadata.internal_operation_results)
+                        .await?;
+                }
+                OperationContent::Origination(origination) => {
+                    let applied = matches!(&origination.metadata, Some(metadata) if is_applied(metadata.operation_result.status));
+                    if applied {
+                        handler.on_origination(context, origination).await?;
+                    }
+                }
+                OperationContent::Delegation(delegation) => {
+                    let applied = matches!(&delegation.metadata, Some(metadata) if is_applied(metadata.operation_result.status));
+                    if applied {
+                        handler.on_delegation(context, delegation).await?;
+                    }
+                }
+                OperationContent::TransferTicket(transfer_ticket) => {
+                    if let Some(metadata) = &transfer_ticket.metadata {
+                        dispatch_internal(handler, context, &metadata.internal_operation_results)
+                            .await?;
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Passes the applied internal operation results emitted by an operation content to `handler`.
+async fn dispatch_internal<Handler: BlockHandler + ?Sized>(
+    handler: &mut Handler,
+    context: OperationContext<'_>,
+    results: &[InternalOperationResult],
+) -> Result<()> {
+    for result in results {
+        match result {
+            InternalOperationResult::Transaction(transaction) => {
+                let applied =
+                    matches!(&transaction.result, Some(result) if is_applied(result.status));
+                if applied && is_destination(handler, &transaction.destination) {
+                    handler
+                        .on_internal_transaction(context, transaction)
+                        .await?;
+                }
+            }
+            InternalOperationResult::Origination(origination) => {
+                if is_applied(origination.result.status) {
+                    handler
+                        .on_internal_origination(context, origination)
+                        .await?;
+                }
+            }
+            InternalOperationResult::Delegation(delegation) => {
+                if is_applied(delegation.result.status) {
+                    handler.on_internal_delegation(context, delegation).await?;
+                }
+            }
+            InternalOperationResult::Unknown(_) => {}
+        }
+    }
+
+    Ok(())
+}
+
+fn is_applied(status: OperationResultStatus) -> bool {
+    status == OperationResultStatus::Applied
+}
+
+fn is_destination<Handler: BlockHandler + ?Sized>(
+    handler: &Handler,
+    destination: &Address,
+) -> bool {
+    match handler.destinations() {
+        Some(destinations) => destinations.contains(destination),
+        None => true,
+    }
+}
@@ -4,6 +4,7 @@ mod register_global_constant;
 mod reveal;
 mod set_deposits_limit;
 mod transaction;
+mod transfer_ticket;
 
 use async_trait::async_trait;
 use num_bigint::BigUint;
@@ -91,6 +92,7 @@ impl FeeOperationLimitsApplier for tezos_operation::operations::OperationContent
     fn apply(self, fee: Option<Mutez>, limits: &OperationLimits) -> Self {
         use tezos_operation::operations::{
             Delegation, Origination, RegisterGlobalConstant, Reveal, SetDepositsLimit, Transaction,
+            TransferTicket,
         };
         if self.has_fee() {
             return self;
@@ -150,6 +152,19 @@ impl FeeOperationLimitsApplier for tezos_operation::operations::OperationContent
                 storage_limit: limits.storage.clone().into(),
                 limit: value.limit,
             }),
+            Self::TransferTicket(value) => Self::TransferTicket(TransferTicket {
+                source: value.source,
+                fee: fee.unwrap_or_default(),
+                counter: value.counter,
+                gas_limit: limits.gas.clone().into(),
+                storage_limit: limits.storage.clone().into(),
+                ticket_contents: value.ticket_contents,
+                ticket_ty: value.ticket_ty,
+                ticket_ticketer: value.ticket_ticketer,
+                ticket_amount: value.ticket_amount,
+                destination: value.destination,
+                entrypoint: value.entrypoint,
+            }),
             _ => self,
         }
     }
@@ -235,6 +250,10 @@ fn operation_content_limits(
             gas: value.gas_limit.clone().into(),
             storage: value.storage_limit.clone().into(),
         },
+        OperationContent::TransferTicket(value) => OperationLimits {
+            gas: value.gas_limit.clone().into(),
+            storage: value.storage_limit.clone().into(),
+        },
         _ => OperationLimits::zero(),
     }
 }
@@ -319,6 +338,10 @@ fn operation_content_matches(
         | (
             tezos_operation::operations::OperationContent::SetDepositsLimit(_),
             OperationContent::SetDepositsLimit(_),
+        )
+        | (
+            tezos_operation::operations::OperationContent::TransferTicket(_),
+            OperationContent::TransferTicket(_),
         ) => true,
         _ => false,
     }
@@ -357,6 +380,11 @@ impl OperationContent {
                 .as_ref()
                 .map(|metadata| metadata.limits())
                 .map_or(Ok(None), |r| r.map(Some)),
+            Self::TransferTicket(value) => value
+                .metadata
+                .as_ref()
+                .map(|metadata| metadata.limits())
+                .map_or(Ok(None), |r| r.map(Some)),
             _ => Ok(None),
         }
     }
@@ -393,6 +421,7 @@ impl InternalOperationResult {
                 .map_or(Ok(OperationLimits::zero()), |result| result.limits()),
             Self::Origination(value) => value.result.limits(),
             Self::Delegation(value) => value.result.limits(),
+            Self::Unknown(_) => Ok(OperationLimits::zero()),
         }
     }
 }
@@ -518,6 +547,7 @@ mod test {
                             paid_storage_size_diff: None,
                             allocated_destination_contract: None,
                             lazy_storage_diff: None,
+                            ticket_updates: None,
                             errors: None
                         },
                         balance_updates: vec![],
@@ -0,0 +1,62 @@
+use crate::models::{
+    error::RpcError,
+    operation::{
+        operation_contents_and_result::transfer_ticket::TransferTicketMetadata,
+        operation_result::{
+            operations::{transfer_ticket::TransferTicketOperationResult, InternalOperationResult},
+            OperationResultStatus,
+        },
+    },
+};
+
+use super::{RpcMetadata, RpcOperationResult};
+
+impl RpcOperationResult for TransferTicketOperationResult {
+    fn status(&self) -> OperationResultStatus {
+        self.status
+    }
+
+    fn number_of_originated_contracts(&self) -> usize {
+        0
+    }
+
+    fn consumed_gas(&self) -> num_bigint::BigUint {
+        self.consumed_gas
+            .as_ref()
+            .map_or(0u8.into(), |consumed_gas| {
+                consumed_gas.parse().unwrap_or(0u8.into())
+            })
+    }
+
+    fn consumed_milligas(&self) -> num_bigint::BigUint {
+        self.consumed_milligas
+            .as_ref()
+            .map_or(0u8.into(), |consumed_gas| {
+                consumed_gas.parse().unwrap_or(0u8.into())
+            })
+    }
+
+    fn paid_storage_size_diff(&self) -> Option<num_bigint::BigUint> {
+        self.paid_storage_size_diff
+            .as_ref()
+            .map(|paid_storage_size_diff| paid_storage_size_diff.parse().unwrap_or(0u8.into()))
+    }
+
+    fn allocated_destination_contract(&self) -> Option<bool> {
+        None
+    }
+
+    fn errors(&self) -> Option<&Vec<RpcError>> {
+        self.errors.as_ref()
+    }
+}
+
+impl RpcMetadata<TransferTicketOperationResult> for TransferTicketMetadata {
+    fn operation_result(&self) -> &TransferTicketOperationResult {
+        &self.operation_result
+    }
+
+    fn internal_operation_results(&self) -> Option<&Vec<InternalOperationResult>> {
+        Some(&self.internal_operation_results)
+    }
+}
@@ -61,11 +61,18 @@
 //!     Ok(())
 //! }
 //! ```
+//!
+//! ## Index Blocks
+//!
+//! An [Indexer](crate::indexer::Indexer) processes blocks in order, passes their applied operations to typed
+//! [BlockHandler](crate::indexer::BlockHandler)s, rolls back to the common ancestor on chain reorganizations and
+//! saves its progress with a [CheckpointStore](crate::indexer::CheckpointStore).
 
 pub mod client;
 pub mod constants;
 mod error;
 pub mod http;
+pub mod indexer;
 pub mod models;
 pub mod protocol_rpc;
 pub mod shell_rpc;
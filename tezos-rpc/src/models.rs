@@ -8,3 +8,5 @@ pub mod error;
 pub mod invalid_block;
 pub mod limits;
 pub mod operation;
+pub mod receipt;
+pub mod ticket;
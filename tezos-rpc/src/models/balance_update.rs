@@ -1,10 +1,31 @@
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[derive(Debug, Serialize, Clone, PartialEq)]
 #[serde(untagged)]
 pub enum BalanceUpdate {
     Contract(Contract),
     Categorized(CategorizedBalanceUpdate),
+    Unknown(serde_json::Value),
+}
+
+impl<'de> Deserialize<'de> for BalanceUpdate {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        // Protocols keep adding kinds, categories and origins: balance updates that can't be
+        // decoded fall back to `Unknown` rather than failing the whole block.
+        let value = serde_json::Value::deserialize(deserializer)?;
+        let kind = value
+            .get("kind")
+            .and_then(|kind| serde_json::from_value::<Kind>(kind.clone()).ok());
+        let balance_update = match kind {
+            Some(Kind::Contract) => serde_json::from_value(value.clone()).map(Self::Contract),
+            Some(_) => serde_json::from_value(value.clone()).map(Self::Categorized),
+            None => return Ok(Self::Unknown(value)),
+        };
+        Ok(balance_update.unwrap_or(Self::Unknown(value)))
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
@@ -87,3 +108,42 @@ pub struct CategorizedBalanceUpdate {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub revelation: Option<bool>,
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_unknown_balance_updates() -> Result<(), serde_json::Error> {
+        let balance_updates: Vec<BalanceUpdate> = serde_json::from_str(
+            r#"[
+                { "kind": "contract", "contract": "tz1YRG68NdqtAcsFEwTUw6FsSsiBb5kagEDo", "change": "-1000", "origin": "block" },
+                { "kind": "freezer", "category": "deposits", "delegate": "tz1YRG68NdqtAcsFEwTUw6FsSsiBb5kagEDo", "change": "1000", "origin": "block" },
+                { "kind": "contract", "contract": "tz1YRG68NdqtAcsFEwTUw6FsSsiBb5kagEDo", "change": "500", "origin": "delayed_operation", "delayed_operation_hash": "ooohBfUnCfHUQrza1aF5mCsY21rYFjzEEiNnQrK8ymzWsU1E1qD" },
+                { "kind": "freezer", "category": "unstaked_deposits", "staker": { "contract": "tz1YRG68NdqtAcsFEwTUw6FsSsiBb5kagEDo", "delegate": "tz1YRG68NdqtAcsFEwTUw6FsSsiBb5kagEDo" }, "cycle": 700, "change": "-500", "origin": "block" },
+                { "kind": "minted", "category": "attesting rewards", "change": "-8000", "origin": "block" },
+                { "kind": "staking", "category": "delegate_denominator", "delegate": "tz1YRG68NdqtAcsFEwTUw6FsSsiBb5kagEDo", "change": "1000", "origin": "block" }
+            ]"#,
+        )?;
+
+        assert!(matches!(balance_updates[0], BalanceUpdate::Contract(_)));
+        assert!(matches!(balance_updates[1], BalanceUpdate::Categorized(_)));
+        assert!(matches!(
+            &balance_updates[2],
+            BalanceUpdate::Unknown(value) if value["origin"] == "delayed_operation"
+        ));
+        assert!(matches!(
+            &balance_updates[3],
+            BalanceUpdate::Unknown(value) if value["category"] == "unstaked_deposits"
+        ));
+        assert!(matches!(
+            &balance_updates[4],
+            BalanceUpdate::Unknown(value) if value["category"] == "attesting rewards"
+        ));
+        assert!(matches!(
+            &balance_updates[5],
+            BalanceUpdate::Unknown(value) if value["kind"] == "staking"
+        ));
+        Ok(())
+    }
+}
@@ -51,6 +51,8 @@ pub struct Header {
     pub liquidity_baking_escape_vote: bool,
     #[serde(default)]
     pub liquidity_baking_toggle_vote: LiquidityBakingToggleVote,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub adaptive_issuance_vote: Option<AdaptiveIssuanceVote>,
     pub signature: Option<Signature>,
 }
 
@@ -76,6 +78,7 @@ impl From<tezos_operation::block_header::BlockHeader> for Header {
             seed_nonce_hash: value.seed_nonce_hash,
             liquidity_baking_escape_vote: Default::default(),
             liquidity_baking_toggle_vote: value.liquidity_baking_toggle_vote.into(),
+            adaptive_issuance_vote: Some(value.adaptive_issuance_vote.into()),
             signature: Some(value.signature),
         }
     }
@@ -103,6 +106,10 @@ impl TryFrom<Header> for tezos_operation::block_header::BlockHeader {
             proof_of_work_nonce: value.proof_of_work_nonce.try_into()?,
             seed_nonce_hash: value.seed_nonce_hash,
             liquidity_baking_toggle_vote: value.liquidity_baking_toggle_vote.into(),
+            adaptive_issuance_vote: value
+                .adaptive_issuance_vote
+                .map(|vote| vote.into())
+                .unwrap_or(tezos_operation::block_header::AdaptiveIssuanceVote::On),
             signature: value.signature.ok_or(Error::InvalidConversion)?,
         })
     }
@@ -142,6 +149,34 @@ impl From<LiquidityBakingToggleVote> for tezos_operation::block_header::Liquidit
     }
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum AdaptiveIssuanceVote {
+    On,
+    Off,
+    Pass,
+}
+
+impl From<tezos_operation::block_header::AdaptiveIssuanceVote> for AdaptiveIssuanceVote {
+    fn from(value: tezos_operation::block_header::AdaptiveIssuanceVote) -> Self {
+        match value {
+            tezos_operation::block_header::AdaptiveIssuanceVote::On => Self::On,
+            tezos_operation::block_header::AdaptiveIssuanceVote::Off => Self::Off,
+            tezos_operation::block_header::AdaptiveIssuanceVote::Pass => Self::Pass,
+        }
+    }
+}
+
+impl From<AdaptiveIssuanceVote> for tezos_operation::block_header::AdaptiveIssuanceVote {
+    fn from(value: AdaptiveIssuanceVote) -> Self {
+        match value {
+            AdaptiveIssuanceVote::On => Self::On,
+            AdaptiveIssuanceVote::Off => Self::Off,
+            AdaptiveIssuanceVote::Pass => Self::Pass,
+        }
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct FullHeader {
     pub protocol: ProtocolHash,
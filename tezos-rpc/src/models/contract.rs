@@ -1,7 +1,12 @@
 use tezos_core::types::encoded::ImplicitAddress;
 
 use {
-    crate::serde_utils,
+    crate::{
+        models::operation::operation_result::{
+            lazy_storage_diff::LazyStorageDiff, operations::InternalOperationResult,
+        },
+        serde_utils,
+    },
     num_bigint::BigInt,
     serde::{Deserialize, Serialize},
     std::collections::HashMap,
@@ -65,3 +70,16 @@ impl Default for UnparsingMode {
 pub struct ContractEntrypoints {
     pub entrypoints: HashMap<String, Micheline>,
 }
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RunCodeResult {
+    pub storage: Micheline,
+    pub operations: Vec<InternalOperationResult>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lazy_storage_diff: Option<Vec<LazyStorageDiff>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RunScriptViewResult {
+    pub data: Micheline,
+}
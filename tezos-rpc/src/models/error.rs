@@ -1,6 +1,12 @@
 use derive_more::Error;
 use serde::{Deserialize, Serialize};
 use std::fmt::Display;
+use tezos_core::types::{
+    encoded::{Address, ImplicitAddress},
+    mutez::Mutez,
+    number::Nat,
+};
+use tezos_michelson::micheline::Micheline;
 
 /// The error schema for all the RPC errors can be found at:
 ///
@@ -20,6 +26,30 @@ pub struct RpcError {
     pub balance: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub contract: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expected: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub found: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub implicit: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub with: Option<Micheline>,
+}
+
+impl RpcError {
+    const PROTOCOL_PREFIX: &'static str = "proto.";
+
+    /// Returns the error identifier without the protocol prefix,
+    /// e.g. `contract.counter_in_the_past` for `proto.014-PtKathma.contract.counter_in_the_past`.
+    pub fn short_id(&self) -> &str {
+        self.id
+            .strip_prefix(Self::PROTOCOL_PREFIX)
+            .and_then(|id| id.split_once('.'))
+            .map(|(_, id)| id)
+            .unwrap_or(&self.id)
+    }
 }
 
 impl Display for RpcError {
@@ -36,9 +66,20 @@ impl Display for RpcError {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RpcErrors(Vec<RpcError>);
 
+impl RpcErrors {
+    pub fn errors(&self) -> &[RpcError] {
+        &self.0
+    }
+
+    /// Returns the errors mapped into [NodeError]s.
+    pub fn node_errors(&self) -> Vec<NodeError> {
+        self.0.iter().map(Into::into).collect()
+    }
+}
+
 impl From<Vec<RpcError>> for RpcErrors {
     fn from(value: Vec<RpcError>) -> Self {
         Self(value)
@@ -59,3 +100,189 @@ impl Display for RpcErrors {
         Ok(())
     }
 }
+
+/// A typed representation of the well-known errors returned by a node.
+///
+/// Errors that are not modeled, or that are missing the expected payload, are kept as [NodeError::Unknown].
+#[derive(Debug, Clone, PartialEq)]
+pub enum NodeError {
+    CounterInThePast {
+        contract: ImplicitAddress,
+        expected: Nat,
+        found: Nat,
+    },
+    CounterInTheFuture {
+        contract: ImplicitAddress,
+        expected: Nat,
+        found: Nat,
+    },
+    BalanceTooLow {
+        contract: Address,
+        balance: Mutez,
+        amount: Mutez,
+    },
+    ScriptRejected {
+        location: Option<i64>,
+        with: Micheline,
+    },
+    GasExhaustedOperation,
+    GasExhaustedBlock,
+    StorageExhaustedOperation,
+    EmptyImplicitContract {
+        implicit: ImplicitAddress,
+    },
+    UnrevealedKey {
+        contract: ImplicitAddress,
+    },
+    InvalidSignature,
+    Unknown(Box<RpcError>),
+}
+
+impl NodeError {
+    const COUNTER_IN_THE_PAST: &'static str = "contract.counter_in_the_past";
+    const COUNTER_IN_THE_FUTURE: &'static str = "contract.counter_in_the_future";
+    const BALANCE_TOO_LOW: &'static str = "contract.balance_too_low";
+    const SCRIPT_REJECTED: &'static str = "michelson_v1.script_rejected";
+    const GAS_EXHAUSTED_OPERATION: &'static str = "gas_exhausted.operation";
+    const GAS_EXHAUSTED_BLOCK: &'static str = "gas_exhausted.block";
+    const STORAGE_EXHAUSTED_OPERATION: &'static str = "storage_exhausted.operation";
+    const EMPTY_IMPLICIT_CONTRACT: &'static str = "implicit.empty_implicit_contract";
+    const UNREVEALED_KEY: &'static str = "contract.unrevealed_key";
+    const INVALID_SIGNATURE: &'static str = "operation.invalid_signature";
+
+    fn from_rpc_error(error: &RpcError) -> Option<Self> {
+        fn parse<'a, T: TryFrom<&'a str>>(value: &'a Option<String>) -> Option<T> {
+            value.as_deref().and_then(|value| value.try_into().ok())
+        }
+
+        Some(match error.short_id() {
+            Self::COUNTER_IN_THE_PAST => Self::CounterInThePast {
+                contract: parse(&error.contract)?,
+                expected: parse(&error.expected)?,
+                found: parse(&error.found)?,
+            },
+            Self::COUNTER_IN_THE_FUTURE => Self::CounterInTheFuture {
+                contract: parse(&error.contract)?,
+                expected: parse(&error.expected)?,
+                found: parse(&error.found)?,
+            },
+            Self::BALANCE_TOO_LOW => Self::BalanceTooLow {
+                contract: parse(&error.contract)?,
+                balance: parse(&error.balance)?,
+                amount: parse(&error.amount)?,
+            },
+            Self::SCRIPT_REJECTED => Self::ScriptRejected {
+                location: error.location,
+                with: error.with.clone()?,
+            },
+            Self::GAS_EXHAUSTED_OPERATION => Self::GasExhaustedOperation,
+            Self::GAS_EXHAUSTED_BLOCK => Self::GasExhaustedBlock,
+            Self::STORAGE_EXHAUSTED_OPERATION => Self::StorageExhaustedOperation,
+            Self::EMPTY_IMPLICIT_CONTRACT => Self::EmptyImplicitContract {
+                implicit: parse(&error.implicit)?,
+            },
+            Self::UNREVEALED_KEY => Self::UnrevealedKey {
+                contract: parse(&error.contract)?,
+            },
+            Self::INVALID_SIGNATURE => Self::InvalidSignature,
+            _ => return None,
+        })
+    }
+}
+
+impl From<&RpcError> for NodeError {
+    fn from(value: &RpcError) -> Self {
+        Self::from_rpc_error(value).unwrap_or_else(|| Self::Unknown(Box::new(value.clone())))
+    }
+}
+
+impl From<RpcError> for NodeError {
+    fn from(value: RpcError) -> Self {
+        (&value).into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn node_errors(json: &str) -> Vec<NodeError> {
+        let errors: Vec<RpcError> = serde_json::from_str(json).unwrap();
+        RpcErrors::from(errors).node_errors()
+    }
+
+    #[test]
+    fn test_short_id() {
+        let error: RpcError = serde_json::from_str(
+            r#"{ "kind": "temporary", "id": "proto.014-PtKathma.contract.counter_in_the_past" }"#,
+        )
+        .unwrap();
+        assert_eq!(error.short_id(), "contract.counter_in_the_past");
+
+        let error: RpcError = serde_json::from_str(
+            r#"{ "kind": "permanent", "id": "node.bootstrap_pipeline.invalid_locator" }"#,
+        )
+        .unwrap();
+        assert_eq!(error.short_id(), "node.bootstrap_pipeline.invalid_locator");
+    }
+
+    #[test]
+    fn test_node_errors() {
+        let errors = node_errors(include_str!("__TEST_DATA__/node_errors.json"));
+
+        assert_eq!(
+            errors,
+            vec![
+                NodeError::CounterInThePast {
+                    contract: "tz1PwXjsrgYBi9wpe3tFhazJpt7JMTVzBp5c".try_into().unwrap(),
+                    expected: 1238u16.into(),
+                    found: 1237u16.into(),
+                },
+                NodeError::CounterInTheFuture {
+                    contract: "tz1PwXjsrgYBi9wpe3tFhazJpt7JMTVzBp5c".try_into().unwrap(),
+                    expected: 1238u16.into(),
+                    found: 1240u16.into(),
+                },
+                NodeError::BalanceTooLow {
+                    contract: "tz1PwXjsrgYBi9wpe3tFhazJpt7JMTVzBp5c".try_into().unwrap(),
+                    balance: 1000u16.into(),
+                    amount: 2000u16.into(),
+                },
+                NodeError::ScriptRejected {
+                    location: Some(96),
+                    with: serde_json::from_str(
+                        r#"{ "prim": "Pair", "args": [{ "string": "NOT_ENOUGH_BALANCE" }, { "int": "10" }] }"#,
+                    )
+                    .unwrap(),
+                },
+                NodeError::GasExhaustedOperation,
+                NodeError::GasExhaustedBlock,
+                NodeError::StorageExhaustedOperation,
+                NodeError::EmptyImplicitContract {
+                    implicit: "tz1PwXjsrgYBi9wpe3tFhazJpt7JMTVzBp5c".try_into().unwrap(),
+                },
+                NodeError::UnrevealedKey {
+                    contract: "tz1PwXjsrgYBi9wpe3tFhazJpt7JMTVzBp5c".try_into().unwrap(),
+                },
+                NodeError::InvalidSignature,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unknown_node_errors() {
+        let errors = node_errors(
+            r#"[
+                { "kind": "temporary", "id": "proto.014-PtKathma.michelson_v1.runtime_error" },
+                { "kind": "temporary", "id": "proto.014-PtKathma.contract.balance_too_low" }
+            ]"#,
+        );
+
+        assert!(
+            matches!(&errors[0], NodeError::Unknown(error) if error.short_id() == "michelson_v1.runtime_error")
+        );
+        assert!(
+            matches!(&errors[1], NodeError::Unknown(error) if error.short_id() == "contract.balance_too_low")
+        );
+    }
+}
@@ -32,7 +32,11 @@ use {
         operation_contents_and_result::tx_rollup_submit_batch::TxRollupSubmitBatch,
     },
     crate::{Error, Result},
-    serde::{Deserialize, Serialize},
+    kind::OperationKind,
+    serde::{
+        de::{self, DeserializeOwned},
+        Deserialize, Deserializer, Serialize,
+    },
     tezos_core::types::encoded::{BlockHash, ChainId, OperationHash, ProtocolHash, Signature},
 };
 
@@ -84,7 +88,31 @@ impl From<tezos_operation::operations::SignedOperation> for Operation {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+impl TryFrom<Operation> for tezos_operation::operations::UnsignedOperation {
+    type Error = Error;
+
+    fn try_from(value: Operation) -> Result<Self> {
+        Ok(Self::new(
+            value.branch,
+            value
+                .contents
+                .into_iter()
+                .map(|content| content.try_into())
+                .collect::<Result<Vec<_>>>()?,
+        ))
+    }
+}
+
+impl TryFrom<Operation> for tezos_operation::operations::SignedOperation {
+    type Error = Error;
+
+    fn try_from(value: Operation) -> Result<Self> {
+        let signature = value.signature.clone().ok_or(Error::InvalidConversion)?;
+        Ok(Self::from(value.try_into()?, signature))
+    }
+}
+
+#[derive(Debug, Serialize, Clone)]
 #[serde(untagged)]
 pub enum OperationContent {
     // Present in alpha protocol
@@ -115,6 +143,76 @@ pub enum OperationContent {
     TxRollupRejection(TxRollupRejection),
     TransferTicket(TransferTicket),
     TxRollupDispatchTickets(TxRollupDispatchTickets),
+    // Kinds that are not modeled yet, e.g. introduced by a newer protocol
+    Unknown(serde_json::Value),
+}
+
+impl<'de> Deserialize<'de> for OperationContent {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        fn parse<T: DeserializeOwned, E: de::Error>(
+            value: serde_json::Value,
+            variant: fn(T) -> OperationContent,
+        ) -> std::result::Result<OperationContent, E> {
+            serde_json::from_value(value)
+                .map(variant)
+                .map_err(E::custom)
+        }
+
+        // The variants are distinguished by `kind` rather than by trying them in order,
+        // otherwise contents without metadata could match a variant of a different kind.
+        // Only contents of a missing or unrecognised kind fall back to `Unknown`.
+        let value = serde_json::Value::deserialize(deserializer)?;
+        let kind = value
+            .get("kind")
+            .and_then(|kind| serde_json::from_value::<OperationKind>(kind.clone()).ok());
+        match kind {
+            Some(OperationKind::Endorsement) => parse(value, Self::Endorsement),
+            Some(OperationKind::Preendorsement) => parse(value, Self::Preendorsement),
+            Some(OperationKind::SeedNonceRevelation) => parse(value, Self::SeedNonceRevelation),
+            Some(OperationKind::DoubleEndorsementEvidence) => {
+                parse(value, Self::DoubleEndorsementEvidence)
+            }
+            Some(OperationKind::DoublePreendorsementEvidence) => {
+                parse(value, Self::DoublePreendorsementEvidence)
+            }
+            Some(OperationKind::DoubleBakingEvidence) => parse(value, Self::DoubleBakingEvidence),
+            Some(OperationKind::ActivateAccount) => parse(value, Self::ActivateAccount),
+            Some(OperationKind::Proposals) => parse(value, Self::Proposals),
+            Some(OperationKind::Ballot) => parse(value, Self::Ballot),
+            Some(OperationKind::Reveal) => parse(value, Self::Reveal),
+            Some(OperationKind::Transaction) => parse(value, Self::Transaction),
+            Some(OperationKind::Origination) => parse(value, Self::Origination),
+            Some(OperationKind::Delegation) => parse(value, Self::Delegation),
+            Some(OperationKind::RegisterGlobalConstant) => {
+                parse(value, Self::RegisterGlobalConstant)
+            }
+            Some(OperationKind::SetDepositsLimit) => parse(value, Self::SetDepositsLimit),
+            Some(OperationKind::FailingNoop) => parse(value, Self::FailingNoop),
+            Some(OperationKind::TxRollupOrigination) => parse(value, Self::TxRollupOrigination),
+            Some(OperationKind::TxRollupSubmitBatch) => parse(value, Self::TxRollupSubmitBatch),
+            Some(OperationKind::TxRollupCommit) => parse(value, Self::TxRollupCommit),
+            Some(OperationKind::TxRollupReturnBond) => parse(value, Self::TxRollupReturnBond),
+            Some(OperationKind::TxRollupFinalizeCommitment) => {
+                parse(value, Self::TxRollupFinalizeCommitment)
+            }
+            Some(OperationKind::TxRollupRemoveCommitment) => {
+                parse(value, Self::TxRollupRemoveCommitment)
+            }
+            Some(OperationKind::TxRollupRejection) => parse(value, Self::TxRollupRejection),
+            Some(OperationKind::TransferTicket) => parse(value, Self::TransferTicket),
+            Some(OperationKind::TxRollupDispatchTickets) => {
+                parse(value, Self::TxRollupDispatchTickets)
+            }
+            Some(OperationKind::ScRollupOriginate)
+            | Some(OperationKind::ScRollupAddMessages)
+            | Some(OperationKind::ScRollupCement)
+            | Some(OperationKind::ScRollupPublish)
+            | None => Ok(Self::Unknown(value)),
+        }
+    }
 }
 
 impl From<tezos_operation::operations::OperationContent> for OperationContent {
@@ -168,6 +266,9 @@ impl From<tezos_operation::operations::OperationContent> for OperationContent {
             tezos_operation::operations::OperationContent::SetDepositsLimit(value) => {
                 Self::SetDepositsLimit(value.into())
             }
+            tezos_operation::operations::OperationContent::TransferTicket(value) => {
+                Self::TransferTicket(value.into())
+            }
         }
     }
 }
@@ -203,6 +304,7 @@ impl TryFrom<OperationContent> for tezos_operation::operations::OperationContent
             OperationContent::SetDepositsLimit(value) => {
                 Ok(Self::SetDepositsLimit(value.try_into()?))
             }
+            OperationContent::TransferTicket(value) => Ok(Self::TransferTicket(value.try_into()?)),
             OperationContent::FailingNoop(value) => Ok(Self::FailingNoop(value.try_into()?)),
             OperationContent::DoubleBakingEvidence(value) => {
                 Ok(Self::DoubleBakingEvidence(value.try_into()?))
@@ -214,8 +316,8 @@ impl TryFrom<OperationContent> for tezos_operation::operations::OperationContent
             | OperationContent::TxRollupFinalizeCommitment(_)
             | OperationContent::TxRollupRemoveCommitment(_)
             | OperationContent::TxRollupRejection(_)
-            | OperationContent::TransferTicket(_)
-            | OperationContent::TxRollupDispatchTickets(_) => Err(Error::OperationNotSupported),
+            | OperationContent::TxRollupDispatchTickets(_)
+            | OperationContent::Unknown(_) => Err(Error::OperationNotSupported),
         }
     }
 }
@@ -226,3 +328,187 @@ pub struct OperationWithMetadata {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub signature: Option<Signature>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::block::Block;
+    use chrono::DateTime;
+    use tezos_core::types::encoded::{Ed25519PublicKey, Ed25519SecretKey};
+    use tezos_michelson::micheline::{int, primitive_application};
+    use tezos_operation::{
+        block_header::{AdaptiveIssuanceVote, BlockHeader, LiquidityBakingToggleVote},
+        operations::{self as op, Operation as _},
+    };
+
+    fn block_header(level: i32) -> BlockHeader {
+        BlockHeader {
+            level,
+            proto: 2,
+            predecessor: "BMaBxGyVhtTiMKd7KA8HXJnbTK4e1TzffNc94G18op55HGQYVRk".try_into().unwrap(),
+            timestamp: DateTime::parse_from_rfc3339("2022-08-04T14:20:32Z").unwrap().naive_utc(),
+            validation_pass: 4,
+            operations_hash: "LLoaNF9sd5z2SZtSmUopYNX6qs77QAUJqrnd5ei378H4bcJhQcPt5".try_into().unwrap(),
+            fitness: vec!["02".try_into().unwrap(), "00000002".try_into().unwrap(), "".try_into().unwrap(), "ffffffff".try_into().unwrap(), "00000001".try_into().unwrap()],
+            context: "CoVj5HxwnPHpC1SgCC6pgqVPgw2vqFEqaC2bF5STqcbyX6giVrGn".try_into().unwrap(),
+            payload_hash: "vh2MHqgJtw8v7CDrZKYWtLmqGJtjzkRvs9yUeHNQqdgDJyCYm21q".try_into().unwrap(),
+            payload_round: 1,
+            proof_of_work_nonce: "336ebf95efce0475".try_into().unwrap(),
+            seed_nonce_hash: Some("nceUeUCJRZ4M7FCSBsAUZU6dmxePdH7irje9Gfj9zWwCdfWd5B4Ee".try_into().unwrap()),
+            liquidity_baking_toggle_vote: LiquidityBakingToggleVote::Pass,
+            adaptive_issuance_vote: AdaptiveIssuanceVote::Off,
+            signature: "sigRsUhHqaFVBeV4qzyCZ6Y9TvoKajyNwyPQQCW3SbgPYY99MrpTqR2FopjzZEHMWoJG7LaTaHu7bnieKQRKqCRLA7hB7Ekp".try_into().unwrap(),
+        }
+    }
+
+    fn operation_contents() -> Vec<op::OperationContent> {
+        vec![
+            op::SeedNonceRevelation::new(1, "6cdaf9367e551995a670a5c642a9396290f8c9d17e6bc3c1555bfaa910d92214".try_into().unwrap()).into(),
+            op::DoubleEndorsementEvidence::new(
+                op::InlinedEndorsement::new(
+                    "BLT3XKN3vFqWnWfuuLenQiyVgEgKcJttnGGdCcQbmE95xz9y7S5".try_into().unwrap(),
+                    op::Endorsement::new(1, 1, 1, "vh2cHpyeaHQhF7g3RFh8usyYmTTpt882UsRyXECuBwPiB3TcsKNd".try_into().unwrap()),
+                    "sigdV5DNZRBLBDDEkbWcqefBuMZevanVyjotoazkkLbk7jXR8oZUmnxt6n3hkQtTe9WbLEkcCUWw1Ey7Ybby5z35nHKqpndn".try_into().unwrap(),
+                ),
+                op::InlinedEndorsement::new(
+                    "BLZS5mP4BufHrZfvzrvw1ReWnj1L2zcQ4mM6Jywoaxe4mHbiCNn".try_into().unwrap(),
+                    op::Endorsement::new(2, 2, 2, "vh2rXj5TAG8p1HKiMyaWDdYrRL2rTBPyFLkVorgzEEBqqd4sgsXG".try_into().unwrap()),
+                    "sigff9imsFxGwyQ8nEpXUR8ZFwTqZWjMJAgKGwub6Mn9Cnu4VvBppTRt84VPp1fRwqpx8JTrLHg76guTGzkm9ETKwFNCzniY".try_into().unwrap(),
+                ),
+            ).into(),
+            op::DoubleBakingEvidence::new(block_header(1), block_header(2)).into(),
+            op::ActivateAccount::new("tz1PokEhtiBGCmekQrcN87pCDmqy99TjaLuN".try_into().unwrap(), "7b27ba02550e6834b50173c8c506de42d901c606".try_into().unwrap()).into(),
+            op::Proposals::new("tz1QVzD6eV73LhtzhNKs94fKbvTg7VjKjEcE".try_into().unwrap(), 1, vec!["PtYnGfhwjiRjtA7VZriogYL6nwFgaAL9ZuVWE6UahXCMn6BoJPv".try_into().unwrap()]).into(),
+            op::Ballot::new("tz1eNhmMTYsti2quW46a5CBJbs4Fde4KGg4F".try_into().unwrap(), 1, "PsjL76mH8vo3fTfUN4qKrdkPvRfXw7KJPWf87isNAxzh1vqdFQv".try_into().unwrap(), op::BallotType::Nay).into(),
+            op::DoublePreendorsementEvidence::new(
+                op::InlinedPreendrosement {
+                    branch: "BLT3XKN3vFqWnWfuuLenQiyVgEgKcJttnGGdCcQbmE95xz9y7S5".try_into().unwrap(),
+                    operations: op::Preendorsement::new(1, 1, 1, "vh2cHpyeaHQhF7g3RFh8usyYmTTpt882UsRyXECuBwPiB3TcsKNd".try_into().unwrap()),
+                    signature: "sigdV5DNZRBLBDDEkbWcqefBuMZevanVyjotoazkkLbk7jXR8oZUmnxt6n3hkQtTe9WbLEkcCUWw1Ey7Ybby5z35nHKqpndn".try_into().unwrap(),
+                },
+                op::InlinedPreendrosement {
+                    branch: "BLZS5mP4BufHrZfvzrvw1ReWnj1L2zcQ4mM6Jywoaxe4mHbiCNn".try_into().unwrap(),
+                    operations: op::Preendorsement::new(2, 2, 2, "vh2rXj5TAG8p1HKiMyaWDdYrRL2rTBPyFLkVorgzEEBqqd4sgsXG".try_into().unwrap()),
+                    signature: "sigff9imsFxGwyQ8nEpXUR8ZFwTqZWjMJAgKGwub6Mn9Cnu4VvBppTRt84VPp1fRwqpx8JTrLHg76guTGzkm9ETKwFNCzniY".try_into().unwrap(),
+                },
+            ).into(),
+            op::FailingNoop::new("cc7e647be422e432a3291ec8a2ee6f5e2210c51825b753758a99e266a0c65b15".try_into().unwrap()).into(),
+            op::Preendorsement::new(1, 1, 1, "vh2KDvhtt44Lyq187SnZjSDyRH1LNXbMj3T9G57miWK9QvqH3fhv".try_into().unwrap()).into(),
+            op::Endorsement::new(1, 1, 1, "vh2WtVuY9PK3mDsnfdzA6iXc4pocgUff8hgamWwXw19r5kDYHVS5".try_into().unwrap()).into(),
+            op::Reveal::new("tz1SZ2CmbQB7MMXgcMSmyyVXpya1rkb9UGUE".try_into().unwrap(), 135675u32.into(), 154u32.into(), 23675u32.into(), 34152u32.into(), "edpkuaARNJPQygG82X1xed6Z2kDutT8XjL3Fmv1XPBbca1uARirj55".try_into().unwrap()).into(),
+            op::Transaction::new("tz1i8xLzLPQHknc5jmeFc3qxijar2HLG2W4Z".try_into().unwrap(), 135675u32.into(), 154u32.into(), 23675u32.into(), 34152u32.into(), 763243u32.into(), "KT1GFYUFQRT4RsNbtG2NU23woUyMp5tx9gx2".try_into().unwrap(), None).into(),
+            op::Transaction::new("tz1i8xLzLPQHknc5jmeFc3qxijar2HLG2W4Z".try_into().unwrap(), 135675u32.into(), 154u32.into(), 23675u32.into(), 34152u32.into(), 763243u32.into(), "KT1GFYUFQRT4RsNbtG2NU23woUyMp5tx9gx2".try_into().unwrap(), Some(op::Parameters::new(op::Entrypoint::Named("named".into()), int(1)))).into(),
+            op::Origination::new("tz1LdF7qHCJg8Efa6Cx4LZrRPkvbh61H8tZq".try_into().unwrap(), 135675u32.into(), 154u32.into(), 23675u32.into(), 34152u32.into(), 763243u32.into(), Some("tz1RY8er4ybXszZBbhtQDrYhA5AYY3VQXiKn".try_into().unwrap()), op::Script::new(vec![].into(), vec![].into())).into(),
+            op::Delegation::new("tz1QVAraV1JDRsPikcqJVE4VccvW7vDWCJHy".try_into().unwrap(), 135675u32.into(), 154u32.into(), 23675u32.into(), 34152u32.into(), Some("tz1dStZpfk5bWsvYvuktDJgDEbpuqDc7ipvi".try_into().unwrap())).into(),
+            op::RegisterGlobalConstant::new("tz1brHnNaHcpxqHDhqwmAXDq1i4F2A4Xaepz".try_into().unwrap(), 135675u32.into(), 154u32.into(), 23675u32.into(), 34152u32.into(), vec![].into()).into(),
+            op::SetDepositsLimit::new("tz1gxabEuUaCKk15qUKnhASJJoXhm9A7DVLM".try_into().unwrap(), 135675u32.into(), 154u32.into(), 23675u32.into(), 34152u32.into(), Some(634u32.into())).into(),
+            op::TransferTicket::new("tz1gxabEuUaCKk15qUKnhASJJoXhm9A7DVLM".try_into().unwrap(), 135675u32.into(), 154u32.into(), 23675u32.into(), 34152u32.into(), int(1), primitive_application("nat").into(), "KT1HNqxFJxnmUcX8wF915wxxaAAU4ixDwWQ7".try_into().unwrap(), 10u8.into(), "tz1YY1LvD6TFH4z74pvxPQXBjAKHE5tB5Q8f".try_into().unwrap(), "default".into()).into(),
+        ]
+    }
+
+    #[test]
+    fn test_operation_content_round_trip() -> Result<()> {
+        for content in operation_contents() {
+            let rpc_content: OperationContent = content.clone().into();
+            let json = serde_json::to_string(&rpc_content)?;
+            let rpc_content: OperationContent = serde_json::from_str(&json)?;
+            let converted: op::OperationContent = rpc_content.try_into()?;
+
+            assert_eq!(converted, content);
+            assert_eq!(converted.to_forged_bytes()?, content.to_forged_bytes()?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_operation_content_deserialization_uses_kind() -> Result<()> {
+        let content: OperationContent = serde_json::from_str(
+            r#"{
+                "kind": "transaction",
+                "source": "tz1i8xLzLPQHknc5jmeFc3qxijar2HLG2W4Z",
+                "fee": "135675",
+                "counter": "154",
+                "gas_limit": "23675",
+                "storage_limit": "34152",
+                "amount": "763243",
+                "destination": "KT1GFYUFQRT4RsNbtG2NU23woUyMp5tx9gx2"
+            }"#,
+        )?;
+        assert!(matches!(content, OperationContent::Transaction(_)));
+
+        let content: OperationContent = serde_json::from_str(
+            r#"{ "kind": "sc_rollup_cement", "rollup": "scr1HLXM32GacPNDrhHDLAssZG88eWqCUbyLF" }"#,
+        )?;
+        assert!(
+            matches!(content, OperationContent::Unknown(value) if value["kind"] == "sc_rollup_cement")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_known_kind_deserialization_errors() -> Result<()> {
+        use crate::models::operation::operation_result::{
+            lazy_storage_diff::LazyStorageDiff, operations::InternalOperationResult,
+        };
+
+        let transaction = r#"{ "kind": "transaction", "source": "tz1i8xLzLPQHknc5jmeFc3qxijar2HLG2W4Z", "amount": "763243" }"#;
+        assert!(serde_json::from_str::<OperationContent>(transaction).is_err());
+        assert!(serde_json::from_str::<InternalOperationResult>(transaction).is_err());
+        assert!(matches!(
+            serde_json::from_str::<OperationContent>(
+                r#"{ "source": "tz1i8xLzLPQHknc5jmeFc3qxijar2HLG2W4Z" }"#
+            )?,
+            OperationContent::Unknown(_)
+        ));
+
+        let diff = r#"{ "kind": "big_map", "id": "9", "diff": { "action": "unknown action" } }"#;
+        assert!(serde_json::from_str::<LazyStorageDiff>(diff).is_err());
+        let diff = r#"{ "kind": "ticket_table", "id": "9" }"#;
+        assert!(matches!(
+            serde_json::from_str::<LazyStorageDiff>(diff)?,
+            LazyStorageDiff::Unknown(_)
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_rehash_block_operations() -> Result<()> {
+        let block: Block = serde_json::from_str(include_str!(
+            "../protocol_rpc/block/__TEST_DATA__/block_ithaca.json"
+        ))?;
+
+        let operations = block.operations.into_iter().flatten().collect::<Vec<_>>();
+        assert!(!operations.is_empty());
+        for operation in operations {
+            let expected = operation.hash.clone().expect("Operation has a hash");
+            let signed: op::SignedOperation = operation.try_into()?;
+
+            assert_eq!(signed.hash()?, expected);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_signed_operation_round_trip() -> Result<()> {
+        let secret_key: Ed25519SecretKey = "edskRv7VyXGVZb8EsrR7D9XKUbbAQNQGtALP6QeB16ZCD7SmmJpzyeneJVg3Mq56YLbxRA1kSdAXiswwPiaVfR3NHGMCXCziuZ".try_into()?;
+        let public_key: Ed25519PublicKey =
+            "edpkttZKC51wemRqL2QxwpMnEKxWnbd35pq47Y6xsCHp5M1f7LN8NP".try_into()?;
+        let unsigned = op::UnsignedOperation::new(
+            "BMdhifZkcb5i9D6FnBi19SSBjft3sYaeKDAsEBgbsRLPTihQQJU".try_into()?,
+            operation_contents(),
+        );
+        let signed = unsigned.into_signed_operation(&secret_key.into())?;
+
+        let json = serde_json::to_string(&Operation::from(signed.clone()))?;
+        let operation: Operation = serde_json::from_str(&json)?;
+        let converted: op::SignedOperation = operation.clone().try_into()?;
+
+        assert_eq!(converted.to_forged_bytes()?, signed.to_forged_bytes()?);
+        assert_eq!(converted.hash()?, signed.hash()?);
+        assert!(converted.verify(&public_key.into())?);
+
+        let converted: op::UnsignedOperation = operation.try_into()?;
+        assert_eq!(converted.contents, signed.contents);
+        Ok(())
+    }
+}
@@ -1,11 +1,17 @@
 use {
-    crate::models::{
-        balance_update::BalanceUpdate, operation::kind::OperationKind,
-        operation::operation_result::operations::transfer_ticket::TransferTicketOperationResult,
+    crate::{
+        models::{
+            balance_update::BalanceUpdate,
+            operation::kind::OperationKind,
+            operation::operation_result::operations::{
+                transfer_ticket::TransferTicketOperationResult, InternalOperationResult,
+            },
+        },
+        Error, Result,
     },
     serde::{Deserialize, Serialize},
     tezos_core::types::{
-        encoded::{ContractAddress, ImplicitAddress},
+        encoded::{Address, ImplicitAddress},
         mutez::Mutez,
     },
     tezos_michelson::micheline::Micheline,
@@ -23,17 +29,59 @@ pub struct TransferTicket {
     pub storage_limit: String,
     pub ticket_contents: Micheline,
     pub ticket_ty: Micheline,
-    pub ticket_ticketer: ContractAddress,
+    pub ticket_ticketer: Address,
     pub ticket_amount: String,
-    pub destination: ContractAddress,
+    pub destination: Address,
     pub entrypoint: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metadata: Option<TransferTicketMetadata>,
 }
 
+impl From<tezos_operation::operations::TransferTicket> for TransferTicket {
+    fn from(value: tezos_operation::operations::TransferTicket) -> Self {
+        Self {
+            kind: OperationKind::TransferTicket,
+            source: value.source,
+            fee: value.fee,
+            counter: value.counter.into(),
+            gas_limit: value.gas_limit.into(),
+            storage_limit: value.storage_limit.into(),
+            ticket_contents: value.ticket_contents,
+            ticket_ty: value.ticket_ty,
+            ticket_ticketer: value.ticket_ticketer,
+            ticket_amount: value.ticket_amount.into(),
+            destination: value.destination,
+            entrypoint: value.entrypoint,
+            metadata: None,
+        }
+    }
+}
+
+impl TryFrom<TransferTicket> for tezos_operation::operations::TransferTicket {
+    type Error = Error;
+
+    fn try_from(value: TransferTicket) -> Result<Self> {
+        Ok(Self {
+            source: value.source,
+            fee: value.fee,
+            counter: value.counter.try_into()?,
+            gas_limit: value.gas_limit.try_into()?,
+            storage_limit: value.storage_limit.try_into()?,
+            ticket_contents: value.ticket_contents,
+            ticket_ty: value.ticket_ty,
+            ticket_ticketer: value.ticket_ticketer,
+            ticket_amount: value.ticket_amount.try_into()?,
+            destination: value.destination,
+            entrypoint: value.entrypoint,
+        })
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct TransferTicketMetadata {
     pub operation_result: TransferTicketOperationResult,
     #[serde(default)]
     pub balance_updates: Vec<BalanceUpdate>,
+    #[serde(default)]
+    pub internal_operation_results: Vec<InternalOperationResult>,
 }
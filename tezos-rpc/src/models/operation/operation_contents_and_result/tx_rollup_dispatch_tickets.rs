@@ -46,5 +46,5 @@ pub struct TicketInfo {
     pub ty: Micheline,
     pub ticketer: ContractAddress,
     pub amount: Mutez,
-    pub claimer: ContractAddress,
+    pub claimer: ImplicitAddress,
 }
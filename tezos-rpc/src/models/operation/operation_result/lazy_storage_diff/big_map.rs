@@ -17,6 +17,7 @@ pub struct BigMap {
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct Diff {
     pub action: DiffAction,
+    #[serde(default)]
     pub updates: Vec<Update>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub key_hash: Option<ScriptExprHash>,
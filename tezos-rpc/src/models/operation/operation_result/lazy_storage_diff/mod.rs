@@ -1,15 +1,35 @@
-use serde::{Deserialize, Serialize};
+use serde::{de, Deserialize, Deserializer, Serialize};
 
 use self::{big_map::BigMap, sapling_state::SaplingState};
 
 pub mod big_map;
 pub mod sapling_state;
 
-#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[derive(Debug, Serialize, Clone, PartialEq)]
 #[serde(untagged)]
 pub enum LazyStorageDiff {
     BigMap(BigMap),
     SaplingState(SaplingState),
+    Unknown(serde_json::Value),
+}
+
+impl<'de> Deserialize<'de> for LazyStorageDiff {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        // Only diffs of a missing or unrecognised kind fall back to `Unknown`.
+        let value = serde_json::Value::deserialize(deserializer)?;
+        let kind = value
+            .get("kind")
+            .and_then(|kind| serde_json::from_value::<Kind>(kind.clone()).ok());
+        match kind {
+            Some(Kind::BigMap) => serde_json::from_value(value).map(Self::BigMap),
+            Some(Kind::SaplingState) => serde_json::from_value(value).map(Self::SaplingState),
+            None => Ok(Self::Unknown(value)),
+        }
+        .map_err(de::Error::custom)
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
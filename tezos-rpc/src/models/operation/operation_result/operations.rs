@@ -32,7 +32,8 @@ use {
         InternalTransactionOperationResult, TransactionOperationResult,
         TransactionSuccessfulManagerOperationResult,
     },
-    serde::{Deserialize, Serialize},
+    crate::models::operation::kind::OperationKind,
+    serde::{de, Deserialize, Deserializer, Serialize},
 };
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
@@ -56,10 +57,35 @@ pub enum SuccessfulManagerOperationResult {
     SetDepositsLimits(SetDepositsLimitSuccessfulManagerOperationResult),
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[derive(Debug, Serialize, Clone, PartialEq)]
 #[serde(untagged)]
 pub enum InternalOperationResult {
     Transaction(InternalTransactionOperationResult),
     Origination(InternalOriginationOperationResult),
     Delegation(InternalDelegationOperationResult),
+    Unknown(serde_json::Value),
+}
+
+impl<'de> Deserialize<'de> for InternalOperationResult {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        // Only results of a missing or not yet modeled kind fall back to `Unknown`.
+        let value = serde_json::Value::deserialize(deserializer)?;
+        let kind = value
+            .get("kind")
+            .and_then(|kind| serde_json::from_value::<OperationKind>(kind.clone()).ok());
+        match kind {
+            Some(OperationKind::Transaction) => {
+                serde_json::from_value(value).map(Self::Transaction)
+            }
+            Some(OperationKind::Origination) => {
+                serde_json::from_value(value).map(Self::Origination)
+            }
+            Some(OperationKind::Delegation) => serde_json::from_value(value).map(Self::Delegation),
+            _ => Ok(Self::Unknown(value)),
+        }
+        .map_err(de::Error::custom)
+    }
 }
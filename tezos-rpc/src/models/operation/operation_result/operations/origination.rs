@@ -8,16 +8,20 @@ use {
         operation::operation_result::{
             big_map_diff::BigMapDiff, lazy_storage_diff::LazyStorageDiff,
         },
+        ticket::TicketUpdates,
     },
     serde::{Deserialize, Serialize},
-    tezos_core::types::encoded::{Address, ContractAddress, ImplicitAddress},
+    tezos_core::types::{
+        encoded::{Address, ContractAddress, ImplicitAddress},
+        mutez::Mutez,
+    },
 };
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct OriginationOperationResult {
     pub status: OperationResultStatus,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub big_map_diff: Option<BigMapDiff>,
+    pub big_map_diff: Option<Vec<BigMapDiff>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub balance_updates: Option<Vec<BalanceUpdate>>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -32,6 +36,9 @@ pub struct OriginationOperationResult {
     pub paid_storage_size_diff: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub lazy_storage_diff: Option<Vec<LazyStorageDiff>>,
+    /// Named `ticket_receipt` since the Mumbai protocol.
+    #[serde(alias = "ticket_receipt", skip_serializing_if = "Option::is_none")]
+    pub ticket_updates: Option<Vec<TicketUpdates>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub errors: Option<Vec<RpcError>>,
 }
@@ -66,8 +73,7 @@ pub struct InternalOriginationOperationResult {
     pub source: Address,
     /// integer ∈ [0, 2^16-1]
     pub nonce: u16,
-    /// Mutez
-    pub balance: u64,
+    pub balance: Mutez,
     /// Address (Base58Check-encoded)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub delegate: Option<ImplicitAddress>,
@@ -16,6 +16,8 @@ pub struct RegisterGlobalConstantOperationResult {
     pub consumed_gas: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub consumed_milligas: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub storage_size: Option<String>,
     /// Script expression (Base58Check-encoded)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub global_address: Option<ScriptExprHash>,
@@ -7,6 +7,7 @@ use {
         models::operation::operation_result::{
             big_map_diff::BigMapDiff, lazy_storage_diff::LazyStorageDiff, OperationResultStatus,
         },
+        models::ticket::TicketUpdates,
     },
     serde::{Deserialize, Serialize},
     tezos_core::types::{
@@ -39,6 +40,9 @@ pub struct TransactionOperationResult {
     pub allocated_destination_contract: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub lazy_storage_diff: Option<Vec<LazyStorageDiff>>,
+    /// Named `ticket_receipt` since the Mumbai protocol.
+    #[serde(alias = "ticket_receipt", skip_serializing_if = "Option::is_none")]
+    pub ticket_updates: Option<Vec<TicketUpdates>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub errors: Option<Vec<RpcError>>,
 }
@@ -1,5 +1,7 @@
 use {
-    crate::{models::error::RpcError, models::operation::operation_result::OperationResultStatus},
+    crate::models::{
+        error::RpcError, operation::operation_result::OperationResultStatus, ticket::TicketUpdates,
+    },
     serde::{Deserialize, Serialize},
 };
 
@@ -12,6 +14,9 @@ pub struct TransferTicketOperationResult {
     pub consumed_milligas: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub paid_storage_size_diff: Option<String>,
+    /// Named `ticket_receipt` since the Mumbai protocol.
+    #[serde(alias = "ticket_receipt", skip_serializing_if = "Option::is_none")]
+    pub ticket_updates: Option<Vec<TicketUpdates>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub errors: Option<Vec<RpcError>>,
 }
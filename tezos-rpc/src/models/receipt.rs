@@ -0,0 +1,676 @@
+use std::collections::BTreeMap;
+
+use {
+    crate::{
+        models::{
+            balance_update::{BalanceUpdate, Kind},
+            constants::Constants,
+            error::RpcError,
+            operation::{
+                kind::OperationKind,
+                operation_result::{
+                    operations::{
+                        delegation::DelegationOperationResult,
+                        origination::OriginationOperationResult,
+                        register_global_constant::RegisterGlobalConstantOperationResult,
+                        reveal::RevealOperationResult,
+                        set_deposits_limit::SetDepositsLimitOperationResult,
+                        transaction::TransactionOperationResult,
+                        transfer_ticket::TransferTicketOperationResult, InternalOperationResult,
+                    },
+                    OperationResultStatus,
+                },
+                Operation, OperationContent, OperationWithMetadata,
+            },
+        },
+        Error, Result,
+    },
+    num_bigint::{BigInt, BigUint},
+    tezos_core::types::{encoded::ContractAddress, mutez::Mutez},
+};
+
+/// A summary of the application of an operation, built from the metadata of its contents
+/// as returned for injected operations (in a block) or by the `run_operation` and `preapply` RPCs.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Receipt {
+    pub contents: Vec<ContentReceipt>,
+}
+
+impl Receipt {
+    /// Creates a [Receipt] from operation contents with metadata.
+    pub fn from_contents(contents: &[OperationContent]) -> Self {
+        Self {
+            contents: contents.iter().map(Into::into).collect(),
+        }
+    }
+
+    /// Returns `true` if every manager content (and its internal operations) has been applied.
+    pub fn is_applied(&self) -> bool {
+        self.contents.iter().all(|content| content.is_applied())
+    }
+
+    /// Returns the sum of the fees paid by the operation contents.
+    pub fn total_fees(&self) -> Mutez {
+        self.contents.iter().map(|content| &content.fee).sum()
+    }
+
+    /// Returns the gas consumed by the operation contents and their internal operations.
+    pub fn total_consumed_milligas(&self) -> BigUint {
+        self.contents
+            .iter()
+            .map(|content| &content.consumed_milligas)
+            .sum()
+    }
+
+    /// Returns the storage paid by the operation contents and their internal operations, in bytes.
+    pub fn total_paid_storage_size_diff(&self) -> BigUint {
+        self.contents
+            .iter()
+            .map(|content| &content.paid_storage_size_diff)
+            .sum()
+    }
+
+    /// Returns the amount burned for storage, including allocated and originated contracts,
+    /// using `cost_per_byte` and `origination_size` from the protocol constants.
+    pub fn burned_storage(&self, constants: &Constants) -> Result<Mutez> {
+        self.contents
+            .iter()
+            .try_fold(Mutez::default(), |acc, content| {
+                acc.checked_add(content.burned_storage(constants)?)
+                    .ok_or(Error::InvalidConversion)
+            })
+    }
+
+    /// Returns the net balance change of every implicit account and contract touched by the operation,
+    /// fees and internal operations included.
+    pub fn balance_changes(&self) -> Result<BTreeMap<String, BigInt>> {
+        self.contents
+            .iter()
+            .try_fold(BTreeMap::new(), |mut acc, content| {
+                for (address, change) in content.balance_changes()? {
+                    *acc.entry(address).or_default() += change;
+                }
+                Ok(acc)
+            })
+    }
+
+    /// Returns the contracts originated by the operation contents and their internal operations.
+    pub fn originated_contracts(&self) -> Vec<&ContractAddress> {
+        self.contents
+            .iter()
+            .flat_map(|content| content.originated_contracts.iter())
+            .collect()
+    }
+
+    /// Returns the errors reported for the operation contents and their internal operations.
+    pub fn errors(&self) -> Vec<&RpcError> {
+        self.contents
+            .iter()
+            .flat_map(|content| content.errors.iter())
+            .collect()
+    }
+}
+
+impl From<&Operation> for Receipt {
+    fn from(value: &Operation) -> Self {
+        Self::from_contents(&value.contents)
+    }
+}
+
+impl From<&OperationWithMetadata> for Receipt {
+    fn from(value: &OperationWithMetadata) -> Self {
+        Self::from_contents(&value.contents)
+    }
+}
+
+/// A summary of the application of a single operation content, including its internal operations.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContentReceipt {
+    /// The content kind, `None` for contents of an unknown kind.
+    pub kind: Option<OperationKind>,
+    /// The status of the content's own result, `None` if the content has no result (e.g. consensus operations).
+    pub status: Option<OperationResultStatus>,
+    pub fee: Mutez,
+    pub consumed_milligas: BigUint,
+    /// The storage paid by the applied results.
+    pub paid_storage_size_diff: BigUint,
+    /// The number of implicit accounts allocated by the content and its applied internal transactions.
+    pub allocated_contracts: usize,
+    pub originated_contracts: Vec<ContractAddress>,
+    /// The balance updates of the content and of its applied result and internal operations.
+    pub balance_updates: Vec<BalanceUpdate>,
+    pub errors: Vec<RpcError>,
+    /// The statuses of the internal operations' results.
+    pub internal_statuses: Vec<OperationResultStatus>,
+}
+
+impl ContentReceipt {
+    fn new(kind: Option<OperationKind>, fee: Mutez) -> Self {
+        Self {
+            kind,
+            status: None,
+            fee,
+            consumed_milligas: 0u8.into(),
+            paid_storage_size_diff: 0u8.into(),
+            allocated_contracts: 0,
+            originated_contracts: vec![],
+            balance_updates: vec![],
+            errors: vec![],
+            internal_statuses: vec![],
+        }
+    }
+
+    /// Returns `true` if the content and all its internal operations have been applied,
+    /// or if the content does not carry a result.
+    pub fn is_applied(&self) -> bool {
+        self.status
+            .iter()
+            .chain(self.internal_statuses.iter())
+            .all(|status| *status == OperationResultStatus::Applied)
+    }
+
+    /// Returns the amount burned for storage, including allocated and originated contracts,
+    /// using `cost_per_byte` and `origination_size` from the protocol constants.
+    pub fn burned_storage(&self, constants: &Constants) -> Result<Mutez> {
+        let cost_per_byte = constants
+            .cost_per_byte
+            .as_ref()
+            .and_then(|value| value.to_biguint())
+            .ok_or(Error::MissingConstant {
+                name: "cost_per_byte".into(),
+            })?;
+        let origination_size = constants
+            .origination_size
+            .and_then(|value| u32::try_from(value).ok())
+            .ok_or(Error::MissingConstant {
+                name: "origination_size".into(),
+            })?;
+
+        let contracts = self.allocated_contracts + self.originated_contracts.len();
+        let bytes = self.paid_storage_size_diff.clone()
+            + BigUint::from(origination_size) * BigUint::from(contracts);
+
+        Ok((bytes * cost_per_byte).try_into()?)
+    }
+
+    /// Returns the net balance change of every implicit account and contract touched by the content.
+    pub fn balance_changes(&self) -> Result<BTreeMap<String, BigInt>> {
+        self.balance_updates
+            .iter()
+            .try_fold(BTreeMap::new(), |mut acc, balance_update| {
+                if let BalanceUpdate::Contract(update) = balance_update {
+                    if update.kind == Kind::Contract {
+                        let change: BigInt = update.change.parse()?;
+                        *acc.entry(update.contract.clone()).or_default() += change;
+                    }
+                }
+                Ok(acc)
+            })
+    }
+
+    fn add_balance_updates(&mut self, balance_updates: Option<&Vec<BalanceUpdate>>) {
+        if let Some(balance_updates) = balance_updates {
+            self.balance_updates.extend(balance_updates.iter().cloned());
+        }
+    }
+
+    fn add_result<R: ReceiptOperationResult>(&mut self, result: &R, internal: bool) {
+        if internal {
+            self.internal_statuses.push(result.status());
+        } else {
+            self.status = Some(result.status());
+        }
+        self.consumed_milligas += result.consumed_milligas();
+        // The effects of failed, backtracked or skipped results have been reverted.
+        if result.status() == OperationResultStatus::Applied {
+            self.paid_storage_size_diff += result.paid_storage_size_diff();
+            if result.allocated_destination_contract() {
+                self.allocated_contracts += 1;
+            }
+            self.originated_contracts
+                .extend(result.originated_contracts().iter().cloned());
+            self.add_balance_updates(result.balance_updates());
+        }
+        if let Some(errors) = result.errors() {
+            self.errors.extend(errors.iter().cloned());
+        }
+    }
+
+    fn add_internal_operation_results(&mut self, results: &[InternalOperationResult]) {
+        for result in results {
+            match result {
+                InternalOperationResult::Transaction(value) => {
+                    if let Some(result) = &value.result {
+                        self.add_result(result, true)
+                    }
+                }
+                InternalOperationResult::Origination(value) => self.add_result(&value.result, true),
+                InternalOperationResult::Delegation(value) => self.add_result(&value.result, true),
+                InternalOperationResult::Unknown(_) => {}
+            }
+        }
+    }
+}
+
+impl From<&OperationContent> for ContentReceipt {
+    fn from(value: &OperationContent) -> Self {
+        match value {
+            OperationContent::Reveal(value) => {
+                let mut receipt = Self::new(Some(value.kind), value.fee);
+                if let Some(metadata) = &value.metadata {
+                    receipt.add_balance_updates(Some(&metadata.balance_updates));
+                    receipt.add_result(&metadata.operation_result, false);
+                }
+                receipt
+            }
+            OperationContent::Transaction(value) => {
+                let mut receipt = Self::new(Some(value.kind), value.fee);
+                if let Some(metadata) = &value.metadata {
+                    receipt.add_balance_updates(Some(&metadata.balance_updates));
+                    receipt.add_result(&metadata.operation_result, false);
+                    receipt.add_internal_operation_results(&metadata.internal_operation_results);
+                }
+                receipt
+            }
+            OperationContent::Origination(value) => {
+                let mut receipt = Self::new(Some(value.kind), value.fee);
+                if let Some(metadata) = &value.metadata {
+                    receipt.add_balance_updates(Some(&metadata.balance_updates));
+                    receipt.add_result(&metadata.operation_result, false);
+                }
+                receipt
+            }
+            OperationContent::Delegation(value) => {
+                let mut receipt = Self::new(Some(value.kind), value.fee);
+                if let Some(metadata) = &value.metadata {
+                    receipt.add_balance_updates(Some(&metadata.balance_updates));
+                    receipt.add_result(&metadata.operation_result, false);
+                }
+                receipt
+            }
+            OperationContent::RegisterGlobalConstant(value) => {
+                let mut receipt = Self::new(Some(value.kind), value.fee);
+                if let Some(metadata) = &value.metadata {
+                    receipt.add_balance_updates(Some(&metadata.balance_updates));
+                    receipt.add_result(&metadata.operation_result, false);
+                }
+                receipt
+            }
+            OperationContent::SetDepositsLimit(value) => {
+                let mut receipt = Self::new(Some(value.kind), value.fee);
+                if let Some(metadata) = &value.metadata {
+                    receipt.add_balance_updates(Some(&metadata.balance_updates));
+                    receipt.add_result(&metadata.operation_result, false);
+                }
+                receipt
+            }
+            OperationContent::TransferTicket(value) => {
+                let mut receipt = Self::new(Some(value.kind), value.fee);
+                if let Some(metadata) = &value.metadata {
+                    receipt.add_balance_updates(Some(&metadata.balance_updates));
+                    receipt.add_result(&metadata.operation_result, false);
+                    receipt.add_internal_operation_results(&metadata.internal_operation_results);
+                }
+                receipt
+            }
+            OperationContent::Endorsement(value) => {
+                let mut receipt = Self::new(Some(value.kind), Mutez::default());
+                if let Some(metadata) = &value.metadata {
+                    receipt.add_balance_updates(metadata.balance_updates.as_ref());
+                }
+                receipt
+            }
+            OperationContent::Preendorsement(value) => {
+                let mut receipt = Self::new(Some(value.kind), Mutez::default());
+                if let Some(metadata) = &value.metadata {
+                    receipt.add_balance_updates(metadata.balance_updates.as_ref());
+                }
+                receipt
+            }
+            OperationContent::SeedNonceRevelation(value) => {
+                let mut receipt = Self::new(Some(value.kind), Mutez::default());
+                if let Some(metadata) = &value.metadata {
+                    receipt.add_balance_updates(metadata.balance_updates.as_ref());
+                }
+                receipt
+            }
+            OperationContent::DoubleEndorsementEvidence(value) => {
+                let mut receipt = Self::new(Some(value.kind), Mutez::default());
+                if let Some(metadata) = &value.metadata {
+                    receipt.add_balance_updates(metadata.balance_updates.as_ref());
+                }
+                receipt
+            }
+            OperationContent::DoublePreendorsementEvidence(value) => {
+                let mut receipt = Self::new(Some(value.kind), Mutez::default());
+                if let Some(metadata) = &value.metadata {
+                    receipt.add_balance_updates(metadata.balance_updates.as_ref());
+                }
+                receipt
+            }
+            OperationContent::DoubleBakingEvidence(value) => {
+                let mut receipt = Self::new(Some(value.kind), Mutez::default());
+                if let Some(metadata) = &value.metadata {
+                    receipt.add_balance_updates(metadata.balance_updates.as_ref());
+                }
+                receipt
+            }
+            OperationContent::ActivateAccount(value) => {
+                let mut receipt = Self::new(Some(value.kind), Mutez::default());
+                if let Some(metadata) = &value.metadata {
+                    receipt.add_balance_updates(metadata.balance_updates.as_ref());
+                }
+                receipt
+            }
+            OperationContent::Proposals(value) => Self::new(Some(value.kind), Mutez::default()),
+            OperationContent::Ballot(value) => Self::new(Some(value.kind), Mutez::default()),
+            OperationContent::FailingNoop(value) => Self::new(Some(value.kind), Mutez::default()),
+            OperationContent::TxRollupOrigination(value) => Self::new(Some(value.kind), value.fee),
+            OperationContent::TxRollupSubmitBatch(value) => Self::new(Some(value.kind), value.fee),
+            OperationContent::TxRollupCommit(value) => Self::new(Some(value.kind), value.fee),
+            OperationContent::TxRollupReturnBond(value) => Self::new(Some(value.kind), value.fee),
+            OperationContent::TxRollupFinalizeCommitment(value) => {
+                Self::new(Some(value.kind), value.fee)
+            }
+            OperationContent::TxRollupRemoveCommitment(value) => {
+                Self::new(Some(value.kind), value.fee)
+            }
+            OperationContent::TxRollupRejection(value) => Self::new(Some(value.kind), value.fee),
+            OperationContent::TxRollupDispatchTickets(value) => {
+                Self::new(Some(value.kind), value.fee)
+            }
+            OperationContent::Unknown(_) => Self::new(None, Mutez::default()),
+        }
+    }
+}
+
+trait ReceiptOperationResult {
+    fn status(&self) -> OperationResultStatus;
+    fn errors(&self) -> Option<&Vec<RpcError>>;
+
+    fn consumed_milligas(&self) -> BigUint {
+        0u8.into()
+    }
+
+    fn paid_storage_size_diff(&self) -> BigUint {
+        0u8.into()
+    }
+
+    fn allocated_destination_contract(&self) -> bool {
+        false
+    }
+
+    fn originated_contracts(&self) -> &[ContractAddress] {
+        &[]
+    }
+
+    fn balance_updates(&self) -> Option<&Vec<BalanceUpdate>> {
+        None
+    }
+}
+
+fn parse_biguint(value: &Option<String>) -> BigUint {
+    value
+        .as_ref()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or_default()
+}
+
+impl ReceiptOperationResult for RevealOperationResult {
+    fn status(&self) -> OperationResultStatus {
+        self.status
+    }
+
+    fn errors(&self) -> Option<&Vec<RpcError>> {
+        self.errors.as_ref()
+    }
+
+    fn consumed_milligas(&self) -> BigUint {
+        parse_biguint(&self.consumed_milligas)
+    }
+}
+
+impl ReceiptOperationResult for TransactionOperationResult {
+    fn status(&self) -> OperationResultStatus {
+        self.status
+    }
+
+    fn errors(&self) -> Option<&Vec<RpcError>> {
+        self.errors.as_ref()
+    }
+
+    fn consumed_milligas(&self) -> BigUint {
+        parse_biguint(&self.consumed_milligas)
+    }
+
+    fn paid_storage_size_diff(&self) -> BigUint {
+        parse_biguint(&self.paid_storage_size_diff)
+    }
+
+    fn allocated_destination_contract(&self) -> bool {
+        self.allocated_destination_contract.unwrap_or(false)
+    }
+
+    fn originated_contracts(&self) -> &[ContractAddress] {
+        self.originated_contracts.as_deref().unwrap_or_default()
+    }
+
+    fn balance_updates(&self) -> Option<&Vec<BalanceUpdate>> {
+        self.balance_updates.as_ref()
+    }
+}
+
+impl ReceiptOperationResult for OriginationOperationResult {
+    fn status(&self) -> OperationResultStatus {
+        self.status
+    }
+
+    fn errors(&self) -> Option<&Vec<RpcError>> {
+        self.errors.as_ref()
+    }
+
+    fn consumed_milligas(&self) -> BigUint {
+        parse_biguint(&self.consumed_milligas)
+    }
+
+    fn paid_storage_size_diff(&self) -> BigUint {
+        parse_biguint(&self.paid_storage_size_diff)
+    }
+
+    fn originated_contracts(&self) -> &[ContractAddress] {
+        self.originated_contracts.as_deref().unwrap_or_default()
+    }
+
+    fn balance_updates(&self) -> Option<&Vec<BalanceUpdate>> {
+        self.balance_updates.as_ref()
+    }
+}
+
+impl ReceiptOperationResult for DelegationOperationResult {
+    fn status(&self) -> OperationResultStatus {
+        self.status
+    }
+
+    fn errors(&self) -> Option<&Vec<RpcError>> {
+        self.errors.as_ref()
+    }
+
+    fn consumed_milligas(&self) -> BigUint {
+        parse_biguint(&self.consumed_milligas)
+    }
+}
+
+impl ReceiptOperationResult for RegisterGlobalConstantOperationResult {
+    fn status(&self) -> OperationResultStatus {
+        self.status
+    }
+
+    fn errors(&self) -> Option<&Vec<RpcError>> {
+        self.errors.as_ref()
+    }
+
+    fn consumed_milligas(&self) -> BigUint {
+        parse_biguint(&self.consumed_milligas)
+    }
+
+    fn paid_storage_size_diff(&self) -> BigUint {
+        parse_biguint(&self.storage_size)
+    }
+
+    fn balance_updates(&self) -> Option<&Vec<BalanceUpdate>> {
+        self.balance_updates.as_ref()
+    }
+}
+
+impl ReceiptOperationResult for SetDepositsLimitOperationResult {
+    fn status(&self) -> OperationResultStatus {
+        self.status
+    }
+
+    fn errors(&self) -> Option<&Vec<RpcError>> {
+        self.errors.as_ref()
+    }
+
+    fn consumed_milligas(&self) -> BigUint {
+        parse_biguint(&self.consumed_milligas)
+    }
+}
+
+impl ReceiptOperationResult for TransferTicketOperationResult {
+    fn status(&self) -> OperationResultStatus {
+        self.status
+    }
+
+    fn errors(&self) -> Option<&Vec<RpcError>> {
+        self.errors.as_ref()
+    }
+
+    fn consumed_milligas(&self) -> BigUint {
+        parse_biguint(&self.consumed_milligas)
+    }
+
+    fn paid_storage_size_diff(&self) -> BigUint {
+        parse_biguint(&self.paid_storage_size_diff)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run_operation_result() -> OperationWithMetadata {
+        serde_json::from_str(include_str!("__TEST_DATA__/run_operation_receipt.json")).unwrap()
+    }
+
+    fn constants() -> Constants {
+        serde_json::from_str(include_str!(
+            "../protocol_rpc/block/context/constants/__TEST_DATA__/ithaca_constants.json"
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_summary() -> Result<()> {
+        let receipt = Receipt::from(&run_operation_result());
+
+        assert_eq!(receipt.contents.len(), 2);
+        assert!(receipt.is_applied());
+        assert_eq!(receipt.total_fees(), 3000u16.into());
+        assert_eq!(receipt.total_consumed_milligas(), 7919540u32.into());
+        assert_eq!(receipt.total_paid_storage_size_diff(), 60u8.into());
+        assert_eq!(receipt.contents[1].allocated_contracts, 1);
+        assert_eq!(
+            receipt.originated_contracts(),
+            vec![&ContractAddress::try_from(
+                "KT1BEqzn5Wx8uJrZNvuS9DVHmLvG9td3fDLi"
+            )?]
+        );
+        assert!(receipt.errors().is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_burned_storage() -> Result<()> {
+        let receipt = Receipt::from(&run_operation_result());
+        let constants = constants();
+
+        // (10 + 50 + 2 * 257) * 250
+        assert_eq!(receipt.burned_storage(&constants)?, 143500u32.into());
+        assert_eq!(receipt.contents[0].burned_storage(&constants)?, 0u8.into());
+
+        let mut constants = constants;
+        constants.cost_per_byte = None;
+        assert!(matches!(
+            receipt.burned_storage(&constants),
+            Err(Error::MissingConstant { .. })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_balance_changes() -> Result<()> {
+        let receipt = Receipt::from(&run_operation_result());
+        let changes = receipt.balance_changes()?;
+
+        assert_eq!(changes.len(), 4);
+        assert_eq!(
+            changes["tz1YRG68NdqtAcsFEwTUw6FsSsiBb5kagEDo"],
+            BigInt::from(-1146500)
+        );
+        assert_eq!(
+            changes["KT1CK9RnWZGnejBeT6gJfgvf4p7f1NwhP9wS"],
+            BigInt::from(400000)
+        );
+        assert_eq!(
+            changes["tz1gjaF81ZRRvdzjobyfVNsAeSC6PScjfQwN"],
+            BigInt::from(500000)
+        );
+        assert_eq!(
+            changes["KT1BEqzn5Wx8uJrZNvuS9DVHmLvG9td3fDLi"],
+            BigInt::from(100000)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_failed_internal_operation() -> Result<()> {
+        let mut operation = run_operation_result();
+        if let OperationContent::Transaction(transaction) = &mut operation.contents[1] {
+            let metadata = transaction.metadata.as_mut().unwrap();
+            metadata.operation_result.status = OperationResultStatus::Backtracked;
+            if let InternalOperationResult::Transaction(transaction) =
+                &mut metadata.internal_operation_results[0]
+            {
+                transaction.result.as_mut().unwrap().status = OperationResultStatus::Backtracked;
+            }
+            if let InternalOperationResult::Origination(origination) =
+                &mut metadata.internal_operation_results[1]
+            {
+                origination.result.status = OperationResultStatus::Failed;
+                origination.result.errors = Some(vec![serde_json::from_str(
+                    r#"{ "kind": "temporary", "id": "proto.alpha.contract.balance_too_low" }"#,
+                )?]);
+            }
+        }
+
+        let receipt = Receipt::from(&operation);
+        assert!(!receipt.is_applied());
+        assert!(receipt.contents[0].is_applied());
+        assert!(!receipt.contents[1].is_applied());
+        assert_eq!(receipt.errors().len(), 1);
+
+        assert_eq!(receipt.total_paid_storage_size_diff(), 0u8.into());
+        assert_eq!(receipt.burned_storage(&constants())?, 0u8.into());
+        assert_eq!(receipt.contents[1].allocated_contracts, 0);
+        assert!(receipt.originated_contracts().is_empty());
+
+        // Only the fees are paid
+        let changes = receipt.balance_changes()?;
+        assert_eq!(changes.len(), 1);
+        assert_eq!(
+            changes["tz1YRG68NdqtAcsFEwTUw6FsSsiBb5kagEDo"],
+            BigInt::from(-3000)
+        );
+        Ok(())
+    }
+}
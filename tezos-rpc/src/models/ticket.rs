@@ -0,0 +1,46 @@
+use {
+    serde::{Deserialize, Serialize},
+    tezos_core::types::encoded::Address,
+    tezos_michelson::micheline::Micheline,
+};
+
+/// Identifies a kind of ticket: the contract which created it and its typed content.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct TicketToken {
+    pub ticketer: Address,
+    pub content_type: Micheline,
+    pub content: Micheline,
+}
+
+/// The balance of a kind of ticket owned by a contract.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct TicketBalance {
+    pub ticketer: Address,
+    pub content_type: Micheline,
+    pub content: Micheline,
+    pub amount: String,
+}
+
+impl From<TicketBalance> for TicketToken {
+    fn from(value: TicketBalance) -> Self {
+        Self {
+            ticketer: value.ticketer,
+            content_type: value.content_type,
+            content: value.content,
+        }
+    }
+}
+
+/// The changes to the balances of a kind of ticket made by an operation.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct TicketUpdates {
+    pub ticket_token: TicketToken,
+    pub updates: Vec<TicketUpdate>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct TicketUpdate {
+    pub account: Address,
+    /// The signed change of the balance of `account`.
+    pub amount: String,
+}
@@ -253,6 +253,66 @@ mod tests {
             .await?;
 
         assert_eq!(block.operations[3].last().unwrap().contents.len(), 6);
+        assert!(matches!(
+            block.operations[3].last().unwrap().contents.last(),
+            Some(crate::models::operation::OperationContent::TxRollupDispatchTickets(_))
+        ));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_block_with_unknown_kinds() -> Result<(), Error> {
+        use crate::models::{
+            balance_update::BalanceUpdate,
+            operation::{
+                operation_result::{
+                    lazy_storage_diff::LazyStorageDiff, operations::InternalOperationResult,
+                },
+                OperationContent,
+            },
+        };
+
+        let server = MockServer::start();
+        let rpc_url = server.base_url();
+
+        let block_id = BlockId::Level(2504461);
+
+        server.mock(|when, then| {
+            when.method(GET)
+                .path(super::path(TezosRpcChainId::Main.value(), &block_id));
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(include_str!("block/__TEST_DATA__/block_unknown_kinds.json"));
+        });
+        let client = TezosRpc::new(rpc_url);
+
+        let block = client.get_block().block_id(&block_id).send().await?;
+
+        let operations = &block.operations[3];
+        let metadata = match &operations[0].contents[0] {
+            OperationContent::Transaction(transaction) => transaction
+                .metadata
+                .as_ref()
+                .expect("Transaction has metadata"),
+            content => panic!("Expected a transaction, got {:?}", content),
+        };
+        assert!(matches!(
+            metadata.balance_updates.last(),
+            Some(BalanceUpdate::Unknown(value)) if value["kind"] == "staking"
+        ));
+        assert!(matches!(
+            metadata.operation_result.lazy_storage_diff.as_ref().and_then(|diffs| diffs.last()),
+            Some(LazyStorageDiff::Unknown(value)) if value["kind"] == "ticket_table"
+        ));
+        assert!(matches!(
+            metadata.internal_operation_results.last(),
+            Some(InternalOperationResult::Unknown(value)) if value["kind"] == "event"
+        ));
+        assert!(matches!(
+            &operations[1].contents[0],
+            OperationContent::Unknown(value) if value["kind"] == "dal_publish_slot_header"
+        ));
 
         Ok(())
     }
@@ -2,12 +2,14 @@ use tezos_core::types::encoded::{Address, Encoded};
 
 use crate::{client::TezosRpcChainId, http::Http};
 
+pub mod all_ticket_balances;
 pub mod balance;
 pub mod counter;
 pub mod delegate;
 pub mod entrypoints;
 pub mod manager_key;
 pub mod script;
+pub mod ticket_balance;
 
 use {
     crate::client::TezosRpcContext, crate::error::Error, crate::models::contract::ContractInfo,
@@ -0,0 +1,122 @@
+use tezos_core::types::encoded::{Address, Encoded};
+
+use crate::{client::TezosRpcChainId, http::Http};
+
+use {
+    crate::client::TezosRpcContext, crate::error::Error, crate::models::ticket::TicketBalance,
+    crate::protocol_rpc::block::BlockId,
+};
+
+fn path<S: AsRef<str>>(chain_id: S, block_id: &BlockId, contract: S) -> String {
+    format!(
+        "{}/all_ticket_balances",
+        super::path(chain_id, block_id, contract)
+    )
+}
+
+/// A builder to construct the properties of a request to access the balances of all the tickets owned by a contract.
+#[derive(Clone, Copy)]
+pub struct RpcRequestBuilder<'a, HttpClient: Http> {
+    ctx: &'a TezosRpcContext<HttpClient>,
+    chain_id: &'a TezosRpcChainId,
+    block_id: &'a BlockId,
+    contract: &'a Address,
+}
+
+impl<'a, HttpClient: Http> RpcRequestBuilder<'a, HttpClient> {
+    pub fn new(ctx: &'a TezosRpcContext<HttpClient>, contract: &'a Address) -> Self {
+        RpcRequestBuilder {
+            ctx,
+            chain_id: ctx.chain_id(),
+            block_id: &BlockId::Head,
+            contract,
+        }
+    }
+
+    /// Modify chain identifier to be used in the request.
+    pub fn chain_id(mut self, chain_id: &'a TezosRpcChainId) -> Self {
+        self.chain_id = chain_id;
+
+        self
+    }
+
+    /// Modify the block identifier to be used in the request.
+    pub fn block_id(mut self, block_id: &'a BlockId) -> Self {
+        self.block_id = block_id;
+
+        self
+    }
+
+    pub async fn send(&self) -> Result<Vec<TicketBalance>, Error> {
+        let path = self::path(self.chain_id.value(), self.block_id, self.contract.value());
+
+        self.ctx.http_client().get(path.as_str()).await
+    }
+}
+
+/// Access the balances of all the tickets owned by an originated contract.
+///
+/// [`GET /chains/<chain_id>/blocks/<block>/context/contracts/<contract_id>/all_ticket_balances`](https://tezos.gitlab.io/active/rpc.html#get-block-id-context-contracts-contract-id-all-ticket-balances)
+pub fn get<'a, HttpClient: Http>(
+    ctx: &'a TezosRpcContext<HttpClient>,
+    address: &'a Address,
+) -> RpcRequestBuilder<'a, HttpClient> {
+    RpcRequestBuilder::new(ctx, address)
+}
+
+#[cfg(all(test, feature = "http"))]
+mod tests {
+    use tezos_core::types::encoded::{Address, Encoded};
+    use tezos_michelson::micheline::{int, primitive_application, Micheline};
+
+    use crate::client::TezosRpcChainId;
+
+    use {
+        crate::client::TezosRpc, crate::error::Error, crate::models::ticket::TicketBalance,
+        crate::protocol_rpc::block::BlockId, httpmock::prelude::*,
+    };
+
+    #[tokio::test]
+    async fn test_get_all_ticket_balances() -> Result<(), Error> {
+        let server = MockServer::start();
+        let rpc_url = server.base_url();
+
+        let contract_address: Address = "KT1HNqxFJxnmUcX8wF915wxxaAAU4ixDwWQ7".try_into().unwrap();
+        let block_id = BlockId::Head;
+
+        server.mock(|when, then| {
+            when.method(GET).path(super::path(
+                TezosRpcChainId::Main.value(),
+                &block_id,
+                contract_address.value(),
+            ));
+            then.status(200)
+                .header("content-type", "application/json")
+                .json_body(serde_json::json!([{
+                    "ticketer": "KT1HNqxFJxnmUcX8wF915wxxaAAU4ixDwWQ7",
+                    "content_type": { "prim": "nat" },
+                    "content": { "int": "1" },
+                    "amount": "10"
+                }]));
+        });
+
+        let client = TezosRpc::new(rpc_url);
+        let balances = client
+            .get_contract_all_ticket_balances(&contract_address)
+            .block_id(&block_id)
+            .send()
+            .await?;
+
+        assert_eq!(
+            balances,
+            vec![TicketBalance {
+                ticketer: contract_address,
+                content_type: primitive_application("nat").into(),
+                content: int::<_, Micheline>(1),
+                amount: "10".into(),
+            }]
+        );
+
+        Ok(())
+    }
+}
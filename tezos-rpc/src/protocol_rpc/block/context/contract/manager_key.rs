@@ -60,7 +60,8 @@ pub fn get<'a, HttpClient: Http>(
 
 #[cfg(all(test, feature = "http"))]
 mod tests {
-    use tezos_core::types::encoded::{Address, Encoded};
+    use tezos_core::types::encoded::{Address, Encoded, ImplicitAddress, SecretKey};
+    use tezos_operation::message::Message;
 
     use crate::client::TezosRpcChainId;
 
@@ -131,4 +132,42 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_verify_message() -> Result<(), Error> {
+        let server = MockServer::start();
+        let rpc_url = server.base_url();
+
+        let address: ImplicitAddress = "tz1RFxSHjxxyaaXyAvJG3uRQeJUCCm4jk2LX".try_into().unwrap();
+        let contract_address: Address = address.clone().into();
+        let secret_key: SecretKey = "edskRv7VyXGVZb8EsrR7D9XKUbbAQNQGtALP6QeB16ZCD7SmmJpzyeneJVg3Mq56YLbxRA1kSdAXiswwPiaVfR3NHGMCXCziuZ".try_into().unwrap();
+
+        server.mock(|when, then| {
+            when.method(GET).path(super::path(
+                TezosRpcChainId::Main.value(),
+                &BlockId::Head,
+                contract_address.value(),
+            ));
+            then.status(200)
+                .header("content-type", "application/json")
+                .json_body("edpkttZKC51wemRqL2QxwpMnEKxWnbd35pq47Y6xsCHp5M1f7LN8NP");
+        });
+
+        let client = TezosRpc::new(rpc_url);
+        let message = Message::micheline("Hello");
+        let signature = message.sign(&secret_key)?;
+
+        assert!(
+            client
+                .verify_message(&message, &signature, &address)
+                .await?
+        );
+        assert!(
+            !client
+                .verify_message(&Message::micheline("Bye"), &signature, &address)
+                .await?
+        );
+
+        Ok(())
+    }
 }
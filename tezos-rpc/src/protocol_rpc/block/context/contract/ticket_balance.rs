@@ -0,0 +1,133 @@
+use tezos_core::types::encoded::{Address, Encoded};
+
+use crate::{client::TezosRpcChainId, http::Http};
+
+use {
+    crate::client::TezosRpcContext, crate::error::Error, crate::models::ticket::TicketToken,
+    crate::protocol_rpc::block::BlockId, num_bigint::BigUint,
+};
+
+fn path<S: AsRef<str>>(chain_id: S, block_id: &BlockId, contract: S) -> String {
+    format!(
+        "{}/ticket_balance",
+        super::path(chain_id, block_id, contract)
+    )
+}
+
+/// A builder to construct the properties of a request to access the balance of a ticket owned by a contract.
+#[derive(Clone, Copy)]
+pub struct RpcRequestBuilder<'a, HttpClient: Http> {
+    ctx: &'a TezosRpcContext<HttpClient>,
+    chain_id: &'a TezosRpcChainId,
+    block_id: &'a BlockId,
+    contract: &'a Address,
+    ticket: &'a TicketToken,
+}
+
+impl<'a, HttpClient: Http> RpcRequestBuilder<'a, HttpClient> {
+    pub fn new(
+        ctx: &'a TezosRpcContext<HttpClient>,
+        contract: &'a Address,
+        ticket: &'a TicketToken,
+    ) -> Self {
+        RpcRequestBuilder {
+            ctx,
+            chain_id: ctx.chain_id(),
+            block_id: &BlockId::Head,
+            contract,
+            ticket,
+        }
+    }
+
+    /// Modify chain identifier to be used in the request.
+    pub fn chain_id(mut self, chain_id: &'a TezosRpcChainId) -> Self {
+        self.chain_id = chain_id;
+
+        self
+    }
+
+    /// Modify the block identifier to be used in the request.
+    pub fn block_id(mut self, block_id: &'a BlockId) -> Self {
+        self.block_id = block_id;
+
+        self
+    }
+
+    pub async fn send(&self) -> Result<BigUint, Error> {
+        let path = self::path(self.chain_id.value(), self.block_id, self.contract.value());
+
+        let balance: String = self
+            .ctx
+            .http_client()
+            .post::<_, _, ()>(path.as_str(), self.ticket, None)
+            .await?;
+
+        Ok(balance.parse::<BigUint>()?)
+    }
+}
+
+/// Access the balance of a ticket owned by a contract.
+///
+/// [`POST /chains/<chain_id>/blocks/<block>/context/contracts/<contract_id>/ticket_balance`](https://tezos.gitlab.io/active/rpc.html#post-block-id-context-contracts-contract-id-ticket-balance)
+pub fn post<'a, HttpClient: Http>(
+    ctx: &'a TezosRpcContext<HttpClient>,
+    address: &'a Address,
+    ticket: &'a TicketToken,
+) -> RpcRequestBuilder<'a, HttpClient> {
+    RpcRequestBuilder::new(ctx, address, ticket)
+}
+
+#[cfg(all(test, feature = "http"))]
+mod tests {
+    use tezos_core::types::encoded::{Address, Encoded};
+    use tezos_michelson::micheline::{primitive_application, try_string, Micheline};
+
+    use crate::client::TezosRpcChainId;
+
+    use {
+        crate::client::TezosRpc, crate::error::Error, crate::models::ticket::TicketToken,
+        crate::protocol_rpc::block::BlockId, httpmock::prelude::*, num_bigint::BigUint,
+    };
+
+    #[tokio::test]
+    async fn test_get_ticket_balance() -> Result<(), Error> {
+        let server = MockServer::start();
+        let rpc_url = server.base_url();
+
+        let contract_address: Address = "tz1YY1LvD6TFH4z74pvxPQXBjAKHE5tB5Q8f".try_into().unwrap();
+        let ticket = TicketToken {
+            ticketer: "KT1HNqxFJxnmUcX8wF915wxxaAAU4ixDwWQ7".try_into().unwrap(),
+            content_type: primitive_application("string").into(),
+            content: try_string::<_, Micheline, _>("ticket").unwrap(),
+        };
+        let block_id = BlockId::Head;
+
+        server.mock(|when, then| {
+            when.method(POST)
+                .path(super::path(
+                    TezosRpcChainId::Main.value(),
+                    &block_id,
+                    contract_address.value(),
+                ))
+                .json_body(serde_json::json!({
+                    "ticketer": "KT1HNqxFJxnmUcX8wF915wxxaAAU4ixDwWQ7",
+                    "content_type": { "prim": "string" },
+                    "content": { "string": "ticket" }
+                }));
+            then.status(200)
+                .header("content-type", "application/json")
+                .json_body("42");
+        });
+
+        let client = TezosRpc::new(rpc_url);
+        let balance = client
+            .get_contract_ticket_balance(&contract_address, &ticket)
+            .block_id(&block_id)
+            .send()
+            .await?;
+
+        assert_eq!(balance, BigUint::from(42u8));
+
+        Ok(())
+    }
+}
@@ -1,4 +1,6 @@
+pub mod run_code;
 pub mod run_operation;
+pub mod run_script_view;
 
 use super::BlockId;
 
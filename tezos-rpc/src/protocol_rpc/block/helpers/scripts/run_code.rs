@@ -0,0 +1,241 @@
+use tezos_core::types::{
+    encoded::{Address, ContractHash, Encoded, ImplicitAddress},
+    mutez::Mutez,
+};
+use tezos_michelson::micheline::{sequence::Sequence, Micheline};
+
+use crate::{client::TezosRpcChainId, http::Http};
+
+use {
+    crate::{
+        client::TezosRpcContext,
+        error::Error,
+        models::contract::{RunCodeResult, UnparsingMode},
+        protocol_rpc::block::BlockId,
+    },
+    serde::Serialize,
+};
+
+fn path<S: AsRef<str>>(chain_id: S, block_id: &BlockId) -> String {
+    format!("{}/run_code", super::path(chain_id, block_id))
+}
+
+#[derive(Serialize)]
+struct RunCodeParam<'a> {
+    script: &'a Sequence,
+    storage: &'a Micheline,
+    input: &'a Micheline,
+    amount: &'a Mutez,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    balance: Option<&'a Mutez>,
+    chain_id: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    source: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    payer: Option<&'a str>,
+    #[serde(rename = "self", skip_serializing_if = "Option::is_none")]
+    self_address: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    entrypoint: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    unparsing_mode: Option<UnparsingMode>,
+}
+
+/// A builder to construct the properties of a request to run a script.
+#[derive(Clone, Copy)]
+pub struct RpcRequestBuilder<'a, HttpClient: Http> {
+    ctx: &'a TezosRpcContext<HttpClient>,
+    chain_id: &'a TezosRpcChainId,
+    block_id: &'a BlockId,
+    script: &'a Sequence,
+    storage: &'a Micheline,
+    input: &'a Micheline,
+    amount: Option<&'a Mutez>,
+    balance: Option<&'a Mutez>,
+    source: Option<&'a Address>,
+    payer: Option<&'a ImplicitAddress>,
+    self_address: Option<&'a ContractHash>,
+    entrypoint: Option<&'a str>,
+    unparsing_mode: Option<UnparsingMode>,
+}
+
+impl<'a, HttpClient: Http> RpcRequestBuilder<'a, HttpClient> {
+    pub fn new(
+        ctx: &'a TezosRpcContext<HttpClient>,
+        script: &'a Sequence,
+        storage: &'a Micheline,
+        input: &'a Micheline,
+    ) -> Self {
+        RpcRequestBuilder {
+            ctx,
+            chain_id: ctx.chain_id(),
+            block_id: &BlockId::Head,
+            script,
+            storage,
+            input,
+            amount: None,
+            balance: None,
+            source: None,
+            payer: None,
+            self_address: None,
+            entrypoint: None,
+            unparsing_mode: None,
+        }
+    }
+
+    /// Modify chain identifier to be used in the request.
+    pub fn chain_id(mut self, chain_id: &'a TezosRpcChainId) -> Self {
+        self.chain_id = chain_id;
+
+        self
+    }
+
+    /// Modify the block identifier to be used in the request.
+    pub fn block_id(mut self, block_id: &'a BlockId) -> Self {
+        self.block_id = block_id;
+
+        self
+    }
+
+    /// The amount transferred to the script, `0` by default.
+    pub fn amount(mut self, amount: &'a Mutez) -> Self {
+        self.amount = Some(amount);
+
+        self
+    }
+
+    /// The balance of the script.
+    pub fn balance(mut self, balance: &'a Mutez) -> Self {
+        self.balance = Some(balance);
+
+        self
+    }
+
+    /// The address returned by the `SENDER` instruction.
+    pub fn source(mut self, source: &'a Address) -> Self {
+        self.source = Some(source);
+
+        self
+    }
+
+    /// The address returned by the `SOURCE` instruction.
+    pub fn payer(mut self, payer: &'a ImplicitAddress) -> Self {
+        self.payer = Some(payer);
+
+        self
+    }
+
+    /// The address returned by the `SELF_ADDRESS` instruction.
+    pub fn self_address(mut self, self_address: &'a ContractHash) -> Self {
+        self.self_address = Some(self_address);
+
+        self
+    }
+
+    /// The entrypoint the script is called with.
+    pub fn entrypoint(mut self, entrypoint: &'a str) -> Self {
+        self.entrypoint = Some(entrypoint);
+
+        self
+    }
+
+    /// Normalize the resulting storage using an unparsing mode.
+    pub fn unparsing_mode(mut self, unparsing_mode: UnparsingMode) -> Self {
+        self.unparsing_mode = Some(unparsing_mode);
+
+        self
+    }
+
+    pub async fn send(&self) -> Result<RunCodeResult, Error> {
+        let path = self::path(self.chain_id.value(), self.block_id);
+        let zero: Mutez = 0u8.into();
+
+        let param = RunCodeParam {
+            script: self.script,
+            storage: self.storage,
+            input: self.input,
+            amount: self.amount.unwrap_or(&zero),
+            balance: self.balance,
+            chain_id: self.chain_id.chain_id_value(),
+            source: self.source.map(|source| source.value()),
+            payer: self.payer.map(|payer| payer.value()),
+            self_address: self.self_address.map(|self_address| self_address.value()),
+            entrypoint: self.entrypoint,
+            unparsing_mode: self.unparsing_mode,
+        };
+
+        self.ctx
+            .http_client()
+            .post::<_, _, ()>(path.as_str(), &param, None)
+            .await
+    }
+}
+
+/// Run a Michelson script in the current context.
+///
+/// [`POST /chains/<chain_id>/blocks/<block_id>/helpers/scripts/run_code`](https://tezos.gitlab.io/active/rpc.html#post-block-id-helpers-scripts-run-code)
+pub fn post<'a, HttpClient: Http>(
+    ctx: &'a TezosRpcContext<HttpClient>,
+    script: &'a Sequence,
+    storage: &'a Micheline,
+    input: &'a Micheline,
+) -> RpcRequestBuilder<'a, HttpClient> {
+    RpcRequestBuilder::new(ctx, script, storage, input)
+}
+
+#[cfg(all(test, feature = "http"))]
+mod tests {
+    use {
+        super::*,
+        crate::{client::TezosRpc, error::Error, protocol_rpc::block::BlockId},
+        httpmock::prelude::*,
+        tezos_michelson::micheline::int,
+    };
+
+    #[tokio::test]
+    async fn test_run_code() -> Result<(), Error> {
+        let server = MockServer::start();
+        let rpc_url = server.base_url();
+
+        let block_id = BlockId::Level(1);
+        let script: Sequence = serde_json::from_str(
+            r#"[
+                { "prim": "parameter", "args": [{ "prim": "nat" }] },
+                { "prim": "storage", "args": [{ "prim": "nat" }] },
+                { "prim": "code", "args": [[{ "prim": "UNPAIR" }, { "prim": "ADD" }, { "prim": "NIL", "args": [{ "prim": "operation" }] }, { "prim": "PAIR" }]] }
+            ]"#,
+        )?;
+        let storage = int(1);
+        let input = int(2);
+        let self_address: ContractHash = "KT1HNqxFJxnmUcX8wF915wxxaAAU4ixDwWQ7".try_into().unwrap();
+
+        server.mock(|when, then| {
+            when.method(POST)
+                .path(super::path(TezosRpcChainId::Main.value(), &block_id))
+                .json_body(serde_json::json!({
+                    "script": script,
+                    "storage": { "int": "1" },
+                    "input": { "int": "2" },
+                    "amount": "0",
+                    "chain_id": TezosRpcChainId::Main.chain_id_value(),
+                    "self": "KT1HNqxFJxnmUcX8wF915wxxaAAU4ixDwWQ7",
+                }));
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(r#"{ "storage": { "int": "3" }, "operations": [] }"#);
+        });
+        let client = TezosRpc::new(rpc_url);
+
+        let result = client
+            .run_code(&script, &storage, &input)
+            .block_id(&block_id)
+            .self_address(&self_address)
+            .send()
+            .await?;
+
+        assert_eq!(result.storage, int(3));
+        assert!(result.operations.is_empty());
+
+        Ok(())
+    }
+}
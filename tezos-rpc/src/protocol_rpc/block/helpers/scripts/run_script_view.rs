@@ -0,0 +1,197 @@
+use tezos_core::types::encoded::{Address, ContractHash, Encoded, ImplicitAddress};
+use tezos_michelson::micheline::Micheline;
+
+use crate::{client::TezosRpcChainId, http::Http};
+
+use {
+    crate::{
+        client::TezosRpcContext,
+        error::Error,
+        models::contract::{RunScriptViewResult, UnparsingMode},
+        protocol_rpc::block::BlockId,
+    },
+    serde::Serialize,
+};
+
+fn path<S: AsRef<str>>(chain_id: S, block_id: &BlockId) -> String {
+    format!("{}/run_script_view", super::path(chain_id, block_id))
+}
+
+#[derive(Serialize)]
+struct RunScriptViewParam<'a> {
+    contract: &'a str,
+    view: &'a str,
+    input: &'a Micheline,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    unlimited_gas: Option<bool>,
+    chain_id: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    source: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    payer: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    unparsing_mode: Option<UnparsingMode>,
+}
+
+/// A builder to construct the properties of a request to simulate a call to a Michelson view.
+#[derive(Clone, Copy)]
+pub struct RpcRequestBuilder<'a, HttpClient: Http> {
+    ctx: &'a TezosRpcContext<HttpClient>,
+    chain_id: &'a TezosRpcChainId,
+    block_id: &'a BlockId,
+    contract: &'a ContractHash,
+    view: &'a str,
+    input: &'a Micheline,
+    unlimited_gas: Option<bool>,
+    source: Option<&'a Address>,
+    payer: Option<&'a ImplicitAddress>,
+    unparsing_mode: Option<UnparsingMode>,
+}
+
+impl<'a, HttpClient: Http> RpcRequestBuilder<'a, HttpClient> {
+    pub fn new(
+        ctx: &'a TezosRpcContext<HttpClient>,
+        contract: &'a ContractHash,
+        view: &'a str,
+        input: &'a Micheline,
+    ) -> Self {
+        RpcRequestBuilder {
+            ctx,
+            chain_id: ctx.chain_id(),
+            block_id: &BlockId::Head,
+            contract,
+            view,
+            input,
+            unlimited_gas: None,
+            source: None,
+            payer: None,
+            unparsing_mode: None,
+        }
+    }
+
+    /// Modify chain identifier to be used in the request.
+    pub fn chain_id(mut self, chain_id: &'a TezosRpcChainId) -> Self {
+        self.chain_id = chain_id;
+
+        self
+    }
+
+    /// Modify the block identifier to be used in the request.
+    pub fn block_id(mut self, block_id: &'a BlockId) -> Self {
+        self.block_id = block_id;
+
+        self
+    }
+
+    /// Whether the view is run without gas limit.
+    pub fn unlimited_gas(mut self, unlimited_gas: bool) -> Self {
+        self.unlimited_gas = Some(unlimited_gas);
+
+        self
+    }
+
+    /// The address returned by the `SENDER` instruction.
+    pub fn source(mut self, source: &'a Address) -> Self {
+        self.source = Some(source);
+
+        self
+    }
+
+    /// The address returned by the `SOURCE` instruction.
+    pub fn payer(mut self, payer: &'a ImplicitAddress) -> Self {
+        self.payer = Some(payer);
+
+        self
+    }
+
+    /// Normalize the returned value using an unparsing mode.
+    pub fn unparsing_mode(mut self, unparsing_mode: UnparsingMode) -> Self {
+        self.unparsing_mode = Some(unparsing_mode);
+
+        self
+    }
+
+    pub async fn send(&self) -> Result<RunScriptViewResult, Error> {
+        let path = self::path(self.chain_id.value(), self.block_id);
+
+        let param = RunScriptViewParam {
+            contract: self.contract.value(),
+            view: self.view,
+            input: self.input,
+            unlimited_gas: self.unlimited_gas,
+            chain_id: self.chain_id.chain_id_value(),
+            source: self.source.map(|source| source.value()),
+            payer: self.payer.map(|payer| payer.value()),
+            unparsing_mode: self.unparsing_mode,
+        };
+
+        self.ctx
+            .http_client()
+            .post::<_, _, ()>(path.as_str(), &param, None)
+            .await
+    }
+}
+
+/// Simulate a call to a Michelson view.
+///
+/// [`POST /chains/<chain_id>/blocks/<block_id>/helpers/scripts/run_script_view`](https://tezos.gitlab.io/active/rpc.html#post-block-id-helpers-scripts-run-script-view)
+pub fn post<'a, HttpClient: Http>(
+    ctx: &'a TezosRpcContext<HttpClient>,
+    contract: &'a ContractHash,
+    view: &'a str,
+    input: &'a Micheline,
+) -> RpcRequestBuilder<'a, HttpClient> {
+    RpcRequestBuilder::new(ctx, contract, view, input)
+}
+
+#[cfg(all(test, feature = "http"))]
+mod tests {
+    use {
+        super::*,
+        crate::{client::TezosRpc, error::Error, protocol_rpc::block::BlockId},
+        httpmock::prelude::*,
+        tezos_michelson::micheline::int,
+    };
+
+    #[tokio::test]
+    async fn test_run_script_view() -> Result<(), Error> {
+        let server = MockServer::start();
+        let rpc_url = server.base_url();
+
+        let block_id = BlockId::Level(1);
+        let contract: ContractHash = "KT1HNqxFJxnmUcX8wF915wxxaAAU4ixDwWQ7".try_into().unwrap();
+        let source: Address = "tz1YY1LvD6TFH4z74pvxPQXBjAKHE5tB5Q8f".try_into().unwrap();
+        let input = int(2);
+
+        server.mock(|when, then| {
+            when.method(POST)
+                .path(super::path(TezosRpcChainId::Main.value(), &block_id))
+                .json_body(serde_json::json!({
+                    "contract": "KT1HNqxFJxnmUcX8wF915wxxaAAU4ixDwWQ7",
+                    "view": "multiply",
+                    "input": { "int": "2" },
+                    "unlimited_gas": true,
+                    "chain_id": TezosRpcChainId::Main.chain_id_value(),
+                    "source": "tz1YY1LvD6TFH4z74pvxPQXBjAKHE5tB5Q8f",
+                    "unparsing_mode": "Readable",
+                }));
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(r#"{ "data": { "int": "84" } }"#);
+        });
+        let client = TezosRpc::new(rpc_url);
+
+        let result = client
+            .run_script_view(&contract, "multiply", &input)
+            .block_id(&block_id)
+            .unlimited_gas(true)
+            .source(&source)
+            .unparsing_mode(UnparsingMode::Readable)
+            .send()
+            .await?;
+
+        assert_eq!(result.data, int(84));
+
+        Ok(())
+    }
+}